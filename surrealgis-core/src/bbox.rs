@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 
 use crate::coordinate::Coordinate;
 use crate::error::GeometryError;
+use crate::geometry::SurrealGeometry;
+use crate::srid::Srid;
 
 /// Axis-aligned bounding box value object.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -102,6 +104,37 @@ impl BoundingBox {
         }
     }
 
+    /// The overlapping rectangle of two boxes, or None if they are disjoint.
+    /// Boxes that only touch yield a zero-area intersection.
+    pub fn intersection(&self, other: &BoundingBox) -> Option<BoundingBox> {
+        if !self.intersects(other) {
+            return None;
+        }
+        Some(BoundingBox {
+            min_x: self.min_x.max(other.min_x),
+            min_y: self.min_y.max(other.min_y),
+            max_x: self.max_x.min(other.max_x),
+            max_y: self.max_y.min(other.max_y),
+        })
+    }
+
+    /// Area of the overlapping rectangle (0.0 if disjoint).
+    pub fn intersection_area(&self, other: &BoundingBox) -> f64 {
+        self.intersection(other).map_or(0.0, |b| b.area())
+    }
+
+    /// Intersection-over-union of two boxes, in [0, 1].
+    /// Returns 0.0 when the union has no area.
+    pub fn iou(&self, other: &BoundingBox) -> f64 {
+        let inter = self.intersection_area(other);
+        let union = self.area() + other.area() - inter;
+        if union <= 0.0 {
+            0.0
+        } else {
+            inter / union
+        }
+    }
+
     pub fn width(&self) -> f64 {
         self.max_x - self.min_x
     }
@@ -113,6 +146,67 @@ impl BoundingBox {
     pub fn area(&self) -> f64 {
         self.width() * self.height()
     }
+
+    /// The midpoint of the box.
+    pub fn center(&self) -> Coordinate {
+        Coordinate::from(geo_types::Coord {
+            x: (self.min_x + self.max_x) / 2.0,
+            y: (self.min_y + self.max_y) / 2.0,
+        })
+    }
+
+    /// The four corners, counter-clockwise from (min_x, min_y).
+    pub fn corners(&self) -> [Coordinate; 4] {
+        [
+            Coordinate::from(geo_types::Coord { x: self.min_x, y: self.min_y }),
+            Coordinate::from(geo_types::Coord { x: self.max_x, y: self.min_y }),
+            Coordinate::from(geo_types::Coord { x: self.max_x, y: self.max_y }),
+            Coordinate::from(geo_types::Coord { x: self.min_x, y: self.max_y }),
+        ]
+    }
+
+    /// The box as a closed 5-vertex Polygon, counter-clockwise from (min_x, min_y).
+    /// Fails if any bound is not finite.
+    pub fn to_polygon(&self, srid: Srid) -> Result<SurrealGeometry, GeometryError> {
+        let exterior = vec![
+            Coordinate::new(self.min_x, self.min_y)?,
+            Coordinate::new(self.max_x, self.min_y)?,
+            Coordinate::new(self.max_x, self.max_y)?,
+            Coordinate::new(self.min_x, self.max_y)?,
+            Coordinate::new(self.min_x, self.min_y)?,
+        ];
+        SurrealGeometry::polygon(exterior, vec![], srid)
+    }
+
+    /// Compact `[min_x, min_y, max_x, max_y]` representation.
+    pub fn to_array(&self) -> [f64; 4] {
+        [self.min_x, self.min_y, self.max_x, self.max_y]
+    }
+
+    /// Build a bounding box from `[min_x, min_y, max_x, max_y]`. Validates that min <= max.
+    pub fn from_array(values: [f64; 4]) -> Result<Self, GeometryError> {
+        let [min_x, min_y, max_x, max_y] = values;
+        Self::new(min_x, min_y, max_x, max_y)
+    }
+}
+
+/// Serde adapter storing a `BoundingBox` as a 4-float array.
+///
+/// Use with `#[serde(with = "surrealgis_core::bbox::compact")]` on a field
+/// where the default object form is too verbose (index entries, tile metadata).
+pub mod compact {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::BoundingBox;
+
+    pub fn serialize<S: Serializer>(bbox: &BoundingBox, serializer: S) -> Result<S::Ok, S::Error> {
+        bbox.to_array().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BoundingBox, D::Error> {
+        let values = <[f64; 4]>::deserialize(deserializer)?;
+        BoundingBox::from_array(values).map_err(serde::de::Error::custom)
+    }
 }
 
 #[cfg(test)]
@@ -206,6 +300,37 @@ mod tests {
         assert_eq!(u.max_y, 8.0);
     }
 
+    #[test]
+    fn intersection_of_overlapping_boxes() {
+        let a = BoundingBox::new(0.0, 0.0, 5.0, 5.0).unwrap();
+        let b = BoundingBox::new(3.0, 2.0, 8.0, 8.0).unwrap();
+        let i = a.intersection(&b).unwrap();
+        assert_eq!(i, BoundingBox::new(3.0, 2.0, 5.0, 5.0).unwrap());
+        assert_eq!(a.intersection_area(&b), 6.0);
+    }
+
+    #[test]
+    fn intersection_of_disjoint_boxes_is_none() {
+        let a = BoundingBox::new(0.0, 0.0, 2.0, 2.0).unwrap();
+        let b = BoundingBox::new(5.0, 5.0, 8.0, 8.0).unwrap();
+        assert!(a.intersection(&b).is_none());
+        assert_eq!(a.intersection_area(&b), 0.0);
+        assert_eq!(a.iou(&b), 0.0);
+    }
+
+    #[test]
+    fn iou_half_overlapping_unit_squares() {
+        let a = BoundingBox::new(0.0, 0.0, 1.0, 1.0).unwrap();
+        let b = BoundingBox::new(0.5, 0.0, 1.5, 1.0).unwrap();
+        assert!((a.iou(&b) - 1.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn iou_identical_boxes() {
+        let a = BoundingBox::new(0.0, 0.0, 2.0, 3.0).unwrap();
+        assert_eq!(a.iou(&a), 1.0);
+    }
+
     #[test]
     fn width_height_area() {
         let bb = BoundingBox::new(1.0, 2.0, 5.0, 7.0).unwrap();
@@ -214,6 +339,87 @@ mod tests {
         assert_eq!(bb.area(), 20.0);
     }
 
+    #[test]
+    fn center_of_box() {
+        let bb = BoundingBox::new(1.0, 2.0, 5.0, 8.0).unwrap();
+        let c = bb.center();
+        assert_eq!(c.x(), 3.0);
+        assert_eq!(c.y(), 5.0);
+    }
+
+    #[test]
+    fn corners_counter_clockwise() {
+        let bb = BoundingBox::new(1.0, 2.0, 5.0, 8.0).unwrap();
+        let corners = bb.corners();
+        let xy: Vec<(f64, f64)> = corners.iter().map(|c| (c.x(), c.y())).collect();
+        assert_eq!(xy, vec![(1.0, 2.0), (5.0, 2.0), (5.0, 8.0), (1.0, 8.0)]);
+    }
+
+    #[test]
+    fn to_polygon_is_closed_ring_matching_box() {
+        use crate::geometry::GeometryType;
+
+        let bb = BoundingBox::new(1.0, 2.0, 5.0, 8.0).unwrap();
+        let poly = bb.to_polygon(Srid::WEB_MERCATOR).unwrap();
+        assert_eq!(poly.srid().code(), Srid::WEB_MERCATOR.code());
+        assert_eq!(poly.bbox(), Some(&bb));
+        match poly.geometry_type() {
+            GeometryType::Polygon { exterior, holes } => {
+                assert_eq!(exterior.len(), 5);
+                assert_eq!(exterior.first(), exterior.last());
+                assert!(holes.is_empty());
+            }
+            _ => panic!("Expected Polygon"),
+        }
+    }
+
+    #[test]
+    fn to_polygon_rejects_non_finite() {
+        let bb = BoundingBox {
+            min_x: f64::NAN,
+            min_y: 0.0,
+            max_x: 1.0,
+            max_y: 1.0,
+        };
+        assert!(bb.to_polygon(Srid::WGS84).is_err());
+    }
+
+    #[test]
+    fn array_roundtrip() {
+        let bb = BoundingBox::new(1.0, 2.0, 5.0, 7.0).unwrap();
+        assert_eq!(bb.to_array(), [1.0, 2.0, 5.0, 7.0]);
+        assert_eq!(BoundingBox::from_array(bb.to_array()).unwrap(), bb);
+    }
+
+    #[test]
+    fn from_array_rejects_min_greater_than_max() {
+        assert!(BoundingBox::from_array([5.0, 0.0, 1.0, 1.0]).is_err());
+        assert!(BoundingBox::from_array([0.0, 5.0, 1.0, 1.0]).is_err());
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Tile {
+        #[serde(with = "compact")]
+        bbox: BoundingBox,
+    }
+
+    #[test]
+    fn compact_serde_roundtrip() {
+        let tile = Tile {
+            bbox: BoundingBox::new(1.0, 2.0, 5.0, 7.0).unwrap(),
+        };
+        let json = serde_json::to_string(&tile).unwrap();
+        assert_eq!(json, r#"{"bbox":[1.0,2.0,5.0,7.0]}"#);
+        let back: Tile = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, tile);
+    }
+
+    #[test]
+    fn compact_serde_rejects_invalid_array() {
+        let result: Result<Tile, _> = serde_json::from_str(r#"{"bbox":[5.0,0.0,1.0,1.0]}"#);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn degenerate_point_bbox() {
         let bb = BoundingBox::new(5.0, 5.0, 5.0, 5.0).unwrap();
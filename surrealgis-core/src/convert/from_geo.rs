@@ -7,6 +7,11 @@ use crate::srid::Srid;
 
 impl SurrealGeometry {
     /// Create a SurrealGeometry from a geo_types::Geometry with a specified SRID.
+    ///
+    /// Rings are taken as geo_types structures them: each polygon's interiors
+    /// stay holes of that polygon whatever their winding, so output of
+    /// boolean ops (which may reorient rings) never promotes a hole to an
+    /// exterior. Ring orientation is kept as given.
     pub fn from_geo(
         geom: &geo_types::Geometry<f64>,
         srid: Srid,
@@ -108,6 +113,32 @@ impl SurrealGeometry {
     }
 }
 
+impl SurrealGeometry {
+    /// Create a SurrealGeometry from a geo_types::Geometry, re-attaching Z values.
+    ///
+    /// `z_values` must hold one entry per coordinate, in the order
+    /// `map_coords` visits them. This is how Z survives an operation that has
+    /// to go through the 2D `geo_types` model but keeps the vertex layout.
+    pub fn from_geo_3d(
+        geom: &geo_types::Geometry<f64>,
+        z_values: &[f64],
+        srid: Srid,
+    ) -> Result<Self, GeometryError> {
+        let flat = SurrealGeometry::from_geo(geom, srid)?;
+        if flat.num_points() != z_values.len() {
+            return Err(GeometryError::DimensionMismatch {
+                expected: format!("{} z values", flat.num_points()),
+                got: format!("{} z values", z_values.len()),
+            });
+        }
+        let mut z_iter = z_values.iter();
+        flat.map_coords(|c| {
+            let z = z_iter.next().copied();
+            Coordinate::from_geo_with_z(c.into(), z)
+        })
+    }
+}
+
 impl From<geo_types::Geometry<f64>> for SurrealGeometry {
     fn from(geom: geo_types::Geometry<f64>) -> Self {
         // Use default SRID 4326; panics on invalid coordinates (which shouldn't happen from valid geo_types)
@@ -166,6 +197,26 @@ mod tests {
         assert_eq!(sg.srid().code(), 4326);
     }
 
+    #[test]
+    fn from_geo_3d_attaches_z_in_order() {
+        let ls = LineString(vec![Coord { x: 0.0, y: 0.0 }, Coord { x: 1.0, y: 1.0 }]);
+        let geo = geo_types::Geometry::LineString(ls);
+        let sg = SurrealGeometry::from_geo_3d(&geo, &[5.0, 6.0], Srid::WGS84).unwrap();
+        if let GeometryType::LineString(cs) = sg.geometry_type() {
+            assert_eq!(cs[0].z(), Some(5.0));
+            assert_eq!(cs[1].z(), Some(6.0));
+        } else {
+            panic!("Expected LineString");
+        }
+    }
+
+    #[test]
+    fn from_geo_3d_rejects_wrong_z_count() {
+        let geo = geo_types::Geometry::Point(Point::new(1.0, 2.0));
+        let result = SurrealGeometry::from_geo_3d(&geo, &[1.0, 2.0], Srid::WGS84);
+        assert!(matches!(result, Err(GeometryError::DimensionMismatch { .. })));
+    }
+
     #[test]
     fn roundtrip_point() {
         let original = SurrealGeometry::point(3.0, 4.0, Srid::WGS84).unwrap();
@@ -201,4 +252,48 @@ mod tests {
         let roundtripped = SurrealGeometry::from_geo(&geo, Srid::WGS84).unwrap();
         assert_eq!(original.num_points(), roundtripped.num_points());
     }
+
+    fn square(x0: f64, y0: f64, size: f64) -> Vec<Coordinate> {
+        [(x0, y0), (x0 + size, y0), (x0 + size, y0 + size), (x0, y0 + size), (x0, y0)]
+            .iter()
+            .map(|&(x, y)| Coordinate::new(x, y).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn roundtrip_polygon_with_hole_keeps_hole() {
+        use geo::Area;
+        let holes = vec![square(2.0, 2.0, 6.0)];
+        let original = SurrealGeometry::polygon(square(0.0, 0.0, 10.0), holes, Srid::WGS84).unwrap();
+        let geo = original.to_geo().unwrap();
+        let roundtripped = SurrealGeometry::from_geo(&geo, Srid::WGS84).unwrap();
+        match roundtripped.geometry_type() {
+            GeometryType::Polygon { holes, .. } => assert_eq!(holes.len(), 1),
+            _ => panic!("Expected Polygon, hole must not become a separate part"),
+        }
+        assert_eq!(roundtripped, original);
+        assert!((roundtripped.to_geo().unwrap().unsigned_area() - 64.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn boolean_op_hole_stays_with_its_polygon() {
+        use geo::{Area, BooleanOps};
+        let outer = SurrealGeometry::polygon(square(0.0, 0.0, 10.0), vec![], Srid::WGS84).unwrap();
+        let inner = SurrealGeometry::polygon(square(2.0, 2.0, 6.0), vec![], Srid::WGS84).unwrap();
+        let (geo_types::Geometry::Polygon(a), geo_types::Geometry::Polygon(b)) =
+            (outer.to_geo().unwrap(), inner.to_geo().unwrap())
+        else {
+            panic!("Expected Polygons");
+        };
+        let diff = geo_types::Geometry::MultiPolygon(a.difference(&b));
+        let result = SurrealGeometry::from_geo(&diff, Srid::WGS84).unwrap();
+        match result.geometry_type() {
+            GeometryType::MultiPolygon(polys) => {
+                assert_eq!(polys.len(), 1);
+                assert_eq!(polys[0].holes.len(), 1);
+            }
+            _ => panic!("Expected MultiPolygon"),
+        }
+        assert!((result.to_geo().unwrap().unsigned_area() - 64.0).abs() < 1e-9);
+    }
 }
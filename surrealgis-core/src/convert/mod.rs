@@ -1,2 +1,14 @@
+//! Conversions between `SurrealGeometry` and `geo_types`.
+//!
+//! `geo_types` is strictly 2D, so any operation that round-trips through
+//! `to_geo`/`from_geo` loses Z and M. Operations fall in two groups:
+//!
+//! - **Z-preserving**: work on the domain coordinates via
+//!   `SurrealGeometry::map_coords` (translate, reverse, snap-to-grid) or
+//!   keep the vertex layout and re-attach Z with `SurrealGeometry::from_geo_3d`.
+//! - **Inherently 2D**: predicates (relate and friends), boolean overlay,
+//!   buffering, hulls and simplification create or drop vertices, so there
+//!   is no Z to carry over and the result is always XY.
+
 pub mod from_geo;
 pub mod to_geo;
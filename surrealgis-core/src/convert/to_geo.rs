@@ -1,10 +1,51 @@
+use std::fmt;
+use std::sync::OnceLock;
+
 use geo_types;
 
 use crate::coordinate::Coordinate;
 use crate::error::GeometryError;
 use crate::geometry::{GeometryType, SurrealGeometry};
 
+/// Lazily filled `geo_types` conversion of a geometry.
+///
+/// Not part of the geometry's value: it is ignored by `PartialEq`, and
+/// dropped by every method that can change coordinates.
+#[derive(Default, Clone)]
+pub(crate) struct GeoCache(OnceLock<geo_types::Geometry<f64>>);
+
+impl PartialEq for GeoCache {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl fmt::Debug for GeoCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(if self.0.get().is_some() { "GeoCache(filled)" } else { "GeoCache(empty)" })
+    }
+}
+
 impl SurrealGeometry {
+    /// Convert to geo_types once and reuse the result on later calls.
+    ///
+    /// Suited to geometries tested repeatedly, e.g. one side of a join.
+    /// The cache is dropped by `compute_bbox` and `set_geometry_type`; use
+    /// `invalidate_cache` after any other edit.
+    pub fn to_geo_cached(&self) -> Result<&geo_types::Geometry<f64>, GeometryError> {
+        if let Some(geo) = self.geo_cache.0.get() {
+            return Ok(geo);
+        }
+        let geo = self.to_geo()?;
+        Ok(self.geo_cache.0.get_or_init(|| geo))
+    }
+
+    /// Drop the cached `geo_types` conversion so the next `to_geo_cached`
+    /// recomputes it from the current coordinates.
+    pub fn invalidate_cache(&mut self) {
+        self.geo_cache.0.take();
+    }
+
     /// Convert this geometry to a geo_types::Geometry.
     pub fn to_geo(&self) -> Result<geo_types::Geometry<f64>, GeometryError> {
         match self.geometry_type() {
@@ -141,4 +182,32 @@ mod tests {
         let geo: geo_types::Geometry<f64> = (&p).try_into().unwrap();
         assert!(matches!(geo, geo_types::Geometry::Point(_)));
     }
+
+    #[test]
+    fn cached_conversion_follows_mutation() {
+        let mut p = SurrealGeometry::point(1.0, 2.0, Srid::WGS84).unwrap();
+        assert_eq!(p.to_geo_cached().unwrap(), &p.to_geo().unwrap());
+
+        p.set_geometry_type(GeometryType::Point(Coordinate::new(5.0, 6.0).unwrap()));
+        match p.to_geo_cached().unwrap() {
+            geo_types::Geometry::Point(pt) => assert_eq!((pt.x(), pt.y()), (5.0, 6.0)),
+            _ => panic!("Expected Point"),
+        }
+    }
+
+    #[test]
+    fn cache_does_not_affect_equality() {
+        let a = SurrealGeometry::point(1.0, 2.0, Srid::WGS84).unwrap();
+        let b = a.clone();
+        a.to_geo_cached().unwrap();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn invalidate_cache_recomputes() {
+        let mut p = SurrealGeometry::point(1.0, 2.0, Srid::WGS84).unwrap();
+        let first = p.to_geo_cached().unwrap().clone();
+        p.invalidate_cache();
+        assert_eq!(p.to_geo_cached().unwrap(), &first);
+    }
 }
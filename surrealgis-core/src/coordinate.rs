@@ -37,6 +37,19 @@ impl Coordinate {
         })
     }
 
+    /// Create a measured 2D coordinate (with M, without Z).
+    pub fn new_m(x: f64, y: f64, m: f64) -> Result<Self, GeometryError> {
+        Self::validate_finite(x, "x")?;
+        Self::validate_finite(y, "y")?;
+        Self::validate_finite(m, "m")?;
+        Ok(Self {
+            x,
+            y,
+            z: None,
+            m: Some(m),
+        })
+    }
+
     /// Create a 4D coordinate (with Z and M).
     pub fn new_4d(x: f64, y: f64, z: f64, m: f64) -> Result<Self, GeometryError> {
         Self::validate_finite(x, "x")?;
@@ -51,6 +64,29 @@ impl Coordinate {
         })
     }
 
+    /// Build a coordinate from a 2D `geo_types::Coord`, attaching an optional Z.
+    ///
+    /// `geo_types` carries only XY, so callers that round-trip through geo
+    /// use this to re-attach the Z value they held on to.
+    pub fn from_geo_with_z(c: geo_types::Coord<f64>, z: Option<f64>) -> Result<Self, GeometryError> {
+        match z {
+            Some(z) => Self::new_3d(c.x, c.y, z),
+            None => Self::new(c.x, c.y),
+        }
+    }
+
+    /// Return a copy with new X/Y values, keeping this coordinate's Z and M.
+    pub fn with_xy(&self, x: f64, y: f64) -> Result<Self, GeometryError> {
+        Self::validate_finite(x, "x")?;
+        Self::validate_finite(y, "y")?;
+        Ok(Self {
+            x,
+            y,
+            z: self.z,
+            m: self.m,
+        })
+    }
+
     pub fn x(&self) -> f64 {
         self.x
     }
@@ -67,6 +103,21 @@ impl Coordinate {
         self.m
     }
 
+    /// True if every ordinate is within `epsilon` of `other`'s. Z and M must
+    /// be present on both or absent on both.
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        let close = |a: f64, b: f64| (a - b).abs() <= epsilon;
+        let close_opt = |a: Option<f64>, b: Option<f64>| match (a, b) {
+            (Some(a), Some(b)) => close(a, b),
+            (None, None) => true,
+            _ => false,
+        };
+        close(self.x, other.x)
+            && close(self.y, other.y)
+            && close_opt(self.z, other.z)
+            && close_opt(self.m, other.m)
+    }
+
     /// Check if the coordinate is a valid geographic coordinate
     /// (longitude in [-180, 180], latitude in [-90, 90]).
     pub fn is_geographic_valid(&self) -> bool {
@@ -138,6 +189,14 @@ mod tests {
         assert_eq!(c.m(), Some(4.0));
     }
 
+    #[test]
+    fn new_measured_coordinate() {
+        let c = Coordinate::new_m(1.0, 2.0, 5.0).unwrap();
+        assert_eq!(c.z(), None);
+        assert_eq!(c.m(), Some(5.0));
+        assert!(Coordinate::new_m(1.0, 2.0, f64::INFINITY).is_err());
+    }
+
     #[test]
     fn nan_x_rejected() {
         let result = Coordinate::new(f64::NAN, 1.0);
@@ -213,6 +272,44 @@ mod tests {
         assert_eq!(c.y(), 4.0);
     }
 
+    #[test]
+    fn from_geo_with_z_attaches_z() {
+        let gc = geo_types::Coord { x: 3.0, y: 4.0 };
+        let c = Coordinate::from_geo_with_z(gc, Some(5.0)).unwrap();
+        assert_eq!(c.x(), 3.0);
+        assert_eq!(c.y(), 4.0);
+        assert_eq!(c.z(), Some(5.0));
+    }
+
+    #[test]
+    fn from_geo_with_z_none_is_2d() {
+        let gc = geo_types::Coord { x: 3.0, y: 4.0 };
+        let c = Coordinate::from_geo_with_z(gc, None).unwrap();
+        assert_eq!(c.z(), None);
+    }
+
+    #[test]
+    fn from_geo_with_z_rejects_nan_z() {
+        let gc = geo_types::Coord { x: 3.0, y: 4.0 };
+        assert!(Coordinate::from_geo_with_z(gc, Some(f64::NAN)).is_err());
+    }
+
+    #[test]
+    fn with_xy_keeps_z_and_m() {
+        let c = Coordinate::new_4d(1.0, 2.0, 3.0, 4.0).unwrap();
+        let moved = c.with_xy(10.0, 20.0).unwrap();
+        assert_eq!(moved.x(), 10.0);
+        assert_eq!(moved.y(), 20.0);
+        assert_eq!(moved.z(), Some(3.0));
+        assert_eq!(moved.m(), Some(4.0));
+    }
+
+    #[test]
+    fn with_xy_rejects_non_finite() {
+        let c = Coordinate::new(1.0, 2.0).unwrap();
+        assert!(c.with_xy(f64::NAN, 0.0).is_err());
+    }
+
     #[test]
     fn coordinate_serialization_roundtrip() {
         let c = Coordinate::new_3d(1.0, 2.0, 3.0).unwrap();
@@ -1,4 +1,5 @@
 use crate::bbox::BoundingBox;
+use crate::convert::to_geo::GeoCache;
 use crate::coordinate::Coordinate;
 use crate::error::GeometryError;
 use crate::flags::GeometryFlags;
@@ -34,6 +35,7 @@ pub struct SurrealGeometry {
     srid: Srid,
     bbox: Option<BoundingBox>,
     flags: GeometryFlags,
+    pub(crate) geo_cache: GeoCache,
 }
 
 impl SurrealGeometry {
@@ -42,17 +44,14 @@ impl SurrealGeometry {
     /// Create a Point geometry.
     pub fn point(x: f64, y: f64, srid: Srid) -> Result<Self, GeometryError> {
         let coord = Coordinate::new(x, y)?;
-        let bbox = BoundingBox::from_coordinates(&[coord.clone()]);
-        let mut flags = GeometryFlags::HAS_SRID;
-        if bbox.is_some() {
-            flags |= GeometryFlags::HAS_BBOX;
-        }
-        Ok(Self {
-            geometry_type: GeometryType::Point(coord),
-            srid,
-            bbox,
-            flags,
-        })
+        let bbox = BoundingBox::from_coordinates(std::slice::from_ref(&coord));
+        Ok(Self::assemble(GeometryType::Point(coord), srid, bbox))
+    }
+
+    /// Create a Point geometry from an existing coordinate, keeping its Z/M.
+    pub fn point_from_coordinate(coord: Coordinate, srid: Srid) -> Result<Self, GeometryError> {
+        let bbox = BoundingBox::from_coordinates(std::slice::from_ref(&coord));
+        Ok(Self::assemble(GeometryType::Point(coord), srid, bbox))
     }
 
     /// Create a LineString geometry.
@@ -62,16 +61,7 @@ impl SurrealGeometry {
     ) -> Result<Self, GeometryError> {
         validation::validate_linestring(&coords)?;
         let bbox = BoundingBox::from_coordinates(&coords);
-        let mut flags = GeometryFlags::HAS_SRID;
-        if bbox.is_some() {
-            flags |= GeometryFlags::HAS_BBOX;
-        }
-        Ok(Self {
-            geometry_type: GeometryType::LineString(coords),
-            srid,
-            bbox,
-            flags,
-        })
+        Ok(Self::assemble(GeometryType::LineString(coords), srid, bbox))
     }
 
     /// Create a Polygon geometry.
@@ -82,16 +72,7 @@ impl SurrealGeometry {
     ) -> Result<Self, GeometryError> {
         validation::validate_polygon(&exterior, &holes)?;
         let bbox = BoundingBox::from_coordinates(&exterior);
-        let mut flags = GeometryFlags::HAS_SRID;
-        if bbox.is_some() {
-            flags |= GeometryFlags::HAS_BBOX;
-        }
-        Ok(Self {
-            geometry_type: GeometryType::Polygon { exterior, holes },
-            srid,
-            bbox,
-            flags,
-        })
+        Ok(Self::assemble(GeometryType::Polygon { exterior, holes }, srid, bbox))
     }
 
     /// Create a MultiPoint geometry.
@@ -103,16 +84,7 @@ impl SurrealGeometry {
             return Err(GeometryError::EmptyGeometry);
         }
         let bbox = BoundingBox::from_coordinates(&coords);
-        let mut flags = GeometryFlags::HAS_SRID;
-        if bbox.is_some() {
-            flags |= GeometryFlags::HAS_BBOX;
-        }
-        Ok(Self {
-            geometry_type: GeometryType::MultiPoint(coords),
-            srid,
-            bbox,
-            flags,
-        })
+        Ok(Self::assemble(GeometryType::MultiPoint(coords), srid, bbox))
     }
 
     /// Create a MultiLineString geometry.
@@ -128,16 +100,7 @@ impl SurrealGeometry {
         }
         let all_coords: Vec<Coordinate> = lines.iter().flatten().cloned().collect();
         let bbox = BoundingBox::from_coordinates(&all_coords);
-        let mut flags = GeometryFlags::HAS_SRID;
-        if bbox.is_some() {
-            flags |= GeometryFlags::HAS_BBOX;
-        }
-        Ok(Self {
-            geometry_type: GeometryType::MultiLineString(lines),
-            srid,
-            bbox,
-            flags,
-        })
+        Ok(Self::assemble(GeometryType::MultiLineString(lines), srid, bbox))
     }
 
     /// Create a MultiPolygon geometry.
@@ -157,16 +120,7 @@ impl SurrealGeometry {
             .cloned()
             .collect();
         let bbox = BoundingBox::from_coordinates(&all_coords);
-        let mut flags = GeometryFlags::HAS_SRID;
-        if bbox.is_some() {
-            flags |= GeometryFlags::HAS_BBOX;
-        }
-        Ok(Self {
-            geometry_type: GeometryType::MultiPolygon(polygons),
-            srid,
-            bbox,
-            flags,
-        })
+        Ok(Self::assemble(GeometryType::MultiPolygon(polygons), srid, bbox))
     }
 
     /// Create a GeometryCollection.
@@ -185,19 +139,96 @@ impl SurrealGeometry {
                 (a, None) => a,
             }
         });
-        let mut flags = GeometryFlags::HAS_SRID;
+        Ok(Self::assemble(GeometryType::GeometryCollection(geometries), srid, bbox))
+    }
+
+    // ── Multi constructors from geometries ──────────────────────────
+
+    /// Create a MultiPoint from a slice of Point geometries sharing `srid`.
+    pub fn multi_point_from(
+        points: &[SurrealGeometry],
+        srid: Srid,
+    ) -> Result<Self, GeometryError> {
+        let coords = points
+            .iter()
+            .map(|g| match g.member_type(srid)? {
+                GeometryType::Point(c) => Ok(c.clone()),
+                _ => Err(g.type_mismatch("Point")),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::multi_point(coords, srid)
+    }
+
+    /// Create a MultiLineString from a slice of LineString geometries sharing `srid`.
+    pub fn multi_line_string_from(
+        lines: &[SurrealGeometry],
+        srid: Srid,
+    ) -> Result<Self, GeometryError> {
+        let lines = lines
+            .iter()
+            .map(|g| match g.member_type(srid)? {
+                GeometryType::LineString(coords) => Ok(coords.clone()),
+                _ => Err(g.type_mismatch("LineString")),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::multi_line_string(lines, srid)
+    }
+
+    /// Create a MultiPolygon from a slice of Polygon geometries sharing `srid`.
+    pub fn multi_polygon_from(
+        polygons: &[SurrealGeometry],
+        srid: Srid,
+    ) -> Result<Self, GeometryError> {
+        let polygons = polygons
+            .iter()
+            .map(|g| match g.member_type(srid)? {
+                GeometryType::Polygon { exterior, holes } => Ok(PolygonData {
+                    exterior: exterior.clone(),
+                    holes: holes.clone(),
+                }),
+                _ => Err(g.type_mismatch("Polygon")),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::multi_polygon(polygons, srid)
+    }
+
+    /// The geometry variant of a multi-geometry member, after checking its SRID.
+    fn member_type(&self, srid: Srid) -> Result<&GeometryType, GeometryError> {
+        if self.srid != srid {
+            return Err(GeometryError::InvalidSrid(format!(
+                "member SRID {} does not match {}",
+                self.srid.code(),
+                srid.code()
+            )));
+        }
+        Ok(&self.geometry_type)
+    }
+
+    fn type_mismatch(&self, expected: &str) -> GeometryError {
+        GeometryError::UnsupportedGeometryType(format!(
+            "expected {expected}, got {}",
+            self.type_name()
+        ))
+    }
+
+    // ── Internal constructor (for conversions) ──────────────────────
+
+    /// Assemble a geometry from checked parts, setting `HAS_SRID`, `HAS_BBOX`
+    /// and the `HAS_Z` / `HAS_M` flags its coordinates call for.
+    fn assemble(geometry_type: GeometryType, srid: Srid, bbox: Option<BoundingBox>) -> Self {
+        let mut flags = GeometryFlags::HAS_SRID | dimension_flags(&geometry_type);
         if bbox.is_some() {
             flags |= GeometryFlags::HAS_BBOX;
         }
-        Ok(Self {
-            geometry_type: GeometryType::GeometryCollection(geometries),
+        Self {
+            geometry_type,
             srid,
             bbox,
             flags,
-        })
+            geo_cache: GeoCache::default(),
+        }
     }
 
-    // ── Internal constructor (for conversions) ──────────────────────
 
     /// Build a SurrealGeometry directly from parts (used by conversion code).
     pub(crate) fn from_parts(
@@ -205,16 +236,7 @@ impl SurrealGeometry {
         srid: Srid,
     ) -> Self {
         let bbox = Self::compute_bbox_for(&geometry_type);
-        let mut flags = GeometryFlags::HAS_SRID;
-        if bbox.is_some() {
-            flags |= GeometryFlags::HAS_BBOX;
-        }
-        Self {
-            geometry_type,
-            srid,
-            bbox,
-            flags,
-        }
+        Self::assemble(geometry_type, srid, bbox)
     }
 
     // ── Accessors ───────────────────────────────────────────────────
@@ -286,8 +308,146 @@ impl SurrealGeometry {
         }
     }
 
+    // ── Approximate Equality ────────────────────────────────────────
+
+    /// Compare structure and coordinates, allowing each ordinate to differ
+    /// by up to `epsilon`.
+    ///
+    /// Geometry types, ring and member counts and vertex counts must match
+    /// exactly; SRIDs are ignored, so a geometry round-tripped through a
+    /// reprojection compares equal to the original. Use
+    /// [`approx_eq_with_srid`](Self::approx_eq_with_srid) to require matching
+    /// SRIDs as well.
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.approx_eq_inner(other, epsilon, false)
+    }
+
+    /// Like [`approx_eq`](Self::approx_eq), but the SRIDs (including those of
+    /// GeometryCollection members) must also be equal.
+    pub fn approx_eq_with_srid(&self, other: &Self, epsilon: f64) -> bool {
+        self.approx_eq_inner(other, epsilon, true)
+    }
+
+    fn approx_eq_inner(&self, other: &Self, epsilon: f64, check_srid: bool) -> bool {
+        fn coords_eq(a: &[Coordinate], b: &[Coordinate], epsilon: f64) -> bool {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.approx_eq(b, epsilon))
+        }
+        fn rings_eq(a: &[Vec<Coordinate>], b: &[Vec<Coordinate>], epsilon: f64) -> bool {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| coords_eq(a, b, epsilon))
+        }
+
+        if check_srid && self.srid != other.srid {
+            return false;
+        }
+        match (&self.geometry_type, &other.geometry_type) {
+            (GeometryType::Point(a), GeometryType::Point(b)) => a.approx_eq(b, epsilon),
+            (GeometryType::LineString(a), GeometryType::LineString(b))
+            | (GeometryType::MultiPoint(a), GeometryType::MultiPoint(b)) => {
+                coords_eq(a, b, epsilon)
+            }
+            (
+                GeometryType::Polygon { exterior: ea, holes: ha },
+                GeometryType::Polygon { exterior: eb, holes: hb },
+            ) => coords_eq(ea, eb, epsilon) && rings_eq(ha, hb, epsilon),
+            (GeometryType::MultiLineString(a), GeometryType::MultiLineString(b)) => {
+                rings_eq(a, b, epsilon)
+            }
+            (GeometryType::MultiPolygon(a), GeometryType::MultiPolygon(b)) => {
+                a.len() == b.len()
+                    && a.iter().zip(b).all(|(a, b)| {
+                        coords_eq(&a.exterior, &b.exterior, epsilon)
+                            && rings_eq(&a.holes, &b.holes, epsilon)
+                    })
+            }
+            (GeometryType::GeometryCollection(a), GeometryType::GeometryCollection(b)) => {
+                a.len() == b.len()
+                    && a.iter()
+                        .zip(b)
+                        .all(|(a, b)| a.approx_eq_inner(b, epsilon, check_srid))
+            }
+            _ => false,
+        }
+    }
+
+    // ── Coordinate Mapping ──────────────────────────────────────────
+
+    /// Apply `f` to every coordinate, keeping the geometry structure and SRID.
+    ///
+    /// Unlike a round-trip through `geo_types`, this works on the domain
+    /// coordinates directly, so whatever Z/M the closure returns is kept.
+    /// Coordinates are visited in storage order: exterior ring before holes,
+    /// members of multi-geometries and collections in sequence.
+    pub fn map_coords<F>(&self, mut f: F) -> Result<Self, GeometryError>
+    where
+        F: FnMut(&Coordinate) -> Result<Coordinate, GeometryError>,
+    {
+        self.map_coords_with(&mut f)
+    }
+
+    fn map_coords_with<F>(&self, f: &mut F) -> Result<Self, GeometryError>
+    where
+        F: FnMut(&Coordinate) -> Result<Coordinate, GeometryError>,
+    {
+        fn map_all<F>(coords: &[Coordinate], f: &mut F) -> Result<Vec<Coordinate>, GeometryError>
+        where
+            F: FnMut(&Coordinate) -> Result<Coordinate, GeometryError>,
+        {
+            coords.iter().map(&mut *f).collect()
+        }
+
+        let geometry_type = match &self.geometry_type {
+            GeometryType::Point(c) => GeometryType::Point(f(c)?),
+            GeometryType::LineString(coords) => GeometryType::LineString(map_all(coords, f)?),
+            GeometryType::Polygon { exterior, holes } => {
+                let exterior = map_all(exterior, f)?;
+                let holes = holes
+                    .iter()
+                    .map(|h| map_all(h, f))
+                    .collect::<Result<Vec<_>, _>>()?;
+                GeometryType::Polygon { exterior, holes }
+            }
+            GeometryType::MultiPoint(coords) => GeometryType::MultiPoint(map_all(coords, f)?),
+            GeometryType::MultiLineString(lines) => GeometryType::MultiLineString(
+                lines
+                    .iter()
+                    .map(|l| map_all(l, f))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+            GeometryType::MultiPolygon(polygons) => {
+                let mut mapped = Vec::with_capacity(polygons.len());
+                for p in polygons {
+                    let exterior = map_all(&p.exterior, f)?;
+                    let holes = p
+                        .holes
+                        .iter()
+                        .map(|h| map_all(h, f))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    mapped.push(PolygonData { exterior, holes });
+                }
+                GeometryType::MultiPolygon(mapped)
+            }
+            GeometryType::GeometryCollection(geoms) => GeometryType::GeometryCollection(
+                geoms
+                    .iter()
+                    .map(|g| g.map_coords_with(f))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+        };
+        Ok(Self::from_parts(geometry_type, self.srid))
+    }
+
+    /// Replace the coordinates in place, keeping the SRID.
+    ///
+    /// Recomputes the bbox and dimension flags and drops the cached
+    /// `geo_types` conversion. Like the conversion constructors, this does
+    /// not re-run constructor validation.
+    pub fn set_geometry_type(&mut self, geometry_type: GeometryType) {
+        *self = Self::from_parts(geometry_type, self.srid);
+    }
+
     /// Recompute the bounding box from coordinates.
     pub fn compute_bbox(&mut self) {
+        self.invalidate_cache();
         self.bbox = Self::compute_bbox_for(&self.geometry_type);
         if self.bbox.is_some() {
             self.flags |= GeometryFlags::HAS_BBOX;
@@ -298,7 +458,7 @@ impl SurrealGeometry {
 
     fn compute_bbox_for(gt: &GeometryType) -> Option<BoundingBox> {
         match gt {
-            GeometryType::Point(c) => BoundingBox::from_coordinates(&[c.clone()]),
+            GeometryType::Point(c) => BoundingBox::from_coordinates(std::slice::from_ref(c)),
             GeometryType::LineString(coords) => BoundingBox::from_coordinates(coords),
             GeometryType::Polygon { exterior, .. } => {
                 BoundingBox::from_coordinates(exterior)
@@ -329,6 +489,40 @@ impl SurrealGeometry {
     }
 }
 
+/// `HAS_Z` if any coordinate carries Z, and `HAS_M` if any carries M.
+fn dimension_flags(gt: &GeometryType) -> GeometryFlags {
+    fn scan<'a>(cs: impl IntoIterator<Item = &'a Coordinate>) -> GeometryFlags {
+        let mut flags = GeometryFlags::empty();
+        for c in cs {
+            if c.z().is_some() {
+                flags |= GeometryFlags::HAS_Z;
+            }
+            if c.m().is_some() {
+                flags |= GeometryFlags::HAS_M;
+            }
+        }
+        flags
+    }
+    match gt {
+        GeometryType::Point(c) => scan([c]),
+        GeometryType::LineString(cs) | GeometryType::MultiPoint(cs) => scan(cs),
+        GeometryType::Polygon { exterior, holes } => {
+            scan(exterior.iter().chain(holes.iter().flatten()))
+        }
+        GeometryType::MultiLineString(lines) => scan(lines.iter().flatten()),
+        GeometryType::MultiPolygon(polys) => scan(
+            polys
+                .iter()
+                .flat_map(|p| p.exterior.iter().chain(p.holes.iter().flatten())),
+        ),
+        GeometryType::GeometryCollection(geoms) => geoms
+            .iter()
+            .fold(GeometryFlags::empty(), |acc, g| {
+                acc | (g.flags() & (GeometryFlags::HAS_Z | GeometryFlags::HAS_M))
+            }),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -342,6 +536,18 @@ mod tests {
         assert!(p.bbox().is_some());
     }
 
+    #[test]
+    fn create_point_from_coordinate_keeps_z() {
+        let c = Coordinate::new_3d(1.0, 2.0, 3.0).unwrap();
+        let p = SurrealGeometry::point_from_coordinate(c, Srid::WGS84).unwrap();
+        if let GeometryType::Point(c) = p.geometry_type() {
+            assert_eq!(c.z(), Some(3.0));
+        } else {
+            panic!("Expected Point");
+        }
+        assert!(p.bbox().is_some());
+    }
+
     #[test]
     fn create_linestring() {
         let coords = vec![
@@ -426,6 +632,68 @@ mod tests {
         assert_eq!(gc.num_points(), 3);
     }
 
+    fn square(x0: f64, srid: Srid) -> SurrealGeometry {
+        let ring = vec![
+            Coordinate::new(x0, 0.0).unwrap(),
+            Coordinate::new(x0 + 1.0, 0.0).unwrap(),
+            Coordinate::new(x0 + 1.0, 1.0).unwrap(),
+            Coordinate::new(x0, 1.0).unwrap(),
+            Coordinate::new(x0, 0.0).unwrap(),
+        ];
+        SurrealGeometry::polygon(ring, vec![], srid).unwrap()
+    }
+
+    #[test]
+    fn multi_polygon_from_two_squares() {
+        let polys = [square(0.0, Srid::WGS84), square(5.0, Srid::WGS84)];
+        let mp = SurrealGeometry::multi_polygon_from(&polys, Srid::WGS84).unwrap();
+        assert_eq!(mp.type_name(), "MultiPolygon");
+        assert_eq!(mp.num_points(), 10);
+        let bb = mp.bbox().unwrap();
+        assert_eq!(bb.min_x, 0.0);
+        assert_eq!(bb.max_x, 6.0);
+    }
+
+    #[test]
+    fn multi_polygon_from_rejects_non_polygon() {
+        let p = SurrealGeometry::point(0.0, 0.0, Srid::WGS84).unwrap();
+        let result = SurrealGeometry::multi_polygon_from(&[square(0.0, Srid::WGS84), p], Srid::WGS84);
+        assert!(matches!(result, Err(GeometryError::UnsupportedGeometryType(_))));
+    }
+
+    #[test]
+    fn multi_polygon_from_rejects_srid_mismatch() {
+        let polys = [square(0.0, Srid::WGS84), square(5.0, Srid::WEB_MERCATOR)];
+        let result = SurrealGeometry::multi_polygon_from(&polys, Srid::WGS84);
+        assert!(matches!(result, Err(GeometryError::InvalidSrid(_))));
+    }
+
+    #[test]
+    fn multi_polygon_from_empty_rejected() {
+        let result = SurrealGeometry::multi_polygon_from(&[], Srid::WGS84);
+        assert!(matches!(result, Err(GeometryError::EmptyGeometry)));
+    }
+
+    #[test]
+    fn multi_point_and_line_string_from() {
+        let pts = [
+            SurrealGeometry::point(0.0, 0.0, Srid::WGS84).unwrap(),
+            SurrealGeometry::point(1.0, 1.0, Srid::WGS84).unwrap(),
+        ];
+        let mp = SurrealGeometry::multi_point_from(&pts, Srid::WGS84).unwrap();
+        assert_eq!(mp.type_name(), "MultiPoint");
+        assert_eq!(mp.num_points(), 2);
+
+        let line = SurrealGeometry::line_string(
+            vec![Coordinate::new(0.0, 0.0).unwrap(), Coordinate::new(1.0, 1.0).unwrap()],
+            Srid::WGS84,
+        )
+        .unwrap();
+        let mls = SurrealGeometry::multi_line_string_from(&[line.clone(), line], Srid::WGS84).unwrap();
+        assert_eq!(mls.type_name(), "MultiLineString");
+        assert_eq!(mls.num_points(), 4);
+    }
+
     #[test]
     fn empty_multi_point_rejected() {
         let result = SurrealGeometry::multi_point(vec![], Srid::WGS84);
@@ -447,12 +715,159 @@ mod tests {
         assert_eq!(bb.min_y, 10.0);
     }
 
+    #[test]
+    fn approx_eq_point_within_epsilon() {
+        let a = SurrealGeometry::point(1.0, 2.0, Srid::WGS84).unwrap();
+        let b = SurrealGeometry::point(1.0 + 1e-10, 2.0 - 1e-10, Srid::WGS84).unwrap();
+        assert_ne!(a, b);
+        assert!(a.approx_eq(&b, 1e-9));
+        assert!(!a.approx_eq(&b, 1e-11));
+
+        let line = SurrealGeometry::line_string(
+            vec![Coordinate::new(1.0, 2.0).unwrap(), Coordinate::new(3.0, 4.0).unwrap()],
+            Srid::WGS84,
+        )
+        .unwrap();
+        assert!(!a.approx_eq(&line, 1.0));
+    }
+
+    #[test]
+    fn approx_eq_linestring_checks_vertices_and_z() {
+        let line = |dx: f64, z: Option<f64>| {
+            let c = |x: f64, y: f64| match z {
+                Some(z) => Coordinate::new_3d(x + dx, y, z).unwrap(),
+                None => Coordinate::new(x + dx, y).unwrap(),
+            };
+            SurrealGeometry::line_string(vec![c(0.0, 0.0), c(1.0, 1.0)], Srid::WEB_MERCATOR)
+                .unwrap()
+        };
+        assert!(line(0.0, None).approx_eq(&line(1e-7, None), 1e-6));
+        assert!(!line(0.0, None).approx_eq(&line(1e-5, None), 1e-6));
+        // Z on one side only is a structural difference
+        assert!(!line(0.0, None).approx_eq(&line(0.0, Some(5.0)), 1e-6));
+
+        let shorter = SurrealGeometry::line_string(
+            vec![Coordinate::new(0.0, 0.0).unwrap(), Coordinate::new(1.0, 1.0).unwrap()],
+            Srid::WEB_MERCATOR,
+        )
+        .unwrap();
+        let longer = SurrealGeometry::line_string(
+            vec![
+                Coordinate::new(0.0, 0.0).unwrap(),
+                Coordinate::new(1.0, 1.0).unwrap(),
+                Coordinate::new(1.0, 1.0).unwrap(),
+            ],
+            Srid::WEB_MERCATOR,
+        )
+        .unwrap();
+        assert!(!shorter.approx_eq(&longer, 1.0));
+    }
+
+    #[test]
+    fn approx_eq_nested_collection_and_srid() {
+        let inner = |x: f64, srid: Srid| {
+            let p = SurrealGeometry::point(x, 0.0, srid).unwrap();
+            SurrealGeometry::geometry_collection(vec![p], srid).unwrap()
+        };
+        let outer = |x: f64, srid: Srid| {
+            let q = SurrealGeometry::point(5.0, 5.0, srid).unwrap();
+            SurrealGeometry::geometry_collection(vec![q, inner(x, srid)], srid).unwrap()
+        };
+        let a = outer(1.0, Srid::WGS84);
+        let b = outer(1.0 + 1e-12, Srid::WEB_MERCATOR);
+        assert!(a.approx_eq(&b, 1e-9));
+        assert!(!a.approx_eq_with_srid(&b, 1e-9));
+        assert!(a.approx_eq_with_srid(&outer(1.0 + 1e-12, Srid::WGS84), 1e-9));
+        assert!(!a.approx_eq(&outer(1.1, Srid::WGS84), 1e-9));
+    }
+
+    #[test]
+    fn map_coords_preserves_z() {
+        let coords = vec![
+            Coordinate::new_3d(0.0, 0.0, 10.0).unwrap(),
+            Coordinate::new_3d(1.0, 1.0, 20.0).unwrap(),
+        ];
+        let line = SurrealGeometry::line_string(coords, Srid::WEB_MERCATOR).unwrap();
+        let shifted = line
+            .map_coords(|c| c.with_xy(c.x() + 1.0, c.y()))
+            .unwrap();
+        assert_eq!(shifted.srid().code(), Srid::WEB_MERCATOR.code());
+        if let GeometryType::LineString(cs) = shifted.geometry_type() {
+            assert_eq!(cs[0].x(), 1.0);
+            assert_eq!(cs[0].z(), Some(10.0));
+            assert_eq!(cs[1].z(), Some(20.0));
+        } else {
+            panic!("Expected LineString");
+        }
+        assert_eq!(shifted.bbox().unwrap().min_x, 1.0);
+    }
+
+    #[test]
+    fn map_coords_visits_in_storage_order() {
+        let poly = SurrealGeometry::polygon(
+            vec![
+                Coordinate::new(0.0, 0.0).unwrap(),
+                Coordinate::new(10.0, 0.0).unwrap(),
+                Coordinate::new(10.0, 10.0).unwrap(),
+                Coordinate::new(0.0, 0.0).unwrap(),
+            ],
+            vec![vec![
+                Coordinate::new(1.0, 1.0).unwrap(),
+                Coordinate::new(2.0, 1.0).unwrap(),
+                Coordinate::new(2.0, 2.0).unwrap(),
+                Coordinate::new(1.0, 1.0).unwrap(),
+            ]],
+            Srid::WGS84,
+        )
+        .unwrap();
+        let mut seen = Vec::new();
+        poly.map_coords(|c| {
+            seen.push(c.x());
+            Ok(c.clone())
+        })
+        .unwrap();
+        assert_eq!(seen, vec![0.0, 10.0, 10.0, 0.0, 1.0, 2.0, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn map_coords_propagates_errors() {
+        let p = SurrealGeometry::point(1.0, 2.0, Srid::WGS84).unwrap();
+        let result = p.map_coords(|c| c.with_xy(c.x() * f64::INFINITY, c.y()));
+        assert!(result.is_err());
+    }
+
     #[test]
     fn dimension_default_is_2d() {
         let p = SurrealGeometry::point(1.0, 2.0, Srid::WGS84).unwrap();
         assert_eq!(p.dimension(), 2);
     }
 
+    #[test]
+    fn constructors_derive_dimension_flags() {
+        let p = SurrealGeometry::point_from_coordinate(
+            Coordinate::new_3d(1.0, 2.0, 3.0).unwrap(),
+            Srid::WGS84,
+        )
+        .unwrap();
+        assert_eq!(p.dimension(), 3);
+        assert!(p.flags().contains(GeometryFlags::HAS_SRID));
+
+        // One measured vertex is enough to flag the whole line
+        let line = SurrealGeometry::line_string(
+            vec![
+                Coordinate::new(0.0, 0.0).unwrap(),
+                Coordinate::new_m(1.0, 1.0, 5.0).unwrap(),
+            ],
+            Srid::WGS84,
+        )
+        .unwrap();
+        assert!(line.flags().contains(GeometryFlags::HAS_M));
+        assert!(!line.flags().contains(GeometryFlags::HAS_Z));
+
+        let gc = SurrealGeometry::geometry_collection(vec![p, line], Srid::WGS84).unwrap();
+        assert_eq!(gc.dimension(), 4);
+    }
+
     #[test]
     fn is_empty_is_false_for_point() {
         let p = SurrealGeometry::point(1.0, 2.0, Srid::WGS84).unwrap();
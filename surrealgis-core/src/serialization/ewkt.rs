@@ -1,5 +1,3 @@
-use ::wkt::TryFromWkt;
-
 use crate::error::GeometryError;
 use crate::geometry::SurrealGeometry;
 use crate::serialization::wkt as surreal_wkt;
@@ -28,11 +26,7 @@ pub fn from_ewkt(ewkt_str: &str) -> Result<SurrealGeometry, GeometryError> {
         let srid = Srid::new(srid_code)?;
 
         let wkt_body = &rest[semicolon_pos + 1..];
-        let geo: geo_types::Geometry<f64> =
-            TryFromWkt::try_from_wkt_str(wkt_body).map_err(|e| {
-                GeometryError::SerializationError(format!("EWKT WKT parse error: {e}"))
-            })?;
-        SurrealGeometry::from_geo(&geo, srid)
+        surreal_wkt::from_wkt_with_srid(wkt_body, srid)
     } else {
         // No SRID prefix, treat as plain WKT
         surreal_wkt::from_wkt(ewkt_str)
@@ -64,6 +64,33 @@ pub fn to_geojson(geom: &SurrealGeometry) -> Result<Value, GeometryError> {
     }
 }
 
+/// Wrap a SurrealGeometry in a GeoJSON Feature object, the per-record shape
+/// web APIs return.
+///
+/// `properties` must be a JSON object or null, and `id`, when given, a string
+/// or number, as RFC 7946 requires.
+pub fn to_geojson_feature(
+    geom: &SurrealGeometry,
+    properties: Value,
+    id: Option<Value>,
+) -> Result<Value, GeometryError> {
+    if !(properties.is_object() || properties.is_null()) {
+        return Err(geojson_err("Feature properties must be an object or null"));
+    }
+    let mut feature = json!({
+        "type": "Feature",
+        "geometry": to_geojson(geom)?,
+        "properties": properties,
+    });
+    if let Some(id) = id {
+        if !(id.is_string() || id.is_number()) {
+            return Err(geojson_err("Feature id must be a string or number"));
+        }
+        feature["id"] = id;
+    }
+    Ok(feature)
+}
+
 /// Parse a GeoJSON geometry object into a SurrealGeometry.
 pub fn from_geojson(value: &Value) -> Result<SurrealGeometry, GeometryError> {
     let type_str = value
@@ -353,4 +380,30 @@ mod tests {
         let sg = from_geojson(&value).unwrap();
         assert_eq!(sg.srid().code(), 4326);
     }
+
+    #[test]
+    fn feature_wraps_geometry_and_properties() {
+        let p = SurrealGeometry::point(1.0, 2.0, Srid::WGS84).unwrap();
+        let props = json!({"name": "depot", "capacity": 12});
+        let feature = to_geojson_feature(&p, props.clone(), Some(json!(7))).unwrap();
+        assert_eq!(feature["type"], "Feature");
+        assert_eq!(feature["geometry"], to_geojson(&p).unwrap());
+        assert_eq!(feature["properties"], props);
+        assert_eq!(feature["id"], 7);
+    }
+
+    #[test]
+    fn feature_without_id_or_properties() {
+        let p = SurrealGeometry::point(1.0, 2.0, Srid::WGS84).unwrap();
+        let feature = to_geojson_feature(&p, Value::Null, None).unwrap();
+        assert!(feature["properties"].is_null());
+        assert!(feature.get("id").is_none());
+    }
+
+    #[test]
+    fn feature_rejects_bad_properties_and_id() {
+        let p = SurrealGeometry::point(1.0, 2.0, Srid::WGS84).unwrap();
+        assert!(to_geojson_feature(&p, json!([1, 2]), None).is_err());
+        assert!(to_geojson_feature(&p, json!({}), Some(json!({"a": 1}))).is_err());
+    }
 }
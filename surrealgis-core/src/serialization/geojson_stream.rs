@@ -0,0 +1,321 @@
+use std::io::{BufRead, BufReader, Read};
+
+use serde_json::Value;
+
+use crate::error::GeometryError;
+use crate::geometry::SurrealGeometry;
+
+use super::geojson::from_geojson;
+
+/// Stream the geometries of a GeoJSON FeatureCollection from a reader.
+///
+/// Only one feature is held in memory at a time: the document is scanned
+/// byte by byte until the top-level `features` array, then each feature is
+/// cut out and parsed on its own. Features with a `null` geometry are skipped.
+/// Reading stops at the end of the `features` array, so members after it are
+/// not checked. The iterator ends after the first error.
+pub fn from_geojson_stream<R: Read>(reader: R) -> GeoJsonStream<R> {
+    GeoJsonStream {
+        reader: BufReader::new(reader),
+        state: StreamState::Start,
+        buf: Vec::new(),
+    }
+}
+
+/// Iterator returned by [`from_geojson_stream`].
+pub struct GeoJsonStream<R> {
+    reader: BufReader<R>,
+    state: StreamState,
+    buf: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum StreamState {
+    Start,
+    InFeatures,
+    Done,
+}
+
+impl<R: Read> Iterator for GeoJsonStream<R> {
+    type Item = Result<SurrealGeometry, GeometryError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let step = match self.state {
+                StreamState::Done => return None,
+                StreamState::Start => self.seek_features().map(|()| None),
+                StreamState::InFeatures => self.next_feature(),
+            };
+            match step {
+                Ok(Some(geom)) => return Some(Ok(geom)),
+                Ok(None) => {}
+                Err(e) => {
+                    self.state = StreamState::Done;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
+impl<R: Read> GeoJsonStream<R> {
+    /// Walk the top-level object up to the opening bracket of `features`.
+    fn seek_features(&mut self) -> Result<(), GeometryError> {
+        self.expect(b'{')?;
+        loop {
+            let key = match self.skip_ws_peek()? {
+                Some(b'"') => self.read_string_value()?,
+                Some(b'}') => return Err(stream_err("missing 'features' array")),
+                _ => return Err(stream_err("expected an object key")),
+            };
+            self.expect(b':')?;
+            match key.as_str() {
+                "features" => {
+                    self.expect(b'[')?;
+                    self.state = StreamState::InFeatures;
+                    return Ok(());
+                }
+                "type" => {
+                    let value = self.read_value_json()?;
+                    if value.as_str() != Some("FeatureCollection") {
+                        return Err(stream_err("expected a FeatureCollection"));
+                    }
+                }
+                _ => {
+                    self.buf.clear();
+                    self.read_raw_value()?;
+                }
+            }
+            match self.skip_ws_next()? {
+                Some(b',') => {}
+                Some(b'}') => return Err(stream_err("missing 'features' array")),
+                _ => return Err(stream_err("expected ',' or '}' in FeatureCollection")),
+            }
+        }
+    }
+
+    /// Parse the next feature, returning None for a skipped feature.
+    fn next_feature(&mut self) -> Result<Option<SurrealGeometry>, GeometryError> {
+        if self.skip_ws_peek()? == Some(b']') {
+            self.state = StreamState::Done;
+            return Ok(None);
+        }
+        let feature = self.read_value_json()?;
+        match self.skip_ws_next()? {
+            Some(b',') => {}
+            Some(b']') => self.state = StreamState::Done,
+            _ => return Err(stream_err("expected ',' or ']' after a feature")),
+        }
+
+        if feature.get("type").and_then(Value::as_str) != Some("Feature") {
+            return Err(stream_err("features must contain Feature objects"));
+        }
+        match feature.get("geometry") {
+            None => Err(stream_err("Feature is missing 'geometry'")),
+            Some(Value::Null) => Ok(None),
+            Some(geometry) => from_geojson(geometry).map(Some),
+        }
+    }
+
+    fn read_value_json(&mut self) -> Result<Value, GeometryError> {
+        self.buf.clear();
+        self.read_raw_value()?;
+        serde_json::from_slice(&self.buf).map_err(|e| stream_err(&e.to_string()))
+    }
+
+    fn read_string_value(&mut self) -> Result<String, GeometryError> {
+        match self.read_value_json()? {
+            Value::String(s) => Ok(s),
+            _ => Err(stream_err("expected a string")),
+        }
+    }
+
+    /// Append the raw bytes of the next JSON value to `buf`.
+    fn read_raw_value(&mut self) -> Result<(), GeometryError> {
+        match self.skip_ws_peek()? {
+            None => Err(stream_err("unexpected end of input")),
+            Some(b'"') => {
+                self.bump_into_buf()?;
+                self.read_string_tail()
+            }
+            Some(b'{' | b'[') => {
+                let mut depth = 0usize;
+                loop {
+                    let byte = self.bump_into_buf()?;
+                    match byte {
+                        b'"' => self.read_string_tail()?,
+                        b'{' | b'[' => depth += 1,
+                        b'}' | b']' => {
+                            depth -= 1;
+                            if depth == 0 {
+                                return Ok(());
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Some(_) => {
+                // Scalar: runs until the next delimiter
+                while let Some(byte) = self.peek()? {
+                    if matches!(byte, b',' | b'}' | b']') || byte.is_ascii_whitespace() {
+                        break;
+                    }
+                    self.bump_into_buf()?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Copy the rest of a string whose opening quote is already in `buf`.
+    fn read_string_tail(&mut self) -> Result<(), GeometryError> {
+        loop {
+            match self.bump_into_buf()? {
+                b'\\' => {
+                    self.bump_into_buf()?;
+                }
+                b'"' => return Ok(()),
+                _ => {}
+            }
+        }
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<(), GeometryError> {
+        match self.skip_ws_next()? {
+            Some(byte) if byte == expected => Ok(()),
+            _ => Err(stream_err(&format!("expected '{}'", expected as char))),
+        }
+    }
+
+    fn skip_ws_peek(&mut self) -> Result<Option<u8>, GeometryError> {
+        while let Some(byte) = self.peek()? {
+            if !byte.is_ascii_whitespace() {
+                return Ok(Some(byte));
+            }
+            self.reader.consume(1);
+        }
+        Ok(None)
+    }
+
+    fn skip_ws_next(&mut self) -> Result<Option<u8>, GeometryError> {
+        let byte = self.skip_ws_peek()?;
+        if byte.is_some() {
+            self.reader.consume(1);
+        }
+        Ok(byte)
+    }
+
+    fn peek(&mut self) -> Result<Option<u8>, GeometryError> {
+        let available = self
+            .reader
+            .fill_buf()
+            .map_err(|e| stream_err(&e.to_string()))?;
+        Ok(available.first().copied())
+    }
+
+    /// Consume one byte, appending it to `buf`.
+    fn bump_into_buf(&mut self) -> Result<u8, GeometryError> {
+        let byte = self
+            .peek()?
+            .ok_or_else(|| stream_err("unexpected end of input"))?;
+        self.reader.consume(1);
+        self.buf.push(byte);
+        Ok(byte)
+    }
+}
+
+fn stream_err(msg: &str) -> GeometryError {
+    GeometryError::SerializationError(format!("GeoJSON stream: {msg}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    const COLLECTION: &str = r#"{
+        "type": "FeatureCollection",
+        "name": "places \"quoted\" ]}",
+        "bbox": [0, 0, 10, 10],
+        "features": [
+            {"type": "Feature", "properties": {"name": "a, [b]"},
+             "geometry": {"type": "Point", "coordinates": [1.5, 2.5]}},
+            {"type": "Feature", "properties": null, "geometry": null},
+            {"type": "Feature", "properties": {},
+             "geometry": {"type": "LineString", "coordinates": [[0, 0], [1, 1], [2, 0]]}},
+            {"type": "Feature", "properties": {"id": 3},
+             "geometry": {"type": "Polygon",
+                          "coordinates": [[[0, 0], [4, 0], [4, 4], [0, 4], [0, 0]]]}}
+        ]
+    }"#;
+
+    fn in_memory(doc: &str) -> Vec<SurrealGeometry> {
+        let value: Value = serde_json::from_str(doc).unwrap();
+        value["features"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|f| !f["geometry"].is_null())
+            .map(|f| from_geojson(&f["geometry"]).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn stream_matches_in_memory_parse() {
+        let streamed: Vec<SurrealGeometry> = from_geojson_stream(Cursor::new(COLLECTION))
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(streamed.len(), 3);
+        assert_eq!(streamed, in_memory(COLLECTION));
+        let types: Vec<&str> = streamed.iter().map(|g| g.type_name()).collect();
+        assert_eq!(types, vec!["Point", "LineString", "Polygon"]);
+    }
+
+    #[test]
+    fn stream_works_through_tiny_reads() {
+        // A reader that hands out one byte per read exercises buffer refills
+        struct OneByte<'a>(&'a [u8]);
+        impl Read for OneByte<'_> {
+            fn read(&mut self, out: &mut [u8]) -> std::io::Result<usize> {
+                match self.0.split_first() {
+                    Some((&b, rest)) if !out.is_empty() => {
+                        out[0] = b;
+                        self.0 = rest;
+                        Ok(1)
+                    }
+                    _ => Ok(0),
+                }
+            }
+        }
+        let streamed: Vec<SurrealGeometry> = from_geojson_stream(OneByte(COLLECTION.as_bytes()))
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(streamed, in_memory(COLLECTION));
+    }
+
+    #[test]
+    fn empty_feature_collection() {
+        let doc = r#"{"type":"FeatureCollection","features":[]}"#;
+        assert_eq!(from_geojson_stream(Cursor::new(doc)).count(), 0);
+    }
+
+    #[test]
+    fn non_feature_collection_rejected() {
+        let doc = r#"{"type":"Point","coordinates":[1,2]}"#;
+        let mut stream = from_geojson_stream(Cursor::new(doc));
+        assert!(matches!(stream.next(), Some(Err(GeometryError::SerializationError(_)))));
+        assert!(stream.next().is_none());
+    }
+
+    #[test]
+    fn truncated_input_errors_then_stops() {
+        let doc = r#"{"type":"FeatureCollection","features":[
+            {"type":"Feature","geometry":{"type":"Point","coordinates":[1,2]}},
+            {"type":"Feature","geometry":{"type":"Point","coor"#;
+        let mut stream = from_geojson_stream(Cursor::new(doc));
+        assert!(stream.next().unwrap().is_ok());
+        assert!(stream.next().unwrap().is_err());
+        assert!(stream.next().is_none());
+    }
+}
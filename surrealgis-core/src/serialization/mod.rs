@@ -1,4 +1,5 @@
 pub mod ewkt;
 pub mod geojson;
+pub mod geojson_stream;
 pub mod wkb;
 pub mod wkt;
@@ -1,20 +1,203 @@
-use wkt::ToWkt;
+use std::fmt::Write;
+use std::str::FromStr;
 
+use wkt::Wkt;
+
+use crate::coordinate::Coordinate;
 use crate::error::GeometryError;
-use crate::geometry::SurrealGeometry;
+use crate::flags::GeometryFlags;
+use crate::geometry::{GeometryType, PolygonData, SurrealGeometry};
 use crate::srid::Srid;
 
 /// Convert a SurrealGeometry to WKT string.
+///
+/// Geometries carrying Z and/or M in any coordinate get the OGC
+/// dimensionality tag, e.g. `POINT Z (1 2 3)` or `POINT ZM (1 2 3 4)`;
+/// plain XY geometries are written untagged, e.g. `POINT(1 2)`. A coordinate
+/// lacking an ordinate the geometry carries is padded with 0.
 pub fn to_wkt(geom: &SurrealGeometry) -> Result<String, GeometryError> {
-    let geo = geom.to_geo()?;
-    Ok(geo.wkt_string())
+    let has_z = geom.flags().contains(GeometryFlags::HAS_Z);
+    let has_m = geom.flags().contains(GeometryFlags::HAS_M);
+    let mut out = String::new();
+    write_geometry(&mut out, geom.geometry_type(), has_z, has_m);
+    Ok(out)
 }
 
 /// Parse a WKT string into a SurrealGeometry with default SRID 4326.
+/// `Z`, `M` and `ZM` tags are honoured, so Z and M values are kept.
 pub fn from_wkt(wkt_str: &str) -> Result<SurrealGeometry, GeometryError> {
-    let geo: geo_types::Geometry<f64> = wkt::TryFromWkt::try_from_wkt_str(wkt_str)
+    from_wkt_with_srid(wkt_str, Srid::DEFAULT)
+}
+
+/// Parse a WKT string into a SurrealGeometry with the given SRID.
+pub(crate) fn from_wkt_with_srid(
+    wkt_str: &str,
+    srid: Srid,
+) -> Result<SurrealGeometry, GeometryError> {
+    let parsed = Wkt::<f64>::from_str(wkt_str)
         .map_err(|e| GeometryError::SerializationError(format!("WKT parse error: {e}")))?;
-    SurrealGeometry::from_geo(&geo, Srid::DEFAULT)
+    from_parsed(&parsed, srid)
+}
+
+/// Build through the validating constructors, closing open rings as the
+/// `geo_types` conversion does, so WKT and EWKT input is checked like any
+/// other geometry.
+fn from_parsed(parsed: &Wkt<f64>, srid: Srid) -> Result<SurrealGeometry, GeometryError> {
+    match parsed {
+        Wkt::Point(p) => {
+            let c = p.0.as_ref().ok_or(GeometryError::EmptyGeometry)?;
+            SurrealGeometry::point_from_coordinate(coord(c)?, srid)
+        }
+        Wkt::LineString(ls) => SurrealGeometry::line_string(coords(&ls.0)?, srid),
+        Wkt::Polygon(poly) => {
+            let (exterior, holes) = rings(&poly.0)?;
+            SurrealGeometry::polygon(exterior, holes, srid)
+        }
+        Wkt::MultiPoint(mp) => SurrealGeometry::multi_point(
+            mp.0.iter()
+                .filter_map(|p| p.0.as_ref())
+                .map(coord)
+                .collect::<Result<_, _>>()?,
+            srid,
+        ),
+        Wkt::MultiLineString(mls) => SurrealGeometry::multi_line_string(
+            mls.0.iter().map(|ls| coords(&ls.0)).collect::<Result<_, _>>()?,
+            srid,
+        ),
+        Wkt::MultiPolygon(mp) => SurrealGeometry::multi_polygon(
+            mp.0.iter()
+                .map(|poly| {
+                    let (exterior, holes) = rings(&poly.0)?;
+                    Ok(PolygonData { exterior, holes })
+                })
+                .collect::<Result<_, GeometryError>>()?,
+            srid,
+        ),
+        Wkt::GeometryCollection(gc) => SurrealGeometry::geometry_collection(
+            gc.0.iter().map(|g| from_parsed(g, srid)).collect::<Result<_, _>>()?,
+            srid,
+        ),
+    }
+}
+
+fn coord(c: &wkt::types::Coord<f64>) -> Result<Coordinate, GeometryError> {
+    match (c.z, c.m) {
+        (Some(z), Some(m)) => Coordinate::new_4d(c.x, c.y, z, m),
+        (Some(z), None) => Coordinate::new_3d(c.x, c.y, z),
+        (None, Some(m)) => Coordinate::new_m(c.x, c.y, m),
+        (None, None) => Coordinate::new(c.x, c.y),
+    }
+}
+
+fn coords(cs: &[wkt::types::Coord<f64>]) -> Result<Vec<Coordinate>, GeometryError> {
+    cs.iter().map(coord).collect()
+}
+
+type Rings = (Vec<Coordinate>, Vec<Vec<Coordinate>>);
+
+fn rings(ls: &[wkt::types::LineString<f64>]) -> Result<Rings, GeometryError> {
+    let (exterior, holes) = ls.split_first().ok_or(GeometryError::EmptyGeometry)?;
+    let holes = holes.iter().map(|h| ring(&h.0)).collect::<Result<_, _>>()?;
+    Ok((ring(&exterior.0)?, holes))
+}
+
+/// A ring's coordinates, with the first repeated at the end if it is open.
+fn ring(cs: &[wkt::types::Coord<f64>]) -> Result<Vec<Coordinate>, GeometryError> {
+    let mut ring = coords(cs)?;
+    if let (Some(first), Some(last)) = (ring.first(), ring.last()) {
+        if first != last {
+            ring.push(first.clone());
+        }
+    }
+    Ok(ring)
+}
+
+fn write_geometry(out: &mut String, gt: &GeometryType, has_z: bool, has_m: bool) {
+    let name = match gt {
+        GeometryType::Point(_) => "POINT",
+        GeometryType::LineString(_) => "LINESTRING",
+        GeometryType::Polygon { .. } => "POLYGON",
+        GeometryType::MultiPoint(_) => "MULTIPOINT",
+        GeometryType::MultiLineString(_) => "MULTILINESTRING",
+        GeometryType::MultiPolygon(_) => "MULTIPOLYGON",
+        GeometryType::GeometryCollection(_) => "GEOMETRYCOLLECTION",
+    };
+    let tag = match (has_z, has_m) {
+        (false, false) => "",
+        (true, false) => " Z",
+        (false, true) => " M",
+        (true, true) => " ZM",
+    };
+    out.push_str(name);
+    out.push_str(tag);
+
+    let mut body = String::new();
+    let dims = (has_z, has_m);
+    match gt {
+        GeometryType::Point(c) => write_coords(&mut body, std::slice::from_ref(c), dims),
+        GeometryType::LineString(cs) => write_coords(&mut body, cs, dims),
+        GeometryType::Polygon { exterior, holes } => write_rings(&mut body, exterior, holes, dims),
+        GeometryType::MultiPoint(cs) => write_list(&mut body, cs, |b, c| {
+            write_coords(b, std::slice::from_ref(c), dims)
+        }),
+        GeometryType::MultiLineString(lines) => {
+            write_list(&mut body, lines, |b, l| write_coords(b, l, dims))
+        }
+        GeometryType::MultiPolygon(polys) => write_list(&mut body, polys, |b, p| {
+            write_rings(b, &p.exterior, &p.holes, dims)
+        }),
+        GeometryType::GeometryCollection(geoms) => write_list(&mut body, geoms, |b, g| {
+            write_geometry(b, g.geometry_type(), has_z, has_m)
+        }),
+    }
+
+    if body.is_empty() {
+        out.push_str(" EMPTY");
+    } else {
+        if !tag.is_empty() {
+            out.push(' ');
+        }
+        out.push_str(&body);
+    }
+}
+
+/// Write `(a,b,...)`, or nothing for an empty list so the caller emits `EMPTY`.
+fn write_list<T>(out: &mut String, items: &[T], mut item: impl FnMut(&mut String, &T)) {
+    if items.is_empty() {
+        return;
+    }
+    out.push('(');
+    for (i, it) in items.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        item(out, it);
+    }
+    out.push(')');
+}
+
+fn write_rings(
+    out: &mut String,
+    exterior: &[Coordinate],
+    holes: &[Vec<Coordinate>],
+    dims: (bool, bool),
+) {
+    let rings: Vec<&[Coordinate]> = std::iter::once(exterior)
+        .chain(holes.iter().map(Vec::as_slice))
+        .collect();
+    write_list(out, &rings, |b, r| write_coords(b, r, dims));
+}
+
+fn write_coords(out: &mut String, cs: &[Coordinate], (has_z, has_m): (bool, bool)) {
+    write_list(out, cs, |b, c| {
+        let _ = write!(b, "{} {}", c.x(), c.y());
+        if has_z {
+            let _ = write!(b, " {}", c.z().unwrap_or(0.0));
+        }
+        if has_m {
+            let _ = write!(b, " {}", c.m().unwrap_or(0.0));
+        }
+    });
 }
 
 #[cfg(test)]
@@ -72,4 +255,67 @@ mod tests {
         let p = from_wkt("POINT(5 10)").unwrap();
         assert_eq!(p.srid().code(), 4326);
     }
+
+    #[test]
+    fn point_z_emits_tag_and_roundtrips() {
+        let c = Coordinate::new_3d(1.0, 2.0, 3.0).unwrap();
+        let p = SurrealGeometry::point_from_coordinate(c, Srid::WGS84).unwrap();
+        let wkt_str = to_wkt(&p).unwrap();
+        assert_eq!(wkt_str, "POINT Z (1 2 3)");
+        let roundtripped = from_wkt(&wkt_str).unwrap();
+        assert_eq!(roundtripped.dimension(), 3);
+        assert_eq!(roundtripped.geometry_type(), p.geometry_type());
+    }
+
+    #[test]
+    fn point_zm_emits_tag_and_roundtrips() {
+        let c = Coordinate::new_4d(1.0, 2.0, 3.0, 4.0).unwrap();
+        let p = SurrealGeometry::point_from_coordinate(c, Srid::WGS84).unwrap();
+        let wkt_str = to_wkt(&p).unwrap();
+        assert_eq!(wkt_str, "POINT ZM (1 2 3 4)");
+        let roundtripped = from_wkt(&wkt_str).unwrap();
+        assert_eq!(roundtripped.dimension(), 4);
+        assert_eq!(roundtripped.geometry_type(), p.geometry_type());
+    }
+
+    #[test]
+    fn measured_line_emits_m_tag() {
+        let coords = vec![
+            Coordinate::new_m(0.0, 0.0, 10.0).unwrap(),
+            Coordinate::new_m(1.0, 1.0, 20.0).unwrap(),
+        ];
+        let ls = SurrealGeometry::line_string(coords, Srid::WGS84).unwrap();
+        let wkt_str = to_wkt(&ls).unwrap();
+        assert_eq!(wkt_str, "LINESTRING M (0 0 10,1 1 20)");
+        let roundtripped = from_wkt(&wkt_str).unwrap();
+        assert_eq!(roundtripped.geometry_type(), ls.geometry_type());
+    }
+
+    #[test]
+    fn xy_geometry_is_untagged() {
+        let p = SurrealGeometry::point(1.5, -2.0, Srid::WGS84).unwrap();
+        assert_eq!(to_wkt(&p).unwrap(), "POINT(1.5 -2)");
+    }
+
+    #[test]
+    fn unclosed_ring_is_closed() {
+        let poly = from_wkt("POLYGON((0 0,10 0,10 10,0 10))").unwrap();
+        assert_eq!(poly.type_name(), "Polygon");
+        assert_eq!(poly.num_points(), 5);
+    }
+
+    #[test]
+    fn single_point_linestring_is_rejected() {
+        assert!(from_wkt("LINESTRING(1 1)").is_err());
+    }
+
+    #[test]
+    fn empty_multi_geometries_are_rejected() {
+        for wkt_str in ["MULTIPOINT EMPTY", "MULTILINESTRING EMPTY", "MULTIPOLYGON EMPTY"] {
+            assert!(
+                matches!(from_wkt(wkt_str), Err(GeometryError::EmptyGeometry)),
+                "{wkt_str} should be rejected"
+            );
+        }
+    }
 }
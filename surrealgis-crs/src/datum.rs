@@ -0,0 +1,189 @@
+use crate::error::CrsError;
+
+/// An explicit datum transformation to apply to a source CRS definition.
+///
+/// proj4rs has no `+proj=pipeline` support, so the shift is expressed the
+/// proj4 way: by rewriting the source definition's datum to carry the
+/// requested `+towgs84` or `+nadgrids` parameters.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DatumShift {
+    /// Helmert parameters to WGS 84: `dx,dy,dz` in meters, optionally followed
+    /// by rotations `rx,ry,rz` in arc-seconds and scale `s` in ppm.
+    ToWgs84(Vec<f64>),
+    /// Comma-separated grid shift files; a leading `@` marks a grid optional.
+    Grids(String),
+}
+
+impl DatumShift {
+    /// Returns the proj4 parameter for this shift, e.g. `+towgs84=-8,160,176`.
+    pub fn to_proj4_param(&self) -> Result<String, CrsError> {
+        match self {
+            DatumShift::ToWgs84(params) => {
+                if params.len() != 3 && params.len() != 7 {
+                    return Err(CrsError::ProjectionError(format!(
+                        "towgs84 needs 3 or 7 parameters, got {}",
+                        params.len()
+                    )));
+                }
+                if params.iter().any(|p| !p.is_finite()) {
+                    return Err(CrsError::ProjectionError(
+                        "towgs84 parameters must be finite".to_string(),
+                    ));
+                }
+                let joined: Vec<String> = params.iter().map(|p| p.to_string()).collect();
+                Ok(format!("+towgs84={}", joined.join(",")))
+            }
+            DatumShift::Grids(grids) => {
+                if grids.trim().is_empty() || grids.contains(char::is_whitespace) {
+                    return Err(CrsError::ProjectionError(format!(
+                        "Invalid nadgrids list: '{grids}'"
+                    )));
+                }
+                Ok(format!("+nadgrids={grids}"))
+            }
+        }
+    }
+
+    /// Rewrites a proj4 definition so this shift replaces its datum.
+    ///
+    /// Any existing `+datum`, `+towgs84` or `+nadgrids` parameter is removed.
+    /// When a named datum is dropped and the definition has no explicit
+    /// ellipsoid, the datum's ellipsoid is kept as `+ellps`.
+    pub fn apply(&self, proj4: &str) -> Result<String, CrsError> {
+        let param = self.to_proj4_param()?;
+        let mut datum = None;
+        let mut tokens: Vec<&str> = Vec::new();
+        for token in proj4.split_whitespace() {
+            if let Some(name) = token.strip_prefix("+datum=") {
+                datum = Some(name);
+            } else if !token.starts_with("+towgs84=") && !token.starts_with("+nadgrids=") {
+                tokens.push(token);
+            }
+        }
+
+        let has_ellipsoid = tokens
+            .iter()
+            .any(|t| t.starts_with("+ellps=") || t.starts_with("+a=") || t.starts_with("+R="));
+        let ellps_param;
+        if let (Some(name), false) = (datum, has_ellipsoid) {
+            let ellps = datum_ellipsoid(name).ok_or_else(|| {
+                CrsError::ProjectionError(format!(
+                    "Unknown datum '{name}'; add an explicit +ellps to the definition"
+                ))
+            })?;
+            ellps_param = format!("+ellps={ellps}");
+            tokens.push(&ellps_param);
+        }
+        tokens.push(&param);
+        Ok(tokens.join(" "))
+    }
+}
+
+/// Rewrites a proj4 definition to use the named ellipsoid, e.g. `"clrk66"`.
+///
+/// Any existing `+ellps`, `+a`, `+b`, `+rf`, `+f` or `+R` parameter is
+/// removed, and so is a named `+datum`, since it implies its own ellipsoid.
+/// Explicit `+towgs84` and `+nadgrids` parameters are kept.
+pub fn apply_ellipsoid(proj4: &str, ellps: &str) -> Result<String, CrsError> {
+    if ellps.is_empty() || !ellps.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return Err(CrsError::ProjectionError(format!(
+            "Invalid ellipsoid name: '{ellps}'"
+        )));
+    }
+    const REPLACED: [&str; 7] = ["+datum=", "+ellps=", "+a=", "+b=", "+rf=", "+f=", "+R="];
+    let mut tokens: Vec<String> = proj4
+        .split_whitespace()
+        .filter(|t| !REPLACED.iter().any(|prefix| t.starts_with(prefix)))
+        .map(String::from)
+        .collect();
+    tokens.push(format!("+ellps={ellps}"));
+    Ok(tokens.join(" "))
+}
+
+/// Ellipsoid of a proj4 named datum.
+fn datum_ellipsoid(datum: &str) -> Option<&'static str> {
+    match datum {
+        "WGS84" => Some("WGS84"),
+        "NAD83" => Some("GRS80"),
+        "NAD27" => Some("clrk66"),
+        "OSGB36" => Some("airy"),
+        "potsdam" => Some("bessel"),
+        "ire65" => Some("mod_airy"),
+        "nzgd49" => Some("intl"),
+        "GGRS87" => Some("GRS80"),
+        "carthage" => Some("clrk80ign"),
+        "hermannskogel" => Some("bessel"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn towgs84_param_three_and_seven() {
+        let three = DatumShift::ToWgs84(vec![-8.0, 160.0, 176.0]);
+        assert_eq!(three.to_proj4_param().unwrap(), "+towgs84=-8,160,176");
+
+        let seven = DatumShift::ToWgs84(vec![1.0, 2.0, 3.0, 0.1, 0.2, 0.3, 1.5]);
+        assert_eq!(
+            seven.to_proj4_param().unwrap(),
+            "+towgs84=1,2,3,0.1,0.2,0.3,1.5"
+        );
+    }
+
+    #[test]
+    fn towgs84_wrong_arity_rejected() {
+        let shift = DatumShift::ToWgs84(vec![1.0, 2.0]);
+        assert!(shift.to_proj4_param().is_err());
+        let shift = DatumShift::ToWgs84(vec![1.0, f64::NAN, 3.0]);
+        assert!(shift.to_proj4_param().is_err());
+    }
+
+    #[test]
+    fn apply_replaces_named_datum_with_its_ellipsoid() {
+        let shift = DatumShift::ToWgs84(vec![-8.0, 160.0, 176.0]);
+        let out = shift
+            .apply("+proj=longlat +datum=NAD27 +no_defs +type=crs")
+            .unwrap();
+        assert_eq!(
+            out,
+            "+proj=longlat +no_defs +type=crs +ellps=clrk66 +towgs84=-8,160,176"
+        );
+    }
+
+    #[test]
+    fn apply_replaces_existing_shift() {
+        let shift = DatumShift::Grids("@conus".to_string());
+        let out = shift
+            .apply("+proj=longlat +ellps=clrk66 +towgs84=-8,160,176 +no_defs")
+            .unwrap();
+        assert!(out.contains("+nadgrids=@conus"));
+        assert!(!out.contains("towgs84"));
+        assert!(out.contains("+ellps=clrk66"));
+    }
+
+    #[test]
+    fn apply_unknown_datum_without_ellipsoid_rejected() {
+        let shift = DatumShift::ToWgs84(vec![0.0, 0.0, 0.0]);
+        assert!(shift.apply("+proj=longlat +datum=mystery").is_err());
+    }
+
+    #[test]
+    fn apply_ellipsoid_replaces_datum_and_ellipsoid() {
+        let out = apply_ellipsoid("+proj=utm +zone=18 +datum=WGS84 +units=m +no_defs", "clrk66")
+            .unwrap();
+        assert_eq!(out, "+proj=utm +zone=18 +units=m +no_defs +ellps=clrk66");
+
+        let out = apply_ellipsoid("+proj=longlat +a=6378137 +rf=298.257 +towgs84=1,2,3", "intl")
+            .unwrap();
+        assert_eq!(out, "+proj=longlat +towgs84=1,2,3 +ellps=intl");
+    }
+
+    #[test]
+    fn apply_ellipsoid_rejects_bad_names() {
+        assert!(apply_ellipsoid("+proj=longlat", "").is_err());
+        assert!(apply_ellipsoid("+proj=longlat", "GRS80 +a=1").is_err());
+    }
+}
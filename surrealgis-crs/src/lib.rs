@@ -1,4 +1,6 @@
+pub mod datum;
 pub mod error;
 pub mod projection;
 pub mod registry;
+pub mod suggest;
 pub mod transform;
@@ -10,17 +10,33 @@ pub struct Projection {
     proj: Proj,
     srid: i32,
     is_geographic: bool,
+    unit: Option<&'static str>,
 }
 
 impl Projection {
     /// Create a new projection from a known SRID.
     ///
-    /// First attempts to use the built-in `crs-definitions` feature of proj4rs
+    /// Definitions registered at runtime via `registry::register_srid` win,
+    /// followed by local definitions that replace grid-based datum shifts.
+    /// Otherwise this first attempts to use the built-in `crs-definitions` feature of proj4rs
     /// for the most accurate definition. Falls back to the local registry's
     /// proj4 string if the EPSG code is not found in proj4rs's built-in database.
     pub fn new(srid: i32) -> Result<Self, CrsError> {
-        // Try proj4rs built-in EPSG definitions first (most accurate)
-        let proj = if srid > 0 && srid <= u16::MAX as i32 {
+        let custom = registry::custom_proj4_string(srid);
+        let unit = custom
+            .as_deref()
+            .or_else(|| registry::get_proj4_string(srid))
+            .and_then(registry::units_of_definition);
+        let proj = if let Some(proj4_str) = &custom {
+            Proj::from_proj_string(proj4_str)
+                .map_err(|e| CrsError::ProjectionError(e.to_string()))
+        } else if registry::overrides_builtin(srid) {
+            let proj4_str = registry::get_proj4_string(srid)
+                .ok_or(CrsError::UnknownSrid(srid))?;
+            Proj::from_proj_string(proj4_str)
+                .map_err(|e| CrsError::ProjectionError(e.to_string()))
+        } else if srid > 0 && srid <= u16::MAX as i32 {
+            // Try proj4rs built-in EPSG definitions first (most accurate)
             Proj::from_epsg_code(srid as u16).or_else(|_| {
                 // Fall back to our local registry
                 let proj4_str = registry::get_proj4_string(srid)
@@ -40,6 +56,22 @@ impl Projection {
             proj,
             srid,
             is_geographic: registry::is_geographic(srid),
+            unit,
+        })
+    }
+
+    /// Create a projection directly from a proj4 string, bypassing the registry.
+    ///
+    /// `srid` is metadata only: it becomes the SRID of geometries produced in
+    /// this projection, since an ad-hoc definition has no code of its own.
+    pub fn from_proj4(proj4: &str, srid: i32) -> Result<Self, CrsError> {
+        let proj = Proj::from_proj_string(proj4)
+            .map_err(|e| CrsError::ProjectionError(e.to_string()))?;
+        Ok(Self {
+            proj,
+            srid,
+            is_geographic: registry::is_latlong_definition(proj4),
+            unit: registry::units_of_definition(proj4),
         })
     }
 
@@ -57,6 +89,15 @@ impl Projection {
     pub fn is_geographic(&self) -> bool {
         self.is_geographic
     }
+
+    /// Returns the unit coordinates are expressed in ("degrees", "meters",
+    /// "feet", ...), or None when the definition does not state one.
+    ///
+    /// Codes resolved only through the proj4rs EPSG database have no local
+    /// definition to read, so their unit is also None.
+    pub fn unit(&self) -> Option<&'static str> {
+        self.unit
+    }
 }
 
 impl fmt::Debug for Projection {
@@ -64,6 +105,7 @@ impl fmt::Debug for Projection {
         f.debug_struct("Projection")
             .field("srid", &self.srid)
             .field("is_geographic", &self.is_geographic)
+            .field("unit", &self.unit)
             .finish()
     }
 }
@@ -124,10 +166,43 @@ mod tests {
         let _proj_ref = projection.proj();
     }
 
+    #[test]
+    fn registered_srid_is_used() {
+        registry::register_srid(910_101, "+proj=longlat +ellps=GRS80 +no_defs").unwrap();
+        let proj = Projection::new(910_101).unwrap();
+        assert_eq!(proj.srid(), 910_101);
+        assert!(proj.is_geographic());
+        assert!(registry::unregister_srid(910_101));
+    }
+
+    #[test]
+    fn from_proj4_string() {
+        let proj = Projection::from_proj4("+proj=longlat +datum=WGS84 +no_defs", 4326).unwrap();
+        assert_eq!(proj.srid(), 4326);
+        assert!(proj.is_geographic());
+    }
+
+    #[test]
+    fn from_invalid_proj4_string() {
+        let result = Projection::from_proj4("+proj=bogus", 1);
+        assert!(matches!(result, Err(CrsError::ProjectionError(_))));
+    }
+
     #[test]
     fn laea_europe_projection() {
         let proj = Projection::new(3035).unwrap();
         assert_eq!(proj.srid(), 3035);
         assert!(!proj.is_geographic());
     }
+
+    #[test]
+    fn unit_from_definition() {
+        assert_eq!(Projection::new(4326).unwrap().unit(), Some("degrees"));
+        assert_eq!(Projection::new(3857).unwrap().unit(), Some("meters"));
+        assert_eq!(Projection::new(32618).unwrap().unit(), Some("meters"));
+        let feet = Projection::from_proj4("+proj=merc +ellps=WGS84 +units=us-ft", 1).unwrap();
+        assert_eq!(feet.unit(), Some("US survey feet"));
+        let unstated = Projection::from_proj4("+proj=merc +ellps=WGS84 +no_defs", 1).unwrap();
+        assert_eq!(unstated.unit(), None);
+    }
 }
@@ -2,7 +2,103 @@
 //!
 //! Provides proj4 string lookups, geographic CRS classification, and
 //! enumeration of known SRIDs for the most commonly used coordinate
-//! reference systems.
+//! reference systems. Codes outside the built-in table can be added at
+//! runtime with [`register_srid`].
+
+use std::collections::HashMap;
+use std::sync::{OnceLock, PoisonError, RwLock};
+
+use proj4rs::Proj;
+
+use crate::error::CrsError;
+
+/// A proj4 definition registered at runtime.
+struct CustomDefinition {
+    proj4: String,
+    is_geographic: bool,
+}
+
+fn custom_definitions() -> &'static RwLock<HashMap<i32, CustomDefinition>> {
+    static CUSTOM: OnceLock<RwLock<HashMap<i32, CustomDefinition>>> = OnceLock::new();
+    CUSTOM.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Registers (or replaces) a proj4 definition for an SRID at runtime.
+///
+/// The definition is parsed up front so a bad string fails here rather than
+/// on the first transform. Registered codes take precedence over both the
+/// proj4rs EPSG database and the built-in table.
+pub fn register_srid(srid: i32, proj4: impl Into<String>) -> Result<(), CrsError> {
+    if srid <= 0 {
+        return Err(CrsError::ProjectionError(format!(
+            "SRID must be positive, got {srid}"
+        )));
+    }
+    let proj4 = proj4.into();
+    Proj::from_proj_string(&proj4)
+        .map_err(|e| CrsError::ProjectionError(e.to_string()))?;
+    let definition = CustomDefinition {
+        is_geographic: is_latlong_definition(&proj4),
+        proj4,
+    };
+    custom_definitions()
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(srid, definition);
+    Ok(())
+}
+
+/// Removes a runtime-registered definition. Returns true if one was present.
+pub fn unregister_srid(srid: i32) -> bool {
+    custom_definitions()
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .remove(&srid)
+        .is_some()
+}
+
+/// Returns the runtime-registered proj4 string for an SRID, if any.
+pub fn custom_proj4_string(srid: i32) -> Option<String> {
+    custom_definitions()
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&srid)
+        .map(|d| d.proj4.clone())
+}
+
+/// Returns true if a proj4 string describes a lon/lat (geographic) CRS.
+pub(crate) fn is_latlong_definition(proj4: &str) -> bool {
+    proj4.contains("+proj=longlat") || proj4.contains("+proj=latlong")
+}
+
+/// Returns the linear unit a proj4 string works in, e.g. "meters".
+///
+/// Geographic definitions are in "degrees". Projected definitions must name
+/// their unit with `+units=`; anything else (including `+to_meter=`) is
+/// reported as unknown rather than guessed.
+pub(crate) fn units_of_definition(proj4: &str) -> Option<&'static str> {
+    if is_latlong_definition(proj4) {
+        return Some("degrees");
+    }
+    let units = proj4
+        .split_whitespace()
+        .find_map(|param| param.strip_prefix("+units="))?;
+    match units {
+        "m" => Some("meters"),
+        "km" => Some("kilometers"),
+        "ft" => Some("feet"),
+        "us-ft" => Some("US survey feet"),
+        _ => None,
+    }
+}
+
+fn custom_is_geographic(srid: i32) -> Option<bool> {
+    custom_definitions()
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&srid)
+        .map(|d| d.is_geographic)
+}
 
 /// Returns the proj4 definition string for a given SRID, or None if unknown.
 pub fn get_proj4_string(srid: i32) -> Option<&'static str> {
@@ -10,7 +106,9 @@ pub fn get_proj4_string(srid: i32) -> Option<&'static str> {
         // Geographic CRS
         4326 => Some("+proj=longlat +datum=WGS84 +no_defs +type=crs"),
         4269 => Some("+proj=longlat +datum=NAD83 +no_defs +type=crs"),
-        4267 => Some("+proj=longlat +datum=NAD27 +no_defs +type=crs"),
+        // NAD27 uses the CONUS Helmert shift (EPSG:1173) instead of +datum=NAD27,
+        // whose grid files are not bundled and would silently shift by nothing.
+        4267 => Some("+proj=longlat +ellps=clrk66 +towgs84=-8,160,176,0,0,0,0 +no_defs +type=crs"),
         4258 => Some("+proj=longlat +ellps=GRS80 +towgs84=0,0,0,0,0,0,0 +no_defs +type=crs"),
         4148 => Some("+proj=longlat +ellps=GRS80 +towgs84=0,0,0,0,0,0,0 +no_defs +type=crs"),
         4674 => Some("+proj=longlat +ellps=GRS80 +towgs84=0,0,0,0,0,0,0 +no_defs +type=crs"),
@@ -83,17 +181,57 @@ pub fn get_proj4_string(srid: i32) -> Option<&'static str> {
     }
 }
 
+/// Returns true if the local registry definition must win over proj4rs's
+/// built-in EPSG table.
+///
+/// proj4rs ships no grid files, so built-in definitions that rely on
+/// `+nadgrids` lose their datum shift; these SRIDs carry explicit
+/// `+towgs84` parameters locally instead.
+pub(crate) fn overrides_builtin(srid: i32) -> bool {
+    matches!(srid, 4267)
+}
+
 /// Returns true if the given SRID represents a geographic (lon/lat in degrees) CRS.
 pub fn is_geographic(srid: i32) -> bool {
-    matches!(srid, 4326 | 4269 | 4267 | 4258 | 4148 | 4674 | 4283 | 4612 | 4490)
+    custom_is_geographic(srid).unwrap_or(matches!(
+        srid,
+        4326 | 4269 | 4267 | 4258 | 4148 | 4674 | 4283 | 4612 | 4490
+    ))
 }
 
-/// Returns true if the given SRID is in the known registry.
+/// Maps an SRID to the representative code of its equivalence class.
+///
+/// Codes in a class describe the same horizontal CRS (e.g. a 2D geographic
+/// CRS and its 3D counterpart, or deprecated aliases of Web Mercator), so
+/// x/y coordinates are interchangeable without reprojection.
+fn canonical_srid(srid: i32) -> i32 {
+    match srid {
+        // WGS 84 (3D)
+        4979 => 4326,
+        // ETRS89 (3D)
+        4937 => 4258,
+        // GDA94 (3D)
+        4939 => 4283,
+        // Legacy Web Mercator aliases
+        900913 | 3785 => 3857,
+        other => other,
+    }
+}
+
+/// Returns true if two SRIDs are horizontally identical, so coordinates in
+/// one can be compared with coordinates in the other directly.
+pub fn srid_equivalent(a: i32, b: i32) -> bool {
+    canonical_srid(a) == canonical_srid(b)
+}
+
+/// Returns true if the given SRID is in the known registry,
+/// either built in or registered at runtime.
 pub fn is_known_srid(srid: i32) -> bool {
-    get_proj4_string(srid).is_some()
+    get_proj4_string(srid).is_some() || custom_is_geographic(srid).is_some()
 }
 
-/// Returns a sorted list of all known SRID codes in the registry.
+/// Returns a sorted list of all built-in SRID codes in the registry.
+/// Runtime-registered codes are not included.
 pub fn list_known_srids() -> Vec<i32> {
     let mut srids = vec![
         // Geographic
@@ -138,7 +276,9 @@ mod tests {
     fn lookup_nad27() {
         let proj4 = get_proj4_string(4267).unwrap();
         assert!(proj4.contains("+proj=longlat"));
-        assert!(proj4.contains("NAD27"));
+        assert!(proj4.contains("+ellps=clrk66"));
+        assert!(proj4.contains("+towgs84=-8,160,176"));
+        assert!(overrides_builtin(4267));
     }
 
     #[test]
@@ -258,6 +398,58 @@ mod tests {
         assert_eq!(srids.len(), 48);
     }
 
+    #[test]
+    fn srid_equivalent_classes() {
+        assert!(srid_equivalent(4326, 4326));
+        assert!(srid_equivalent(4326, 4979));
+        assert!(srid_equivalent(4979, 4326));
+        assert!(srid_equivalent(3857, 900913));
+        assert!(srid_equivalent(3785, 900913));
+        assert!(srid_equivalent(4258, 4937));
+    }
+
+    #[test]
+    fn srid_not_equivalent() {
+        assert!(!srid_equivalent(4326, 3857));
+        assert!(!srid_equivalent(4326, 4269));
+        assert!(!srid_equivalent(4979, 4937));
+    }
+
+    #[test]
+    fn register_custom_srid() {
+        // A throwaway code: the registry is process-wide and shared by every test
+        register_srid(
+            910_003,
+            "+proj=utm +zone=32 +ellps=GRS80 +towgs84=0,0,0,0,0,0,0 +units=m +no_defs",
+        )
+        .unwrap();
+        assert!(is_known_srid(910_003));
+        assert!(!is_geographic(910_003));
+        assert!(custom_proj4_string(910_003).unwrap().contains("+zone=32"));
+        assert!(!list_known_srids().contains(&910_003));
+        assert!(unregister_srid(910_003));
+    }
+
+    #[test]
+    fn register_custom_geographic_srid() {
+        register_srid(910_001, "+proj=longlat +ellps=GRS80 +no_defs").unwrap();
+        assert!(is_geographic(910_001));
+        assert!(unregister_srid(910_001));
+        assert!(!is_known_srid(910_001));
+    }
+
+    #[test]
+    fn register_invalid_proj4_rejected() {
+        let result = register_srid(910_002, "+proj=notaprojection");
+        assert!(matches!(result, Err(CrsError::ProjectionError(_))));
+        assert!(!is_known_srid(910_002));
+    }
+
+    #[test]
+    fn register_non_positive_srid_rejected() {
+        assert!(register_srid(0, "+proj=longlat +datum=WGS84 +no_defs").is_err());
+    }
+
     #[test]
     fn nsidc_ease_grid_srids() {
         assert!(is_known_srid(3408));
@@ -0,0 +1,138 @@
+use surrealgis_core::geometry::SurrealGeometry;
+
+use crate::registry;
+
+/// Suggests a projected CRS in which planar area is accurate for a
+/// geographic geometry.
+///
+/// Planar area of lon/lat coordinates is in "square degrees", whose size
+/// shrinks with latitude, so it is not a usable area. `st_area` measures
+/// geographic input on the ellipsoid instead; for planar work (overlay,
+/// buffering, then measuring) reproject into the suggested CRS:
+///
+/// 1. A geometry inside one UTM zone gets that zone (326xx north, 327xx
+///    south). UTM is conformal, but its scale stays within 0.04% of 1
+///    inside a zone, so area error is well under 0.1%.
+/// 2. Larger geometries inside Europe get ETRS89 / LAEA Europe (3035), and
+///    inside the United States the US National Atlas Equal Area (2163).
+/// 3. Anything else gets the global cylindrical equal-area EASE grid (3410).
+///
+/// Returns `None` for geometries that are already projected or have no
+/// bounding box.
+pub fn recommended_area_crs(geom: &SurrealGeometry) -> Option<i32> {
+    if !registry::is_geographic(geom.srid().code()) {
+        return None;
+    }
+    let bbox = geom.bbox()?;
+
+    if bbox.min_y >= -80.0 && bbox.max_y <= 84.0 {
+        let zone = utm_zone(bbox.min_x);
+        if zone == utm_zone(bbox.max_x) {
+            let center_y = (bbox.min_y + bbox.max_y) / 2.0;
+            let base = if center_y >= 0.0 { 32600 } else { 32700 };
+            return Some(base + zone);
+        }
+    }
+
+    let within = |min_x: f64, min_y: f64, max_x: f64, max_y: f64| {
+        bbox.min_x >= min_x && bbox.max_x <= max_x && bbox.min_y >= min_y && bbox.max_y <= max_y
+    };
+    if within(-35.58, 24.6, 44.83, 84.73) {
+        Some(3035)
+    } else if within(-180.0, 15.56, -65.69, 74.71) {
+        Some(2163)
+    } else {
+        Some(3410)
+    }
+}
+
+/// Suggests the UTM zone (326xx north, 327xx south) containing the centre
+/// of a geographic geometry, for measuring it as it would be drawn on a
+/// projected map. Unlike [`recommended_area_crs`] this always picks a zone,
+/// even for geometries spanning several.
+///
+/// Returns `None` for geometries that are already projected or have no
+/// bounding box.
+pub fn best_fit_utm(geom: &SurrealGeometry) -> Option<i32> {
+    if !registry::is_geographic(geom.srid().code()) {
+        return None;
+    }
+    let bbox = geom.bbox()?;
+    let center_y = (bbox.min_y + bbox.max_y) / 2.0;
+    let base = if center_y >= 0.0 { 32600 } else { 32700 };
+    Some(base + utm_zone((bbox.min_x + bbox.max_x) / 2.0))
+}
+
+/// UTM zone number (1-60) containing a longitude.
+fn utm_zone(lon: f64) -> i32 {
+    let zone = ((lon + 180.0) / 6.0).floor() as i32 + 1;
+    zone.clamp(1, 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use surrealgis_core::coordinate::Coordinate;
+    use surrealgis_core::srid::Srid;
+
+    use crate::projection::Projection;
+
+    fn rect(min_x: f64, min_y: f64, max_x: f64, max_y: f64, srid: Srid) -> SurrealGeometry {
+        let exterior = vec![
+            Coordinate::new(min_x, min_y).unwrap(),
+            Coordinate::new(max_x, min_y).unwrap(),
+            Coordinate::new(max_x, max_y).unwrap(),
+            Coordinate::new(min_x, max_y).unwrap(),
+            Coordinate::new(min_x, min_y).unwrap(),
+        ];
+        SurrealGeometry::polygon(exterior, vec![], srid).unwrap()
+    }
+
+    #[test]
+    fn mid_latitude_polygon_gets_its_utm_zone() {
+        // Paris sits in UTM zone 31N
+        let paris = rect(2.22, 48.81, 2.47, 48.90, Srid::WGS84);
+        let srid = recommended_area_crs(&paris).unwrap();
+        assert_eq!(srid, 32631);
+        assert!(Projection::new(srid).is_ok());
+    }
+
+    #[test]
+    fn southern_hemisphere_uses_south_zone() {
+        // Sydney, zone 56S
+        let sydney = rect(151.0, -34.0, 151.3, -33.7, Srid::WGS84);
+        assert_eq!(recommended_area_crs(&sydney), Some(32756));
+    }
+
+    #[test]
+    fn multi_zone_european_polygon_gets_laea_europe() {
+        let central_europe = rect(5.0, 45.0, 20.0, 55.0, Srid::WGS84);
+        assert_eq!(recommended_area_crs(&central_europe), Some(3035));
+    }
+
+    #[test]
+    fn multi_zone_us_polygon_gets_national_atlas() {
+        let kansas_to_ohio = rect(-102.0, 37.0, -80.5, 42.0, Srid::WGS84);
+        assert_eq!(recommended_area_crs(&kansas_to_ohio), Some(2163));
+    }
+
+    #[test]
+    fn other_large_polygon_gets_global_equal_area() {
+        let siberia = rect(60.0, 50.0, 140.0, 70.0, Srid::WGS84);
+        assert_eq!(recommended_area_crs(&siberia), Some(3410));
+    }
+
+    #[test]
+    fn projected_geometry_has_no_suggestion() {
+        let projected = rect(0.0, 0.0, 1000.0, 1000.0, Srid::WEB_MERCATOR);
+        assert_eq!(recommended_area_crs(&projected), None);
+    }
+
+    #[test]
+    fn utm_zone_edges() {
+        assert_eq!(utm_zone(-180.0), 1);
+        assert_eq!(utm_zone(-174.0), 2);
+        assert_eq!(utm_zone(0.0), 31);
+        assert_eq!(utm_zone(180.0), 60);
+    }
+}
@@ -1,9 +1,12 @@
+use proj4rs::errors::Error as ProjError;
 use surrealgis_core::coordinate::Coordinate;
 use surrealgis_core::geometry::{GeometryType, PolygonData, SurrealGeometry};
 use surrealgis_core::srid::Srid;
 
+use crate::datum::{apply_ellipsoid, DatumShift};
 use crate::error::CrsError;
 use crate::projection::Projection;
+use crate::registry;
 
 /// Transforms a geometry from one coordinate reference system to another.
 ///
@@ -17,6 +20,29 @@ pub fn transform_geometry(
     geom: &SurrealGeometry,
     from_srid: i32,
     to_srid: i32,
+) -> Result<SurrealGeometry, CrsError> {
+    transform_geometry_impl(geom, from_srid, to_srid, false)
+}
+
+/// Transforms a geometry like [`transform_geometry`], but always emits 3D
+/// coordinates.
+///
+/// Inputs without Z enter the proj pipeline at height 0 and come out with
+/// whatever height the target CRS assigns, which is what vertical datum
+/// shifts need. Geographic degree/radian conversion applies to x/y only.
+pub fn transform_geometry_3d(
+    geom: &SurrealGeometry,
+    from_srid: i32,
+    to_srid: i32,
+) -> Result<SurrealGeometry, CrsError> {
+    transform_geometry_impl(geom, from_srid, to_srid, true)
+}
+
+fn transform_geometry_impl(
+    geom: &SurrealGeometry,
+    from_srid: i32,
+    to_srid: i32,
+    force_z: bool,
 ) -> Result<SurrealGeometry, CrsError> {
     if from_srid == to_srid {
         return Err(CrsError::SameSrid(from_srid));
@@ -25,18 +51,138 @@ pub fn transform_geometry(
     let src_proj = Projection::new(from_srid)?;
     let dst_proj = Projection::new(to_srid)?;
 
-    let target_srid = Srid::new(to_srid)
+    transform_between(geom, &src_proj, &dst_proj, force_z)
+}
+
+/// Transforms a geometry between two explicit proj4 definitions.
+///
+/// Neither string is looked up in the registry, so this works for ad-hoc
+/// CRSs such as local grids. Since there is no EPSG code to infer, the
+/// result is tagged with `to_srid`.
+pub fn transform_geometry_proj4(
+    geom: &SurrealGeometry,
+    from_proj4: &str,
+    to_proj4: &str,
+    to_srid: i32,
+) -> Result<SurrealGeometry, CrsError> {
+    let src_proj = Projection::from_proj4(from_proj4, geom.srid().code())?;
+    let dst_proj = Projection::from_proj4(to_proj4, to_srid)?;
+
+    transform_between(geom, &src_proj, &dst_proj, false)
+}
+
+/// Transforms a geometry like [`transform_geometry`], but with an explicit
+/// datum shift replacing the source CRS's own datum definition.
+///
+/// Use this when the registry's shift is not the one you need, e.g. a
+/// regional Helmert set or a grid file available on this system.
+pub fn transform_geometry_with_datum_shift(
+    geom: &SurrealGeometry,
+    from_srid: i32,
+    to_srid: i32,
+    shift: &DatumShift,
+) -> Result<SurrealGeometry, CrsError> {
+    let source = source_proj4(from_srid)?;
+    let src_proj = Projection::from_proj4(&shift.apply(&source)?, from_srid)?;
+    let dst_proj = Projection::new(to_srid)?;
+
+    transform_between(geom, &src_proj, &dst_proj, false)
+}
+
+/// Transforms a geometry like [`transform_geometry`], but with the source
+/// CRS's ellipsoid replaced by `ellps` (a proj4 name such as `"clrk66"`).
+///
+/// Use this for legacy or custom datums that the EPSG definition does not
+/// capture. The source datum name is dropped along with its ellipsoid, so
+/// no datum shift is applied unless the definition carries an explicit one.
+pub fn transform_geometry_with_ellipsoid(
+    geom: &SurrealGeometry,
+    from_srid: i32,
+    to_srid: i32,
+    ellps: &str,
+) -> Result<SurrealGeometry, CrsError> {
+    let source = source_proj4(from_srid)?;
+    let src_proj = Projection::from_proj4(&apply_ellipsoid(&source, ellps)?, from_srid)?;
+    let dst_proj = Projection::new(to_srid)?;
+
+    transform_between(geom, &src_proj, &dst_proj, false)
+}
+
+/// Proj4 definition of a source SRID, preferring runtime registrations.
+fn source_proj4(srid: i32) -> Result<String, CrsError> {
+    registry::custom_proj4_string(srid)
+        .or_else(|| registry::get_proj4_string(srid).map(String::from))
+        .ok_or(CrsError::UnknownSrid(srid))
+}
+
+fn transform_between(
+    geom: &SurrealGeometry,
+    src_proj: &Projection,
+    dst_proj: &Projection,
+    force_z: bool,
+) -> Result<SurrealGeometry, CrsError> {
+    let target_srid = Srid::new(dst_proj.srid())
         .map_err(|e| CrsError::ProjectionError(e.to_string()))?;
 
     let transformed_type = transform_geometry_type(
         geom.geometry_type(),
-        &src_proj,
-        &dst_proj,
+        src_proj,
+        dst_proj,
+        force_z,
     )?;
 
     rebuild_geometry(transformed_type, target_srid)
 }
 
+/// Number of progress reports a batch transform aims for, besides the final one.
+const PROGRESS_STEPS: usize = 100;
+
+/// Transforms many geometries from one CRS to another.
+///
+/// Both projections are built once and shared across the batch, so this is
+/// cheaper than calling [`transform_geometry`] per geometry. Fails on the
+/// first geometry that cannot be transformed.
+pub fn transform_geometries(
+    geoms: &[SurrealGeometry],
+    from_srid: i32,
+    to_srid: i32,
+) -> Result<Vec<SurrealGeometry>, CrsError> {
+    transform_geometries_with_progress(geoms, from_srid, to_srid, |_, _| {})
+}
+
+/// Transforms many geometries like [`transform_geometries`], reporting progress.
+///
+/// `callback(done, total)` is invoked roughly every 1% of the batch and once
+/// more when all `total` geometries are done, with `done` strictly increasing.
+pub fn transform_geometries_with_progress<F>(
+    geoms: &[SurrealGeometry],
+    from_srid: i32,
+    to_srid: i32,
+    mut callback: F,
+) -> Result<Vec<SurrealGeometry>, CrsError>
+where
+    F: FnMut(usize, usize),
+{
+    if from_srid == to_srid {
+        return Err(CrsError::SameSrid(from_srid));
+    }
+
+    let src_proj = Projection::new(from_srid)?;
+    let dst_proj = Projection::new(to_srid)?;
+
+    let total = geoms.len();
+    let step = (total / PROGRESS_STEPS).max(1);
+    let mut result = Vec::with_capacity(total);
+    for (i, geom) in geoms.iter().enumerate() {
+        result.push(transform_between(geom, &src_proj, &dst_proj, false)?);
+        let done = i + 1;
+        if done % step == 0 || done == total {
+            callback(done, total);
+        }
+    }
+    Ok(result)
+}
+
 /// Changes the SRID metadata of a geometry without reprojecting coordinates.
 ///
 /// This is useful when you know coordinates are already in the target CRS
@@ -68,6 +214,7 @@ fn transform_coordinate(
     coord: &Coordinate,
     src_proj: &Projection,
     dst_proj: &Projection,
+    force_z: bool,
 ) -> Result<Coordinate, CrsError> {
     let mut x = coord.x();
     let mut y = coord.y();
@@ -79,10 +226,19 @@ fn transform_coordinate(
         y = degrees_to_radians(y);
     }
 
-    // Use proj4rs Transform trait on a mutable point
+    // Use proj4rs Transform trait on a mutable point. Domain and tolerance
+    // failures mean this coordinate cannot be projected (e.g. a pole in Mercator).
     let mut point = TransformPoint { x, y, z };
-    proj4rs::transform::transform(src_proj.proj(), dst_proj.proj(), &mut point)
-        .map_err(|e| CrsError::ProjectionError(e.to_string()))?;
+    proj4rs::transform::transform(src_proj.proj(), dst_proj.proj(), &mut point).map_err(
+        |e| match e {
+            ProjError::LatitudeOutOfRange
+            | ProjError::CoordinateOutOfRange
+            | ProjError::ToleranceConditionError => {
+                invalid_coordinate(coord, dst_proj, &e.to_string())
+            }
+            _ => CrsError::ProjectionError(e.to_string()),
+        },
+    )?;
 
     // proj4rs outputs radians for geographic CRS
     if dst_proj.is_geographic() {
@@ -90,13 +246,23 @@ fn transform_coordinate(
         point.y = radians_to_degrees(point.y);
     }
 
-    if coord.z().is_some() {
+    // Near singularities proj4rs can emit NaN or infinity instead of an error,
+    // which the Coordinate constructors reject
+    let projected = if force_z || coord.z().is_some() {
         Coordinate::new_3d(point.x, point.y, point.z)
-            .map_err(|e| CrsError::InvalidCoordinate(e.to_string()))
     } else {
         Coordinate::new(point.x, point.y)
-            .map_err(|e| CrsError::InvalidCoordinate(e.to_string()))
-    }
+    };
+    projected.map_err(|e| invalid_coordinate(coord, dst_proj, &e.to_string()))
+}
+
+fn invalid_coordinate(coord: &Coordinate, dst_proj: &Projection, reason: &str) -> CrsError {
+    CrsError::InvalidCoordinate(format!(
+        "({}, {}) cannot be projected to SRID {}: {reason}",
+        coord.x(),
+        coord.y(),
+        dst_proj.srid()
+    ))
 }
 
 /// Internal point type implementing proj4rs::transform::Transform.
@@ -125,10 +291,11 @@ fn transform_coords(
     coords: &[Coordinate],
     src: &Projection,
     dst: &Projection,
+    force_z: bool,
 ) -> Result<Vec<Coordinate>, CrsError> {
     coords
         .iter()
-        .map(|c| transform_coordinate(c, src, dst))
+        .map(|c| transform_coordinate(c, src, dst, force_z))
         .collect()
 }
 
@@ -136,10 +303,11 @@ fn transform_rings(
     rings: &[Vec<Coordinate>],
     src: &Projection,
     dst: &Projection,
+    force_z: bool,
 ) -> Result<Vec<Vec<Coordinate>>, CrsError> {
     rings
         .iter()
-        .map(|ring| transform_coords(ring, src, dst))
+        .map(|ring| transform_coords(ring, src, dst, force_z))
         .collect()
 }
 
@@ -149,38 +317,39 @@ fn transform_geometry_type(
     gt: &GeometryType,
     src: &Projection,
     dst: &Projection,
+    force_z: bool,
 ) -> Result<GeometryType, CrsError> {
     match gt {
         GeometryType::Point(coord) => {
-            let new_coord = transform_coordinate(coord, src, dst)?;
+            let new_coord = transform_coordinate(coord, src, dst, force_z)?;
             Ok(GeometryType::Point(new_coord))
         }
         GeometryType::LineString(coords) => {
-            let new_coords = transform_coords(coords, src, dst)?;
+            let new_coords = transform_coords(coords, src, dst, force_z)?;
             Ok(GeometryType::LineString(new_coords))
         }
         GeometryType::Polygon { exterior, holes } => {
-            let new_exterior = transform_coords(exterior, src, dst)?;
-            let new_holes = transform_rings(holes, src, dst)?;
+            let new_exterior = transform_coords(exterior, src, dst, force_z)?;
+            let new_holes = transform_rings(holes, src, dst, force_z)?;
             Ok(GeometryType::Polygon {
                 exterior: new_exterior,
                 holes: new_holes,
             })
         }
         GeometryType::MultiPoint(coords) => {
-            let new_coords = transform_coords(coords, src, dst)?;
+            let new_coords = transform_coords(coords, src, dst, force_z)?;
             Ok(GeometryType::MultiPoint(new_coords))
         }
         GeometryType::MultiLineString(lines) => {
-            let new_lines = transform_rings(lines, src, dst)?;
+            let new_lines = transform_rings(lines, src, dst, force_z)?;
             Ok(GeometryType::MultiLineString(new_lines))
         }
         GeometryType::MultiPolygon(polygons) => {
             let new_polygons = polygons
                 .iter()
                 .map(|p| {
-                    let exterior = transform_coords(&p.exterior, src, dst)?;
-                    let holes = transform_rings(&p.holes, src, dst)?;
+                    let exterior = transform_coords(&p.exterior, src, dst, force_z)?;
+                    let holes = transform_rings(&p.holes, src, dst, force_z)?;
                     Ok(PolygonData { exterior, holes })
                 })
                 .collect::<Result<Vec<_>, CrsError>>()?;
@@ -190,7 +359,7 @@ fn transform_geometry_type(
             let new_geoms = geoms
                 .iter()
                 .map(|g| {
-                    let new_type = transform_geometry_type(g.geometry_type(), src, dst)?;
+                    let new_type = transform_geometry_type(g.geometry_type(), src, dst, force_z)?;
                     let target_srid = Srid::new(dst.srid())
                         .map_err(|e| CrsError::ProjectionError(e.to_string()))?;
                     rebuild_geometry(new_type, target_srid)
@@ -215,7 +384,7 @@ fn rebuild_geometry(
 ) -> Result<SurrealGeometry, CrsError> {
     match gt {
         GeometryType::Point(coord) => {
-            SurrealGeometry::point(coord.x(), coord.y(), srid)
+            SurrealGeometry::point_from_coordinate(coord, srid)
                 .map_err(CrsError::from)
         }
         GeometryType::LineString(coords) => {
@@ -259,6 +428,124 @@ mod tests {
         }
     }
 
+    // ── Batch transforms ─────────────────────────────────────────────────
+
+    #[test]
+    fn batch_transform_matches_single_transforms() {
+        let geoms: Vec<SurrealGeometry> = (0..10)
+            .map(|i| SurrealGeometry::point(i as f64, i as f64 * 2.0, Srid::WGS84).unwrap())
+            .collect();
+        let batch = transform_geometries(&geoms, 4326, 3857).unwrap();
+        assert_eq!(batch.len(), geoms.len());
+        for (geom, out) in geoms.iter().zip(&batch) {
+            let single = transform_geometry(geom, 4326, 3857).unwrap();
+            assert_eq!(point_coords(out), point_coords(&single));
+            assert_eq!(out.srid().code(), 3857);
+        }
+    }
+
+    #[test]
+    fn batch_transform_reports_monotonic_progress() {
+        let geoms: Vec<SurrealGeometry> = (0..1000)
+            .map(|i| {
+                let lon = -180.0 + (i as f64) * 0.36;
+                SurrealGeometry::point(lon, 45.0, Srid::WGS84).unwrap()
+            })
+            .collect();
+
+        let mut calls: Vec<(usize, usize)> = Vec::new();
+        let result =
+            transform_geometries_with_progress(&geoms, 4326, 3857, |done, total| {
+                calls.push((done, total));
+            })
+            .unwrap();
+
+        assert_eq!(result.len(), 1000);
+        assert!(calls.len() > 1);
+        assert!(calls.iter().all(|&(_, total)| total == 1000));
+        assert!(calls.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(calls.last(), Some(&(1000, 1000)));
+    }
+
+    #[test]
+    fn batch_transform_same_srid_fails() {
+        let geoms = vec![SurrealGeometry::point(0.0, 0.0, Srid::WGS84).unwrap()];
+        let result = transform_geometries(&geoms, 4326, 4326);
+        assert_eq!(result.unwrap_err(), CrsError::SameSrid(4326));
+    }
+
+    // ── Datum shifts ─────────────────────────────────────────────────────
+
+    #[test]
+    fn transform_nad27_to_wgs84_applies_datum_shift() {
+        // Kansas, near the NAD27 datum origin at Meades Ranch
+        let p = SurrealGeometry::point(-98.5, 39.2, Srid::new(4267).unwrap()).unwrap();
+        let result = transform_geometry(&p, 4267, 4326).unwrap();
+        let (x, y) = point_coords(&result);
+
+        // A naive identity (ignored grids/towgs84) would return the input
+        let shift = (x + 98.5).abs().max((y - 39.2).abs());
+        assert!(shift > 1e-5, "NAD27 -> WGS84 shift not applied: ({x}, {y})");
+        // The NAD27/WGS84 difference is tens of meters, not kilometers
+        assert!(shift < 0.01, "NAD27 -> WGS84 shift too large: ({x}, {y})");
+    }
+
+    #[test]
+    fn explicit_datum_shift_matches_registry_shift() {
+        let p = SurrealGeometry::point(-98.5, 39.2, Srid::new(4267).unwrap()).unwrap();
+        let default = transform_geometry(&p, 4267, 4326).unwrap();
+        let shift = DatumShift::ToWgs84(vec![-8.0, 160.0, 176.0]);
+        let explicit = transform_geometry_with_datum_shift(&p, 4267, 4326, &shift).unwrap();
+
+        let (dx, dy) = point_coords(&default);
+        let (ex, ey) = point_coords(&explicit);
+        assert_abs_diff_eq!(dx, ex, epsilon = 1e-9);
+        assert_abs_diff_eq!(dy, ey, epsilon = 1e-9);
+        assert_eq!(explicit.srid().code(), 4326);
+    }
+
+    #[test]
+    fn explicit_datum_shift_changes_result() {
+        let p = SurrealGeometry::point(-98.5, 39.2, Srid::new(4267).unwrap()).unwrap();
+        let default = transform_geometry(&p, 4267, 4326).unwrap();
+        // Alaska parameters (EPSG:1176) differ from the CONUS set by ~10 m
+        let shift = DatumShift::ToWgs84(vec![-5.0, 135.0, 172.0]);
+        let regional = transform_geometry_with_datum_shift(&p, 4267, 4326, &shift).unwrap();
+
+        let (dx, dy) = point_coords(&default);
+        let (rx, ry) = point_coords(&regional);
+        assert!((dx - rx).abs().max((dy - ry).abs()) > 1e-5);
+    }
+
+    #[test]
+    fn ellipsoid_override_changes_result() {
+        let p = SurrealGeometry::point(583_960.0, 4_507_523.0, Srid::new(32618).unwrap()).unwrap();
+        let default = transform_geometry(&p, 32618, 4326).unwrap();
+        let legacy = transform_geometry_with_ellipsoid(&p, 32618, 4326, "clrk66").unwrap();
+
+        let (dx, dy) = point_coords(&default);
+        let (lx, ly) = point_coords(&legacy);
+        assert!((dx - lx).abs().max((dy - ly).abs()) > 1e-5);
+        // Same ballpark: only the ellipsoid changed
+        assert_abs_diff_eq!(dx, lx, epsilon = 0.01);
+        assert_abs_diff_eq!(dy, ly, epsilon = 0.01);
+    }
+
+    #[test]
+    fn ellipsoid_override_bad_name_rejected() {
+        let p = SurrealGeometry::point(0.0, 0.0, Srid::WGS84).unwrap();
+        let result = transform_geometry_with_ellipsoid(&p, 4326, 3857, "no such");
+        assert!(matches!(result, Err(CrsError::ProjectionError(_))));
+    }
+
+    #[test]
+    fn datum_shift_unknown_source_srid() {
+        let p = SurrealGeometry::point(0.0, 0.0, Srid::WGS84).unwrap();
+        let shift = DatumShift::ToWgs84(vec![0.0, 0.0, 0.0]);
+        let result = transform_geometry_with_datum_shift(&p, 999_999, 4326, &shift);
+        assert!(matches!(result, Err(CrsError::UnknownSrid(999_999))));
+    }
+
     // ── WGS 84 -> Web Mercator (NYC) ────────────────────────────────────
 
     #[test]
@@ -276,6 +563,30 @@ mod tests {
         assert_abs_diff_eq!(y, 4_975_293.0, epsilon = 500.0);
     }
 
+    // ── Points outside the projection domain ────────────────────────────
+
+    #[test]
+    fn pole_to_mercator_is_invalid_coordinate() {
+        // Mercator sends the poles to infinity
+        let pole = SurrealGeometry::point(0.0, 90.0, Srid::WGS84).unwrap();
+        match transform_geometry(&pole, 4326, 3857) {
+            Err(CrsError::InvalidCoordinate(msg)) => {
+                assert!(msg.starts_with("(0, 90) cannot be projected to SRID 3857"), "{msg}");
+            }
+            other => panic!("Expected InvalidCoordinate, got {other:?}"),
+        }
+        // One bad vertex fails the whole geometry
+        let line = SurrealGeometry::line_string(
+            vec![Coordinate::new(0.0, 0.0).unwrap(), Coordinate::new(0.0, -90.0).unwrap()],
+            Srid::WGS84,
+        )
+        .unwrap();
+        assert!(matches!(
+            transform_geometry(&line, 4326, 3857),
+            Err(CrsError::InvalidCoordinate(_))
+        ));
+    }
+
     // ── Round-trip 4326 -> 3857 -> 4326 ─────────────────────────────────
 
     #[test]
@@ -323,6 +634,111 @@ mod tests {
         assert_abs_diff_eq!(y, 4_512_000.0, epsilon = 2000.0);
     }
 
+    // ── 3D output ───────────────────────────────────────────────────────
+
+    fn point_z(geom: &SurrealGeometry) -> Option<f64> {
+        match geom.geometry_type() {
+            GeometryType::Point(c) => c.z(),
+            _ => panic!("Expected Point geometry"),
+        }
+    }
+
+    #[test]
+    fn transform_3d_adds_z_to_2d_input() {
+        let nyc = SurrealGeometry::point(-73.9857, 40.7484, Srid::WGS84).unwrap();
+        let result = transform_geometry_3d(&nyc, 4326, 3857).unwrap();
+
+        let (x, _) = point_coords(&result);
+        assert_abs_diff_eq!(x, -8_235_851.0, epsilon = 500.0);
+        assert_abs_diff_eq!(point_z(&result).unwrap(), 0.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn transform_3d_keeps_height_out_of_radian_conversion() {
+        let coord = Coordinate::new_3d(-73.9857, 40.7484, 120.0).unwrap();
+        let nyc = SurrealGeometry::point_from_coordinate(coord, Srid::WGS84).unwrap();
+        let mercator = transform_geometry_3d(&nyc, 4326, 3857).unwrap();
+        let back = transform_geometry_3d(&mercator, 3857, 4326).unwrap();
+
+        let (x, y) = point_coords(&back);
+        assert_abs_diff_eq!(x, -73.9857, epsilon = 1e-6);
+        assert_abs_diff_eq!(y, 40.7484, epsilon = 1e-6);
+        assert_abs_diff_eq!(point_z(&back).unwrap(), 120.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn transform_2d_stays_2d() {
+        let nyc = SurrealGeometry::point(-73.9857, 40.7484, Srid::WGS84).unwrap();
+        let result = transform_geometry(&nyc, 4326, 3857).unwrap();
+        assert_eq!(point_z(&result), None);
+    }
+
+    #[test]
+    fn transform_3d_linestring() {
+        let coords = vec![
+            Coordinate::new(0.0, 0.0).unwrap(),
+            Coordinate::new(1.0, 1.0).unwrap(),
+        ];
+        let line = SurrealGeometry::line_string(coords, Srid::WGS84).unwrap();
+        let result = transform_geometry_3d(&line, 4326, 3857).unwrap();
+        match result.geometry_type() {
+            GeometryType::LineString(cs) => assert!(cs.iter().all(|c| c.z().is_some())),
+            _ => panic!("Expected LineString"),
+        }
+    }
+
+    // ── Explicit proj4 strings ──────────────────────────────────────────
+
+    #[test]
+    fn transform_between_explicit_mercator_definitions() {
+        // Spherical Mercator and a Mercator with a false easting/northing
+        let from = "+proj=merc +a=6378137 +b=6378137 +lat_ts=0 +lon_0=0 +x_0=0 +y_0=0 +k=1 +units=m +no_defs";
+        let to = "+proj=merc +a=6378137 +b=6378137 +lat_ts=0 +lon_0=0 +x_0=1000 +y_0=2000 +k=1 +units=m +no_defs";
+        let pt = SurrealGeometry::point(500_000.0, 300_000.0, Srid::WEB_MERCATOR).unwrap();
+        let result = transform_geometry_proj4(&pt, from, to, 900_001).unwrap();
+
+        let (x, y) = point_coords(&result);
+        assert_eq!(result.srid().code(), 900_001);
+        assert_abs_diff_eq!(x, 501_000.0, epsilon = 1e-3);
+        assert_abs_diff_eq!(y, 302_000.0, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn transform_proj4_invalid_definition() {
+        let pt = SurrealGeometry::point(0.0, 0.0, Srid::WGS84).unwrap();
+        let result = transform_geometry_proj4(&pt, "+proj=bogus", "+proj=longlat +datum=WGS84", 4326);
+        assert!(matches!(result, Err(CrsError::ProjectionError(_))));
+    }
+
+    #[test]
+    fn transform_proj4_invalid_target_srid() {
+        let pt = SurrealGeometry::point(0.0, 0.0, Srid::WGS84).unwrap();
+        let wgs84 = "+proj=longlat +datum=WGS84 +no_defs";
+        let merc = "+proj=merc +a=6378137 +b=6378137 +units=m +no_defs";
+        assert!(transform_geometry_proj4(&pt, wgs84, merc, 0).is_err());
+    }
+
+    // ── Runtime-registered CRS ──────────────────────────────────────────
+
+    #[test]
+    fn transform_into_registered_srid() {
+        // ETRS89 / UTM zone 32N, registered under a code outside the EPSG range
+        crate::registry::register_srid(
+            925_832,
+            "+proj=utm +zone=32 +ellps=GRS80 +towgs84=0,0,0,0,0,0,0 +units=m +no_defs",
+        )
+        .unwrap();
+        // Frankfurt am Main
+        let frankfurt = SurrealGeometry::point(8.6821, 50.1109, Srid::WGS84).unwrap();
+        let result = transform_geometry(&frankfurt, 4326, 925_832).unwrap();
+
+        let (x, y) = point_coords(&result);
+        assert_eq!(result.srid().code(), 925_832);
+        assert_abs_diff_eq!(x, 477_000.0, epsilon = 2000.0);
+        assert_abs_diff_eq!(y, 5_551_000.0, epsilon = 2000.0);
+        assert!(crate::registry::unregister_srid(925_832));
+    }
+
     // ── set_srid only changes metadata ──────────────────────────────────
 
     #[test]
@@ -6,9 +6,7 @@ use crate::FunctionError;
 pub fn st_x(geom: &SurrealGeometry) -> Result<f64, FunctionError> {
     match geom.geometry_type() {
         GeometryType::Point(coord) => Ok(coord.x()),
-        _ => Err(FunctionError::InvalidArgument(
-            "st_x requires a Point geometry".to_string(),
-        )),
+        _ => Err(FunctionError::type_mismatch("st_x", &["Point"], geom)),
     }
 }
 
@@ -16,9 +14,7 @@ pub fn st_x(geom: &SurrealGeometry) -> Result<f64, FunctionError> {
 pub fn st_y(geom: &SurrealGeometry) -> Result<f64, FunctionError> {
     match geom.geometry_type() {
         GeometryType::Point(coord) => Ok(coord.y()),
-        _ => Err(FunctionError::InvalidArgument(
-            "st_y requires a Point geometry".to_string(),
-        )),
+        _ => Err(FunctionError::type_mismatch("st_y", &["Point"], geom)),
     }
 }
 
@@ -26,9 +22,7 @@ pub fn st_y(geom: &SurrealGeometry) -> Result<f64, FunctionError> {
 pub fn st_z(geom: &SurrealGeometry) -> Result<Option<f64>, FunctionError> {
     match geom.geometry_type() {
         GeometryType::Point(coord) => Ok(coord.z()),
-        _ => Err(FunctionError::InvalidArgument(
-            "st_z requires a Point geometry".to_string(),
-        )),
+        _ => Err(FunctionError::type_mismatch("st_z", &["Point"], geom)),
     }
 }
 
@@ -71,9 +65,7 @@ pub fn st_start_point(geom: &SurrealGeometry) -> Result<SurrealGeometry, Functio
             let c = &coords[0];
             Ok(SurrealGeometry::point(c.x(), c.y(), *geom.srid())?)
         }
-        _ => Err(FunctionError::InvalidArgument(
-            "st_start_point requires a LineString geometry".to_string(),
-        )),
+        _ => Err(FunctionError::type_mismatch("st_start_point", &["LineString"], geom)),
     }
 }
 
@@ -89,16 +81,134 @@ pub fn st_end_point(geom: &SurrealGeometry) -> Result<SurrealGeometry, FunctionE
             let c = coords.last().unwrap();
             Ok(SurrealGeometry::point(c.x(), c.y(), *geom.srid())?)
         }
-        _ => Err(FunctionError::InvalidArgument(
-            "st_end_point requires a LineString geometry".to_string(),
-        )),
+        _ => Err(FunctionError::type_mismatch("st_end_point", &["LineString"], geom)),
     }
 }
 
+/// Return the number of component geometries: the member count for Multi*
+/// and GeometryCollection, 1 for single geometries.
+pub fn st_num_geometries(geom: &SurrealGeometry) -> usize {
+    match geom.geometry_type() {
+        GeometryType::Point(_) | GeometryType::LineString(_) | GeometryType::Polygon { .. } => 1,
+        GeometryType::MultiPoint(coords) => coords.len(),
+        GeometryType::MultiLineString(lines) => lines.len(),
+        GeometryType::MultiPolygon(polygons) => polygons.len(),
+        GeometryType::GeometryCollection(geoms) => geoms.len(),
+    }
+}
+
+/// Return the 1-based nth component of a geometry, like PostGIS ST_GeometryN.
+/// Multi* members come back as their single type (a MultiPolygon member as a
+/// Polygon); a single geometry is its own first component.
+pub fn st_geometry_n(geom: &SurrealGeometry, n: usize) -> Result<SurrealGeometry, FunctionError> {
+    let i = one_based_index("st_geometry_n", n, st_num_geometries(geom))?;
+    let srid = *geom.srid();
+    let component = match geom.geometry_type() {
+        GeometryType::Point(_) | GeometryType::LineString(_) | GeometryType::Polygon { .. } => {
+            geom.clone()
+        }
+        GeometryType::MultiPoint(coords) => {
+            SurrealGeometry::point_from_coordinate(coords[i].clone(), srid)?
+        }
+        GeometryType::MultiLineString(lines) => {
+            SurrealGeometry::line_string(lines[i].clone(), srid)?
+        }
+        GeometryType::MultiPolygon(polygons) => {
+            let p = &polygons[i];
+            SurrealGeometry::polygon(p.exterior.clone(), p.holes.clone(), srid)?
+        }
+        GeometryType::GeometryCollection(geoms) => geoms[i].clone(),
+    };
+    Ok(component)
+}
+
+/// Return the 1-based nth vertex of a LineString as a Point, keeping Z and M.
+pub fn st_point_n(geom: &SurrealGeometry, n: usize) -> Result<SurrealGeometry, FunctionError> {
+    match geom.geometry_type() {
+        GeometryType::LineString(coords) => {
+            let i = one_based_index("st_point_n", n, coords.len())?;
+            Ok(SurrealGeometry::point_from_coordinate(coords[i].clone(), *geom.srid())?)
+        }
+        _ => Err(FunctionError::type_mismatch("st_point_n", &["LineString"], geom)),
+    }
+}
+
+/// Return the exterior ring of a Polygon as a LineString.
+pub fn st_exterior_ring(geom: &SurrealGeometry) -> Result<SurrealGeometry, FunctionError> {
+    match geom.geometry_type() {
+        GeometryType::Polygon { exterior, .. } => {
+            Ok(SurrealGeometry::line_string(exterior.clone(), *geom.srid())?)
+        }
+        _ => Err(FunctionError::type_mismatch("st_exterior_ring", &["Polygon"], geom)),
+    }
+}
+
+/// Return the number of holes in a Polygon.
+pub fn st_num_interior_rings(geom: &SurrealGeometry) -> Result<usize, FunctionError> {
+    match geom.geometry_type() {
+        GeometryType::Polygon { holes, .. } => Ok(holes.len()),
+        _ => Err(FunctionError::type_mismatch("st_num_interior_rings", &["Polygon"], geom)),
+    }
+}
+
+/// Return the 1-based nth hole of a Polygon as a LineString.
+pub fn st_interior_ring_n(
+    geom: &SurrealGeometry,
+    n: usize,
+) -> Result<SurrealGeometry, FunctionError> {
+    match geom.geometry_type() {
+        GeometryType::Polygon { holes, .. } => {
+            let i = one_based_index("st_interior_ring_n", n, holes.len())?;
+            Ok(SurrealGeometry::line_string(holes[i].clone(), *geom.srid())?)
+        }
+        _ => Err(FunctionError::type_mismatch("st_interior_ring_n", &["Polygon"], geom)),
+    }
+}
+
+/// Convert a 1-based index into a 0-based one, rejecting 0 and `n > len`.
+fn one_based_index(name: &str, n: usize, len: usize) -> Result<usize, FunctionError> {
+    if n == 0 || n > len {
+        return Err(FunctionError::InvalidArgument(format!(
+            "{name} index {n} is out of range 1..={len}"
+        )));
+    }
+    Ok(n - 1)
+}
+
+/// Deepest GeometryCollection nesting `st_collection_depth` will walk.
+pub const MAX_COLLECTION_DEPTH: usize = 64;
+
+/// Return the maximum nesting depth of GeometryCollections.
+/// Non-collections and flat collections report 1; each nested collection adds 1.
+/// Fails if the nesting exceeds `MAX_COLLECTION_DEPTH`, which guards against
+/// pathological imported data.
+pub fn st_collection_depth(geom: &SurrealGeometry) -> Result<usize, FunctionError> {
+    // Walk with an explicit stack so deep input cannot overflow the call stack
+    let mut max_depth = 1;
+    let mut stack = vec![(geom, 1)];
+    while let Some((g, depth)) = stack.pop() {
+        if let GeometryType::GeometryCollection(children) = g.geometry_type() {
+            max_depth = max_depth.max(depth);
+            for child in children {
+                if matches!(child.geometry_type(), GeometryType::GeometryCollection(_)) {
+                    if depth + 1 > MAX_COLLECTION_DEPTH {
+                        return Err(FunctionError::InvalidArgument(format!(
+                            "GeometryCollection nesting exceeds the maximum depth of {MAX_COLLECTION_DEPTH}"
+                        )));
+                    }
+                    stack.push((child, depth + 1));
+                }
+            }
+        }
+    }
+    Ok(max_depth)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use surrealgis_core::coordinate::Coordinate;
+    use surrealgis_core::geometry::PolygonData;
     use surrealgis_core::srid::Srid;
 
     fn make_point() -> SurrealGeometry {
@@ -141,7 +251,14 @@ mod tests {
 
     #[test]
     fn test_st_x_on_linestring_fails() {
-        assert!(st_x(&make_linestring()).is_err());
+        assert_eq!(
+            st_x(&make_linestring()).unwrap_err(),
+            FunctionError::TypeMismatch {
+                function: "st_x",
+                expected: vec!["Point"],
+                got: "LineString".to_string(),
+            }
+        );
     }
 
     #[test]
@@ -188,4 +305,154 @@ mod tests {
     fn test_start_point_on_point_fails() {
         assert!(st_start_point(&make_point()).is_err());
     }
+
+    fn make_multipolygon() -> SurrealGeometry {
+        let square = |x: f64| {
+            vec![
+                Coordinate::new(x, 0.0).unwrap(),
+                Coordinate::new(x + 1.0, 0.0).unwrap(),
+                Coordinate::new(x + 1.0, 1.0).unwrap(),
+                Coordinate::new(x, 0.0).unwrap(),
+            ]
+        };
+        let polygons = vec![
+            PolygonData { exterior: square(0.0), holes: vec![] },
+            PolygonData { exterior: square(5.0), holes: vec![] },
+        ];
+        SurrealGeometry::multi_polygon(polygons, Srid::WGS84).unwrap()
+    }
+
+    #[test]
+    fn test_st_num_geometries() {
+        assert_eq!(st_num_geometries(&make_point()), 1);
+        assert_eq!(st_num_geometries(&make_polygon()), 1);
+        assert_eq!(st_num_geometries(&make_multipolygon()), 2);
+        let gc = collect(vec![make_point(), make_linestring(), make_polygon()]);
+        assert_eq!(st_num_geometries(&gc), 3);
+    }
+
+    #[test]
+    fn test_st_geometry_n_multipolygon_member_is_polygon() {
+        let second = st_geometry_n(&make_multipolygon(), 2).unwrap();
+        assert_eq!(st_geometry_type(&second), "Polygon");
+        assert_eq!(second.bbox().unwrap().min_x, 5.0);
+        assert_eq!(st_srid(&second), 4326);
+    }
+
+    #[test]
+    fn test_st_geometry_n_single_and_collection() {
+        assert_eq!(st_geometry_n(&make_point(), 1).unwrap(), make_point());
+        let gc = collect(vec![make_point(), make_linestring()]);
+        assert_eq!(st_geometry_n(&gc, 2).unwrap(), make_linestring());
+    }
+
+    #[test]
+    fn test_st_geometry_n_out_of_range() {
+        for n in [0, 3] {
+            assert!(matches!(
+                st_geometry_n(&make_multipolygon(), n),
+                Err(FunctionError::InvalidArgument(_))
+            ));
+        }
+        assert!(st_geometry_n(&make_point(), 2).is_err());
+    }
+
+    fn make_polygon_with_holes() -> SurrealGeometry {
+        let ring = |coords: &[(f64, f64)]| -> Vec<Coordinate> {
+            coords.iter().map(|&(x, y)| Coordinate::new(x, y).unwrap()).collect()
+        };
+        let exterior = ring(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0), (0.0, 0.0)]);
+        let holes = vec![
+            ring(&[(1.0, 1.0), (2.0, 1.0), (2.0, 2.0), (1.0, 1.0)]),
+            ring(&[(5.0, 5.0), (6.0, 5.0), (6.0, 6.0), (5.0, 5.0)]),
+        ];
+        SurrealGeometry::polygon(exterior, holes, Srid::WGS84).unwrap()
+    }
+
+    #[test]
+    fn test_st_point_n() {
+        let second = st_point_n(&make_linestring(), 2).unwrap();
+        assert_eq!(st_x(&second).unwrap(), 1.0);
+        assert_eq!(st_y(&second).unwrap(), 1.0);
+        assert!(st_point_n(&make_linestring(), 0).is_err());
+        assert!(st_point_n(&make_linestring(), 4).is_err());
+        assert!(st_point_n(&make_linestring(), usize::MAX).is_err());
+        assert!(matches!(
+            st_point_n(&make_polygon(), 1),
+            Err(FunctionError::TypeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_st_exterior_ring() {
+        let shell = st_exterior_ring(&make_polygon_with_holes()).unwrap();
+        assert_eq!(st_geometry_type(&shell), "LineString");
+        assert_eq!(st_num_points(&shell), 5);
+        assert!(st_exterior_ring(&make_linestring()).is_err());
+    }
+
+    #[test]
+    fn test_st_num_interior_rings() {
+        assert_eq!(st_num_interior_rings(&make_polygon()).unwrap(), 0);
+        assert_eq!(st_num_interior_rings(&make_polygon_with_holes()).unwrap(), 2);
+        assert!(st_num_interior_rings(&make_point()).is_err());
+    }
+
+    #[test]
+    fn test_st_interior_ring_n() {
+        let poly = make_polygon_with_holes();
+        let hole = st_interior_ring_n(&poly, 2).unwrap();
+        assert_eq!(st_geometry_type(&hole), "LineString");
+        let start = st_start_point(&hole).unwrap();
+        assert_eq!(st_x(&start).unwrap(), 5.0);
+        assert!(st_interior_ring_n(&poly, 0).is_err());
+        assert!(st_interior_ring_n(&poly, 3).is_err());
+        assert!(st_interior_ring_n(&make_polygon(), 1).is_err());
+        assert!(matches!(
+            st_interior_ring_n(&make_point(), 1),
+            Err(FunctionError::TypeMismatch { .. })
+        ));
+    }
+
+    fn collect(geoms: Vec<SurrealGeometry>) -> SurrealGeometry {
+        SurrealGeometry::geometry_collection(geoms, Srid::WGS84).unwrap()
+    }
+
+    #[test]
+    fn test_collection_depth_non_collection() {
+        assert_eq!(st_collection_depth(&make_point()).unwrap(), 1);
+        assert_eq!(st_collection_depth(&make_polygon()).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_collection_depth_flat_collection() {
+        let gc = collect(vec![make_point(), make_linestring()]);
+        assert_eq!(st_collection_depth(&gc).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_collection_depth_nested() {
+        let inner = collect(vec![make_point()]);
+        let outer = collect(vec![inner]);
+        assert_eq!(st_collection_depth(&outer).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_collection_depth_takes_deepest_branch() {
+        let deep = collect(vec![collect(vec![make_point()])]);
+        let outer = collect(vec![make_linestring(), deep, collect(vec![make_polygon()])]);
+        assert_eq!(st_collection_depth(&outer).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_collection_depth_guard() {
+        let mut geom = collect(vec![make_point()]);
+        for _ in 0..MAX_COLLECTION_DEPTH {
+            geom = collect(vec![geom]);
+        }
+        assert!(matches!(
+            st_collection_depth(&geom),
+            Err(FunctionError::InvalidArgument(_))
+        ));
+    }
 }
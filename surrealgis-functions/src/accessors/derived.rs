@@ -1,26 +1,14 @@
-use geo::algorithm::{BoundingRect, Centroid, InteriorPoint};
-use surrealgis_core::coordinate::Coordinate;
+use geo::algorithm::{Centroid, InteriorPoint};
 use surrealgis_core::geometry::{GeometryType, SurrealGeometry};
 
 use crate::FunctionError;
 
 /// Return the bounding box of a geometry as a Polygon.
 pub fn st_envelope(geom: &SurrealGeometry) -> Result<SurrealGeometry, FunctionError> {
-    let geo_geom = geom.to_geo()?;
-    let rect = geo_geom
-        .bounding_rect()
+    let bbox = geom
+        .bbox()
         .ok_or_else(|| FunctionError::InvalidArgument("Cannot compute envelope".to_string()))?;
-
-    let min = rect.min();
-    let max = rect.max();
-    let exterior = vec![
-        Coordinate::new(min.x, min.y)?,
-        Coordinate::new(max.x, min.y)?,
-        Coordinate::new(max.x, max.y)?,
-        Coordinate::new(min.x, max.y)?,
-        Coordinate::new(min.x, min.y)?,
-    ];
-    Ok(SurrealGeometry::polygon(exterior, vec![], *geom.srid())?)
+    Ok(bbox.to_polygon(*geom.srid())?)
 }
 
 /// Return the centroid of a geometry as a Point.
@@ -43,8 +31,17 @@ pub fn st_point_on_surface(geom: &SurrealGeometry) -> Result<SurrealGeometry, Fu
         geo_types::Geometry::Point(p) => Some(*p),
         geo_types::Geometry::MultiPoint(mp) => mp.interior_point(),
         _ => {
-            return Err(FunctionError::UnsupportedOperation(
-                "st_point_on_surface not supported for this geometry type".to_string(),
+            return Err(FunctionError::type_mismatch(
+                "st_point_on_surface",
+                &[
+                    "Point",
+                    "LineString",
+                    "Polygon",
+                    "MultiPoint",
+                    "MultiLineString",
+                    "MultiPolygon",
+                ],
+                geom,
             ))
         }
     };
@@ -82,15 +79,14 @@ pub fn st_boundary(geom: &SurrealGeometry) -> Result<SurrealGeometry, FunctionEr
                 Ok(SurrealGeometry::multi_point(vec![start, end], *geom.srid())?)
             }
         }
-        _ => Err(FunctionError::UnsupportedOperation(
-            "st_boundary only supports Polygon and LineString geometries".to_string(),
-        )),
+        _ => Err(FunctionError::type_mismatch("st_boundary", &["Polygon", "LineString"], geom)),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use surrealgis_core::coordinate::Coordinate;
     use surrealgis_core::srid::Srid;
 
     fn make_polygon() -> SurrealGeometry {
@@ -0,0 +1,136 @@
+use surrealgis_core::coordinate::Coordinate;
+use surrealgis_core::geometry::{GeometryType, SurrealGeometry};
+
+use super::basic::{st_geometry_n, st_num_geometries};
+use crate::FunctionError;
+
+/// Explode a geometry into its leaf components, like PostGIS ST_Dump.
+/// Multi* members come back as single geometries and nested
+/// GeometryCollections are flattened; a single geometry dumps to itself.
+/// Every output keeps the source SRID.
+pub fn st_dump(geom: &SurrealGeometry) -> Result<Vec<SurrealGeometry>, FunctionError> {
+    let mut leaves = Vec::new();
+    dump_into(geom, &mut leaves)?;
+    Ok(leaves)
+}
+
+fn dump_into(geom: &SurrealGeometry, out: &mut Vec<SurrealGeometry>) -> Result<(), FunctionError> {
+    match geom.geometry_type() {
+        GeometryType::Point(_) | GeometryType::LineString(_) | GeometryType::Polygon { .. } => {
+            out.push(geom.clone());
+        }
+        GeometryType::GeometryCollection(geoms) => {
+            for child in geoms {
+                dump_into(child, out)?;
+            }
+        }
+        _ => {
+            for n in 1..=st_num_geometries(geom) {
+                out.push(st_geometry_n(geom, n)?);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Return every vertex of a geometry as a Point, in traversal order, like
+/// PostGIS ST_DumpPoints. Closing ring vertices are included; Z and M are
+/// kept and every Point carries the source SRID.
+pub fn st_dump_points(geom: &SurrealGeometry) -> Result<Vec<SurrealGeometry>, FunctionError> {
+    let srid = *geom.srid();
+    let mut coords = Vec::new();
+    collect_coords(geom, &mut coords);
+    coords
+        .into_iter()
+        .map(|c| SurrealGeometry::point_from_coordinate(c, srid).map_err(FunctionError::from))
+        .collect()
+}
+
+fn collect_coords(geom: &SurrealGeometry, out: &mut Vec<Coordinate>) {
+    match geom.geometry_type() {
+        GeometryType::Point(c) => out.push(c.clone()),
+        GeometryType::LineString(coords) | GeometryType::MultiPoint(coords) => {
+            out.extend(coords.iter().cloned())
+        }
+        GeometryType::Polygon { exterior, holes } => {
+            out.extend(exterior.iter().chain(holes.iter().flatten()).cloned())
+        }
+        GeometryType::MultiLineString(lines) => out.extend(lines.iter().flatten().cloned()),
+        GeometryType::MultiPolygon(polygons) => {
+            for p in polygons {
+                out.extend(p.exterior.iter().chain(p.holes.iter().flatten()).cloned());
+            }
+        }
+        GeometryType::GeometryCollection(geoms) => {
+            for child in geoms {
+                collect_coords(child, out);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::coords;
+    use surrealgis_core::srid::Srid;
+
+    fn xy(geom: &SurrealGeometry) -> (f64, f64) {
+        match geom.geometry_type() {
+            GeometryType::Point(c) => (c.x(), c.y()),
+            _ => panic!("Expected Point"),
+        }
+    }
+
+    #[test]
+    fn dump_flattens_nested_collections() {
+        let srid = Srid::WEB_MERCATOR;
+        let mp = SurrealGeometry::multi_point(coords(&[(0.0, 0.0), (1.0, 1.0)]), srid).unwrap();
+        let line = SurrealGeometry::line_string(coords(&[(0.0, 0.0), (2.0, 0.0)]), srid).unwrap();
+        let inner = SurrealGeometry::geometry_collection(vec![line.clone()], srid).unwrap();
+        let outer = SurrealGeometry::geometry_collection(vec![mp, inner], srid).unwrap();
+
+        let leaves = st_dump(&outer).unwrap();
+        let types: Vec<&str> = leaves.iter().map(|g| g.type_name()).collect();
+        assert_eq!(types, vec!["Point", "Point", "LineString"]);
+        assert_eq!(leaves[2], line);
+        assert!(leaves.iter().all(|g| *g.srid() == srid));
+    }
+
+    #[test]
+    fn dump_of_single_geometry_is_itself() {
+        let p = SurrealGeometry::point(1.0, 2.0, Srid::WGS84).unwrap();
+        assert_eq!(st_dump(&p).unwrap(), vec![p]);
+    }
+
+    #[test]
+    fn dump_points_in_traversal_order() {
+        let srid = Srid::WEB_MERCATOR;
+        let square = coords(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 0.0)]);
+        let poly = SurrealGeometry::polygon(square, vec![], srid).unwrap();
+        let p = SurrealGeometry::point(5.0, 5.0, srid).unwrap();
+        let gc = SurrealGeometry::geometry_collection(vec![poly, p], srid).unwrap();
+
+        let points = st_dump_points(&gc).unwrap();
+        let got: Vec<(f64, f64)> = points.iter().map(xy).collect();
+        assert_eq!(got, vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 0.0), (5.0, 5.0)]);
+        assert!(points.iter().all(|g| *g.srid() == srid));
+    }
+
+    #[test]
+    fn dump_points_keeps_z() {
+        let line = SurrealGeometry::line_string(
+            vec![
+                Coordinate::new_3d(0.0, 0.0, 3.0).unwrap(),
+                Coordinate::new_3d(1.0, 0.0, 4.0).unwrap(),
+            ],
+            Srid::WGS84,
+        )
+        .unwrap();
+        let points = st_dump_points(&line).unwrap();
+        match points[1].geometry_type() {
+            GeometryType::Point(c) => assert_eq!(c.z(), Some(4.0)),
+            _ => panic!("Expected Point"),
+        }
+    }
+}
@@ -1,10 +1,17 @@
 mod basic;
 mod predicates;
 mod derived;
+mod dump;
 
 pub use basic::{
     st_x, st_y, st_z, st_srid, st_geometry_type, st_num_points,
-    st_dimension, st_start_point, st_end_point,
+    st_dimension, st_start_point, st_end_point, st_num_geometries, st_geometry_n,
+    st_point_n, st_exterior_ring, st_num_interior_rings, st_interior_ring_n,
+    st_collection_depth, MAX_COLLECTION_DEPTH,
 };
-pub use predicates::{st_is_empty, st_is_valid, st_is_closed, st_is_ring};
+pub use predicates::{
+    st_is_empty, st_is_valid, st_valid_detail, st_is_closed, st_is_ring, ValidDetail,
+};
+pub(crate) use predicates::hole_outside_shell;
 pub use derived::{st_envelope, st_centroid, st_point_on_surface, st_boundary};
+pub use dump::{st_dump, st_dump_points};
@@ -1,7 +1,15 @@
+use geo::coordinate_position::{CoordPos, CoordinatePosition};
+use geo::line_intersection::{line_intersection, LineIntersection};
+use geo_types::{Coord, Line, LineString, Polygon};
+use surrealgis_core::coordinate::Coordinate;
 use surrealgis_core::geometry::{GeometryType, SurrealGeometry};
 
 use crate::FunctionError;
 
+/// Validity of a geometry with the reason and location of the first problem,
+/// as returned by [`st_valid_detail`]: `(valid, reason, location)`.
+pub type ValidDetail = (bool, Option<String>, Option<SurrealGeometry>);
+
 /// Check if the geometry is empty (has no coordinates).
 pub fn st_is_empty(geom: &SurrealGeometry) -> bool {
     geom.is_empty()
@@ -55,6 +63,153 @@ pub fn st_is_valid(geom: &SurrealGeometry) -> Result<bool, FunctionError> {
     }
 }
 
+/// Report validity together with the reason and location (a Point) of the first
+/// problem found, like PostGIS ST_IsValidDetail. Valid geometries return
+/// `(true, None, None)`.
+///
+/// On top of the structural checks of [`st_is_valid`], polygon rings are checked
+/// for self-intersections, rings crossing each other, and holes outside the shell.
+pub fn st_valid_detail(geom: &SurrealGeometry) -> Result<ValidDetail, FunctionError> {
+    match first_problem(geom) {
+        None => Ok((true, None, None)),
+        Some((reason, at)) => {
+            let location = SurrealGeometry::point(at.x, at.y, *geom.srid())?;
+            Ok((false, Some(reason.to_string()), Some(location)))
+        }
+    }
+}
+
+type Problem = (&'static str, Coord<f64>);
+
+fn first_problem(geom: &SurrealGeometry) -> Option<Problem> {
+    match geom.geometry_type() {
+        GeometryType::Point(_) | GeometryType::MultiPoint(_) => None,
+        GeometryType::LineString(coords) => line_problem(coords),
+        GeometryType::MultiLineString(lines) => lines.iter().find_map(|l| line_problem(l)),
+        GeometryType::Polygon { exterior, holes } => polygon_problem(exterior, holes),
+        GeometryType::MultiPolygon(polygons) => polygons
+            .iter()
+            .find_map(|p| polygon_problem(&p.exterior, &p.holes)),
+        GeometryType::GeometryCollection(geoms) => geoms.iter().find_map(first_problem),
+    }
+}
+
+fn to_coord(c: &Coordinate) -> Coord<f64> {
+    Coord { x: c.x(), y: c.y() }
+}
+
+fn line_problem(coords: &[Coordinate]) -> Option<Problem> {
+    let first = coords.first().map(to_coord).unwrap_or_default();
+    (coords.len() < 2).then_some(("Too few points", first))
+}
+
+fn polygon_problem(exterior: &[Coordinate], holes: &[Vec<Coordinate>]) -> Option<Problem> {
+    let rings: Vec<Vec<Coord<f64>>> = std::iter::once(exterior)
+        .chain(holes.iter().map(Vec::as_slice))
+        .map(|ring| ring.iter().map(to_coord).collect())
+        .collect();
+
+    for ring in &rings {
+        if let Some(problem) = ring_structure_problem(ring) {
+            return Some(problem);
+        }
+    }
+    for ring in &rings {
+        if let Some(at) = ring_self_intersection(ring) {
+            return Some(("Self-intersection", at));
+        }
+    }
+    for (i, a) in rings.iter().enumerate() {
+        for b in &rings[i + 1..] {
+            if let Some(at) = rings_cross(a, b) {
+                return Some(("Self-intersection", at));
+            }
+        }
+    }
+
+    hole_outside_shell(exterior, holes).map(|(_, at)| ("Hole lies outside shell", at))
+}
+
+/// Index of the first hole with a vertex outside the shell, and that vertex.
+/// Vertices on the shell boundary count as inside.
+pub(crate) fn hole_outside_shell(
+    exterior: &[Coordinate],
+    holes: &[Vec<Coordinate>],
+) -> Option<(usize, Coord<f64>)> {
+    let shell = Polygon::new(exterior.iter().map(to_coord).collect::<LineString<f64>>(), vec![]);
+    holes.iter().enumerate().find_map(|(i, hole)| {
+        hole.iter()
+            .map(to_coord)
+            .find(|c| shell.coordinate_position(c) == CoordPos::Outside)
+            .map(|at| (i, at))
+    })
+}
+
+fn ring_structure_problem(ring: &[Coord<f64>]) -> Option<Problem> {
+    let first = ring.first().copied().unwrap_or_default();
+    if ring.len() < 4 {
+        return Some(("Too few points", first));
+    }
+    if ring.first() != ring.last() {
+        return Some(("Ring not closed", first));
+    }
+    None
+}
+
+/// Non-zero-length segments of a ring, in order.
+fn ring_segments(ring: &[Coord<f64>]) -> Vec<Line<f64>> {
+    ring.windows(2)
+        .filter(|w| w[0] != w[1])
+        .map(|w| Line::new(w[0], w[1]))
+        .collect()
+}
+
+/// First point where a ring touches or crosses itself, other than at the
+/// vertices shared by consecutive segments.
+fn ring_self_intersection(ring: &[Coord<f64>]) -> Option<Coord<f64>> {
+    let segments = ring_segments(ring);
+    let n = segments.len();
+    for i in 0..n {
+        for j in i + 1..n {
+            let adjacent = j == i + 1 || (i == 0 && j == n - 1);
+            match line_intersection(segments[i], segments[j]) {
+                None => {}
+                // Consecutive segments always meet at their shared vertex
+                Some(LineIntersection::SinglePoint { .. }) if adjacent => {}
+                Some(LineIntersection::SinglePoint { intersection, .. }) => {
+                    return Some(intersection)
+                }
+                // A collinear overlap is a spike when adjacent, a retraced edge otherwise
+                Some(LineIntersection::Collinear { intersection }) => {
+                    return Some(intersection.start)
+                }
+            }
+        }
+    }
+    None
+}
+
+/// First point where two rings properly cross or share an edge. Rings may
+/// touch at isolated points.
+fn rings_cross(a: &[Coord<f64>], b: &[Coord<f64>]) -> Option<Coord<f64>> {
+    let segs_b = ring_segments(b);
+    for sa in ring_segments(a) {
+        for sb in &segs_b {
+            match line_intersection(sa, *sb) {
+                Some(LineIntersection::SinglePoint {
+                    intersection,
+                    is_proper: true,
+                }) => return Some(intersection),
+                Some(LineIntersection::Collinear { intersection }) => {
+                    return Some(intersection.start)
+                }
+                _ => {}
+            }
+        }
+    }
+    None
+}
+
 /// Check if a LineString is closed (first point == last point).
 pub fn st_is_closed(geom: &SurrealGeometry) -> Result<bool, FunctionError> {
     match geom.geometry_type() {
@@ -68,8 +223,10 @@ pub fn st_is_closed(geom: &SurrealGeometry) -> Result<bool, FunctionError> {
             // All linestrings must be closed
             Ok(lines.iter().all(|l| l.len() >= 2 && l.first() == l.last()))
         }
-        _ => Err(FunctionError::InvalidArgument(
-            "st_is_closed requires a LineString or MultiLineString".to_string(),
+        _ => Err(FunctionError::type_mismatch(
+            "st_is_closed",
+            &["LineString", "MultiLineString"],
+            geom,
         )),
     }
 }
@@ -84,9 +241,7 @@ pub fn st_is_ring(geom: &SurrealGeometry) -> Result<bool, FunctionError> {
             }
             Ok(coords.first() == coords.last())
         }
-        _ => Err(FunctionError::InvalidArgument(
-            "st_is_ring requires a LineString geometry".to_string(),
-        )),
+        _ => Err(FunctionError::type_mismatch("st_is_ring", &["LineString"], geom)),
     }
 }
 
@@ -120,6 +275,76 @@ mod tests {
         assert!(st_is_valid(&poly).unwrap());
     }
 
+    fn polygon_from(exterior: &[(f64, f64)], holes: &[&[(f64, f64)]]) -> SurrealGeometry {
+        let ring = |pts: &[(f64, f64)]| -> Vec<Coordinate> {
+            pts.iter().map(|&(x, y)| Coordinate::new(x, y).unwrap()).collect()
+        };
+        let holes = holes.iter().map(|h| ring(h)).collect();
+        SurrealGeometry::polygon(ring(exterior), holes, Srid::WEB_MERCATOR).unwrap()
+    }
+
+    fn location_xy(detail: &ValidDetail) -> (f64, f64) {
+        match detail.2.as_ref().unwrap().geometry_type() {
+            GeometryType::Point(c) => (c.x(), c.y()),
+            _ => panic!("Expected Point location"),
+        }
+    }
+
+    #[test]
+    fn valid_detail_bowtie() {
+        let bowtie = polygon_from(&[(0.0, 0.0), (2.0, 2.0), (2.0, 0.0), (0.0, 2.0), (0.0, 0.0)], &[]);
+        let detail = st_valid_detail(&bowtie).unwrap();
+        assert!(!detail.0);
+        assert_eq!(detail.1.as_deref(), Some("Self-intersection"));
+        let (x, y) = location_xy(&detail);
+        assert!((x - 1.0).abs() < 1e-12 && (y - 1.0).abs() < 1e-12, "got ({x}, {y})");
+        assert_eq!(detail.2.unwrap().srid().code(), Srid::WEB_MERCATOR.code());
+    }
+
+    #[test]
+    fn valid_detail_valid_polygon() {
+        let square = polygon_from(
+            &[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0), (0.0, 0.0)],
+            &[&[(2.0, 2.0), (4.0, 2.0), (4.0, 4.0), (2.0, 2.0)]],
+        );
+        assert_eq!(st_valid_detail(&square).unwrap(), (true, None, None));
+    }
+
+    #[test]
+    fn valid_detail_hole_crossing_shell() {
+        let poly = polygon_from(
+            &[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0), (0.0, 0.0)],
+            &[&[(8.0, 2.0), (12.0, 2.0), (12.0, 4.0), (8.0, 2.0)]],
+        );
+        let detail = st_valid_detail(&poly).unwrap();
+        assert_eq!(detail.1.as_deref(), Some("Self-intersection"));
+        let (x, _) = location_xy(&detail);
+        assert!((x - 10.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn valid_detail_hole_outside_shell() {
+        let poly = polygon_from(
+            &[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0), (0.0, 0.0)],
+            &[&[(20.0, 20.0), (22.0, 20.0), (22.0, 22.0), (20.0, 20.0)]],
+        );
+        let detail = st_valid_detail(&poly).unwrap();
+        assert_eq!(detail.1.as_deref(), Some("Hole lies outside shell"));
+        assert_eq!(location_xy(&detail), (20.0, 20.0));
+    }
+
+    #[test]
+    fn valid_detail_point_and_line() {
+        let p = SurrealGeometry::point(1.0, 2.0, Srid::WGS84).unwrap();
+        assert!(st_valid_detail(&p).unwrap().0);
+        let ls = SurrealGeometry::line_string(
+            vec![Coordinate::new(0.0, 0.0).unwrap(), Coordinate::new(1.0, 1.0).unwrap()],
+            Srid::WGS84,
+        )
+        .unwrap();
+        assert!(st_valid_detail(&ls).unwrap().0);
+    }
+
     #[test]
     fn closed_linestring() {
         let coords = vec![
@@ -7,3 +7,27 @@ pub use st_translate::st_translate;
 pub use st_rotate::st_rotate;
 pub use st_scale::st_scale;
 pub use st_affine::st_affine;
+
+use geo::Centroid;
+use surrealgis_core::geometry::SurrealGeometry;
+
+use crate::FunctionError;
+
+/// Apply `x' = a*x + b*y + xoff, y' = d*x + e*y + yoff` to every coordinate.
+///
+/// Works on the domain coordinates via `map_coords` rather than a geo_types
+/// round-trip, so Z/M are kept and GeometryCollection members keep their own
+/// SRIDs.
+pub(crate) fn affine_in_place(
+    geom: &SurrealGeometry,
+    [a, b, d, e, xoff, yoff]: [f64; 6],
+) -> Result<SurrealGeometry, FunctionError> {
+    geom.map_coords(|c| c.with_xy(a * c.x() + b * c.y() + xoff, d * c.x() + e * c.y() + yoff))
+        .map_err(FunctionError::from)
+}
+
+/// Centroid of the whole geometry, used as the pivot for rotate and scale.
+/// Empty geometries have no coordinates to move, so the origin will do.
+pub(crate) fn pivot(geom: &SurrealGeometry) -> Result<(f64, f64), FunctionError> {
+    Ok(geom.to_geo()?.centroid().map_or((0.0, 0.0), |c| (c.x(), c.y())))
+}
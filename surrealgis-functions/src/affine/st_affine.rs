@@ -1,4 +1,3 @@
-use geo::{AffineOps, AffineTransform};
 use surrealgis_core::geometry::SurrealGeometry;
 
 use crate::FunctionError;
@@ -12,7 +11,8 @@ use crate::FunctionError;
 /// | 0  0  1    |
 /// ```
 ///
-/// New coordinates: x' = a*x + b*y + xoff, y' = d*x + e*y + yoff
+/// New coordinates: x' = a*x + b*y + xoff, y' = d*x + e*y + yoff.
+/// Z/M and the SRIDs of GeometryCollection members are preserved.
 pub fn st_affine(
     geom: &SurrealGeometry,
     a: f64,
@@ -22,10 +22,7 @@ pub fn st_affine(
     xoff: f64,
     yoff: f64,
 ) -> Result<SurrealGeometry, FunctionError> {
-    let geo_geom = geom.to_geo()?;
-    let transform = AffineTransform::new(a, b, xoff, d, e, yoff);
-    let result = geo_geom.affine_transform(&transform);
-    SurrealGeometry::from_geo(&result, *geom.srid()).map_err(FunctionError::from)
+    super::affine_in_place(geom, [a, b, d, e, xoff, yoff])
 }
 
 #[cfg(test)]
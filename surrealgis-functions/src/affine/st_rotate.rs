@@ -1,17 +1,22 @@
-use geo::Rotate;
 use surrealgis_core::geometry::SurrealGeometry;
 
 use crate::FunctionError;
 
+use super::{affine_in_place, pivot};
+
 /// Rotate a geometry around its centroid by a given angle in degrees.
-/// Positive angle rotates counter-clockwise.
+/// Positive angle rotates counter-clockwise. Z/M and the SRIDs of
+/// GeometryCollection members are preserved.
 pub fn st_rotate(
     geom: &SurrealGeometry,
     angle_degrees: f64,
 ) -> Result<SurrealGeometry, FunctionError> {
-    let geo_geom = geom.to_geo()?;
-    let result = geo_geom.rotate_around_centroid(angle_degrees);
-    SurrealGeometry::from_geo(&result, *geom.srid()).map_err(FunctionError::from)
+    let (cx, cy) = pivot(geom)?;
+    let (sin, cos) = angle_degrees.to_radians().sin_cos();
+    affine_in_place(
+        geom,
+        [cos, -sin, sin, cos, cx - cos * cx + sin * cy, cy - sin * cx - cos * cy],
+    )
 }
 
 #[cfg(test)]
@@ -78,4 +83,41 @@ mod tests {
         let result = st_rotate(&p, 45.0).unwrap();
         assert_eq!(result.srid().code(), Srid::WEB_MERCATOR.code());
     }
+
+    #[test]
+    fn rotate_collection_keeps_member_srids() {
+        let utm = Srid::new(32632).unwrap();
+        let a = SurrealGeometry::point(2.0, 0.0, Srid::WEB_MERCATOR).unwrap();
+        let b = SurrealGeometry::multi_point(
+            vec![
+                Coordinate::new_3d(-1.0, 0.0, 7.0).unwrap(),
+                Coordinate::new_3d(-1.0, 0.0, 8.0).unwrap(),
+            ],
+            utm,
+        )
+        .unwrap();
+        let gc = SurrealGeometry::geometry_collection(vec![a, b], Srid::WEB_MERCATOR).unwrap();
+
+        let result = st_rotate(&gc, 90.0).unwrap();
+        assert_eq!(result.srid().code(), Srid::WEB_MERCATOR.code());
+        let GeometryType::GeometryCollection(members) = result.geometry_type() else {
+            panic!("Expected GeometryCollection");
+        };
+        assert_eq!(members[0].srid().code(), Srid::WEB_MERCATOR.code());
+        assert_eq!(members[1].srid().code(), 32632);
+        // Pivot is the vertex centroid (0, 0); (2, 0) goes to (0, 2)
+        if let GeometryType::Point(c) = members[0].geometry_type() {
+            assert!(c.x().abs() < 1e-10);
+            assert!((c.y() - 2.0).abs() < 1e-10);
+        } else {
+            panic!("Expected Point");
+        }
+        if let GeometryType::MultiPoint(cs) = members[1].geometry_type() {
+            assert!((cs[0].y() + 1.0).abs() < 1e-10);
+            assert_eq!(cs[0].z(), Some(7.0));
+            assert_eq!(cs[1].z(), Some(8.0));
+        } else {
+            panic!("Expected MultiPoint");
+        }
+    }
 }
@@ -1,21 +1,19 @@
-use geo::{Centroid, Scale};
 use surrealgis_core::geometry::SurrealGeometry;
 
 use crate::FunctionError;
 
+use super::{affine_in_place, pivot};
+
 /// Scale a geometry by the given x and y factors relative to its centroid.
-/// A factor of 1.0 keeps the dimension unchanged.
+/// A factor of 1.0 keeps the dimension unchanged. Z/M and the SRIDs of
+/// GeometryCollection members are preserved.
 pub fn st_scale(
     geom: &SurrealGeometry,
     sx: f64,
     sy: f64,
 ) -> Result<SurrealGeometry, FunctionError> {
-    let geo_geom = geom.to_geo()?;
-    let result = match geo_geom.centroid() {
-        Some(centroid) => geo_geom.scale_around_point(sx, sy, centroid),
-        None => geo_geom.scale_xy(sx, sy),
-    };
-    SurrealGeometry::from_geo(&result, *geom.srid()).map_err(FunctionError::from)
+    let (cx, cy) = pivot(geom)?;
+    affine_in_place(geom, [sx, 0.0, 0.0, sy, cx - sx * cx, cy - sy * cy])
 }
 
 #[cfg(test)]
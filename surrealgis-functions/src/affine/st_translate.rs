@@ -1,18 +1,17 @@
-use geo::Translate;
 use surrealgis_core::geometry::SurrealGeometry;
 
 use crate::FunctionError;
 
 /// Translate (shift) a geometry by the given offsets.
 /// Returns a new geometry with all coordinates shifted by (dx, dy).
+/// Z and M are carried through unchanged.
 pub fn st_translate(
     geom: &SurrealGeometry,
     dx: f64,
     dy: f64,
 ) -> Result<SurrealGeometry, FunctionError> {
-    let geo_geom = geom.to_geo()?;
-    let result = geo_geom.translate(dx, dy);
-    SurrealGeometry::from_geo(&result, *geom.srid()).map_err(FunctionError::from)
+    geom.map_coords(|c| c.with_xy(c.x() + dx, c.y() + dy))
+        .map_err(FunctionError::from)
 }
 
 #[cfg(test)]
@@ -83,4 +82,22 @@ mod tests {
             panic!("Expected LineString");
         }
     }
+
+    #[test]
+    fn translate_preserves_z() {
+        use surrealgis_core::coordinate::Coordinate;
+        let coords = vec![
+            Coordinate::new_3d(0.0, 0.0, 5.0).unwrap(),
+            Coordinate::new_3d(1.0, 1.0, 6.0).unwrap(),
+        ];
+        let line = SurrealGeometry::line_string(coords, Srid::WEB_MERCATOR).unwrap();
+        let result = st_translate(&line, 10.0, 10.0).unwrap();
+        if let GeometryType::LineString(cs) = result.geometry_type() {
+            assert!((cs[0].x() - 10.0).abs() < 1e-10);
+            assert_eq!(cs[0].z(), Some(5.0));
+            assert_eq!(cs[1].z(), Some(6.0));
+        } else {
+            panic!("Expected LineString");
+        }
+    }
 }
@@ -1,10 +1,16 @@
 mod st_cluster_dbscan;
+mod st_cluster_intersecting;
 mod st_cluster_kmeans;
 mod st_cluster_within;
 
-pub use st_cluster_dbscan::st_cluster_dbscan;
-pub use st_cluster_kmeans::st_cluster_kmeans;
-pub use st_cluster_within::st_cluster_within;
+pub use st_cluster_dbscan::{cluster_dbscan_labels, st_cluster_dbscan, st_cluster_dbscan_collect};
+pub use st_cluster_intersecting::st_cluster_intersecting;
+pub use st_cluster_kmeans::{
+    cluster_kmeans_labels, cluster_kmeans_labels_seeded, st_cluster_kmeans,
+    st_cluster_kmeans_collect, st_cluster_kmeans_opts, st_cluster_kmeans_seeded,
+    DEFAULT_KMEANS_MAX_ITER, DEFAULT_KMEANS_TOL,
+};
+pub use st_cluster_within::{cluster_within_labels, st_cluster_within, st_cluster_within_collect};
 
 use geo::Centroid;
 use surrealgis_core::geometry::SurrealGeometry;
@@ -29,6 +35,49 @@ pub(crate) fn extract_centroids(
         .collect()
 }
 
+/// Union-Find: find root with path compression.
+pub(crate) fn find(parent: &mut [usize], i: usize) -> usize {
+    if parent[i] != i {
+        parent[i] = find(parent, parent[i]);
+    }
+    parent[i]
+}
+
+/// Union-Find: union by rank.
+pub(crate) fn union(parent: &mut [usize], rank: &mut [usize], a: usize, b: usize) {
+    let ra = find(parent, a);
+    let rb = find(parent, b);
+    if ra != rb {
+        if rank[ra] < rank[rb] {
+            parent[ra] = rb;
+        } else if rank[ra] > rank[rb] {
+            parent[rb] = ra;
+        } else {
+            parent[rb] = ra;
+            rank[ra] += 1;
+        }
+    }
+}
+
+/// Number the Union-Find roots in order of first appearance and return
+/// one label per element.
+pub(crate) fn root_labels(parent: &mut [usize]) -> Vec<Option<usize>> {
+    let mut cluster_map: std::collections::HashMap<usize, usize> =
+        std::collections::HashMap::new();
+    let mut next_id = 0;
+    (0..parent.len())
+        .map(|i| {
+            let root = find(parent, i);
+            let id = *cluster_map.entry(root).or_insert_with(|| {
+                let id = next_id;
+                next_id += 1;
+                id
+            });
+            Some(id)
+        })
+        .collect()
+}
+
 /// Build a GeometryCollection of MultiPoints from cluster assignments.
 /// Each cluster becomes one MultiPoint in the collection.
 /// `assignments[i] == None` means the point is noise (excluded).
@@ -71,3 +120,58 @@ pub(crate) fn build_cluster_result(
     let result = geo_types::Geometry::GeometryCollection(gc);
     SurrealGeometry::from_geo(&result, srid).map_err(FunctionError::from)
 }
+
+/// Build a GeometryCollection of GeometryCollections from cluster assignments.
+/// Each cluster holds clones of its original input geometries, in input order.
+/// `assignments[i] == None` means the geometry is noise (excluded).
+pub(crate) fn build_cluster_collection(
+    geoms: &[SurrealGeometry],
+    assignments: &[Option<usize>],
+    srid: Srid,
+) -> Result<SurrealGeometry, FunctionError> {
+    use std::collections::BTreeMap;
+
+    let mut clusters: BTreeMap<usize, Vec<SurrealGeometry>> = BTreeMap::new();
+    for (geom, assignment) in geoms.iter().zip(assignments) {
+        if let Some(cluster_id) = assignment {
+            clusters.entry(*cluster_id).or_default().push(geom.clone());
+        }
+    }
+
+    if clusters.is_empty() {
+        return Err(FunctionError::InvalidArgument(
+            "No clusters formed".into(),
+        ));
+    }
+
+    let members = clusters
+        .into_values()
+        .map(|members| SurrealGeometry::geometry_collection(members, srid))
+        .collect::<Result<Vec<_>, _>>()?;
+    SurrealGeometry::geometry_collection(members, srid).map_err(FunctionError::from)
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use surrealgis_core::geometry::{GeometryType, SurrealGeometry};
+
+    /// Axis-aligned square of `side` centred on `(cx, cy)`.
+    pub(crate) fn square(cx: f64, cy: f64, side: f64) -> SurrealGeometry {
+        let h = side / 2.0;
+        crate::test_support::square(cx - h, cy - h, side)
+    }
+
+    /// The member geometries of each cluster in a `*_collect` result.
+    pub(crate) fn cluster_members(result: &SurrealGeometry) -> Vec<Vec<SurrealGeometry>> {
+        match result.geometry_type() {
+            GeometryType::GeometryCollection(clusters) => clusters
+                .iter()
+                .map(|c| match c.geometry_type() {
+                    GeometryType::GeometryCollection(members) => members.clone(),
+                    _ => panic!("Expected GeometryCollection per cluster"),
+                })
+                .collect(),
+            _ => panic!("Expected GeometryCollection"),
+        }
+    }
+}
@@ -1,4 +1,6 @@
+use surrealgis_core::coordinate::Coordinate;
 use surrealgis_core::geometry::SurrealGeometry;
+use surrealgis_index::{RTreeSpatialIndex, SpatialIndex};
 
 use crate::FunctionError;
 
@@ -15,6 +17,28 @@ pub fn st_cluster_dbscan(
     eps: f64,
     min_points: usize,
 ) -> Result<SurrealGeometry, FunctionError> {
+    let assignments = cluster_dbscan_labels(geoms, eps, min_points)?;
+    super::build_cluster_result(geoms, &assignments, *geoms[0].srid())
+}
+
+/// Like [`st_cluster_dbscan`], but each cluster is a GeometryCollection of the original
+/// input geometries rather than a MultiPoint of their centroids.
+pub fn st_cluster_dbscan_collect(
+    geoms: &[SurrealGeometry],
+    eps: f64,
+    min_points: usize,
+) -> Result<SurrealGeometry, FunctionError> {
+    let assignments = cluster_dbscan_labels(geoms, eps, min_points)?;
+    super::build_cluster_collection(geoms, &assignments, *geoms[0].srid())
+}
+
+/// Cluster label per input geometry, in input order, so results can be
+/// joined back to their source rows. `None` marks noise.
+pub fn cluster_dbscan_labels(
+    geoms: &[SurrealGeometry],
+    eps: f64,
+    min_points: usize,
+) -> Result<Vec<Option<usize>>, FunctionError> {
     if geoms.is_empty() {
         return Err(FunctionError::InvalidArgument(
             "Empty geometry input".into(),
@@ -32,22 +56,35 @@ pub fn st_cluster_dbscan(
     }
 
     let centroids = super::extract_centroids(geoms)?;
-    let points: Vec<[f64; 2]> = centroids.iter().map(|p| [p.x(), p.y()]).collect();
-    let n = points.len();
+    let n = centroids.len();
+
+    // Index the centroids so each neighbourhood lookup is logarithmic
+    // rather than a scan over every point
+    let entries = centroids
+        .iter()
+        .zip(geoms)
+        .enumerate()
+        .map(|(i, (p, g))| Ok((i, SurrealGeometry::point(p.x(), p.y(), *g.srid())?)))
+        .collect::<Result<Vec<_>, FunctionError>>()?;
+    let index = RTreeSpatialIndex::bulk_load(entries)
+        .map_err(|e| FunctionError::InvalidArgument(e.to_string()))?;
+    let coords = centroids
+        .iter()
+        .map(|p| Coordinate::new(p.x(), p.y()))
+        .collect::<Result<Vec<_>, _>>()?;
 
     let mut assignments: Vec<Option<usize>> = vec![None; n];
     let mut visited = vec![false; n];
+    let mut queued = vec![false; n];
     let mut cluster_id = 0;
 
-    let eps_squared = eps * eps;
-
     for i in 0..n {
         if visited[i] {
             continue;
         }
         visited[i] = true;
 
-        let neighbors = region_query(&points, i, eps_squared);
+        let neighbors = region_query(&index, &coords[i], eps);
 
         if neighbors.len() < min_points {
             // Noise point - leave assignment as None
@@ -56,6 +93,9 @@ pub fn st_cluster_dbscan(
 
         // Start a new cluster
         assignments[i] = Some(cluster_id);
+        for &nb in &neighbors {
+            queued[nb] = true;
+        }
         let mut queue = neighbors;
         let mut qi = 0;
 
@@ -65,11 +105,12 @@ pub fn st_cluster_dbscan(
 
             if !visited[j] {
                 visited[j] = true;
-                let j_neighbors = region_query(&points, j, eps_squared);
+                let j_neighbors = region_query(&index, &coords[j], eps);
                 if j_neighbors.len() >= min_points {
                     // Expand the cluster
-                    for &nb in &j_neighbors {
-                        if !queue.contains(&nb) {
+                    for nb in j_neighbors {
+                        if !queued[nb] {
+                            queued[nb] = true;
                             queue.push(nb);
                         }
                     }
@@ -81,31 +122,27 @@ pub fn st_cluster_dbscan(
             }
         }
 
+        // Reset only the entries this cluster touched
+        for &q in &queue {
+            queued[q] = false;
+        }
         cluster_id += 1;
     }
 
-    let srid = *geoms[0].srid();
-    super::build_cluster_result(geoms, &assignments, srid)
+    Ok(assignments)
 }
 
-/// Find all points within squared distance of the given point.
-fn region_query(points: &[[f64; 2]], idx: usize, eps_squared: f64) -> Vec<usize> {
-    let p = &points[idx];
-    points
-        .iter()
-        .enumerate()
-        .filter(|(_, q)| {
-            let dx = p[0] - q[0];
-            let dy = p[1] - q[1];
-            dx * dx + dy * dy <= eps_squared
-        })
-        .map(|(i, _)| i)
-        .collect()
+/// Find all points within `eps` of the given point, in index order.
+fn region_query(index: &RTreeSpatialIndex, point: &Coordinate, eps: f64) -> Vec<usize> {
+    let mut neighbors = index.query_within_distance(point, eps);
+    neighbors.sort_unstable();
+    neighbors
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clustering::test_helpers::{cluster_members, square};
     use surrealgis_core::srid::Srid;
 
     fn make_point(x: f64, y: f64) -> SurrealGeometry {
@@ -234,4 +271,136 @@ mod tests {
         let result = st_cluster_dbscan(&geoms, 2.0, 1).unwrap();
         assert_eq!(*result.srid(), Srid::WEB_MERCATOR);
     }
+
+    #[test]
+    fn collect_keeps_original_polygons() {
+        let geoms = vec![square(0.0, 0.0, 1.0), square(1.5, 0.0, 2.0), square(10.0, 10.0, 1.0)];
+        let result = st_cluster_dbscan_collect(&geoms, 2.0, 1).unwrap();
+        let clusters = cluster_members(&result);
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[0], vec![geoms[0].clone(), geoms[1].clone()]);
+        assert_eq!(clusters[1], vec![geoms[2].clone()]);
+
+        // Per-cluster areas are recoverable from the originals
+        let area: f64 = clusters[0]
+            .iter()
+            .map(|g| geo::Area::unsigned_area(&g.to_geo().unwrap()))
+            .sum();
+        assert!((area - 5.0).abs() < 1e-9, "area was {area}");
+        assert_eq!(*result.srid(), Srid::WEB_MERCATOR);
+    }
+
+    #[test]
+    fn collect_excludes_noise() {
+        let geoms = vec![make_point(0.0, 0.0), make_point(1.0, 0.0), make_point(50.0, 50.0)];
+        let result = st_cluster_dbscan_collect(&geoms, 2.0, 2).unwrap();
+        let clusters = cluster_members(&result);
+        assert_eq!(clusters, vec![vec![geoms[0].clone(), geoms[1].clone()]]);
+    }
+
+    #[test]
+    fn labels_align_with_input_order() {
+        let geoms = vec![
+            make_point(0.0, 0.0),
+            make_point(10.0, 10.0),
+            make_point(50.0, 50.0),
+            make_point(1.0, 0.0),
+            make_point(10.0, 11.0),
+        ];
+        let labels = cluster_dbscan_labels(&geoms, 2.0, 2).unwrap();
+        assert_eq!(labels, vec![Some(0), Some(1), None, Some(0), Some(1)]);
+    }
+
+    /// Reference O(n²) DBSCAN over raw points, as the index-free version did it.
+    fn brute_force_labels(points: &[[f64; 2]], eps: f64, min_points: usize) -> Vec<Option<usize>> {
+        let within = |i: usize| -> Vec<usize> {
+            (0..points.len())
+                .filter(|&j| {
+                    let dx = points[i][0] - points[j][0];
+                    let dy = points[i][1] - points[j][1];
+                    dx * dx + dy * dy <= eps * eps
+                })
+                .collect()
+        };
+        let n = points.len();
+        let mut labels = vec![None; n];
+        let mut visited = vec![false; n];
+        let mut cluster_id = 0;
+        for i in 0..n {
+            if visited[i] {
+                continue;
+            }
+            visited[i] = true;
+            let neighbors = within(i);
+            if neighbors.len() < min_points {
+                continue;
+            }
+            labels[i] = Some(cluster_id);
+            let mut queue = neighbors;
+            let mut qi = 0;
+            while qi < queue.len() {
+                let j = queue[qi];
+                qi += 1;
+                if !visited[j] {
+                    visited[j] = true;
+                    let more = within(j);
+                    if more.len() >= min_points {
+                        for nb in more {
+                            if !queue.contains(&nb) {
+                                queue.push(nb);
+                            }
+                        }
+                    }
+                }
+                if labels[j].is_none() {
+                    labels[j] = Some(cluster_id);
+                }
+            }
+            cluster_id += 1;
+        }
+        labels
+    }
+
+    #[test]
+    fn index_matches_brute_force() {
+        // Deterministic pseudo-random scatter with a mix of dense and sparse areas
+        let mut state: u64 = 42;
+        let mut next = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+        let points: Vec<[f64; 2]> = (0..600)
+            .map(|i| {
+                let spread = if i % 3 == 0 { 100.0 } else { 20.0 };
+                [next() * spread, next() * spread]
+            })
+            .collect();
+        let geoms: Vec<SurrealGeometry> = points.iter().map(|p| make_point(p[0], p[1])).collect();
+
+        for (eps, min_points) in [(1.0, 3), (2.5, 4), (0.0, 1), (5.0, 10)] {
+            let expected = brute_force_labels(&points, eps, min_points);
+            let labels = cluster_dbscan_labels(&geoms, eps, min_points).unwrap();
+            assert_eq!(labels, expected, "eps {eps}, min_points {min_points}");
+        }
+    }
+
+    #[test]
+    fn scale_20k_points() {
+        // 20 blobs of 40x25 lattice points at 0.1 spacing, 100 units apart
+        let geoms: Vec<SurrealGeometry> = (0..20)
+            .flat_map(|blob| {
+                let (bx, by) = ((blob % 5) as f64 * 100.0, (blob / 5) as f64 * 100.0);
+                (0..1000).map(move |i| {
+                    make_point(bx + (i % 40) as f64 * 0.1, by + (i / 40) as f64 * 0.1)
+                })
+            })
+            .collect();
+        assert_eq!(geoms.len(), 20_000);
+
+        let labels = cluster_dbscan_labels(&geoms, 0.15, 3).unwrap();
+        assert!(labels.iter().all(Option::is_some));
+        for (i, label) in labels.iter().enumerate() {
+            assert_eq!(*label, Some(i / 1000));
+        }
+    }
 }
@@ -0,0 +1,117 @@
+use surrealgis_core::geometry::SurrealGeometry;
+use surrealgis_index::bbox_filter::bbox_intersects;
+
+use crate::relationships::st_intersects;
+use crate::FunctionError;
+
+/// Intersection-based clustering using Union-Find.
+///
+/// Groups geometries that intersect, directly or through a chain of
+/// intersecting geometries. Every geometry belongs to exactly one cluster.
+///
+/// Returns a GeometryCollection with one GeometryCollection of the original
+/// geometries per cluster, like PostGIS ST_ClusterIntersecting.
+pub fn st_cluster_intersecting(geoms: &[SurrealGeometry]) -> Result<SurrealGeometry, FunctionError> {
+    if geoms.is_empty() {
+        return Err(FunctionError::InvalidArgument(
+            "Empty geometry input".into(),
+        ));
+    }
+
+    let n = geoms.len();
+    let mut parent: Vec<usize> = (0..n).collect();
+    let mut rank: Vec<usize> = vec![0; n];
+
+    for i in 0..n {
+        let Some(bbox_i) = geoms[i].bbox() else {
+            continue;
+        };
+        for j in (i + 1)..n {
+            // Cheap checks first: already connected, or bboxes apart
+            if super::find(&mut parent, i) == super::find(&mut parent, j) {
+                continue;
+            }
+            let Some(bbox_j) = geoms[j].bbox() else {
+                continue;
+            };
+            if !bbox_intersects(bbox_i, bbox_j) {
+                continue;
+            }
+            if st_intersects(&geoms[i], &geoms[j])? {
+                super::union(&mut parent, &mut rank, i, j);
+            }
+        }
+    }
+
+    let assignments = super::root_labels(&mut parent);
+    super::build_cluster_collection(geoms, &assignments, *geoms[0].srid())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clustering::test_helpers::cluster_members;
+    use surrealgis_core::coordinate::Coordinate;
+    use surrealgis_core::srid::Srid;
+
+    fn rect(x1: f64, y1: f64, x2: f64, y2: f64) -> SurrealGeometry {
+        let exterior = vec![
+            Coordinate::new(x1, y1).unwrap(),
+            Coordinate::new(x2, y1).unwrap(),
+            Coordinate::new(x2, y2).unwrap(),
+            Coordinate::new(x1, y2).unwrap(),
+            Coordinate::new(x1, y1).unwrap(),
+        ];
+        SurrealGeometry::polygon(exterior, vec![], Srid::WEB_MERCATOR).unwrap()
+    }
+
+    #[test]
+    fn overlapping_pair_and_disjoint_polygon() {
+        let geoms = vec![
+            rect(0.0, 0.0, 2.0, 2.0),
+            rect(10.0, 10.0, 11.0, 11.0),
+            rect(1.0, 1.0, 3.0, 3.0),
+        ];
+        let result = st_cluster_intersecting(&geoms).unwrap();
+        let found = cluster_members(&result);
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0], vec![geoms[0].clone(), geoms[2].clone()]);
+        assert_eq!(found[1], vec![geoms[1].clone()]);
+        assert_eq!(*result.srid(), Srid::WEB_MERCATOR);
+    }
+
+    #[test]
+    fn transitive_chain_forms_one_cluster() {
+        // a touches c, c overlaps b; a and b are disjoint
+        let geoms = vec![
+            rect(0.0, 0.0, 1.0, 1.0),
+            rect(4.0, 0.0, 6.0, 1.0),
+            rect(1.0, 0.0, 4.5, 1.0),
+        ];
+        let found = cluster_members(&st_cluster_intersecting(&geoms).unwrap());
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].len(), 3);
+    }
+
+    #[test]
+    fn bbox_overlap_without_intersection_stays_separate() {
+        // An L-shaped line whose bbox covers the point but which misses it
+        let line = SurrealGeometry::line_string(
+            vec![
+                Coordinate::new(0.0, 0.0).unwrap(),
+                Coordinate::new(10.0, 0.0).unwrap(),
+                Coordinate::new(10.0, 10.0).unwrap(),
+            ],
+            Srid::WEB_MERCATOR,
+        )
+        .unwrap();
+        let point = SurrealGeometry::point(2.0, 8.0, Srid::WEB_MERCATOR).unwrap();
+        let found = cluster_members(&st_cluster_intersecting(&[line, point]).unwrap());
+        assert_eq!(found.len(), 2);
+    }
+
+    #[test]
+    fn empty_input_returns_error() {
+        assert!(st_cluster_intersecting(&[]).is_err());
+    }
+}
@@ -1,18 +1,94 @@
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use surrealgis_core::geometry::SurrealGeometry;
 
 use crate::FunctionError;
 
+/// Lloyd iteration cap used by [`st_cluster_kmeans`].
+pub const DEFAULT_KMEANS_MAX_ITER: usize = 100;
+
+/// Centroid shift threshold used by [`st_cluster_kmeans`]. Zero means the
+/// iteration only stops early once assignments stop changing.
+pub const DEFAULT_KMEANS_TOL: f64 = 0.0;
+
 /// K-means++ clustering algorithm for geometries.
 ///
 /// Groups geometries into exactly `k` clusters using Lloyd's algorithm
 /// with k-means++ initialization. Each point is assigned to exactly one cluster.
 ///
 /// Returns a GeometryCollection of MultiPoints (one per cluster).
+/// Runs at most [`DEFAULT_KMEANS_MAX_ITER`] iterations with a centroid shift
+/// tolerance of [`DEFAULT_KMEANS_TOL`]; use [`st_cluster_kmeans_opts`] to
+/// change them.
 pub fn st_cluster_kmeans(
     geoms: &[SurrealGeometry],
     k: usize,
 ) -> Result<SurrealGeometry, FunctionError> {
+    let assignments = cluster_kmeans_labels(geoms, k)?;
+    super::build_cluster_result(geoms, &assignments, *geoms[0].srid())
+}
+
+/// Like [`st_cluster_kmeans`], with an explicit iteration cap and convergence
+/// tolerance. Lloyd's iteration stops after `max_iter` steps, once assignments
+/// stop changing, or once no centroid moves by `tol` or more in a step.
+pub fn st_cluster_kmeans_opts(
+    geoms: &[SurrealGeometry],
+    k: usize,
+    max_iter: usize,
+    tol: f64,
+) -> Result<SurrealGeometry, FunctionError> {
+    let assignments = kmeans_labels(geoms, k, rand::random(), max_iter, tol)?;
+    super::build_cluster_result(geoms, &assignments, *geoms[0].srid())
+}
+
+/// Like [`st_cluster_kmeans`], but with the k-means++ initialization drawn
+/// from a `StdRng` seeded with `seed`, so the same inputs and seed always
+/// produce the same clusters.
+pub fn st_cluster_kmeans_seeded(
+    geoms: &[SurrealGeometry],
+    k: usize,
+    seed: u64,
+) -> Result<SurrealGeometry, FunctionError> {
+    let assignments = cluster_kmeans_labels_seeded(geoms, k, seed)?;
+    super::build_cluster_result(geoms, &assignments, *geoms[0].srid())
+}
+
+/// Like [`st_cluster_kmeans`], but each cluster is a GeometryCollection of the original
+/// input geometries rather than a MultiPoint of their centroids.
+pub fn st_cluster_kmeans_collect(
+    geoms: &[SurrealGeometry],
+    k: usize,
+) -> Result<SurrealGeometry, FunctionError> {
+    let assignments = cluster_kmeans_labels(geoms, k)?;
+    super::build_cluster_collection(geoms, &assignments, *geoms[0].srid())
+}
+
+/// Cluster label per input geometry, in input order, so results can be
+/// joined back to their source rows. Every input is assigned a cluster,
+/// so labels are never `None`.
+pub fn cluster_kmeans_labels(
+    geoms: &[SurrealGeometry],
+    k: usize,
+) -> Result<Vec<Option<usize>>, FunctionError> {
+    cluster_kmeans_labels_seeded(geoms, k, rand::random())
+}
+
+/// Seeded form of [`cluster_kmeans_labels`]; see [`st_cluster_kmeans_seeded`].
+pub fn cluster_kmeans_labels_seeded(
+    geoms: &[SurrealGeometry],
+    k: usize,
+    seed: u64,
+) -> Result<Vec<Option<usize>>, FunctionError> {
+    kmeans_labels(geoms, k, seed, DEFAULT_KMEANS_MAX_ITER, DEFAULT_KMEANS_TOL)
+}
+
+fn kmeans_labels(
+    geoms: &[SurrealGeometry],
+    k: usize,
+    seed: u64,
+    max_iter: usize,
+    tol: f64,
+) -> Result<Vec<Option<usize>>, FunctionError> {
     if geoms.is_empty() {
         return Err(FunctionError::InvalidArgument(
             "Empty geometry input".into(),
@@ -23,14 +99,25 @@ pub fn st_cluster_kmeans(
             "k must be at least 1".into(),
         ));
     }
+    if max_iter == 0 {
+        return Err(FunctionError::InvalidArgument(
+            "max_iter must be at least 1".into(),
+        ));
+    }
+    if !tol.is_finite() || tol < 0.0 {
+        return Err(FunctionError::InvalidArgument(
+            "tol must be non-negative and finite".into(),
+        ));
+    }
 
     let centroids = super::extract_centroids(geoms)?;
     let k = k.min(centroids.len()); // Can't have more clusters than points
 
     let points: Vec<[f64; 2]> = centroids.iter().map(|p| [p.x(), p.y()]).collect();
 
-    // K-means++ initialization
-    let mut rng = rand::thread_rng();
+    // K-means++ initialization. All randomness comes from this seeded rng;
+    // distance ties go to the lowest center index.
+    let mut rng = StdRng::seed_from_u64(seed);
     let first = rng.gen_range(0..points.len());
     let mut centers: Vec<[f64; 2]> = vec![points[first]];
 
@@ -65,9 +152,9 @@ pub fn st_cluster_kmeans(
         }
     }
 
-    // Lloyd's iteration (max 100 iterations)
+    // Lloyd's iteration
     let mut assignments = vec![0usize; points.len()];
-    for _ in 0..100 {
+    for _ in 0..max_iter {
         let mut changed = false;
 
         // Assignment step
@@ -92,7 +179,8 @@ pub fn st_cluster_kmeans(
             break;
         }
 
-        // Update step - recompute centers
+        // Update step - recompute centers, tracking the largest move
+        let mut max_shift: f64 = 0.0;
         for (ci, center) in centers.iter_mut().enumerate() {
             let members: Vec<&[f64; 2]> = points
                 .iter()
@@ -104,19 +192,24 @@ pub fn st_cluster_kmeans(
                 let sx: f64 = members.iter().map(|p| p[0]).sum();
                 let sy: f64 = members.iter().map(|p| p[1]).sum();
                 let n = members.len() as f64;
-                *center = [sx / n, sy / n];
+                let moved = [sx / n, sy / n];
+                max_shift = max_shift.max((moved[0] - center[0]).hypot(moved[1] - center[1]));
+                *center = moved;
             }
         }
+
+        if max_shift < tol {
+            break;
+        }
     }
 
-    let opt_assignments: Vec<Option<usize>> = assignments.into_iter().map(Some).collect();
-    let srid = *geoms[0].srid();
-    super::build_cluster_result(geoms, &opt_assignments, srid)
+    Ok(assignments.into_iter().map(Some).collect())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clustering::test_helpers::{cluster_members, square};
     use surrealgis_core::srid::Srid;
 
     fn make_point(x: f64, y: f64) -> SurrealGeometry {
@@ -236,4 +329,96 @@ mod tests {
         let result = st_cluster_kmeans(&geoms, 1).unwrap();
         assert_eq!(*result.srid(), Srid::WEB_MERCATOR);
     }
+
+    #[test]
+    fn collect_keeps_original_polygons() {
+        let geoms = vec![square(0.0, 0.0, 1.0), square(0.5, 0.5, 1.0), square(100.0, 100.0, 3.0)];
+        let result = st_cluster_kmeans_collect(&geoms, 2).unwrap();
+        let mut clusters = cluster_members(&result);
+        clusters.sort_by_key(|c| c.len());
+        assert_eq!(clusters[0], vec![geoms[2].clone()]);
+        assert_eq!(clusters[1], vec![geoms[0].clone(), geoms[1].clone()]);
+    }
+
+    #[test]
+    fn labels_align_with_input_order() {
+        let geoms = vec![
+            make_point(0.0, 0.0),
+            make_point(100.0, 100.0),
+            make_point(1.0, 0.0),
+            make_point(101.0, 100.0),
+        ];
+        let labels = cluster_kmeans_labels(&geoms, 2).unwrap();
+        assert_eq!(labels.len(), geoms.len());
+        assert!(labels.iter().all(Option::is_some));
+        assert_eq!(labels[0], labels[2]);
+        assert_eq!(labels[1], labels[3]);
+        assert_ne!(labels[0], labels[1]);
+    }
+
+    #[test]
+    fn seeded_runs_are_reproducible() {
+        // Ambiguous layout where different initial centers give different results
+        let geoms: Vec<SurrealGeometry> = (0..60)
+            .map(|i| {
+                let t = i as f64 * 0.37;
+                make_point(t.cos() * (1.0 + (i % 7) as f64), t.sin() * (1.0 + (i % 5) as f64))
+            })
+            .collect();
+        for seed in [0, 1, 42, u64::MAX] {
+            let a = cluster_kmeans_labels_seeded(&geoms, 4, seed).unwrap();
+            let b = cluster_kmeans_labels_seeded(&geoms, 4, seed).unwrap();
+            assert_eq!(a, b, "seed {seed}");
+            assert_eq!(
+                st_cluster_kmeans_seeded(&geoms, 4, seed).unwrap(),
+                st_cluster_kmeans_seeded(&geoms, 4, seed).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn seeded_validates_arguments() {
+        assert!(st_cluster_kmeans_seeded(&[], 2, 7).is_err());
+        let geoms = vec![make_point(0.0, 0.0)];
+        assert!(st_cluster_kmeans_seeded(&geoms, 0, 7).is_err());
+    }
+
+    fn spread_points() -> Vec<SurrealGeometry> {
+        (0..200)
+            .map(|i| {
+                let t = i as f64 * 0.61;
+                make_point(t.cos() * (i % 13) as f64, t.sin() * (i % 11) as f64)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn opts_single_iteration_assigns_every_point() {
+        let geoms = spread_points();
+        let result = st_cluster_kmeans_opts(&geoms, 3, 1, 0.0).unwrap();
+        assert_eq!(result.num_points(), geoms.len());
+    }
+
+    #[test]
+    fn opts_large_tolerance_matches_single_iteration() {
+        // A tolerance larger than any possible shift stops after the first update,
+        // which is exactly what a one-iteration cap does
+        let geoms = spread_points();
+        let capped = kmeans_labels(&geoms, 3, 9, 1, 0.0).unwrap();
+        let loose = kmeans_labels(&geoms, 3, 9, 1000, 1e9).unwrap();
+        assert_eq!(capped, loose);
+
+        let defaults = cluster_kmeans_labels_seeded(&geoms, 3, 9).unwrap();
+        let explicit =
+            kmeans_labels(&geoms, 3, 9, DEFAULT_KMEANS_MAX_ITER, DEFAULT_KMEANS_TOL).unwrap();
+        assert_eq!(defaults, explicit);
+    }
+
+    #[test]
+    fn opts_invalid_arguments_rejected() {
+        let geoms = spread_points();
+        assert!(st_cluster_kmeans_opts(&geoms, 3, 0, 0.0).is_err());
+        assert!(st_cluster_kmeans_opts(&geoms, 3, 10, -1.0).is_err());
+        assert!(st_cluster_kmeans_opts(&geoms, 3, 10, f64::NAN).is_err());
+    }
 }
@@ -13,6 +13,27 @@ pub fn st_cluster_within(
     geoms: &[SurrealGeometry],
     distance: f64,
 ) -> Result<SurrealGeometry, FunctionError> {
+    let assignments = cluster_within_labels(geoms, distance)?;
+    super::build_cluster_result(geoms, &assignments, *geoms[0].srid())
+}
+
+/// Like [`st_cluster_within`], but each cluster is a GeometryCollection of the original
+/// input geometries rather than a MultiPoint of their centroids.
+pub fn st_cluster_within_collect(
+    geoms: &[SurrealGeometry],
+    distance: f64,
+) -> Result<SurrealGeometry, FunctionError> {
+    let assignments = cluster_within_labels(geoms, distance)?;
+    super::build_cluster_collection(geoms, &assignments, *geoms[0].srid())
+}
+
+/// Cluster label per input geometry, in input order, so results can be
+/// joined back to their source rows. Every input is assigned a cluster,
+/// so labels are never `None`.
+pub fn cluster_within_labels(
+    geoms: &[SurrealGeometry],
+    distance: f64,
+) -> Result<Vec<Option<usize>>, FunctionError> {
     if geoms.is_empty() {
         return Err(FunctionError::InvalidArgument(
             "Empty geometry input".into(),
@@ -40,58 +61,18 @@ pub fn st_cluster_within(
             let dx = points[i][0] - points[j][0];
             let dy = points[i][1] - points[j][1];
             if dx * dx + dy * dy <= dist_sq {
-                union(&mut parent, &mut rank, i, j);
+                super::union(&mut parent, &mut rank, i, j);
             }
         }
     }
 
-    // Build cluster assignments from Union-Find roots
-    let mut cluster_map: std::collections::HashMap<usize, usize> =
-        std::collections::HashMap::new();
-    let mut next_id = 0;
-    let assignments: Vec<Option<usize>> = (0..n)
-        .map(|i| {
-            let root = find(&mut parent, i);
-            let id = *cluster_map.entry(root).or_insert_with(|| {
-                let id = next_id;
-                next_id += 1;
-                id
-            });
-            Some(id)
-        })
-        .collect();
-
-    let srid = *geoms[0].srid();
-    super::build_cluster_result(geoms, &assignments, srid)
-}
-
-/// Find root with path compression.
-fn find(parent: &mut [usize], i: usize) -> usize {
-    if parent[i] != i {
-        parent[i] = find(parent, parent[i]);
-    }
-    parent[i]
-}
-
-/// Union by rank.
-fn union(parent: &mut [usize], rank: &mut [usize], a: usize, b: usize) {
-    let ra = find(parent, a);
-    let rb = find(parent, b);
-    if ra != rb {
-        if rank[ra] < rank[rb] {
-            parent[ra] = rb;
-        } else if rank[ra] > rank[rb] {
-            parent[rb] = ra;
-        } else {
-            parent[rb] = ra;
-            rank[ra] += 1;
-        }
-    }
+    Ok(super::root_labels(&mut parent))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clustering::test_helpers::{cluster_members, square};
     use surrealgis_core::srid::Srid;
 
     fn make_point(x: f64, y: f64) -> SurrealGeometry {
@@ -237,4 +218,27 @@ mod tests {
         let result = st_cluster_within(&geoms, 2.0).unwrap();
         assert_eq!(*result.srid(), Srid::WEB_MERCATOR);
     }
+
+    #[test]
+    fn collect_keeps_original_polygons() {
+        let geoms = vec![square(0.0, 0.0, 1.0), square(20.0, 0.0, 4.0), square(1.0, 0.0, 1.0)];
+        let result = st_cluster_within_collect(&geoms, 1.5).unwrap();
+        let clusters = cluster_members(&result);
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[0], vec![geoms[0].clone(), geoms[2].clone()]);
+        assert_eq!(clusters[1], vec![geoms[1].clone()]);
+        assert_eq!(clusters[1][0].type_name(), "Polygon");
+    }
+
+    #[test]
+    fn labels_align_with_input_order() {
+        let geoms = vec![
+            make_point(0.0, 0.0),
+            make_point(10.0, 0.0),
+            make_point(0.5, 0.0),
+            make_point(30.0, 0.0),
+        ];
+        let labels = cluster_within_labels(&geoms, 1.0).unwrap();
+        assert_eq!(labels, vec![Some(0), Some(1), Some(0), Some(2)]);
+    }
 }
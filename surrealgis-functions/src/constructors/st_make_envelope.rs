@@ -1,4 +1,4 @@
-use surrealgis_core::coordinate::Coordinate;
+use surrealgis_core::bbox::BoundingBox;
 use surrealgis_core::geometry::SurrealGeometry;
 use surrealgis_core::srid::Srid;
 
@@ -24,14 +24,7 @@ pub fn st_make_envelope(
     }
 
     let srid = Srid::new(srid)?;
-    let exterior = vec![
-        Coordinate::new(xmin, ymin)?,
-        Coordinate::new(xmax, ymin)?,
-        Coordinate::new(xmax, ymax)?,
-        Coordinate::new(xmin, ymax)?,
-        Coordinate::new(xmin, ymin)?,
-    ];
-    let geom = SurrealGeometry::polygon(exterior, vec![], srid)?;
+    let geom = BoundingBox::new(xmin, ymin, xmax, ymax)?.to_polygon(srid)?;
     Ok(geom)
 }
 
@@ -2,10 +2,12 @@ use surrealgis_core::coordinate::Coordinate;
 use surrealgis_core::geometry::SurrealGeometry;
 use surrealgis_core::srid::Srid;
 
+use crate::accessors::hole_outside_shell;
 use crate::FunctionError;
 
 /// Create a Polygon from exterior ring coordinates and optional hole rings.
-/// Each ring is a slice of (x, y) pairs. Rings must be closed (first == last).
+/// Each ring is a slice of (x, y) pairs. Rings must be closed (first == last)
+/// and every hole must lie within the exterior ring.
 pub fn st_make_polygon(
     exterior: &[(f64, f64)],
     holes: &[Vec<(f64, f64)>],
@@ -27,7 +29,15 @@ pub fn st_make_polygon(
         })
         .collect();
 
-    let geom = SurrealGeometry::polygon(ext_coords?, hole_coords?, srid)?;
+    let (ext_coords, hole_coords) = (ext_coords?, hole_coords?);
+    if let Some((i, at)) = hole_outside_shell(&ext_coords, &hole_coords) {
+        return Err(FunctionError::InvalidArgument(format!(
+            "Hole {i} lies outside the exterior ring at ({}, {})",
+            at.x, at.y
+        )));
+    }
+
+    let geom = SurrealGeometry::polygon(ext_coords, hole_coords, srid)?;
     Ok(geom)
 }
 
@@ -63,4 +73,16 @@ mod tests {
         let result = st_make_polygon(&exterior, &[], 4326);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn make_polygon_hole_outside_shell_fails() {
+        let exterior = vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0), (0.0, 0.0)];
+        let hole = vec![(20.0, 20.0), (22.0, 20.0), (22.0, 22.0), (20.0, 20.0)];
+        match st_make_polygon(&exterior, &[hole], 4326) {
+            Err(FunctionError::InvalidArgument(msg)) => {
+                assert!(msg.contains("Hole 0 lies outside the exterior ring"), "{msg}");
+            }
+            other => panic!("Expected InvalidArgument, got {other:?}"),
+        }
+    }
 }
@@ -1,15 +1,31 @@
 mod st_reverse;
 mod st_force_2d;
+mod st_force_3d;
+mod st_force_sfs;
 mod st_snap_to_grid;
 mod st_collect;
 mod st_multi;
 mod st_line_merge;
 mod st_unary_union;
+mod st_edit_ring;
+mod st_edit_line;
+mod st_normalize;
+mod st_remove_repeated_points;
+mod st_segmentize;
+mod st_drape;
 
 pub use st_reverse::st_reverse;
-pub use st_force_2d::st_force_2d;
-pub use st_snap_to_grid::st_snap_to_grid;
+pub use st_force_2d::{st_force_2d, force_2d_checked};
+pub use st_force_3d::st_force_3d;
+pub use st_force_sfs::st_force_sfs;
+pub use st_snap_to_grid::{st_snap_to_grid, snap_with_cell};
 pub use st_collect::st_collect;
 pub use st_multi::st_multi;
 pub use st_line_merge::st_line_merge;
 pub use st_unary_union::st_unary_union;
+pub use st_edit_ring::{st_remove_ring_point, st_set_ring_point};
+pub use st_edit_line::{st_add_point, st_remove_point, st_set_point};
+pub use st_normalize::st_normalize;
+pub use st_remove_repeated_points::st_remove_repeated_points;
+pub use st_segmentize::st_segmentize;
+pub use st_drape::st_drape;
@@ -0,0 +1,150 @@
+use surrealgis_core::coordinate::Coordinate;
+use surrealgis_core::geometry::{GeometryType, SurrealGeometry};
+use surrealgis_crs::registry;
+
+use crate::FunctionError;
+
+/// Number of nearest reference points blended for each vertex.
+const IDW_NEIGHBOURS: usize = 4;
+
+/// Drape a geometry over a sampled surface, replacing each vertex's Z with a
+/// value interpolated from the nearest 3D reference points by inverse-distance
+/// weighting (power 2). A vertex that coincides with a reference point takes
+/// its Z exactly. M values are kept. The reference points must be in the
+/// same CRS as the geometry.
+pub fn st_drape(
+    geom: &SurrealGeometry,
+    reference_points: &[SurrealGeometry],
+) -> Result<SurrealGeometry, FunctionError> {
+    if reference_points.is_empty() {
+        return Err(FunctionError::InvalidArgument(
+            "st_drape requires at least one reference point".into(),
+        ));
+    }
+
+    if let Some(p) = reference_points
+        .iter()
+        .find(|p| !registry::srid_equivalent(p.srid().code(), geom.srid().code()))
+    {
+        return Err(FunctionError::InvalidArgument(format!(
+            "st_drape reference points must have SRID {}, got {}",
+            geom.srid().code(),
+            p.srid().code()
+        )));
+    }
+
+    let samples = reference_points
+        .iter()
+        .map(|p| match p.geometry_type() {
+            GeometryType::Point(c) => c.z().map(|z| (c.x(), c.y(), z)).ok_or_else(|| {
+                FunctionError::InvalidArgument("st_drape reference points must have Z".into())
+            }),
+            _ => Err(FunctionError::InvalidArgument(format!(
+                "st_drape reference geometries must be Points, got {}",
+                p.type_name()
+            ))),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    geom.map_coords(|c| {
+        let z = interpolate_z(&samples, c.x(), c.y());
+        match c.m() {
+            Some(m) => Coordinate::new_4d(c.x(), c.y(), z, m),
+            None => Coordinate::new_3d(c.x(), c.y(), z),
+        }
+    })
+    .map_err(FunctionError::from)
+}
+
+fn interpolate_z(samples: &[(f64, f64, f64)], x: f64, y: f64) -> f64 {
+    let mut nearest: Vec<(f64, f64)> = samples
+        .iter()
+        .map(|&(sx, sy, sz)| ((sx - x).powi(2) + (sy - y).powi(2), sz))
+        .collect();
+    // Partition out the closest few instead of sorting every sample
+    if nearest.len() > IDW_NEIGHBOURS {
+        nearest.select_nth_unstable_by(IDW_NEIGHBOURS - 1, |a, b| a.0.total_cmp(&b.0));
+        nearest.truncate(IDW_NEIGHBOURS);
+    }
+
+    if let Some(&(_, z)) = nearest.iter().find(|(d2, _)| *d2 == 0.0) {
+        return z;
+    }
+    // Squared distances already give the power-2 weights
+    let (weighted, total) = nearest
+        .iter()
+        .fold((0.0, 0.0), |(wz, wt), &(d2, z)| (wz + z / d2, wt + 1.0 / d2));
+    weighted / total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use surrealgis_core::srid::Srid;
+
+    fn reference(x: f64, y: f64, z: f64) -> SurrealGeometry {
+        let c = Coordinate::new_3d(x, y, z).unwrap();
+        SurrealGeometry::point_from_coordinate(c, Srid::WEB_MERCATOR).unwrap()
+    }
+
+    #[test]
+    fn drape_line_between_two_heights() {
+        let coords = vec![
+            Coordinate::new(0.0, 0.0).unwrap(),
+            Coordinate::new(2.5, 0.0).unwrap(),
+            Coordinate::new(5.0, 0.0).unwrap(),
+            Coordinate::new(10.0, 0.0).unwrap(),
+        ];
+        let line = SurrealGeometry::line_string(coords, Srid::WEB_MERCATOR).unwrap();
+        let refs = vec![reference(0.0, 0.0, 0.0), reference(10.0, 0.0, 100.0)];
+        let result = st_drape(&line, &refs).unwrap();
+        assert_eq!(result.dimension(), 3);
+        match result.geometry_type() {
+            GeometryType::LineString(cs) => {
+                let zs: Vec<f64> = cs.iter().map(|c| c.z().unwrap()).collect();
+                assert_eq!(zs[0], 0.0);
+                assert_eq!(zs[3], 100.0);
+                assert!((zs[2] - 50.0).abs() < 1e-9);
+                assert!(zs[1] > 0.0 && zs[1] < zs[2]);
+            }
+            _ => panic!("Expected LineString"),
+        }
+        assert_eq!(result.srid().code(), Srid::WEB_MERCATOR.code());
+    }
+
+    #[test]
+    fn drape_rejects_empty_reference_set() {
+        let p = SurrealGeometry::point(1.0, 1.0, Srid::WEB_MERCATOR).unwrap();
+        assert!(matches!(st_drape(&p, &[]), Err(FunctionError::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn drape_uses_only_the_nearest_references() {
+        // Far-off samples beyond the nearest four must not pull the value
+        let mut refs: Vec<_> = [(1.0, 0.0), (-1.0, 0.0), (0.0, 1.0), (0.0, -1.0)]
+            .iter()
+            .map(|&(x, y)| reference(x, y, 10.0))
+            .collect();
+        refs.extend((0..20).map(|i| reference(100.0 + i as f64, 100.0, 1000.0)));
+        let p = SurrealGeometry::point(0.0, 0.0, Srid::WEB_MERCATOR).unwrap();
+        match st_drape(&p, &refs).unwrap().geometry_type() {
+            GeometryType::Point(c) => assert!((c.z().unwrap() - 10.0).abs() < 1e-9),
+            _ => panic!("Expected Point"),
+        }
+    }
+
+    #[test]
+    fn drape_rejects_reference_in_other_srid() {
+        let p = SurrealGeometry::point(1.0, 1.0, Srid::WEB_MERCATOR).unwrap();
+        let c = Coordinate::new_3d(0.0, 0.0, 5.0).unwrap();
+        let wgs84 = SurrealGeometry::point_from_coordinate(c, Srid::WGS84).unwrap();
+        assert!(matches!(st_drape(&p, &[wgs84]), Err(FunctionError::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn drape_rejects_reference_without_z() {
+        let p = SurrealGeometry::point(1.0, 1.0, Srid::WEB_MERCATOR).unwrap();
+        let flat = SurrealGeometry::point(0.0, 0.0, Srid::WEB_MERCATOR).unwrap();
+        assert!(matches!(st_drape(&p, &[flat]), Err(FunctionError::InvalidArgument(_))));
+    }
+}
@@ -0,0 +1,168 @@
+use surrealgis_core::coordinate::Coordinate;
+use surrealgis_core::geometry::{GeometryType, SurrealGeometry};
+
+use crate::FunctionError;
+
+/// Insert a Point into a LineString before vertex `position` (0-based),
+/// or append it when `position` is None. `position` may equal the vertex
+/// count, which also appends.
+pub fn st_add_point(
+    line: &SurrealGeometry,
+    point: &SurrealGeometry,
+    position: Option<usize>,
+) -> Result<SurrealGeometry, FunctionError> {
+    let coord = point_coord(point, "st_add_point")?;
+    edit_line(line, "st_add_point", |coords| {
+        let n = coords.len();
+        let position = position.unwrap_or(n);
+        if position > n {
+            return Err(FunctionError::InvalidArgument(format!(
+                "Insert position {position} out of range for line with {n} points"
+            )));
+        }
+        let mut result = coords.to_vec();
+        result.insert(position, coord);
+        Ok(result)
+    })
+}
+
+/// Remove vertex `index` (0-based) from a LineString. The line must keep at
+/// least 2 points.
+pub fn st_remove_point(
+    line: &SurrealGeometry,
+    index: usize,
+) -> Result<SurrealGeometry, FunctionError> {
+    edit_line(line, "st_remove_point", |coords| {
+        check_line_index(coords.len(), index)?;
+        let mut result = coords.to_vec();
+        result.remove(index);
+        Ok(result)
+    })
+}
+
+/// Replace vertex `index` (0-based) of a LineString with the given Point.
+pub fn st_set_point(
+    line: &SurrealGeometry,
+    index: usize,
+    point: &SurrealGeometry,
+) -> Result<SurrealGeometry, FunctionError> {
+    let coord = point_coord(point, "st_set_point")?;
+    edit_line(line, "st_set_point", |coords| {
+        check_line_index(coords.len(), index)?;
+        let mut result = coords.to_vec();
+        result[index] = coord;
+        Ok(result)
+    })
+}
+
+fn point_coord(point: &SurrealGeometry, name: &str) -> Result<Coordinate, FunctionError> {
+    match point.geometry_type() {
+        GeometryType::Point(c) => Ok(c.clone()),
+        _ => Err(FunctionError::InvalidArgument(format!(
+            "{name} requires a Point as the vertex"
+        ))),
+    }
+}
+
+fn check_line_index(n: usize, index: usize) -> Result<(), FunctionError> {
+    if index >= n {
+        return Err(FunctionError::InvalidArgument(format!(
+            "Point index {index} out of range for line with {n} points"
+        )));
+    }
+    Ok(())
+}
+
+fn edit_line<F>(
+    line: &SurrealGeometry,
+    name: &str,
+    edit: F,
+) -> Result<SurrealGeometry, FunctionError>
+where
+    F: FnOnce(&[Coordinate]) -> Result<Vec<Coordinate>, FunctionError>,
+{
+    let coords = match line.geometry_type() {
+        GeometryType::LineString(coords) => coords,
+        _ => {
+            return Err(FunctionError::UnsupportedOperation(format!(
+                "{name} requires a LineString geometry, got {}",
+                line.type_name()
+            )))
+        }
+    };
+    let result = edit(coords)?;
+    if result.len() < 2 {
+        return Err(FunctionError::InvalidArgument(format!(
+            "{name} would leave a LineString with {} point(s); at least 2 are required",
+            result.len()
+        )));
+    }
+    SurrealGeometry::line_string(result, *line.srid()).map_err(FunctionError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::line;
+    use surrealgis_core::srid::Srid;
+
+    fn point(x: f64, y: f64) -> SurrealGeometry {
+        SurrealGeometry::point(x, y, Srid::WEB_MERCATOR).unwrap()
+    }
+
+    fn xy(geom: &SurrealGeometry) -> Vec<(f64, f64)> {
+        match geom.geometry_type() {
+            GeometryType::LineString(coords) => coords.iter().map(|c| (c.x(), c.y())).collect(),
+            _ => panic!("Expected LineString"),
+        }
+    }
+
+    #[test]
+    fn add_point_appends_or_inserts() {
+        let l = line(&[(0.0, 0.0), (2.0, 0.0)]);
+        let appended = st_add_point(&l, &point(3.0, 0.0), None).unwrap();
+        assert_eq!(xy(&appended), vec![(0.0, 0.0), (2.0, 0.0), (3.0, 0.0)]);
+        let inserted = st_add_point(&l, &point(1.0, 1.0), Some(1)).unwrap();
+        assert_eq!(xy(&inserted), vec![(0.0, 0.0), (1.0, 1.0), (2.0, 0.0)]);
+        assert_eq!(*inserted.srid(), Srid::WEB_MERCATOR);
+        assert!(st_add_point(&l, &point(1.0, 1.0), Some(3)).is_err());
+    }
+
+    #[test]
+    fn remove_point_keeps_at_least_two() {
+        let l = line(&[(0.0, 0.0), (1.0, 1.0), (2.0, 0.0)]);
+        let removed = st_remove_point(&l, 1).unwrap();
+        assert_eq!(xy(&removed), vec![(0.0, 0.0), (2.0, 0.0)]);
+        assert!(matches!(
+            st_remove_point(&removed, 0),
+            Err(FunctionError::InvalidArgument(_))
+        ));
+        assert!(st_remove_point(&l, 3).is_err());
+    }
+
+    #[test]
+    fn set_point_replaces_vertex() {
+        let l = line(&[(0.0, 0.0), (1.0, 1.0), (2.0, 0.0)]);
+        let moved = st_set_point(&l, 2, &point(5.0, 5.0)).unwrap();
+        assert_eq!(xy(&moved), vec![(0.0, 0.0), (1.0, 1.0), (5.0, 5.0)]);
+        assert!(st_set_point(&l, 3, &point(5.0, 5.0)).is_err());
+        assert!(st_set_point(&l, 0, &l).is_err());
+    }
+
+    #[test]
+    fn non_linestring_is_unsupported() {
+        let p = point(0.0, 0.0);
+        assert!(matches!(
+            st_add_point(&p, &p, None),
+            Err(FunctionError::UnsupportedOperation(_))
+        ));
+        assert!(matches!(
+            st_remove_point(&p, 0),
+            Err(FunctionError::UnsupportedOperation(_))
+        ));
+        assert!(matches!(
+            st_set_point(&p, 0, &p),
+            Err(FunctionError::UnsupportedOperation(_))
+        ));
+    }
+}
@@ -0,0 +1,238 @@
+use surrealgis_core::coordinate::Coordinate;
+use surrealgis_core::geometry::{GeometryType, SurrealGeometry};
+
+use crate::FunctionError;
+
+/// Remove a vertex from a Polygon ring, keeping the ring closed.
+/// `ring` 0 is the exterior, 1.. are the holes in order.
+/// Removing the first or closing vertex drops it and re-closes the ring
+/// on the next vertex. The ring must still have at least 4 points afterwards.
+pub fn st_remove_ring_point(
+    geom: &SurrealGeometry,
+    ring: usize,
+    index: usize,
+) -> Result<SurrealGeometry, FunctionError> {
+    edit_ring(geom, ring, "st_remove_ring_point", |coords| {
+        remove_ring_point(coords, index)
+    })
+}
+
+/// Replace a vertex of a Polygon ring with the given Point.
+/// `ring` 0 is the exterior, 1.. are the holes in order.
+/// Setting the first or closing vertex updates both so the ring stays closed.
+pub fn st_set_ring_point(
+    geom: &SurrealGeometry,
+    ring: usize,
+    index: usize,
+    point: &SurrealGeometry,
+) -> Result<SurrealGeometry, FunctionError> {
+    let coord = match point.geometry_type() {
+        GeometryType::Point(c) => c.clone(),
+        _ => {
+            return Err(FunctionError::InvalidArgument(
+                "st_set_ring_point requires a Point as the replacement vertex".to_string(),
+            ))
+        }
+    };
+    edit_ring(geom, ring, "st_set_ring_point", |coords| {
+        set_ring_point(coords, index, coord)
+    })
+}
+
+/// Remove vertex `index` from a closed ring, re-closing it if needed.
+pub(crate) fn remove_ring_point(
+    coords: &[Coordinate],
+    index: usize,
+) -> Result<Vec<Coordinate>, FunctionError> {
+    let n = coords.len();
+    check_ring_index(n, index)?;
+    if n <= 4 {
+        return Err(FunctionError::InvalidArgument(format!(
+            "Cannot remove a point from a ring with {n} points; a ring needs at least 4"
+        )));
+    }
+    let mut result = coords.to_vec();
+    if index == 0 || index == n - 1 {
+        // Drop the shared start/end vertex and close on the new first vertex
+        result.remove(0);
+        let last = result.len() - 1;
+        result[last] = result[0].clone();
+    } else {
+        result.remove(index);
+    }
+    Ok(result)
+}
+
+/// Replace vertex `index` of a closed ring, keeping first and last in sync.
+pub(crate) fn set_ring_point(
+    coords: &[Coordinate],
+    index: usize,
+    coord: Coordinate,
+) -> Result<Vec<Coordinate>, FunctionError> {
+    let n = coords.len();
+    check_ring_index(n, index)?;
+    let mut result = coords.to_vec();
+    if index == 0 || index == n - 1 {
+        result[0] = coord.clone();
+        result[n - 1] = coord;
+    } else {
+        result[index] = coord;
+    }
+    Ok(result)
+}
+
+fn check_ring_index(n: usize, index: usize) -> Result<(), FunctionError> {
+    if index >= n {
+        return Err(FunctionError::InvalidArgument(format!(
+            "Point index {index} out of range for ring with {n} points"
+        )));
+    }
+    Ok(())
+}
+
+fn edit_ring<F>(
+    geom: &SurrealGeometry,
+    ring: usize,
+    name: &str,
+    edit: F,
+) -> Result<SurrealGeometry, FunctionError>
+where
+    F: FnOnce(&[Coordinate]) -> Result<Vec<Coordinate>, FunctionError>,
+{
+    let (exterior, holes) = match geom.geometry_type() {
+        GeometryType::Polygon { exterior, holes } => (exterior, holes),
+        _ => {
+            return Err(FunctionError::InvalidArgument(format!(
+                "{name} requires a Polygon geometry"
+            )))
+        }
+    };
+    let mut exterior = exterior.clone();
+    let mut holes = holes.clone();
+    if ring == 0 {
+        exterior = edit(&exterior)?;
+    } else {
+        let ring_count = holes.len() + 1;
+        let hole = holes.get_mut(ring - 1).ok_or_else(|| {
+            FunctionError::InvalidArgument(format!(
+                "Ring index {ring} out of range for polygon with {ring_count} rings"
+            ))
+        })?;
+        *hole = edit(hole)?;
+    }
+    SurrealGeometry::polygon(exterior, holes, *geom.srid()).map_err(FunctionError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use surrealgis_core::srid::Srid;
+
+    fn square_with_hole() -> SurrealGeometry {
+        let exterior = vec![
+            Coordinate::new(0.0, 0.0).unwrap(),
+            Coordinate::new(10.0, 0.0).unwrap(),
+            Coordinate::new(10.0, 10.0).unwrap(),
+            Coordinate::new(0.0, 10.0).unwrap(),
+            Coordinate::new(0.0, 0.0).unwrap(),
+        ];
+        let hole = vec![
+            Coordinate::new(2.0, 2.0).unwrap(),
+            Coordinate::new(4.0, 2.0).unwrap(),
+            Coordinate::new(4.0, 4.0).unwrap(),
+            Coordinate::new(2.0, 2.0).unwrap(),
+        ];
+        SurrealGeometry::polygon(exterior, vec![hole], Srid::WEB_MERCATOR).unwrap()
+    }
+
+    fn exterior_of(geom: &SurrealGeometry) -> Vec<Coordinate> {
+        match geom.geometry_type() {
+            GeometryType::Polygon { exterior, .. } => exterior.clone(),
+            _ => panic!("Expected Polygon"),
+        }
+    }
+
+    #[test]
+    fn set_closing_vertex_keeps_ring_closed() {
+        let poly = square_with_hole();
+        let p = SurrealGeometry::point(-1.0, -1.0, Srid::WEB_MERCATOR).unwrap();
+        let result = st_set_ring_point(&poly, 0, 4, &p).unwrap();
+        let ext = exterior_of(&result);
+        assert_eq!(ext[0], ext[4]);
+        assert!((ext[0].x() + 1.0).abs() < 1e-10);
+        assert!((ext[0].y() + 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn set_first_vertex_keeps_ring_closed() {
+        let poly = square_with_hole();
+        let p = SurrealGeometry::point(-2.0, 0.0, Srid::WEB_MERCATOR).unwrap();
+        let result = st_set_ring_point(&poly, 0, 0, &p).unwrap();
+        let ext = exterior_of(&result);
+        assert_eq!(ext.first(), ext.last());
+        assert!((ext[4].x() + 2.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn set_middle_vertex() {
+        let poly = square_with_hole();
+        let p = SurrealGeometry::point(12.0, 12.0, Srid::WEB_MERCATOR).unwrap();
+        let result = st_set_ring_point(&poly, 0, 2, &p).unwrap();
+        let ext = exterior_of(&result);
+        assert!((ext[2].x() - 12.0).abs() < 1e-10);
+        assert_eq!(ext.len(), 5);
+    }
+
+    #[test]
+    fn remove_closing_vertex_recloses_ring() {
+        let poly = square_with_hole();
+        let result = st_remove_ring_point(&poly, 0, 4).unwrap();
+        let ext = exterior_of(&result);
+        assert_eq!(ext.len(), 4);
+        assert_eq!(ext.first(), ext.last());
+        assert!((ext[0].x() - 10.0).abs() < 1e-10);
+        assert!((ext[0].y() - 0.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn remove_middle_vertex() {
+        let poly = square_with_hole();
+        let result = st_remove_ring_point(&poly, 0, 2).unwrap();
+        let ext = exterior_of(&result);
+        assert_eq!(ext.len(), 4);
+        assert_eq!(ext.first(), ext.last());
+    }
+
+    #[test]
+    fn over_removal_rejected() {
+        let poly = square_with_hole();
+        // The hole is a triangle (4 points) and cannot lose any vertex
+        let result = st_remove_ring_point(&poly, 1, 1);
+        assert!(matches!(result, Err(FunctionError::InvalidArgument(_))));
+
+        // The exterior can lose one vertex but not two
+        let once = st_remove_ring_point(&poly, 0, 1).unwrap();
+        assert!(st_remove_ring_point(&once, 0, 1).is_err());
+    }
+
+    #[test]
+    fn ring_index_out_of_range() {
+        let poly = square_with_hole();
+        assert!(st_remove_ring_point(&poly, 2, 0).is_err());
+        assert!(st_remove_ring_point(&poly, 0, 5).is_err());
+    }
+
+    #[test]
+    fn non_polygon_rejected() {
+        let p = SurrealGeometry::point(1.0, 2.0, Srid::WGS84).unwrap();
+        assert!(st_remove_ring_point(&p, 0, 0).is_err());
+        assert!(st_set_ring_point(&p, 0, 0, &p).is_err());
+    }
+
+    #[test]
+    fn set_ring_point_requires_point() {
+        let poly = square_with_hole();
+        let result = st_set_ring_point(&poly, 0, 1, &poly);
+        assert!(matches!(result, Err(FunctionError::InvalidArgument(_))));
+    }
+}
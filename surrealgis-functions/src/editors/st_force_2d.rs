@@ -1,21 +1,110 @@
-use surrealgis_core::geometry::SurrealGeometry;
+use surrealgis_core::coordinate::Coordinate;
+use surrealgis_core::geometry::{GeometryType, SurrealGeometry};
 
 use crate::FunctionError;
 
+/// A flattened ring whose area is at most this fraction of its squared
+/// perimeter is treated as collapsed (a sliver with no 2D extent).
+const DEGENERATE_AREA_RATIO: f64 = 1e-9;
+
 /// Force a geometry to 2D by stripping any Z/M coordinates.
-/// Since our domain model is already 2D (only x, y in Coordinate),
-/// this is effectively a roundtrip through geo to normalize the geometry.
 /// Implemented for PostGIS compatibility.
 pub fn st_force_2d(geom: &SurrealGeometry) -> Result<SurrealGeometry, FunctionError> {
-    let geo_geom = geom.to_geo()?;
-    SurrealGeometry::from_geo(&geo_geom, *geom.srid()).map_err(FunctionError::from)
+    geom.map_coords(|c| Coordinate::new(c.x(), c.y()))
+        .map_err(FunctionError::from)
+}
+
+/// Force a geometry to 2D and report any parts that became degenerate.
+/// A line needs 2 distinct vertices; a ring needs 3 distinct vertices and
+/// non-zero area once flattened (e.g. a vertical 3D wall collapses to a line).
+/// On failure, returns one message per degenerate part so batch importers
+/// can log and skip the geometry.
+pub fn force_2d_checked(geom: &SurrealGeometry) -> Result<SurrealGeometry, Vec<String>> {
+    let flat = st_force_2d(geom).map_err(|e| vec![e.to_string()])?;
+    let mut problems = Vec::new();
+    collect_degenerate(flat.geometry_type(), flat.type_name(), &mut problems);
+    if problems.is_empty() {
+        Ok(flat)
+    } else {
+        Err(problems)
+    }
+}
+
+fn collect_degenerate(gt: &GeometryType, path: &str, out: &mut Vec<String>) {
+    match gt {
+        GeometryType::Point(_) | GeometryType::MultiPoint(_) => {}
+        GeometryType::LineString(coords) => check_line(coords, path, out),
+        GeometryType::Polygon { exterior, holes } => {
+            check_ring(exterior, &format!("{path} exterior ring"), out);
+            for (i, hole) in holes.iter().enumerate() {
+                check_ring(hole, &format!("{path} hole {i}"), out);
+            }
+        }
+        GeometryType::MultiLineString(lines) => {
+            for (i, line) in lines.iter().enumerate() {
+                check_line(line, &format!("{path}[{i}]"), out);
+            }
+        }
+        GeometryType::MultiPolygon(polygons) => {
+            for (i, poly) in polygons.iter().enumerate() {
+                check_ring(&poly.exterior, &format!("{path}[{i}] exterior ring"), out);
+                for (j, hole) in poly.holes.iter().enumerate() {
+                    check_ring(hole, &format!("{path}[{i}] hole {j}"), out);
+                }
+            }
+        }
+        GeometryType::GeometryCollection(geoms) => {
+            for (i, g) in geoms.iter().enumerate() {
+                let child = format!("{path}[{i}] {}", g.type_name());
+                collect_degenerate(g.geometry_type(), &child, out);
+            }
+        }
+    }
+}
+
+fn check_line(coords: &[Coordinate], path: &str, out: &mut Vec<String>) {
+    if distinct_vertices(coords) < 2 {
+        out.push(format!("{path}: fewer than 2 distinct vertices"));
+    }
+}
+
+fn check_ring(coords: &[Coordinate], path: &str, out: &mut Vec<String>) {
+    if distinct_vertices(coords) < 3 {
+        out.push(format!("{path}: fewer than 3 distinct vertices"));
+        return;
+    }
+    let mut twice_area = 0.0;
+    let mut perimeter = 0.0;
+    for w in coords.windows(2) {
+        twice_area += w[0].x() * w[1].y() - w[1].x() * w[0].y();
+        perimeter += ((w[1].x() - w[0].x()).powi(2) + (w[1].y() - w[0].y()).powi(2)).sqrt();
+    }
+    let area = (twice_area / 2.0).abs();
+    if area <= DEGENERATE_AREA_RATIO * perimeter * perimeter {
+        out.push(format!("{path}: collapsed to zero area"));
+    }
+}
+
+/// Count vertices after dropping consecutive duplicates and a closing repeat.
+fn distinct_vertices(coords: &[Coordinate]) -> usize {
+    let mut count = 0;
+    let mut prev: Option<(f64, f64)> = None;
+    for c in coords {
+        let xy = (c.x(), c.y());
+        if prev != Some(xy) {
+            count += 1;
+            prev = Some(xy);
+        }
+    }
+    if count > 1 && coords.first().map(|c| (c.x(), c.y())) == prev {
+        count -= 1;
+    }
+    count
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use surrealgis_core::coordinate::Coordinate;
-    use surrealgis_core::geometry::GeometryType;
     use surrealgis_core::srid::Srid;
 
     #[test]
@@ -70,4 +159,67 @@ mod tests {
         let result = st_force_2d(&p).unwrap();
         assert_eq!(result.dimension(), 2);
     }
+
+    #[test]
+    fn force_2d_strips_z() {
+        let coords = vec![
+            Coordinate::new_3d(0.0, 0.0, 1.0).unwrap(),
+            Coordinate::new_3d(1.0, 1.0, 2.0).unwrap(),
+        ];
+        let line = SurrealGeometry::line_string(coords, Srid::WGS84).unwrap();
+        let result = st_force_2d(&line).unwrap();
+        if let GeometryType::LineString(cs) = result.geometry_type() {
+            assert!(cs.iter().all(|c| c.z().is_none()));
+        } else {
+            panic!("Expected LineString");
+        }
+    }
+
+    #[test]
+    fn checked_accepts_regular_polygon() {
+        let exterior = vec![
+            Coordinate::new_3d(0.0, 0.0, 5.0).unwrap(),
+            Coordinate::new_3d(10.0, 0.0, 5.0).unwrap(),
+            Coordinate::new_3d(10.0, 10.0, 5.0).unwrap(),
+            Coordinate::new_3d(0.0, 0.0, 5.0).unwrap(),
+        ];
+        let poly = SurrealGeometry::polygon(exterior, vec![], Srid::WEB_MERCATOR).unwrap();
+        let result = force_2d_checked(&poly).unwrap();
+        assert_eq!(result.num_points(), 4);
+    }
+
+    #[test]
+    fn checked_flags_vertical_sliver_polygon() {
+        // A wall standing almost vertically: large Z extent, ~zero Y extent
+        let exterior = vec![
+            Coordinate::new_3d(0.0, 0.0, 0.0).unwrap(),
+            Coordinate::new_3d(10.0, 1e-12, 0.0).unwrap(),
+            Coordinate::new_3d(10.0, 2e-12, 30.0).unwrap(),
+            Coordinate::new_3d(0.0, 1e-12, 30.0).unwrap(),
+            Coordinate::new_3d(0.0, 0.0, 0.0).unwrap(),
+        ];
+        let poly = SurrealGeometry::polygon(exterior, vec![], Srid::WEB_MERCATOR).unwrap();
+        let problems = force_2d_checked(&poly).unwrap_err();
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains("Polygon exterior ring"), "{problems:?}");
+        assert!(problems[0].contains("zero area"), "{problems:?}");
+    }
+
+    #[test]
+    fn checked_flags_collapsed_line_in_collection() {
+        let line = SurrealGeometry::line_string(
+            vec![
+                Coordinate::new_3d(1.0, 1.0, 0.0).unwrap(),
+                Coordinate::new_3d(1.0, 1.0, 9.0).unwrap(),
+            ],
+            Srid::WEB_MERCATOR,
+        )
+        .unwrap();
+        let p = SurrealGeometry::point(0.0, 0.0, Srid::WEB_MERCATOR).unwrap();
+        let gc = SurrealGeometry::geometry_collection(vec![p, line], Srid::WEB_MERCATOR).unwrap();
+        let problems = force_2d_checked(&gc).unwrap_err();
+        assert_eq!(problems, vec![
+            "GeometryCollection[1] LineString: fewer than 2 distinct vertices".to_string()
+        ]);
+    }
 }
@@ -0,0 +1,77 @@
+use surrealgis_core::coordinate::Coordinate;
+use surrealgis_core::geometry::SurrealGeometry;
+
+use crate::FunctionError;
+
+/// Force a geometry to 3D, giving every 2D coordinate the Z value `z`.
+/// Coordinates that already carry Z keep their own value, and M is kept.
+/// Implemented for PostGIS compatibility.
+pub fn st_force_3d(geom: &SurrealGeometry, z: f64) -> Result<SurrealGeometry, FunctionError> {
+    geom.map_coords(|c| {
+        let z = c.z().unwrap_or(z);
+        match c.m() {
+            Some(m) => Coordinate::new_4d(c.x(), c.y(), z, m),
+            None => Coordinate::new_3d(c.x(), c.y(), z),
+        }
+    })
+    .map_err(FunctionError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use surrealgis_core::flags::GeometryFlags;
+    use surrealgis_core::geometry::GeometryType;
+    use surrealgis_core::srid::Srid;
+
+    #[test]
+    fn force_3d_sets_z_and_dimension() {
+        let p = SurrealGeometry::point(5.0, 10.0, Srid::WGS84).unwrap();
+        let result = st_force_3d(&p, 7.5).unwrap();
+        assert_eq!(result.dimension(), 3);
+        match result.geometry_type() {
+            GeometryType::Point(c) => assert_eq!(c.z(), Some(7.5)),
+            _ => panic!("Expected Point"),
+        }
+        assert_eq!(result.srid().code(), Srid::WGS84.code());
+    }
+
+    #[test]
+    fn force_3d_keeps_existing_z_and_m() {
+        let coords = vec![
+            Coordinate::new_3d(0.0, 0.0, 1.0).unwrap(),
+            Coordinate::new(1.0, 1.0).unwrap(),
+            Coordinate::new_m(2.0, 2.0, 40.0).unwrap(),
+        ];
+        let line = SurrealGeometry::line_string(coords, Srid::WGS84).unwrap();
+        let result = st_force_3d(&line, 0.0).unwrap();
+        // The measured vertex keeps M, so the line as a whole is 4D
+        assert_eq!(result.dimension(), 4);
+        match result.geometry_type() {
+            GeometryType::LineString(cs) => {
+                let zs: Vec<Option<f64>> = cs.iter().map(|c| c.z()).collect();
+                assert_eq!(zs, vec![Some(1.0), Some(0.0), Some(0.0)]);
+                assert_eq!(cs[2].m(), Some(40.0));
+            }
+            _ => panic!("Expected LineString"),
+        }
+    }
+
+    #[test]
+    fn force_3d_of_measured_point_is_4d() {
+        let p = SurrealGeometry::point_from_coordinate(
+            Coordinate::new_m(1.0, 2.0, 9.0).unwrap(),
+            Srid::WGS84,
+        )
+        .unwrap();
+        assert!(p.flags().contains(GeometryFlags::HAS_M));
+        let result = st_force_3d(&p, 3.0).unwrap();
+        assert_eq!(result.dimension(), 4);
+    }
+
+    #[test]
+    fn force_3d_rejects_non_finite_z() {
+        let p = SurrealGeometry::point(5.0, 10.0, Srid::WGS84).unwrap();
+        assert!(st_force_3d(&p, f64::NAN).is_err());
+    }
+}
@@ -0,0 +1,104 @@
+use surrealgis_core::geometry::{GeometryType, SurrealGeometry};
+
+use crate::accessors::st_dump;
+use crate::FunctionError;
+
+/// Force a geometry into a shape strict OGC Simple Features consumers accept.
+///
+/// GeometryCollections are flattened to their leaf geometries; if every leaf
+/// has the same type the result is the matching Multi* type, otherwise a flat
+/// GeometryCollection. Empty parts are dropped. Non-collections are returned
+/// with their empty parts removed. Fails if nothing non-empty remains.
+pub fn st_force_sfs(geom: &SurrealGeometry) -> Result<SurrealGeometry, FunctionError> {
+    let srid = *geom.srid();
+    let result = match geom.geometry_type() {
+        GeometryType::GeometryCollection(_) => {
+            let leaves: Vec<SurrealGeometry> =
+                st_dump(geom)?.into_iter().filter(|g| !is_empty_part(g)).collect();
+            // The shared leaf type, if there is exactly one
+            let common_type = leaves
+                .first()
+                .map(|g| g.type_name().to_string())
+                .filter(|t| leaves.iter().all(|g| g.type_name() == t));
+            match common_type.as_deref() {
+                Some("Point") => SurrealGeometry::multi_point_from(&leaves, srid)?,
+                Some("LineString") => SurrealGeometry::multi_line_string_from(&leaves, srid)?,
+                Some("Polygon") => SurrealGeometry::multi_polygon_from(&leaves, srid)?,
+                _ => SurrealGeometry::geometry_collection(leaves, srid)?,
+            }
+        }
+        GeometryType::MultiLineString(lines) => SurrealGeometry::multi_line_string(
+            lines.iter().filter(|l| !l.is_empty()).cloned().collect(),
+            srid,
+        )?,
+        GeometryType::MultiPolygon(polygons) => SurrealGeometry::multi_polygon(
+            polygons
+                .iter()
+                .filter(|p| !p.exterior.is_empty())
+                .cloned()
+                .collect(),
+            srid,
+        )?,
+        _ => geom.clone(),
+    };
+    Ok(result)
+}
+
+fn is_empty_part(geom: &SurrealGeometry) -> bool {
+    match geom.geometry_type() {
+        GeometryType::LineString(coords) => coords.is_empty(),
+        GeometryType::Polygon { exterior, .. } => exterior.is_empty(),
+        _ => geom.is_empty(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::square;
+    use surrealgis_core::srid::Srid;
+
+    fn collect(geoms: Vec<SurrealGeometry>) -> SurrealGeometry {
+        SurrealGeometry::geometry_collection(geoms, Srid::WEB_MERCATOR).unwrap()
+    }
+
+    #[test]
+    fn polygon_collection_becomes_multipolygon() {
+        let gc = collect(vec![
+            square(0.0, 0.0, 1.0),
+            collect(vec![square(2.0, 0.0, 1.0)]),
+            square(4.0, 0.0, 1.0),
+        ]);
+        let result = st_force_sfs(&gc).unwrap();
+        match result.geometry_type() {
+            GeometryType::MultiPolygon(polygons) => assert_eq!(polygons.len(), 3),
+            _ => panic!("Expected MultiPolygon, got {}", result.type_name()),
+        }
+        assert_eq!(*result.srid(), Srid::WEB_MERCATOR);
+    }
+
+    #[test]
+    fn point_collection_becomes_multipoint() {
+        let p = |x: f64| SurrealGeometry::point(x, 0.0, Srid::WEB_MERCATOR).unwrap();
+        let result = st_force_sfs(&collect(vec![p(0.0), p(1.0)])).unwrap();
+        assert_eq!(result.type_name(), "MultiPoint");
+        assert_eq!(result.num_points(), 2);
+    }
+
+    #[test]
+    fn mixed_collection_is_flattened() {
+        let p = SurrealGeometry::point(9.0, 9.0, Srid::WEB_MERCATOR).unwrap();
+        let gc = collect(vec![square(0.0, 0.0, 1.0), collect(vec![p.clone()])]);
+        match st_force_sfs(&gc).unwrap().geometry_type() {
+            GeometryType::GeometryCollection(items) => {
+                assert_eq!(items, &vec![square(0.0, 0.0, 1.0), p]);
+            }
+            _ => panic!("Expected GeometryCollection"),
+        }
+    }
+
+    #[test]
+    fn simple_geometry_passes_through() {
+        assert_eq!(st_force_sfs(&square(0.0, 0.0, 1.0)).unwrap(), square(0.0, 0.0, 1.0));
+    }
+}
@@ -0,0 +1,216 @@
+use std::cmp::Ordering;
+
+use surrealgis_core::coordinate::Coordinate;
+use surrealgis_core::geometry::{GeometryType, PolygonData, SurrealGeometry};
+
+use crate::FunctionError;
+
+/// Rewrite a geometry into a canonical form, like PostGIS ST_Normalize, so that
+/// structurally different encodings of the same shape compare equal.
+///
+/// - Rings start at their lowest vertex (by x, then y); exterior rings are
+///   clockwise and holes counter-clockwise.
+/// - LineStrings run from their lower endpoint.
+/// - Holes and the members of multi geometries and collections are sorted.
+///
+/// Z and M are carried along with their vertices.
+pub fn st_normalize(geom: &SurrealGeometry) -> Result<SurrealGeometry, FunctionError> {
+    let srid = *geom.srid();
+    let normalized = match geom.geometry_type() {
+        GeometryType::Point(_) => geom.clone(),
+        GeometryType::LineString(coords) => {
+            SurrealGeometry::line_string(normalize_line(coords), srid)?
+        }
+        GeometryType::Polygon { exterior, holes } => {
+            let polygon = normalize_polygon(exterior, holes);
+            SurrealGeometry::polygon(polygon.exterior, polygon.holes, srid)?
+        }
+        GeometryType::MultiPoint(coords) => {
+            let mut coords = coords.clone();
+            coords.sort_by(cmp_coord);
+            SurrealGeometry::multi_point(coords, srid)?
+        }
+        GeometryType::MultiLineString(lines) => {
+            let mut lines: Vec<Vec<Coordinate>> =
+                lines.iter().map(|l| normalize_line(l)).collect();
+            lines.sort_by(|a, b| cmp_coords(a, b));
+            SurrealGeometry::multi_line_string(lines, srid)?
+        }
+        GeometryType::MultiPolygon(polygons) => {
+            let mut polygons: Vec<PolygonData> = polygons
+                .iter()
+                .map(|p| normalize_polygon(&p.exterior, &p.holes))
+                .collect();
+            polygons.sort_by(|a, b| cmp_coords(&a.exterior, &b.exterior));
+            SurrealGeometry::multi_polygon(polygons, srid)?
+        }
+        GeometryType::GeometryCollection(geoms) => {
+            let mut members = geoms
+                .iter()
+                .map(st_normalize)
+                .collect::<Result<Vec<_>, _>>()?;
+            members.sort_by(cmp_geometry);
+            SurrealGeometry::geometry_collection(members, srid)?
+        }
+    };
+    Ok(normalized)
+}
+
+fn cmp_coord(a: &Coordinate, b: &Coordinate) -> Ordering {
+    a.x().total_cmp(&b.x()).then(a.y().total_cmp(&b.y()))
+}
+
+fn cmp_coords(a: &[Coordinate], b: &[Coordinate]) -> Ordering {
+    a.iter()
+        .zip(b)
+        .map(|(ca, cb)| cmp_coord(ca, cb))
+        .find(|o| o.is_ne())
+        .unwrap_or_else(|| a.len().cmp(&b.len()))
+}
+
+/// Order collection members by type, then by their vertices.
+fn cmp_geometry(a: &SurrealGeometry, b: &SurrealGeometry) -> Ordering {
+    a.type_name()
+        .cmp(b.type_name())
+        .then_with(|| cmp_coords(&all_coords(a), &all_coords(b)))
+}
+
+fn all_coords(geom: &SurrealGeometry) -> Vec<Coordinate> {
+    match geom.geometry_type() {
+        GeometryType::Point(c) => vec![c.clone()],
+        GeometryType::LineString(coords) | GeometryType::MultiPoint(coords) => coords.clone(),
+        GeometryType::Polygon { exterior, holes } => {
+            exterior.iter().chain(holes.iter().flatten()).cloned().collect()
+        }
+        GeometryType::MultiLineString(lines) => lines.iter().flatten().cloned().collect(),
+        GeometryType::MultiPolygon(polygons) => polygons
+            .iter()
+            .flat_map(|p| p.exterior.iter().chain(p.holes.iter().flatten()))
+            .cloned()
+            .collect(),
+        GeometryType::GeometryCollection(geoms) => geoms.iter().flat_map(all_coords).collect(),
+    }
+}
+
+fn normalize_line(coords: &[Coordinate]) -> Vec<Coordinate> {
+    let mut coords = coords.to_vec();
+    if let (Some(first), Some(last)) = (coords.first(), coords.last()) {
+        if cmp_coord(last, first).is_lt() {
+            coords.reverse();
+        }
+    }
+    coords
+}
+
+fn normalize_polygon(exterior: &[Coordinate], holes: &[Vec<Coordinate>]) -> PolygonData {
+    let mut holes: Vec<Vec<Coordinate>> = holes.iter().map(|h| normalize_ring(h, false)).collect();
+    holes.sort_by(|a, b| cmp_coords(a, b));
+    PolygonData {
+        exterior: normalize_ring(exterior, true),
+        holes,
+    }
+}
+
+/// Rotate a closed ring to start at its lowest vertex and orient it
+/// clockwise (`clockwise == true`) or counter-clockwise.
+fn normalize_ring(ring: &[Coordinate], clockwise: bool) -> Vec<Coordinate> {
+    if ring.len() < 2 || ring.first() != ring.last() {
+        return ring.to_vec();
+    }
+    let mut open = ring[..ring.len() - 1].to_vec();
+    if (signed_area(&open) < 0.0) != clockwise {
+        open.reverse();
+    }
+    let start = open
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| cmp_coord(a, b))
+        .map_or(0, |(i, _)| i);
+    open.rotate_left(start);
+    open.push(open[0].clone());
+    open
+}
+
+/// Shoelace area of an open ring: positive when counter-clockwise.
+fn signed_area(open: &[Coordinate]) -> f64 {
+    let n = open.len();
+    (0..n)
+        .map(|i| {
+            let (a, b) = (&open[i], &open[(i + 1) % n]);
+            a.x() * b.y() - b.x() * a.y()
+        })
+        .sum::<f64>()
+        / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use surrealgis_core::srid::Srid;
+
+    fn ring(coords: &[(f64, f64)]) -> Vec<Coordinate> {
+        coords.iter().map(|&(x, y)| Coordinate::new(x, y).unwrap()).collect()
+    }
+
+    fn exterior_of(geom: &SurrealGeometry) -> Vec<(f64, f64)> {
+        match geom.geometry_type() {
+            GeometryType::Polygon { exterior, .. } => {
+                exterior.iter().map(|c| (c.x(), c.y())).collect()
+            }
+            _ => panic!("Expected Polygon"),
+        }
+    }
+
+    #[test]
+    fn exterior_is_clockwise_from_lowest_vertex() {
+        let ccw = ring(&[(2.0, 0.0), (2.0, 2.0), (0.0, 2.0), (0.0, 0.0), (2.0, 0.0)]);
+        let poly = SurrealGeometry::polygon(ccw, vec![], Srid::WEB_MERCATOR).unwrap();
+        let normalized = st_normalize(&poly).unwrap();
+        assert_eq!(
+            exterior_of(&normalized),
+            vec![(0.0, 0.0), (0.0, 2.0), (2.0, 2.0), (2.0, 0.0), (0.0, 0.0)]
+        );
+    }
+
+    #[test]
+    fn holes_are_counter_clockwise_and_sorted() {
+        let exterior = ring(&[(0.0, 0.0), (0.0, 10.0), (10.0, 10.0), (10.0, 0.0), (0.0, 0.0)]);
+        let hole_b = ring(&[(6.0, 6.0), (6.0, 8.0), (8.0, 8.0), (6.0, 6.0)]);
+        let hole_a = ring(&[(1.0, 1.0), (3.0, 1.0), (3.0, 3.0), (1.0, 1.0)]);
+        let poly =
+            SurrealGeometry::polygon(exterior, vec![hole_b, hole_a], Srid::WEB_MERCATOR).unwrap();
+        match st_normalize(&poly).unwrap().geometry_type() {
+            GeometryType::Polygon { holes, .. } => {
+                assert_eq!(holes[0][0], Coordinate::new(1.0, 1.0).unwrap());
+                assert_eq!(holes[1][0], Coordinate::new(6.0, 6.0).unwrap());
+                assert!(holes.iter().all(|h| signed_area(&h[..h.len() - 1]) > 0.0));
+            }
+            _ => panic!("Expected Polygon"),
+        }
+    }
+
+    #[test]
+    fn reversed_line_and_shuffled_multipoint_normalize_equal() {
+        let line = SurrealGeometry::line_string(ring(&[(0.0, 0.0), (1.0, 1.0)]), Srid::WGS84)
+            .unwrap();
+        let reversed =
+            SurrealGeometry::line_string(ring(&[(1.0, 1.0), (0.0, 0.0)]), Srid::WGS84).unwrap();
+        assert_eq!(st_normalize(&line).unwrap(), st_normalize(&reversed).unwrap());
+
+        let a = SurrealGeometry::multi_point(ring(&[(3.0, 1.0), (1.0, 2.0)]), Srid::WGS84).unwrap();
+        let b = SurrealGeometry::multi_point(ring(&[(1.0, 2.0), (3.0, 1.0)]), Srid::WGS84).unwrap();
+        assert_eq!(st_normalize(&a).unwrap(), st_normalize(&b).unwrap());
+    }
+
+    #[test]
+    fn normalize_is_idempotent() {
+        let poly = SurrealGeometry::polygon(
+            ring(&[(1.0, 0.0), (3.0, 2.0), (0.0, 3.0), (1.0, 0.0)]),
+            vec![],
+            Srid::WGS84,
+        )
+        .unwrap();
+        let once = st_normalize(&poly).unwrap();
+        assert_eq!(st_normalize(&once).unwrap(), once);
+    }
+}
@@ -0,0 +1,181 @@
+use surrealgis_core::coordinate::Coordinate;
+use surrealgis_core::geometry::{GeometryType, PolygonData, SurrealGeometry};
+
+use crate::FunctionError;
+
+/// Drop each vertex that lies within `tolerance` of the previously kept one,
+/// like PostGIS ST_RemoveRepeatedPoints. A tolerance of 0 removes only exact
+/// duplicates.
+///
+/// Applies to LineStrings and polygon rings, recursing into Multi* and
+/// GeometryCollections; Points and MultiPoints are returned unchanged.
+/// Endpoints are always kept, so rings stay closed. A LineString keeps at
+/// least 2 vertices and a ring at least 4; sequences that would collapse
+/// below that are left as they are.
+pub fn st_remove_repeated_points(
+    geom: &SurrealGeometry,
+    tolerance: f64,
+) -> Result<SurrealGeometry, FunctionError> {
+    if !tolerance.is_finite() || tolerance < 0.0 {
+        return Err(FunctionError::InvalidArgument(
+            "Tolerance must be finite and non-negative".to_string(),
+        ));
+    }
+    let srid = *geom.srid();
+    let result = match geom.geometry_type() {
+        GeometryType::Point(_) | GeometryType::MultiPoint(_) => geom.clone(),
+        GeometryType::LineString(coords) => {
+            SurrealGeometry::line_string(dedup(coords, tolerance, 2), srid)?
+        }
+        GeometryType::Polygon { exterior, holes } => {
+            let polygon = dedup_polygon(exterior, holes, tolerance);
+            SurrealGeometry::polygon(polygon.exterior, polygon.holes, srid)?
+        }
+        GeometryType::MultiLineString(lines) => SurrealGeometry::multi_line_string(
+            lines.iter().map(|l| dedup(l, tolerance, 2)).collect(),
+            srid,
+        )?,
+        GeometryType::MultiPolygon(polygons) => SurrealGeometry::multi_polygon(
+            polygons
+                .iter()
+                .map(|p| dedup_polygon(&p.exterior, &p.holes, tolerance))
+                .collect(),
+            srid,
+        )?,
+        GeometryType::GeometryCollection(geoms) => {
+            let children = geoms
+                .iter()
+                .map(|g| st_remove_repeated_points(g, tolerance))
+                .collect::<Result<Vec<_>, _>>()?;
+            SurrealGeometry::geometry_collection(children, srid)?
+        }
+    };
+    Ok(result)
+}
+
+fn dedup_polygon(exterior: &[Coordinate], holes: &[Vec<Coordinate>], tolerance: f64) -> PolygonData {
+    PolygonData {
+        exterior: dedup(exterior, tolerance, 4),
+        holes: holes.iter().map(|h| dedup(h, tolerance, 4)).collect(),
+    }
+}
+
+/// Remove repeated vertices, always keeping the first and last one.
+/// Returns the input unchanged if fewer than `min_points` would remain.
+fn dedup(coords: &[Coordinate], tolerance: f64, min_points: usize) -> Vec<Coordinate> {
+    let Some((last, rest)) = coords.split_last() else {
+        return Vec::new();
+    };
+    if rest.is_empty() {
+        return coords.to_vec();
+    }
+    let mut kept: Vec<Coordinate> = vec![rest[0].clone()];
+    for c in &rest[1..] {
+        if distance(kept.last().unwrap(), c) > tolerance {
+            kept.push(c.clone());
+        }
+    }
+    // Make room for the final vertex rather than drop it
+    if kept.len() > 1 && distance(kept.last().unwrap(), last) <= tolerance {
+        kept.pop();
+    }
+    kept.push(last.clone());
+    if kept.len() < min_points {
+        return coords.to_vec();
+    }
+    kept
+}
+
+fn distance(a: &Coordinate, b: &Coordinate) -> f64 {
+    (a.x() - b.x()).hypot(a.y() - b.y())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::coords;
+    use surrealgis_core::srid::Srid;
+
+    fn xy(coords: &[Coordinate]) -> Vec<(f64, f64)> {
+        coords.iter().map(|c| (c.x(), c.y())).collect()
+    }
+
+    #[test]
+    fn zero_tolerance_removes_exact_duplicates() {
+        let line = SurrealGeometry::line_string(
+            coords(&[(0.0, 0.0), (0.0, 0.0), (1.0, 0.0), (1.0, 0.0), (1.0, 0.001), (2.0, 0.0)]),
+            Srid::WEB_MERCATOR,
+        )
+        .unwrap();
+        let result = st_remove_repeated_points(&line, 0.0).unwrap();
+        match result.geometry_type() {
+            GeometryType::LineString(cs) => {
+                assert_eq!(xy(cs), vec![(0.0, 0.0), (1.0, 0.0), (1.0, 0.001), (2.0, 0.0)]);
+            }
+            _ => panic!("Expected LineString"),
+        }
+        assert_eq!(*result.srid(), Srid::WEB_MERCATOR);
+    }
+
+    #[test]
+    fn tolerance_keeps_endpoints() {
+        let line = SurrealGeometry::line_string(
+            coords(&[(0.0, 0.0), (0.05, 0.0), (1.0, 0.0), (1.95, 0.0), (2.0, 0.0)]),
+            Srid::WEB_MERCATOR,
+        )
+        .unwrap();
+        match st_remove_repeated_points(&line, 0.1).unwrap().geometry_type() {
+            GeometryType::LineString(cs) => {
+                assert_eq!(xy(cs), vec![(0.0, 0.0), (1.0, 0.0), (2.0, 0.0)]);
+            }
+            _ => panic!("Expected LineString"),
+        }
+    }
+
+    #[test]
+    fn rings_stay_closed() {
+        let exterior = coords(&[
+            (0.0, 0.0), (10.0, 0.0), (10.0, 0.0), (10.0, 10.0),
+            (0.0, 10.0), (0.0, 0.05), (0.0, 0.0),
+        ]);
+        let poly = SurrealGeometry::polygon(exterior, vec![], Srid::WEB_MERCATOR).unwrap();
+        match st_remove_repeated_points(&poly, 0.1).unwrap().geometry_type() {
+            GeometryType::Polygon { exterior, .. } => {
+                assert_eq!(
+                    xy(exterior),
+                    vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0), (0.0, 0.0)]
+                );
+            }
+            _ => panic!("Expected Polygon"),
+        }
+    }
+
+    #[test]
+    fn collapsing_ring_is_left_unchanged() {
+        let tiny = coords(&[(0.0, 0.0), (0.01, 0.0), (0.01, 0.01), (0.0, 0.0)]);
+        let poly = SurrealGeometry::polygon(tiny.clone(), vec![], Srid::WEB_MERCATOR).unwrap();
+        match st_remove_repeated_points(&poly, 1.0).unwrap().geometry_type() {
+            GeometryType::Polygon { exterior, .. } => assert_eq!(*exterior, tiny),
+            _ => panic!("Expected Polygon"),
+        }
+    }
+
+    #[test]
+    fn recurses_into_collections() {
+        let line = SurrealGeometry::line_string(
+            coords(&[(0.0, 0.0), (0.0, 0.0), (1.0, 1.0)]),
+            Srid::WEB_MERCATOR,
+        )
+        .unwrap();
+        let gc = SurrealGeometry::geometry_collection(vec![line], Srid::WEB_MERCATOR).unwrap();
+        let result = st_remove_repeated_points(&gc, 0.0).unwrap();
+        assert_eq!(result.num_points(), 2);
+    }
+
+    #[test]
+    fn negative_tolerance_rejected() {
+        let p = SurrealGeometry::point(0.0, 0.0, Srid::WGS84).unwrap();
+        assert!(st_remove_repeated_points(&p, -1.0).is_err());
+        assert!(st_remove_repeated_points(&p, f64::NAN).is_err());
+    }
+}
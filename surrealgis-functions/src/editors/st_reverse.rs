@@ -1,7 +1,5 @@
-use geo_types::{
-    Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon, Polygon,
-};
-use surrealgis_core::geometry::SurrealGeometry;
+use surrealgis_core::coordinate::Coordinate;
+use surrealgis_core::geometry::{GeometryType, PolygonData, SurrealGeometry};
 
 use crate::FunctionError;
 
@@ -10,61 +8,56 @@ use crate::FunctionError;
 /// For Polygon: reverses exterior and each hole ring.
 /// For Multi types: reverses each sub-geometry.
 /// For GeometryCollection: reverses each child.
+/// Works on the domain coordinates, so Z and M are preserved: each vertex
+/// keeps its own measure, so a measured line reads start-to-end after reversal.
 pub fn st_reverse(geom: &SurrealGeometry) -> Result<SurrealGeometry, FunctionError> {
-    let geo_geom = geom.to_geo()?;
-    let reversed = reverse_geometry(geo_geom);
-    SurrealGeometry::from_geo(&reversed, *geom.srid()).map_err(FunctionError::from)
-}
-
-fn reverse_geometry(g: Geometry<f64>) -> Geometry<f64> {
-    match g {
-        Geometry::Point(p) => Geometry::Point(p),
-        Geometry::LineString(ls) => Geometry::LineString(reverse_linestring(ls)),
-        Geometry::Polygon(p) => {
-            let ext = reverse_linestring(p.exterior().clone());
-            let holes: Vec<LineString<f64>> =
-                p.interiors().iter().map(|h| reverse_linestring(h.clone())).collect();
-            Geometry::Polygon(Polygon::new(ext, holes))
+    let srid = *geom.srid();
+    let result = match geom.geometry_type() {
+        GeometryType::Point(_) => geom.clone(),
+        GeometryType::LineString(coords) => {
+            SurrealGeometry::line_string(reversed(coords), srid)?
         }
-        Geometry::MultiPoint(mp) => {
+        GeometryType::Polygon { exterior, holes } => SurrealGeometry::polygon(
+            reversed(exterior),
+            holes.iter().map(|h| reversed(h)).collect(),
+            srid,
+        )?,
+        GeometryType::MultiPoint(coords) => {
             // Reverse the order of points in the collection
-            let mut points = mp.0;
-            points.reverse();
-            Geometry::MultiPoint(MultiPoint(points))
-        }
-        Geometry::MultiLineString(mls) => {
-            let lines: Vec<LineString<f64>> =
-                mls.0.into_iter().map(reverse_linestring).collect();
-            Geometry::MultiLineString(MultiLineString(lines))
-        }
-        Geometry::MultiPolygon(mp) => {
-            let polys: Vec<Polygon<f64>> = mp.0.into_iter().map(|p| {
-                let ext = reverse_linestring(p.exterior().clone());
-                let holes: Vec<LineString<f64>> =
-                    p.interiors().iter().map(|h| reverse_linestring(h.clone())).collect();
-                Polygon::new(ext, holes)
-            }).collect();
-            Geometry::MultiPolygon(MultiPolygon(polys))
+            SurrealGeometry::multi_point(reversed(coords), srid)?
         }
-        Geometry::GeometryCollection(gc) => {
-            let geoms: Vec<Geometry<f64>> =
-                gc.0.into_iter().map(reverse_geometry).collect();
-            Geometry::GeometryCollection(GeometryCollection(geoms))
+        GeometryType::MultiLineString(lines) => SurrealGeometry::multi_line_string(
+            lines.iter().map(|l| reversed(l)).collect(),
+            srid,
+        )?,
+        GeometryType::MultiPolygon(polygons) => SurrealGeometry::multi_polygon(
+            polygons
+                .iter()
+                .map(|p| PolygonData {
+                    exterior: reversed(&p.exterior),
+                    holes: p.holes.iter().map(|h| reversed(h)).collect(),
+                })
+                .collect(),
+            srid,
+        )?,
+        GeometryType::GeometryCollection(geoms) => {
+            let children = geoms
+                .iter()
+                .map(st_reverse)
+                .collect::<Result<Vec<_>, _>>()?;
+            SurrealGeometry::geometry_collection(children, srid)?
         }
-        other => other,
-    }
+    };
+    Ok(result)
 }
 
-fn reverse_linestring(mut ls: LineString<f64>) -> LineString<f64> {
-    ls.0.reverse();
-    ls
+fn reversed(coords: &[Coordinate]) -> Vec<Coordinate> {
+    coords.iter().rev().cloned().collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use surrealgis_core::coordinate::Coordinate;
-    use surrealgis_core::geometry::GeometryType;
     use surrealgis_core::srid::Srid;
 
     #[test]
@@ -152,4 +145,37 @@ mod tests {
             panic!("Expected MultiLineString");
         }
     }
+
+    #[test]
+    fn reverse_preserves_z() {
+        let coords = vec![
+            Coordinate::new_3d(0.0, 0.0, 1.0).unwrap(),
+            Coordinate::new_3d(1.0, 1.0, 2.0).unwrap(),
+        ];
+        let line = SurrealGeometry::line_string(coords, Srid::WGS84).unwrap();
+        let result = st_reverse(&line).unwrap();
+        if let GeometryType::LineString(cs) = result.geometry_type() {
+            assert_eq!(cs[0].z(), Some(2.0));
+            assert_eq!(cs[1].z(), Some(1.0));
+        } else {
+            panic!("Expected LineString");
+        }
+    }
+
+    #[test]
+    fn reverse_carries_m_with_vertices() {
+        let coords = vec![
+            Coordinate::new_m(0.0, 0.0, 0.0).unwrap(),
+            Coordinate::new_m(5.0, 0.0, 5.0).unwrap(),
+            Coordinate::new_m(10.0, 0.0, 10.0).unwrap(),
+        ];
+        let line = SurrealGeometry::line_string(coords, Srid::WGS84).unwrap();
+        let result = st_reverse(&line).unwrap();
+        if let GeometryType::LineString(cs) = result.geometry_type() {
+            let xm: Vec<(f64, Option<f64>)> = cs.iter().map(|c| (c.x(), c.m())).collect();
+            assert_eq!(xm, vec![(10.0, Some(10.0)), (5.0, Some(5.0)), (0.0, Some(0.0))]);
+        } else {
+            panic!("Expected LineString");
+        }
+    }
 }
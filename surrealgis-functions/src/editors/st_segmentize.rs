@@ -0,0 +1,197 @@
+use surrealgis_core::coordinate::Coordinate;
+use surrealgis_core::geometry::{GeometryType, PolygonData, SurrealGeometry};
+
+use crate::FunctionError;
+
+/// Most vertices a segmentized geometry may hold in total.
+const MAX_SEGMENTIZE_VERTICES: usize = 1 << 22;
+
+/// Densify a geometry so no segment is longer than `max_segment_length`
+/// (Euclidean, in the geometry's units), like PostGIS ST_Segmentize.
+///
+/// A segment of length L is split into `ceil(L / max_segment_length)` equal
+/// parts by linear interpolation, so inserted vertices lie on the original
+/// segment; Z and M are interpolated when both ends carry them. Applies to
+/// LineStrings, polygon rings, their Multi* variants and collection members.
+/// Points are returned unchanged. A `max_segment_length` so small that the
+/// result would exceed 2^22 vertices is rejected.
+pub fn st_segmentize(
+    geom: &SurrealGeometry,
+    max_segment_length: f64,
+) -> Result<SurrealGeometry, FunctionError> {
+    if !max_segment_length.is_finite() || max_segment_length <= 0.0 {
+        return Err(FunctionError::InvalidArgument(
+            "max_segment_length must be positive and finite".to_string(),
+        ));
+    }
+    let mut remaining = MAX_SEGMENTIZE_VERTICES;
+    segmentize(geom, max_segment_length, &mut remaining)
+}
+
+/// Segmentize `geom`, drawing every output vertex from the `remaining` budget.
+fn segmentize(
+    geom: &SurrealGeometry,
+    max: f64,
+    remaining: &mut usize,
+) -> Result<SurrealGeometry, FunctionError> {
+    let srid = *geom.srid();
+    let mut dense = |coords: &[Coordinate]| densify(coords, max, remaining);
+    let result = match geom.geometry_type() {
+        GeometryType::Point(_) | GeometryType::MultiPoint(_) => geom.clone(),
+        GeometryType::LineString(coords) => SurrealGeometry::line_string(dense(coords)?, srid)?,
+        GeometryType::Polygon { exterior, holes } => SurrealGeometry::polygon(
+            dense(exterior)?,
+            holes.iter().map(|h| dense(h)).collect::<Result<_, _>>()?,
+            srid,
+        )?,
+        GeometryType::MultiLineString(lines) => SurrealGeometry::multi_line_string(
+            lines.iter().map(|l| dense(l)).collect::<Result<_, _>>()?,
+            srid,
+        )?,
+        GeometryType::MultiPolygon(polygons) => SurrealGeometry::multi_polygon(
+            polygons
+                .iter()
+                .map(|p| {
+                    Ok(PolygonData {
+                        exterior: dense(&p.exterior)?,
+                        holes: p
+                            .holes
+                            .iter()
+                            .map(|h| dense(h))
+                            .collect::<Result<_, _>>()?,
+                    })
+                })
+                .collect::<Result<_, FunctionError>>()?,
+            srid,
+        )?,
+        GeometryType::GeometryCollection(geoms) => {
+            let children = geoms
+                .iter()
+                .map(|g| segmentize(g, max, remaining))
+                .collect::<Result<Vec<_>, _>>()?;
+            SurrealGeometry::geometry_collection(children, srid)?
+        }
+    };
+    Ok(result)
+}
+
+fn densify(
+    coords: &[Coordinate],
+    max: f64,
+    remaining: &mut usize,
+) -> Result<Vec<Coordinate>, FunctionError> {
+    let mut out = Vec::with_capacity(coords.len());
+    for (i, b) in coords.iter().enumerate() {
+        // Vertices this step adds: those inserted before `b`, then `b` itself
+        let parts = match i.checked_sub(1).map(|j| &coords[j]) {
+            Some(a) => ((b.x() - a.x()).hypot(b.y() - a.y()) / max).ceil().max(1.0),
+            None => 1.0,
+        };
+        if parts > *remaining as f64 {
+            return Err(FunctionError::InvalidArgument(format!(
+                "st_segmentize would produce more than {MAX_SEGMENTIZE_VERTICES} vertices"
+            )));
+        }
+        let parts = parts as usize;
+        *remaining -= parts;
+        if i > 0 {
+            let a = &coords[i - 1];
+            for k in 1..parts {
+                out.push(interpolate(a, b, k as f64 / parts as f64)?);
+            }
+        }
+        out.push(b.clone());
+    }
+    Ok(out)
+}
+
+fn interpolate(a: &Coordinate, b: &Coordinate, t: f64) -> Result<Coordinate, FunctionError> {
+    let lerp = |u: f64, v: f64| u + t * (v - u);
+    let (x, y) = (lerp(a.x(), b.x()), lerp(a.y(), b.y()));
+    let z = a.z().zip(b.z()).map(|(za, zb)| lerp(za, zb));
+    let m = a.m().zip(b.m()).map(|(ma, mb)| lerp(ma, mb));
+    let coord = match (z, m) {
+        (Some(z), Some(m)) => Coordinate::new_4d(x, y, z, m)?,
+        (Some(z), None) => Coordinate::new_3d(x, y, z)?,
+        (None, Some(m)) => Coordinate::new_m(x, y, m)?,
+        (None, None) => Coordinate::new(x, y)?,
+    };
+    Ok(coord)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::coords;
+    use surrealgis_core::srid::Srid;
+
+    #[test]
+    fn ten_unit_segment_at_three_gives_four_parts() {
+        let line =
+            SurrealGeometry::line_string(coords(&[(0.0, 0.0), (10.0, 0.0)]), Srid::WEB_MERCATOR)
+                .unwrap();
+        let result = st_segmentize(&line, 3.0).unwrap();
+        match result.geometry_type() {
+            GeometryType::LineString(cs) => {
+                assert_eq!(cs.len(), 5);
+                let xs: Vec<f64> = cs.iter().map(|c| c.x()).collect();
+                assert_eq!(xs, vec![0.0, 2.5, 5.0, 7.5, 10.0]);
+                assert!(cs.iter().all(|c| c.y() == 0.0));
+            }
+            _ => panic!("Expected LineString"),
+        }
+        assert_eq!(*result.srid(), Srid::WEB_MERCATOR);
+    }
+
+    #[test]
+    fn short_segments_untouched_and_rings_closed() {
+        let ring = coords(&[(0.0, 0.0), (4.0, 0.0), (4.0, 1.0), (0.0, 0.0)]);
+        let poly = SurrealGeometry::polygon(ring, vec![], Srid::WEB_MERCATOR).unwrap();
+        match st_segmentize(&poly, 2.0).unwrap().geometry_type() {
+            GeometryType::Polygon { exterior, .. } => {
+                // 4 -> 2 parts, 1 -> 1 part, sqrt(17) -> 3 parts
+                assert_eq!(exterior.len(), 7);
+                assert_eq!(exterior.first(), exterior.last());
+            }
+            _ => panic!("Expected Polygon"),
+        }
+    }
+
+    #[test]
+    fn z_is_interpolated() {
+        let line = SurrealGeometry::line_string(
+            vec![
+                Coordinate::new_3d(0.0, 0.0, 0.0).unwrap(),
+                Coordinate::new_3d(2.0, 0.0, 10.0).unwrap(),
+            ],
+            Srid::WEB_MERCATOR,
+        )
+        .unwrap();
+        match st_segmentize(&line, 1.0).unwrap().geometry_type() {
+            GeometryType::LineString(cs) => assert_eq!(cs[1].z(), Some(5.0)),
+            _ => panic!("Expected LineString"),
+        }
+    }
+
+    #[test]
+    fn output_beyond_vertex_cap_rejected() {
+        let line =
+            SurrealGeometry::line_string(coords(&[(0.0, 0.0), (1e9, 0.0)]), Srid::WEB_MERCATOR)
+                .unwrap();
+        assert!(matches!(
+            st_segmentize(&line, 1.0),
+            Err(FunctionError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn non_positive_length_rejected() {
+        let p = SurrealGeometry::point(0.0, 0.0, Srid::WGS84).unwrap();
+        for bad in [0.0, -1.0, f64::NAN] {
+            assert!(matches!(
+                st_segmentize(&p, bad),
+                Err(FunctionError::InvalidArgument(_))
+            ));
+        }
+    }
+}
@@ -1,5 +1,4 @@
-use geo::MapCoords;
-use surrealgis_core::geometry::SurrealGeometry;
+use surrealgis_core::geometry::{GeometryType, SurrealGeometry};
 
 use crate::FunctionError;
 
@@ -7,6 +6,7 @@ use crate::FunctionError;
 /// Each coordinate is rounded to the nearest grid point:
 ///   snapped_x = round(x / size) * size
 ///   snapped_y = round(y / size) * size
+/// Z and M are carried through unchanged.
 pub fn st_snap_to_grid(
     geom: &SurrealGeometry,
     size: f64,
@@ -16,19 +16,35 @@ pub fn st_snap_to_grid(
             "Grid size must be positive".to_string(),
         ));
     }
-    let geo_geom = geom.to_geo()?;
-    let snapped = geo_geom.map_coords(|coord| geo_types::Coord {
-        x: (coord.x / size).round() * size,
-        y: (coord.y / size).round() * size,
-    });
-    SurrealGeometry::from_geo(&snapped, *geom.srid()).map_err(FunctionError::from)
+    geom.map_coords(|c| {
+        c.with_xy((c.x() / size).round() * size, (c.y() / size).round() * size)
+    })
+    .map_err(FunctionError::from)
+}
+
+/// Snap a geometry to the grid and, for Point inputs, report the grid cell
+/// `(col, row)` it falls in, so callers can bin and snap in one pass.
+/// Cells are counted from a zero origin: col = floor(x / size), row = floor(y / size).
+/// The cell is None for non-Point geometries.
+pub fn snap_with_cell(
+    geom: &SurrealGeometry,
+    size: f64,
+) -> Result<(SurrealGeometry, Option<(i64, i64)>), FunctionError> {
+    let snapped = st_snap_to_grid(geom, size)?;
+    let cell = match geom.geometry_type() {
+        GeometryType::Point(c) => Some((
+            (c.x() / size).floor() as i64,
+            (c.y() / size).floor() as i64,
+        )),
+        _ => None,
+    };
+    Ok((snapped, cell))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use surrealgis_core::coordinate::Coordinate;
-    use surrealgis_core::geometry::GeometryType;
     use surrealgis_core::srid::Srid;
 
     #[test]
@@ -108,4 +124,52 @@ mod tests {
             panic!("Expected Point");
         }
     }
+
+    #[test]
+    fn snap_preserves_z() {
+        let coords = vec![
+            Coordinate::new_3d(0.2, 0.2, 7.5).unwrap(),
+            Coordinate::new_3d(1.8, 1.8, 8.5).unwrap(),
+        ];
+        let line = SurrealGeometry::line_string(coords, Srid::WEB_MERCATOR).unwrap();
+        let result = st_snap_to_grid(&line, 1.0).unwrap();
+        if let GeometryType::LineString(cs) = result.geometry_type() {
+            assert!((cs[1].x() - 2.0).abs() < 1e-10);
+            assert_eq!(cs[0].z(), Some(7.5));
+            assert_eq!(cs[1].z(), Some(8.5));
+        } else {
+            panic!("Expected LineString");
+        }
+    }
+
+    #[test]
+    fn snap_with_cell_point() {
+        let p = SurrealGeometry::point(3.2, 7.8, Srid::WEB_MERCATOR).unwrap();
+        let (snapped, cell) = snap_with_cell(&p, 1.0).unwrap();
+        if let GeometryType::Point(c) = snapped.geometry_type() {
+            assert!((c.x() - 3.0).abs() < 1e-10);
+            assert!((c.y() - 8.0).abs() < 1e-10);
+        } else {
+            panic!("Expected Point");
+        }
+        assert_eq!(cell, Some((3, 7)));
+    }
+
+    #[test]
+    fn snap_with_cell_negative_coordinates() {
+        let p = SurrealGeometry::point(-0.5, -2.5, Srid::WEB_MERCATOR).unwrap();
+        let (_, cell) = snap_with_cell(&p, 2.0).unwrap();
+        assert_eq!(cell, Some((-1, -2)));
+    }
+
+    #[test]
+    fn snap_with_cell_non_point_has_no_cell() {
+        let coords = vec![
+            Coordinate::new(0.3, 0.7).unwrap(),
+            Coordinate::new(1.6, 1.2).unwrap(),
+        ];
+        let line = SurrealGeometry::line_string(coords, Srid::WEB_MERCATOR).unwrap();
+        let (_, cell) = snap_with_cell(&line, 1.0).unwrap();
+        assert_eq!(cell, None);
+    }
 }
@@ -10,7 +10,11 @@ pub mod overlay;
 pub mod editors;
 pub mod linear_ref;
 pub mod clustering;
+pub mod support;
+#[cfg(test)]
+mod test_support;
 
+use surrealgis_core::geometry::SurrealGeometry;
 use thiserror::Error;
 
 #[derive(Error, Debug, Clone, PartialEq)]
@@ -23,4 +27,26 @@ pub enum FunctionError {
     UnsupportedOperation(String),
     #[error("CRS error: {0}")]
     CrsError(String),
+    #[error("Type mismatch: {function} requires {} input, got {got}", expected.join(" or "))]
+    TypeMismatch {
+        function: &'static str,
+        expected: Vec<&'static str>,
+        got: String,
+    },
+}
+
+impl FunctionError {
+    /// A `TypeMismatch` for `geom` passed to `function`, which is none of the
+    /// `expected` types.
+    pub(crate) fn type_mismatch(
+        function: &'static str,
+        expected: &[&'static str],
+        geom: &SurrealGeometry,
+    ) -> Self {
+        FunctionError::TypeMismatch {
+            function,
+            expected: expected.to_vec(),
+            got: geom.type_name().to_string(),
+        }
+    }
 }
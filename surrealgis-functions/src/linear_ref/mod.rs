@@ -1,7 +1,13 @@
+mod st_interpolate_measure;
 mod st_line_interpolate_point;
+mod st_line_interpolate_points;
 mod st_line_locate_point;
 mod st_line_substring;
+mod st_locate_between;
 
+pub use st_interpolate_measure::st_interpolate_measure;
 pub use st_line_interpolate_point::st_line_interpolate_point;
+pub use st_line_interpolate_points::st_line_interpolate_points;
 pub use st_line_locate_point::st_line_locate_point;
 pub use st_line_substring::st_line_substring;
+pub use st_locate_between::st_locate_between;
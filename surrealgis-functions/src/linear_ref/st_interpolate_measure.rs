@@ -0,0 +1,133 @@
+use surrealgis_core::coordinate::Coordinate;
+use surrealgis_core::geometry::{GeometryType, SurrealGeometry};
+
+use crate::FunctionError;
+
+/// Return the M value at the location on a measured LineString closest to
+/// `point`, like PostGIS ST_InterpolatePoint.
+///
+/// The point is projected onto the nearest segment and the M values of the
+/// segment's vertices are interpolated linearly. MultiLineStrings are searched
+/// across all parts. Every vertex must carry an M value.
+pub fn st_interpolate_measure(
+    line: &SurrealGeometry,
+    point: &SurrealGeometry,
+) -> Result<f64, FunctionError> {
+    let target = match point.geometry_type() {
+        GeometryType::Point(c) => (c.x(), c.y()),
+        _ => {
+            return Err(FunctionError::InvalidArgument(
+                "st_interpolate_measure requires a Point".into(),
+            ))
+        }
+    };
+    let lines: Vec<&Vec<Coordinate>> = match line.geometry_type() {
+        GeometryType::LineString(coords) => vec![coords],
+        GeometryType::MultiLineString(lines) => lines.iter().collect(),
+        _ => {
+            return Err(FunctionError::InvalidArgument(
+                "st_interpolate_measure requires a LineString or MultiLineString".into(),
+            ))
+        }
+    };
+
+    // (squared distance, measure) of the closest location so far; ties keep the first
+    let mut best: Option<(f64, f64)> = None;
+    for coords in lines {
+        let measures = coords
+            .iter()
+            .map(|c| c.m())
+            .collect::<Option<Vec<f64>>>()
+            .ok_or_else(|| {
+                FunctionError::InvalidArgument(
+                    "st_interpolate_measure requires a line with M values".into(),
+                )
+            })?;
+        if coords.len() == 1 {
+            let d = dist_sq((coords[0].x(), coords[0].y()), target);
+            if best.is_none_or(|(best_d, _)| d < best_d) {
+                best = Some((d, measures[0]));
+            }
+        }
+        for i in 1..coords.len() {
+            let (a, b) = (&coords[i - 1], &coords[i]);
+            let t = project(a, b, target);
+            let on_segment = (a.x() + t * (b.x() - a.x()), a.y() + t * (b.y() - a.y()));
+            let d = dist_sq(on_segment, target);
+            if best.is_none_or(|(best_d, _)| d < best_d) {
+                best = Some((d, measures[i - 1] + t * (measures[i] - measures[i - 1])));
+            }
+        }
+    }
+    best.map(|(_, m)| m).ok_or_else(|| {
+        FunctionError::InvalidArgument("st_interpolate_measure requires a non-empty line".into())
+    })
+}
+
+/// Parameter in `[0, 1]` of the point on segment `a`-`b` closest to `p`.
+fn project(a: &Coordinate, b: &Coordinate, p: (f64, f64)) -> f64 {
+    let (dx, dy) = (b.x() - a.x(), b.y() - a.y());
+    let len_sq = dx * dx + dy * dy;
+    if len_sq == 0.0 {
+        return 0.0;
+    }
+    (((p.0 - a.x()) * dx + (p.1 - a.y()) * dy) / len_sq).clamp(0.0, 1.0)
+}
+
+fn dist_sq(a: (f64, f64), b: (f64, f64)) -> f64 {
+    (a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use surrealgis_core::srid::Srid;
+
+    fn measured_line(coords: &[(f64, f64, f64)]) -> SurrealGeometry {
+        let coords = coords
+            .iter()
+            .map(|&(x, y, m)| Coordinate::new_m(x, y, m).unwrap())
+            .collect();
+        SurrealGeometry::line_string(coords, Srid::WEB_MERCATOR).unwrap()
+    }
+
+    fn point(x: f64, y: f64) -> SurrealGeometry {
+        SurrealGeometry::point(x, y, Srid::WEB_MERCATOR).unwrap()
+    }
+
+    #[test]
+    fn point_near_middle_returns_half_measure() {
+        let line = measured_line(&[(0.0, 0.0, 0.0), (10.0, 0.0, 10.0), (10.0, 10.0, 20.0)]);
+        let m = st_interpolate_measure(&line, &point(9.0, 1.0)).unwrap();
+        // Equidistant from both segments; the first one wins, at x = 9
+        assert!((m - 9.0).abs() < 1e-12, "m was {m}");
+
+        let m = st_interpolate_measure(&line, &point(10.5, 0.2)).unwrap();
+        assert!((m - 10.2).abs() < 1e-12, "m was {m}");
+    }
+
+    #[test]
+    fn point_beyond_ends_clamps() {
+        let line = measured_line(&[(0.0, 0.0, 0.0), (10.0, 0.0, 20.0)]);
+        assert_eq!(st_interpolate_measure(&line, &point(-5.0, 3.0)).unwrap(), 0.0);
+        assert_eq!(st_interpolate_measure(&line, &point(15.0, -3.0)).unwrap(), 20.0);
+        let m = st_interpolate_measure(&line, &point(5.0, 2.0)).unwrap();
+        assert!((m - 10.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn line_without_m_rejected() {
+        let coords = vec![Coordinate::new(0.0, 0.0).unwrap(), Coordinate::new(1.0, 0.0).unwrap()];
+        let line = SurrealGeometry::line_string(coords, Srid::WEB_MERCATOR).unwrap();
+        assert!(matches!(
+            st_interpolate_measure(&line, &point(0.5, 0.0)),
+            Err(FunctionError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn non_point_rejected() {
+        let line = measured_line(&[(0.0, 0.0, 0.0), (10.0, 0.0, 20.0)]);
+        assert!(st_interpolate_measure(&line, &line).is_err());
+    }
+}
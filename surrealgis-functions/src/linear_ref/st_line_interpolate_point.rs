@@ -1,10 +1,15 @@
-use geo::{Euclidean, InterpolateLine};
+use geo::line_measures::LengthMeasurable;
+use geo::Euclidean;
+use geo_types::Point;
 use surrealgis_core::geometry::SurrealGeometry;
 
+use super::st_line_substring::interpolate_along;
 use crate::FunctionError;
 
 /// Returns a point interpolated along a line at a given fraction.
 /// Fraction 0.0 returns the start point, 1.0 returns the end point.
+/// Shares its interpolation with `st_line_substring`, so a zero-width
+/// substring and this function agree on the location.
 pub fn st_line_interpolate_point(
     geom: &SurrealGeometry,
     fraction: f64,
@@ -18,12 +23,13 @@ pub fn st_line_interpolate_point(
     let geo_geom = geom.to_geo()?;
     match geo_geom {
         geo_types::Geometry::LineString(ref line) => {
-            let pt = Euclidean.point_at_ratio_from_start(line, fraction).ok_or_else(|| {
-                FunctionError::InvalidArgument(
+            if line.0.is_empty() {
+                return Err(FunctionError::InvalidArgument(
                     "Cannot interpolate point on empty line".into(),
-                )
-            })?;
-            let result = geo_types::Geometry::Point(pt);
+                ));
+            }
+            let pt = interpolate_along(line, fraction * line.length(&Euclidean));
+            let result = geo_types::Geometry::Point(Point::from(pt));
             SurrealGeometry::from_geo(&result, *geom.srid()).map_err(FunctionError::from)
         }
         _ => Err(FunctionError::UnsupportedOperation(
@@ -139,4 +145,26 @@ mod tests {
             _ => panic!("Expected Point"),
         }
     }
+
+    #[test]
+    fn interpolate_matches_degenerate_substring() {
+        use crate::linear_ref::st_line_substring;
+        let coords = vec![
+            Coordinate::new(0.0, 0.0).unwrap(),
+            Coordinate::new(3.0, 4.0).unwrap(),
+            Coordinate::new(3.0, 10.0).unwrap(),
+        ];
+        let line = SurrealGeometry::line_string(coords, Srid::WEB_MERCATOR).unwrap();
+        for fraction in [0.0, 0.25, 0.5, 0.9, 1.0] {
+            let a = st_line_interpolate_point(&line, fraction).unwrap();
+            let b = st_line_substring(&line, fraction, fraction).unwrap();
+            match (a.geometry_type(), b.geometry_type()) {
+                (GeometryType::Point(p), GeometryType::Point(q)) => {
+                    assert!((p.x() - q.x()).abs() < 1e-9);
+                    assert!((p.y() - q.y()).abs() < 1e-9);
+                }
+                _ => panic!("Expected Points"),
+            }
+        }
+    }
 }
@@ -0,0 +1,117 @@
+use geo::line_measures::LengthMeasurable;
+use geo::Euclidean;
+use geo_types::{MultiPoint, Point};
+use surrealgis_core::geometry::SurrealGeometry;
+
+use super::st_line_substring::interpolate_along;
+use crate::FunctionError;
+
+/// Returns points spaced at `fraction` intervals along a line.
+/// With `repeat` the result is a MultiPoint at `fraction`, `2 * fraction`, ...
+/// up to the end of the line (PostGIS semantics); without it a single Point
+/// at `fraction` is returned, matching `st_line_interpolate_point`.
+pub fn st_line_interpolate_points(
+    geom: &SurrealGeometry,
+    fraction: f64,
+    repeat: bool,
+) -> Result<SurrealGeometry, FunctionError> {
+    if fraction.is_nan() || fraction <= 0.0 || fraction > 1.0 {
+        return Err(FunctionError::InvalidArgument(format!(
+            "Fraction must be greater than 0.0 and at most 1.0, got {fraction}"
+        )));
+    }
+
+    let geo_geom = geom.to_geo()?;
+    match geo_geom {
+        geo_types::Geometry::LineString(ref line) => {
+            if line.0.is_empty() {
+                return Err(FunctionError::InvalidArgument(
+                    "Cannot interpolate points on empty line".into(),
+                ));
+            }
+            let total_length = line.length(&Euclidean);
+            let result = if repeat {
+                // Tolerate rounding so that e.g. 0.1 yields ten points, not nine
+                let count = ((1.0 / fraction) + 1e-9).floor() as usize;
+                let points: Vec<Point<f64>> = (1..=count)
+                    .map(|i| {
+                        let f = (i as f64 * fraction).min(1.0);
+                        Point::from(interpolate_along(line, f * total_length))
+                    })
+                    .collect();
+                geo_types::Geometry::MultiPoint(MultiPoint(points))
+            } else {
+                let pt = interpolate_along(line, fraction * total_length);
+                geo_types::Geometry::Point(Point::from(pt))
+            };
+            SurrealGeometry::from_geo(&result, *geom.srid()).map_err(FunctionError::from)
+        }
+        _ => Err(FunctionError::UnsupportedOperation(
+            "st_line_interpolate_points requires a LineString input".into(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use surrealgis_core::coordinate::Coordinate;
+    use surrealgis_core::geometry::GeometryType;
+    use surrealgis_core::srid::Srid;
+
+    fn make_line() -> SurrealGeometry {
+        let coords = vec![
+            Coordinate::new(0.0, 0.0).unwrap(),
+            Coordinate::new(10.0, 0.0).unwrap(),
+        ];
+        SurrealGeometry::line_string(coords, Srid::WEB_MERCATOR).unwrap()
+    }
+
+    #[test]
+    fn repeat_spaces_points_evenly() {
+        let result = st_line_interpolate_points(&make_line(), 0.25, true).unwrap();
+        match result.geometry_type() {
+            GeometryType::MultiPoint(coords) => {
+                let xs: Vec<f64> = coords.iter().map(|c| c.x()).collect();
+                assert_eq!(xs.len(), 4);
+                for (x, expected) in xs.iter().zip([2.5, 5.0, 7.5, 10.0]) {
+                    assert!((x - expected).abs() < 1e-9);
+                }
+            }
+            _ => panic!("Expected MultiPoint"),
+        }
+    }
+
+    #[test]
+    fn repeat_tolerates_rounding() {
+        let result = st_line_interpolate_points(&make_line(), 0.1, true).unwrap();
+        assert_eq!(result.num_points(), 10);
+    }
+
+    #[test]
+    fn no_repeat_returns_single_point() {
+        let result = st_line_interpolate_points(&make_line(), 0.3, false).unwrap();
+        match result.geometry_type() {
+            GeometryType::Point(c) => assert!((c.x() - 3.0).abs() < 1e-9),
+            _ => panic!("Expected Point"),
+        }
+    }
+
+    #[test]
+    fn preserves_srid() {
+        let result = st_line_interpolate_points(&make_line(), 0.5, true).unwrap();
+        assert_eq!(result.srid().code(), Srid::WEB_MERCATOR.code());
+    }
+
+    #[test]
+    fn zero_fraction_rejected() {
+        let result = st_line_interpolate_points(&make_line(), 0.0, true);
+        assert!(matches!(result, Err(FunctionError::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn fraction_above_one_rejected() {
+        let result = st_line_interpolate_points(&make_line(), 1.5, false);
+        assert!(matches!(result, Err(FunctionError::InvalidArgument(_))));
+    }
+}
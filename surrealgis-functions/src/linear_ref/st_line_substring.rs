@@ -6,7 +6,7 @@ use surrealgis_core::geometry::SurrealGeometry;
 use crate::FunctionError;
 
 /// Interpolate a coordinate at a given distance along a LineString.
-fn interpolate_along(line: &LineString<f64>, target_dist: f64) -> Coord<f64> {
+pub(super) fn interpolate_along(line: &LineString<f64>, target_dist: f64) -> Coord<f64> {
     let mut accumulated = 0.0;
     for window in line.0.windows(2) {
         let seg_start = window[0];
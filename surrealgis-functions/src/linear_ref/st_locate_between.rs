@@ -0,0 +1,227 @@
+use surrealgis_core::coordinate::Coordinate;
+use surrealgis_core::geometry::{GeometryType, SurrealGeometry};
+
+use crate::FunctionError;
+
+/// Extract the portions of a measured LineString or MultiLineString whose
+/// M values lie between `from_measure` and `to_measure` (inclusive), like
+/// PostGIS ST_LocateBetween.
+///
+/// Where the range boundary falls inside a segment, X, Y, Z and M are
+/// interpolated linearly. Returns a LineString when a single portion
+/// matches, otherwise a MultiLineString. Every vertex must carry an M value.
+pub fn st_locate_between(
+    geom: &SurrealGeometry,
+    from_measure: f64,
+    to_measure: f64,
+) -> Result<SurrealGeometry, FunctionError> {
+    if !from_measure.is_finite() || !to_measure.is_finite() {
+        return Err(FunctionError::InvalidArgument(
+            "Measures must be finite".into(),
+        ));
+    }
+    if from_measure > to_measure {
+        return Err(FunctionError::InvalidArgument(
+            "from_measure must be <= to_measure".into(),
+        ));
+    }
+    let lines: Vec<&Vec<Coordinate>> = match geom.geometry_type() {
+        GeometryType::LineString(coords) => vec![coords],
+        GeometryType::MultiLineString(lines) => lines.iter().collect(),
+        _ => {
+            return Err(FunctionError::InvalidArgument(
+                "st_locate_between requires a LineString or MultiLineString".into(),
+            ))
+        }
+    };
+    if lines.iter().flat_map(|l| l.iter()).any(|c| c.m().is_none()) {
+        return Err(FunctionError::InvalidArgument(
+            "st_locate_between requires a line with M values".into(),
+        ));
+    }
+
+    let mut parts = Vec::new();
+    for line in lines {
+        locate_in_line(line, from_measure, to_measure, &mut parts)?;
+    }
+
+    let srid = *geom.srid();
+    match parts.len() {
+        0 => Err(FunctionError::InvalidArgument(format!(
+            "No portion of the line lies between measures {from_measure} and {to_measure}"
+        ))),
+        1 => SurrealGeometry::line_string(parts.remove(0), srid).map_err(FunctionError::from),
+        _ => SurrealGeometry::multi_line_string(parts, srid).map_err(FunctionError::from),
+    }
+}
+
+/// Append the in-range portions of one line to `parts`.
+/// Portions that collapse to a single point are dropped.
+fn locate_in_line(
+    line: &[Coordinate],
+    lo: f64,
+    hi: f64,
+    parts: &mut Vec<Vec<Coordinate>>,
+) -> Result<(), FunctionError> {
+    let mut current: Vec<Coordinate> = Vec::new();
+    let flush = |current: &mut Vec<Coordinate>, parts: &mut Vec<Vec<Coordinate>>| {
+        if current.len() >= 2 {
+            parts.push(std::mem::take(current));
+        } else {
+            current.clear();
+        }
+    };
+
+    for window in line.windows(2) {
+        let (a, b) = (&window[0], &window[1]);
+        let Some((t0, t1)) = clip_segment(measure(a), measure(b), lo, hi) else {
+            flush(&mut current, parts);
+            continue;
+        };
+        let start = interpolate(a, b, t0)?;
+        if current.last() != Some(&start) {
+            flush(&mut current, parts);
+            current.push(start);
+        }
+        let end = interpolate(a, b, t1)?;
+        if current.last() != Some(&end) {
+            current.push(end);
+        }
+        if t1 < 1.0 {
+            // The line leaves the range inside this segment
+            flush(&mut current, parts);
+        }
+    }
+    flush(&mut current, parts);
+    Ok(())
+}
+
+fn measure(c: &Coordinate) -> f64 {
+    c.m().unwrap_or_default()
+}
+
+/// Parameter range `[t0, t1]` of the segment whose measures lie in `[lo, hi]`.
+fn clip_segment(ma: f64, mb: f64, lo: f64, hi: f64) -> Option<(f64, f64)> {
+    if ma == mb {
+        return (lo..=hi).contains(&ma).then_some((0.0, 1.0));
+    }
+    // Parameters where the measure crosses each bound, ordered along the segment
+    let at = |m: f64| (m - ma) / (mb - ma);
+    let (t_lo, t_hi) = if mb > ma { (at(lo), at(hi)) } else { (at(hi), at(lo)) };
+    let t0 = t_lo.max(0.0);
+    let t1 = t_hi.min(1.0);
+    (t0 <= t1).then_some((t0, t1))
+}
+
+fn interpolate(a: &Coordinate, b: &Coordinate, t: f64) -> Result<Coordinate, FunctionError> {
+    if t == 0.0 {
+        return Ok(a.clone());
+    }
+    if t == 1.0 {
+        return Ok(b.clone());
+    }
+    let lerp = |u: f64, v: f64| u + t * (v - u);
+    let (x, y) = (lerp(a.x(), b.x()), lerp(a.y(), b.y()));
+    let m = lerp(measure(a), measure(b));
+    let coord = match (a.z(), b.z()) {
+        (Some(za), Some(zb)) => Coordinate::new_4d(x, y, lerp(za, zb), m)?,
+        _ => Coordinate::new_m(x, y, m)?,
+    };
+    Ok(coord)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use surrealgis_core::srid::Srid;
+
+    fn measured_line(coords: &[(f64, f64, f64)]) -> SurrealGeometry {
+        let coords = coords
+            .iter()
+            .map(|&(x, y, m)| Coordinate::new_m(x, y, m).unwrap())
+            .collect();
+        SurrealGeometry::line_string(coords, Srid::WEB_MERCATOR).unwrap()
+    }
+
+    fn xym(coords: &[Coordinate]) -> Vec<(f64, f64, f64)> {
+        coords.iter().map(|c| (c.x(), c.y(), c.m().unwrap())).collect()
+    }
+
+    #[test]
+    fn extracts_middle_portion_with_interpolated_ends() {
+        // M runs 0..100 over x = 0..10
+        let line = measured_line(&[(0.0, 0.0, 0.0), (4.0, 0.0, 40.0), (10.0, 0.0, 100.0)]);
+        let result = st_locate_between(&line, 25.0, 75.0).unwrap();
+        match result.geometry_type() {
+            GeometryType::LineString(coords) => {
+                let got = xym(coords);
+                assert_eq!(got.len(), 3);
+                assert!((got[0].0 - 2.5).abs() < 1e-12 && (got[0].2 - 25.0).abs() < 1e-12);
+                assert_eq!(got[1], (4.0, 0.0, 40.0));
+                assert!((got[2].0 - 7.5).abs() < 1e-12 && (got[2].2 - 75.0).abs() < 1e-12);
+            }
+            _ => panic!("Expected LineString"),
+        }
+        assert_eq!(result.srid().code(), Srid::WEB_MERCATOR.code());
+    }
+
+    #[test]
+    fn non_monotonic_measures_give_multilinestring() {
+        // Measure goes up to 100 and back down to 0
+        let line = measured_line(&[(0.0, 0.0, 0.0), (10.0, 0.0, 100.0), (20.0, 0.0, 0.0)]);
+        let result = st_locate_between(&line, 80.0, 100.0).unwrap();
+        match result.geometry_type() {
+            GeometryType::LineString(coords) => {
+                // Both pieces meet at the vertex with M = 100 and join up
+                let got = xym(coords);
+                assert_eq!(got.len(), 3);
+                assert!((got[0].0 - 8.0).abs() < 1e-12);
+                assert!((got[2].0 - 12.0).abs() < 1e-12);
+            }
+            _ => panic!("Expected LineString"),
+        }
+
+        let result = st_locate_between(&line, 10.0, 30.0).unwrap();
+        match result.geometry_type() {
+            GeometryType::MultiLineString(parts) => {
+                assert_eq!(parts.len(), 2);
+                assert!((parts[0][0].x() - 1.0).abs() < 1e-12);
+                assert!((parts[0][1].x() - 3.0).abs() < 1e-12);
+                assert!((parts[1][0].x() - 17.0).abs() < 1e-12);
+                assert!((parts[1][1].x() - 19.0).abs() < 1e-12);
+            }
+            _ => panic!("Expected MultiLineString"),
+        }
+    }
+
+    #[test]
+    fn z_is_interpolated_alongside_m() {
+        let coords = vec![
+            Coordinate::new_4d(0.0, 0.0, 10.0, 0.0).unwrap(),
+            Coordinate::new_4d(10.0, 0.0, 20.0, 100.0).unwrap(),
+        ];
+        let line = SurrealGeometry::line_string(coords, Srid::WEB_MERCATOR).unwrap();
+        let result = st_locate_between(&line, 50.0, 100.0).unwrap();
+        match result.geometry_type() {
+            GeometryType::LineString(coords) => assert_eq!(coords[0].z(), Some(15.0)),
+            _ => panic!("Expected LineString"),
+        }
+    }
+
+    #[test]
+    fn line_without_m_rejected() {
+        let coords = vec![Coordinate::new(0.0, 0.0).unwrap(), Coordinate::new(1.0, 0.0).unwrap()];
+        let line = SurrealGeometry::line_string(coords, Srid::WEB_MERCATOR).unwrap();
+        let result = st_locate_between(&line, 0.0, 1.0);
+        assert!(matches!(result, Err(FunctionError::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn out_of_range_and_bad_arguments_rejected() {
+        let line = measured_line(&[(0.0, 0.0, 0.0), (10.0, 0.0, 100.0)]);
+        assert!(st_locate_between(&line, 200.0, 300.0).is_err());
+        assert!(st_locate_between(&line, 75.0, 25.0).is_err());
+        let p = SurrealGeometry::point(0.0, 0.0, Srid::WEB_MERCATOR).unwrap();
+        assert!(st_locate_between(&p, 0.0, 1.0).is_err());
+    }
+}
@@ -1,13 +1,29 @@
 mod st_distance;
+mod st_distance_with_points;
 mod st_area;
 mod st_length;
 mod st_perimeter;
 mod st_azimuth;
 mod st_dwithin;
+mod st_project;
+mod st_closest_point;
+mod st_hausdorff_distance;
+mod st_frechet_distance;
+mod st_max_distance;
+mod st_3d_distance;
 
-pub use st_distance::{st_distance, st_distance_sphere};
-pub use st_area::st_area;
-pub use st_length::st_length;
-pub use st_perimeter::st_perimeter;
+pub use st_distance::{st_distance, st_distance_sphere, st_distance_with_unit};
+pub use st_distance_with_points::{st_distance_with_points, DistanceWithPoints};
+#[allow(deprecated)]
+pub use st_area::st_geodesic_area;
+pub use st_area::{st_area, st_area_geodesic, st_signed_area};
+pub use st_length::{st_length, st_length_geodesic, st_rendered_length};
+pub use st_perimeter::{st_perimeter, st_perimeter_geodesic};
 pub use st_azimuth::st_azimuth;
 pub use st_dwithin::st_dwithin;
+pub use st_project::{st_project, destination_then_azimuth};
+pub use st_closest_point::{st_closest_point, st_shortest_line};
+pub use st_hausdorff_distance::st_hausdorff_distance;
+pub use st_frechet_distance::st_frechet_distance;
+pub use st_max_distance::st_max_distance;
+pub use st_3d_distance::st_3d_distance;
@@ -0,0 +1,108 @@
+use surrealgis_core::coordinate::Coordinate;
+use surrealgis_core::geometry::{GeometryType, SurrealGeometry};
+
+use crate::FunctionError;
+
+type Xyz = [f64; 3];
+
+/// Compute the Euclidean distance in three dimensions between a Point and a
+/// Point or LineString (in either order). A missing Z is treated as 0.
+/// Distances are planar, in the units of the SRID; other geometry
+/// combinations return UnsupportedOperation.
+pub fn st_3d_distance(a: &SurrealGeometry, b: &SurrealGeometry) -> Result<f64, FunctionError> {
+    match (a.geometry_type(), b.geometry_type()) {
+        (GeometryType::Point(p), GeometryType::Point(q)) => Ok(dist(xyz(p), xyz(q))),
+        (GeometryType::Point(p), GeometryType::LineString(line))
+        | (GeometryType::LineString(line), GeometryType::Point(p)) => {
+            Ok(point_line_distance(xyz(p), line))
+        }
+        _ => Err(FunctionError::UnsupportedOperation(format!(
+            "st_3d_distance supports Point-Point and Point-LineString, got {} and {}",
+            a.type_name(),
+            b.type_name()
+        ))),
+    }
+}
+
+fn xyz(c: &Coordinate) -> Xyz {
+    [c.x(), c.y(), c.z().unwrap_or(0.0)]
+}
+
+fn dist(p: Xyz, q: Xyz) -> f64 {
+    ((p[0] - q[0]).powi(2) + (p[1] - q[1]).powi(2) + (p[2] - q[2]).powi(2)).sqrt()
+}
+
+fn point_line_distance(p: Xyz, line: &[Coordinate]) -> f64 {
+    if line.len() == 1 {
+        return dist(p, xyz(&line[0]));
+    }
+    line.windows(2)
+        .map(|w| {
+            let (s, e) = (xyz(&w[0]), xyz(&w[1]));
+            let d = [e[0] - s[0], e[1] - s[1], e[2] - s[2]];
+            let len2 = d[0] * d[0] + d[1] * d[1] + d[2] * d[2];
+            let t = if len2 > 0.0 {
+                (((p[0] - s[0]) * d[0] + (p[1] - s[1]) * d[1] + (p[2] - s[2]) * d[2]) / len2)
+                    .clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            dist(p, [s[0] + t * d[0], s[1] + t * d[1], s[2] + t * d[2]])
+        })
+        .fold(f64::INFINITY, f64::min)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::measurement::st_distance;
+    use surrealgis_core::srid::Srid;
+
+    fn point_3d(x: f64, y: f64, z: f64) -> SurrealGeometry {
+        let c = Coordinate::new_3d(x, y, z).unwrap();
+        SurrealGeometry::point_from_coordinate(c, Srid::WEB_MERCATOR).unwrap()
+    }
+
+    #[test]
+    fn vertical_separation_counts_in_3d_only() {
+        let a = point_3d(0.0, 0.0, 0.0);
+        let b = point_3d(0.0, 0.0, 5.0);
+        assert_eq!(st_3d_distance(&a, &b).unwrap(), 5.0);
+        assert_eq!(st_distance(&a, &b).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn missing_z_is_zero() {
+        let a = SurrealGeometry::point(3.0, 0.0, Srid::WEB_MERCATOR).unwrap();
+        let b = point_3d(0.0, 0.0, 4.0);
+        assert_eq!(st_3d_distance(&a, &b).unwrap(), 5.0);
+    }
+
+    #[test]
+    fn point_above_line_interior() {
+        // Horizontal line at z=0; the point sits 3 above its midpoint
+        let coords = vec![
+            Coordinate::new_3d(0.0, 0.0, 0.0).unwrap(),
+            Coordinate::new_3d(10.0, 0.0, 0.0).unwrap(),
+        ];
+        let line = SurrealGeometry::line_string(coords, Srid::WEB_MERCATOR).unwrap();
+        let p = point_3d(5.0, 0.0, 3.0);
+        assert_eq!(st_3d_distance(&p, &line).unwrap(), 3.0);
+        assert_eq!(st_3d_distance(&line, &p).unwrap(), 3.0);
+    }
+
+    #[test]
+    fn polygon_unsupported() {
+        let exterior = vec![
+            Coordinate::new(0.0, 0.0).unwrap(),
+            Coordinate::new(1.0, 0.0).unwrap(),
+            Coordinate::new(1.0, 1.0).unwrap(),
+            Coordinate::new(0.0, 0.0).unwrap(),
+        ];
+        let poly = SurrealGeometry::polygon(exterior, vec![], Srid::WEB_MERCATOR).unwrap();
+        assert!(matches!(
+            st_3d_distance(&poly, &point_3d(0.0, 0.0, 0.0)),
+            Err(FunctionError::UnsupportedOperation(_))
+        ));
+    }
+}
@@ -1,32 +1,72 @@
-use geo::algorithm::Area;
+use geo::algorithm::{Area, GeodesicArea};
+use geo_types::Polygon;
 use surrealgis_core::geometry::{GeometryType, SurrealGeometry};
+use surrealgis_crs::registry;
 
 use crate::FunctionError;
 
 /// Compute the area of a geometry.
 /// Returns unsigned area. For projected CRS, returns area in projection units squared.
-/// For geographic CRS, returns approximate area (use with caution).
+/// For geographic CRS, dispatches to `st_area_geodesic` and returns square
+/// meters on the WGS84 ellipsoid rather than meaningless square degrees.
 pub fn st_area(geom: &SurrealGeometry) -> Result<f64, FunctionError> {
-    let geo_geom = geom.to_geo()?;
-    match &geo_geom {
-        geo_types::Geometry::Polygon(p) => Ok(p.unsigned_area()),
-        geo_types::Geometry::MultiPolygon(mp) => Ok(mp.unsigned_area()),
-        geo_types::Geometry::Rect(r) => Ok(r.unsigned_area()),
-        geo_types::Geometry::Triangle(t) => Ok(t.unsigned_area()),
-        _ => match geom.geometry_type() {
-            GeometryType::Point(_)
-            | GeometryType::LineString(_)
-            | GeometryType::MultiPoint(_)
-            | GeometryType::MultiLineString(_) => Ok(0.0),
-            GeometryType::GeometryCollection(geoms) => {
-                let mut total = 0.0;
-                for g in geoms {
-                    total += st_area(g)?;
-                }
-                Ok(total)
-            }
+    if registry::is_geographic(geom.srid().code()) {
+        return st_area_geodesic(geom);
+    }
+    polygonal_area(geom, |p| p.unsigned_area())
+}
+
+/// Compute the planar signed area of a geometry.
+/// Each polygon is positive when its exterior ring is counter-clockwise and
+/// negative when clockwise, with holes reducing its magnitude; multi-parts
+/// and collection members are summed, so oppositely wound parts cancel.
+pub fn st_signed_area(geom: &SurrealGeometry) -> Result<f64, FunctionError> {
+    polygonal_area(geom, |p| p.signed_area())
+}
+
+/// Compute the area of a geometry in square meters on the WGS84 ellipsoid,
+/// using Karney's geodesic polygon area.
+/// Coordinates must be longitude/latitude, so the SRID must be geographic.
+pub fn st_area_geodesic(geom: &SurrealGeometry) -> Result<f64, FunctionError> {
+    if !registry::is_geographic(geom.srid().code()) {
+        return Err(FunctionError::InvalidArgument(format!(
+            "st_area_geodesic requires a geographic SRID, got {}",
+            geom.srid().code()
+        )));
+    }
+    polygonal_area(geom, |p| p.geodesic_area_unsigned())
+}
+
+/// Former name of [`st_area_geodesic`].
+#[deprecated(note = "renamed to st_area_geodesic")]
+pub fn st_geodesic_area(geom: &SurrealGeometry) -> Result<f64, FunctionError> {
+    st_area_geodesic(geom)
+}
+
+/// Shared dispatch for the area family: `area` is applied to each polygon
+/// (holes are its job), multi-parts and collection members are summed, and
+/// puntal or lineal geometries contribute 0.
+fn polygonal_area(
+    geom: &SurrealGeometry,
+    area: fn(&Polygon<f64>) -> f64,
+) -> Result<f64, FunctionError> {
+    match geom.geometry_type() {
+        GeometryType::Polygon { .. } | GeometryType::MultiPolygon(_) => match geom.to_geo()? {
+            geo_types::Geometry::Polygon(p) => Ok(area(&p)),
+            geo_types::Geometry::MultiPolygon(mp) => Ok(mp.0.iter().map(area).sum()),
             _ => Ok(0.0),
         },
+        GeometryType::GeometryCollection(geoms) => {
+            let mut total = 0.0;
+            for g in geoms {
+                total += polygonal_area(g, area)?;
+            }
+            Ok(total)
+        }
+        GeometryType::Point(_)
+        | GeometryType::LineString(_)
+        | GeometryType::MultiPoint(_)
+        | GeometryType::MultiLineString(_) => Ok(0.0),
     }
 }
 
@@ -100,4 +140,86 @@ mod tests {
         let ls = SurrealGeometry::line_string(coords, Srid::WGS84).unwrap();
         assert_eq!(st_area(&ls).unwrap(), 0.0);
     }
+
+    fn ring(points: &[(f64, f64)]) -> Vec<Coordinate> {
+        points.iter().map(|&(x, y)| Coordinate::new(x, y).unwrap()).collect()
+    }
+
+    #[test]
+    fn area_with_hole_is_exterior_minus_hole() {
+        let exterior = ring(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0), (0.0, 0.0)]);
+        let hole = ring(&[(2.0, 2.0), (5.0, 2.0), (5.0, 4.0), (2.0, 4.0), (2.0, 2.0)]);
+        let outer = SurrealGeometry::polygon(exterior.clone(), vec![], Srid::WEB_MERCATOR).unwrap();
+        let inner = SurrealGeometry::polygon(hole.clone(), vec![], Srid::WEB_MERCATOR).unwrap();
+        let holed = SurrealGeometry::polygon(exterior, vec![hole], Srid::WEB_MERCATOR).unwrap();
+        let expected = st_area(&outer).unwrap() - st_area(&inner).unwrap();
+        assert!((st_area(&holed).unwrap() - expected).abs() < 1e-9);
+        assert!((st_signed_area(&holed).unwrap().abs() - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn area_is_abs_of_signed_area() {
+        let ccw = ring(&[(0.0, 0.0), (4.0, 0.0), (2.0, 3.0), (0.0, 0.0)]);
+        let cw: Vec<Coordinate> = ccw.iter().rev().cloned().collect();
+        for (r, sign) in [(ccw, 1.0), (cw, -1.0)] {
+            let poly = SurrealGeometry::polygon(r, vec![], Srid::WEB_MERCATOR).unwrap();
+            let signed = st_signed_area(&poly).unwrap();
+            assert_eq!(signed.signum(), sign);
+            assert!((st_area(&poly).unwrap() - signed.abs()).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn variants_sum_collection_members() {
+        let a = SurrealGeometry::polygon(
+            ring(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0), (0.0, 0.0)]),
+            vec![],
+            Srid::WGS84,
+        )
+        .unwrap();
+        let b = SurrealGeometry::polygon(
+            ring(&[(2.0, 0.0), (3.0, 0.0), (3.0, 1.0), (2.0, 1.0), (2.0, 0.0)]),
+            vec![],
+            Srid::WGS84,
+        )
+        .unwrap();
+        let line = SurrealGeometry::line_string(ring(&[(0.0, 5.0), (1.0, 5.0)]), Srid::WGS84)
+            .unwrap();
+        let gc =
+            SurrealGeometry::geometry_collection(vec![a.clone(), b.clone(), line], Srid::WGS84)
+                .unwrap();
+        type AreaFn = fn(&SurrealGeometry) -> Result<f64, FunctionError>;
+        for f in [st_area as AreaFn, st_signed_area, st_area_geodesic] {
+            let parts = f(&a).unwrap() + f(&b).unwrap();
+            assert!((f(&gc).unwrap() - parts).abs() < 1e-6 * parts.abs().max(1.0));
+        }
+    }
+
+    #[test]
+    fn geodesic_area_requires_geographic_srid() {
+        let poly = SurrealGeometry::polygon(
+            ring(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 0.0)]),
+            vec![],
+            Srid::WEB_MERCATOR,
+        )
+        .unwrap();
+        assert!(matches!(
+            st_area_geodesic(&poly),
+            Err(FunctionError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn one_degree_cell_at_equator() {
+        let cell = SurrealGeometry::polygon(
+            ring(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0), (0.0, 0.0)]),
+            vec![],
+            Srid::WGS84,
+        )
+        .unwrap();
+        // About 111.3 km by 110.6 km on the WGS84 ellipsoid
+        let km2 = st_area(&cell).unwrap() / 1e6;
+        assert!((12_250.0..12_370.0).contains(&km2), "{km2}");
+        assert_eq!(st_area(&cell).unwrap(), st_area_geodesic(&cell).unwrap());
+    }
 }
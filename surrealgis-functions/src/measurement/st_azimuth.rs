@@ -5,6 +5,8 @@ use crate::FunctionError;
 
 /// Compute the azimuth (bearing) between two points.
 /// Returns the angle in radians from north (clockwise).
+/// Uses the geodesic bearing for geographic SRIDs and the planar
+/// grid bearing for projected ones, matching `st_project`.
 pub fn st_azimuth(a: &SurrealGeometry, b: &SurrealGeometry) -> Result<f64, FunctionError> {
     let (pa, pb) = match (a.geometry_type(), b.geometry_type()) {
         (GeometryType::Point(ca), GeometryType::Point(cb)) => {
@@ -13,16 +15,18 @@ pub fn st_azimuth(a: &SurrealGeometry, b: &SurrealGeometry) -> Result<f64, Funct
                 geo_types::Point::new(cb.x(), cb.y()),
             )
         }
-        _ => {
-            return Err(FunctionError::InvalidArgument(
-                "st_azimuth requires two Point geometries".to_string(),
-            ))
+        (GeometryType::Point(_), _) => {
+            return Err(FunctionError::type_mismatch("st_azimuth", &["Point"], b))
         }
+        _ => return Err(FunctionError::type_mismatch("st_azimuth", &["Point"], a)),
     };
 
-    let bearing_degrees = Geodesic.bearing(pa, pb);
-    // Convert from degrees to radians
-    let bearing_radians = bearing_degrees.to_radians();
+    let bearing_radians = if a.srid().is_geographic() {
+        // Convert from degrees to radians
+        Geodesic.bearing(pa, pb).to_radians()
+    } else {
+        (pb.x() - pa.x()).atan2(pb.y() - pa.y())
+    };
     // Normalize to [0, 2*PI)
     let normalized = if bearing_radians < 0.0 {
         bearing_radians + 2.0 * std::f64::consts::PI
@@ -56,6 +60,14 @@ mod tests {
         assert!((az - PI / 2.0).abs() < 0.01, "Azimuth was {az}");
     }
 
+    #[test]
+    fn azimuth_projected_is_planar() {
+        let a = SurrealGeometry::point(0.0, 0.0, Srid::WEB_MERCATOR).unwrap();
+        let b = SurrealGeometry::point(-1.0, -1.0, Srid::WEB_MERCATOR).unwrap();
+        let az = st_azimuth(&a, &b).unwrap();
+        assert!((az - 5.0 * PI / 4.0).abs() < 1e-10, "Azimuth was {az}");
+    }
+
     #[test]
     fn azimuth_requires_points() {
         let a = SurrealGeometry::point(0.0, 0.0, Srid::WGS84).unwrap();
@@ -0,0 +1,88 @@
+use surrealgis_core::coordinate::Coordinate;
+use surrealgis_core::geometry::{GeometryType, SurrealGeometry};
+
+use super::st_distance_with_points;
+use crate::FunctionError;
+
+/// Returns the point on `a` that is closest to `b`, in `a`'s SRID.
+/// The point may lie in the interior of a segment rather than at a vertex.
+pub fn st_closest_point(
+    a: &SurrealGeometry,
+    b: &SurrealGeometry,
+) -> Result<SurrealGeometry, FunctionError> {
+    let (_, on_a, _) = st_distance_with_points(a, b)?;
+    Ok(on_a)
+}
+
+/// Returns the two-vertex LineString running from the closest point on `a`
+/// to the closest point on `b`, in `a`'s SRID. Intersecting geometries give
+/// a zero-length line.
+pub fn st_shortest_line(
+    a: &SurrealGeometry,
+    b: &SurrealGeometry,
+) -> Result<SurrealGeometry, FunctionError> {
+    let (_, on_a, on_b) = st_distance_with_points(a, b)?;
+    let coords = [&on_a, &on_b]
+        .iter()
+        .map(|p| match p.geometry_type() {
+            GeometryType::Point(c) => Coordinate::new(c.x(), c.y()),
+            _ => unreachable!("st_distance_with_points returns Points"),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    SurrealGeometry::line_string(coords, *a.srid()).map_err(FunctionError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{line, square};
+    use surrealgis_core::srid::Srid;
+
+    #[test]
+    fn closest_point_in_segment_interior() {
+        let a = line(&[(0.0, 0.0), (10.0, 0.0)]);
+        let b = SurrealGeometry::point(3.0, 4.0, Srid::WEB_MERCATOR).unwrap();
+        let result = st_closest_point(&a, &b).unwrap();
+        match result.geometry_type() {
+            GeometryType::Point(c) => assert_eq!((c.x(), c.y()), (3.0, 0.0)),
+            _ => panic!("Expected Point"),
+        }
+        assert_eq!(result.srid().code(), Srid::WEB_MERCATOR.code());
+    }
+
+    #[test]
+    fn closest_point_on_polygon_boundary() {
+        let b = SurrealGeometry::point(5.0, 1.0, Srid::WEB_MERCATOR).unwrap();
+        let result = st_closest_point(&square(0.0, 0.0, 2.0), &b).unwrap();
+        match result.geometry_type() {
+            GeometryType::Point(c) => assert_eq!((c.x(), c.y()), (2.0, 1.0)),
+            _ => panic!("Expected Point"),
+        }
+    }
+
+    #[test]
+    fn shortest_line_connects_closest_points() {
+        let a = line(&[(0.0, 0.0), (10.0, 0.0)]);
+        let result = st_shortest_line(&a, &square(4.0, 3.0, 2.0)).unwrap();
+        match result.geometry_type() {
+            GeometryType::LineString(cs) => {
+                assert_eq!(cs.len(), 2);
+                assert_eq!(cs[0].y(), 0.0);
+                assert_eq!(cs[1].y(), 3.0);
+                assert_eq!(cs[0].x(), cs[1].x());
+            }
+            _ => panic!("Expected LineString"),
+        }
+    }
+
+    #[test]
+    fn shortest_line_between_intersecting_geometries_has_zero_length() {
+        let a = line(&[(0.0, 0.0), (2.0, 2.0)]);
+        let b = line(&[(0.0, 2.0), (2.0, 0.0)]);
+        let result = st_shortest_line(&a, &b).unwrap();
+        match result.geometry_type() {
+            GeometryType::LineString(cs) => assert_eq!(cs[0], cs[1]),
+            _ => panic!("Expected LineString"),
+        }
+    }
+}
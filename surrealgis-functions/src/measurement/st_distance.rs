@@ -1,5 +1,7 @@
 use geo::{Distance, Euclidean, Geodesic};
-use surrealgis_core::geometry::SurrealGeometry;
+use surrealgis_core::geometry::{GeometryType, SurrealGeometry};
+use surrealgis_crs::projection::Projection;
+use surrealgis_crs::registry;
 
 use crate::FunctionError;
 
@@ -11,7 +13,7 @@ pub fn st_distance(a: &SurrealGeometry, b: &SurrealGeometry) -> Result<f64, Func
     let ga = a.to_geo()?;
     let gb = b.to_geo()?;
 
-    if a.srid().is_geographic() {
+    if registry::is_geographic(a.srid().code()) {
         // Use geodesic distance for geographic CRS (returns meters)
         // Geodesic distance only supports Point-to-Point
         match (&ga, &gb) {
@@ -29,6 +31,32 @@ pub fn st_distance(a: &SurrealGeometry, b: &SurrealGeometry) -> Result<f64, Func
     }
 }
 
+/// Like [`st_distance`], also naming the unit of the returned value.
+///
+/// Geographic Point-to-Point distances are geodesic, in "meters"; other
+/// geographic pairs fall back to planar distance in "degrees". Projected
+/// SRIDs report the projection's unit, or "unknown" when the CRS is not
+/// registered or its definition does not state one.
+pub fn st_distance_with_unit(
+    a: &SurrealGeometry,
+    b: &SurrealGeometry,
+) -> Result<(f64, &'static str), FunctionError> {
+    let value = st_distance(a, b)?;
+    let unit = if registry::is_geographic(a.srid().code()) {
+        let both_points = matches!(
+            (a.geometry_type(), b.geometry_type()),
+            (GeometryType::Point(_), GeometryType::Point(_))
+        );
+        if both_points { "meters" } else { "degrees" }
+    } else {
+        Projection::new(a.srid().code())
+            .ok()
+            .and_then(|p| p.unit())
+            .unwrap_or("unknown")
+    };
+    Ok((value, unit))
+}
+
 /// Always compute geodesic distance regardless of SRID (returns meters).
 /// Only supports Point-to-Point.
 pub fn st_distance_sphere(a: &SurrealGeometry, b: &SurrealGeometry) -> Result<f64, FunctionError> {
@@ -39,9 +67,10 @@ pub fn st_distance_sphere(a: &SurrealGeometry, b: &SurrealGeometry) -> Result<f6
         (geo_types::Geometry::Point(pa), geo_types::Geometry::Point(pb)) => {
             Ok(Geodesic.distance(*pa, *pb))
         }
-        _ => Err(FunctionError::UnsupportedOperation(
-            "st_distance_sphere only supports Point-to-Point".to_string(),
-        )),
+        (geo_types::Geometry::Point(_), _) => {
+            Err(FunctionError::type_mismatch("st_distance_sphere", &["Point"], b))
+        }
+        _ => Err(FunctionError::type_mismatch("st_distance_sphere", &["Point"], a)),
     }
 }
 
@@ -83,4 +112,22 @@ mod tests {
         let d = st_distance_sphere(&nyc, &la).unwrap();
         assert!(d > 3900000.0 && d < 4000000.0, "Distance was {d}");
     }
+
+    #[test]
+    fn distance_unit_follows_crs() {
+        let a = SurrealGeometry::point(0.0, 0.0, Srid::WGS84).unwrap();
+        let b = SurrealGeometry::point(1.0, 0.0, Srid::WGS84).unwrap();
+        let (d, unit) = st_distance_with_unit(&a, &b).unwrap();
+        assert_eq!(unit, "meters");
+        assert!((d - 111_319.49).abs() < 1.0, "distance was {d}");
+
+        let a = SurrealGeometry::point(0.0, 0.0, Srid::WEB_MERCATOR).unwrap();
+        let b = SurrealGeometry::point(3.0, 4.0, Srid::WEB_MERCATOR).unwrap();
+        assert_eq!(st_distance_with_unit(&a, &b).unwrap(), (5.0, "meters"));
+
+        let unregistered = Srid::new(99999).unwrap();
+        let a = SurrealGeometry::point(0.0, 0.0, unregistered).unwrap();
+        let b = SurrealGeometry::point(3.0, 4.0, unregistered).unwrap();
+        assert_eq!(st_distance_with_unit(&a, &b).unwrap(), (5.0, "unknown"));
+    }
 }
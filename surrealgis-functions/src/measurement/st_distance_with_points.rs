@@ -0,0 +1,233 @@
+use geo::coordinate_position::{CoordPos, CoordinatePosition};
+use geo::{Distance, Geodesic};
+use surrealgis_core::coordinate::Coordinate;
+use surrealgis_core::geometry::{GeometryType, SurrealGeometry};
+
+use crate::FunctionError;
+
+/// Distance together with the closest point on `a` and the closest point on `b`.
+pub type DistanceWithPoints = (f64, SurrealGeometry, SurrealGeometry);
+
+/// Compute the distance between two geometries and the pair of points that
+/// realise it, in a single pass over their segments.
+///
+/// Distances follow `st_distance`: geodesic meters for two Points in a
+/// geographic SRID, planar units otherwise. When the geometries intersect the
+/// distance is 0 and both points are the same shared location. The points
+/// carry the SRID of their source geometry.
+pub fn st_distance_with_points(
+    a: &SurrealGeometry,
+    b: &SurrealGeometry,
+) -> Result<DistanceWithPoints, FunctionError> {
+    let parts_a = parts(a);
+    let parts_b = parts(b);
+    if parts_a.is_empty() || parts_b.is_empty() {
+        return Err(FunctionError::InvalidArgument(
+            "st_distance_with_points requires non-empty geometries".to_string(),
+        ));
+    }
+
+    let mut best: Option<(f64, Xy, Xy)> = None;
+    'search: for pa in &parts_a {
+        for pb in &parts_b {
+            for sa in segments(pa) {
+                for sb in segments(pb) {
+                    let (d, on_a, on_b) = closest_between_segments(sa, sb);
+                    if best.is_none_or(|(best_d, _, _)| d < best_d) {
+                        best = Some((d, on_a, on_b));
+                        if d == 0.0 {
+                            break 'search;
+                        }
+                    }
+                }
+            }
+        }
+    }
+    let (mut dist_sq, mut on_a, mut on_b) = best.expect("both geometries have parts");
+
+    if dist_sq > 0.0 {
+        // Boundaries are apart, but one geometry may lie inside the other's area
+        if let Some(inside) = vertex_inside(&parts_a, b)? {
+            (dist_sq, on_a, on_b) = (0.0, inside, inside);
+        } else if let Some(inside) = vertex_inside(&parts_b, a)? {
+            (dist_sq, on_a, on_b) = (0.0, inside, inside);
+        }
+    }
+
+    let mut distance = dist_sq.sqrt();
+    let both_points = matches!(
+        (a.geometry_type(), b.geometry_type()),
+        (GeometryType::Point(_), GeometryType::Point(_))
+    );
+    if both_points && a.srid().is_geographic() {
+        let to_point = |(x, y): Xy| geo_types::Point::new(x, y);
+        distance = Geodesic.distance(to_point(on_a), to_point(on_b));
+    }
+
+    Ok((
+        distance,
+        SurrealGeometry::point(on_a.0, on_a.1, *a.srid())?,
+        SurrealGeometry::point(on_b.0, on_b.1, *b.srid())?,
+    ))
+}
+
+type Xy = (f64, f64);
+
+/// Vertex sequences of a geometry: a single vertex for points, the vertices
+/// of each line or ring otherwise.
+fn parts(geom: &SurrealGeometry) -> Vec<Vec<Xy>> {
+    let xy = |coords: &[Coordinate]| coords.iter().map(|c| (c.x(), c.y())).collect::<Vec<_>>();
+    let sequences: Vec<Vec<Xy>> = match geom.geometry_type() {
+        GeometryType::Point(c) => vec![vec![(c.x(), c.y())]],
+        GeometryType::MultiPoint(coords) => coords.iter().map(|c| vec![(c.x(), c.y())]).collect(),
+        GeometryType::LineString(coords) => vec![xy(coords)],
+        GeometryType::MultiLineString(lines) => lines.iter().map(|l| xy(l)).collect(),
+        GeometryType::Polygon { exterior, holes } => {
+            std::iter::once(exterior).chain(holes).map(|r| xy(r)).collect()
+        }
+        GeometryType::MultiPolygon(polygons) => polygons
+            .iter()
+            .flat_map(|p| std::iter::once(&p.exterior).chain(&p.holes))
+            .map(|r| xy(r))
+            .collect(),
+        GeometryType::GeometryCollection(geoms) => geoms.iter().flat_map(parts).collect(),
+    };
+    sequences.into_iter().filter(|part| !part.is_empty()).collect()
+}
+
+/// Segments of a vertex sequence; a lone vertex is a zero-length segment.
+fn segments(part: &[Xy]) -> Vec<(Xy, Xy)> {
+    if part.len() == 1 {
+        return vec![(part[0], part[0])];
+    }
+    part.windows(2).map(|w| (w[0], w[1])).collect()
+}
+
+/// First vertex of `parts` lying inside or on `geom`, if any.
+fn vertex_inside(parts: &[Vec<Xy>], geom: &SurrealGeometry) -> Result<Option<Xy>, FunctionError> {
+    let area = geom.to_geo()?;
+    Ok(parts.iter().flatten().copied().find(|&(x, y)| {
+        area.coordinate_position(&geo_types::coord! { x: x, y: y }) != CoordPos::Outside
+    }))
+}
+
+/// Squared distance and closest points between segments `a` and `b`.
+fn closest_between_segments(a: (Xy, Xy), b: (Xy, Xy)) -> (f64, Xy, Xy) {
+    if let Some(p) = crossing(a, b) {
+        return (0.0, p, p);
+    }
+    let candidates = [
+        (a.0, project(b, a.0)),
+        (a.1, project(b, a.1)),
+        (project(a, b.0), b.0),
+        (project(a, b.1), b.1),
+    ];
+    candidates
+        .into_iter()
+        .map(|(pa, pb)| (dist_sq(pa, pb), pa, pb))
+        .fold(None, |best: Option<(f64, Xy, Xy)>, c| match best {
+            Some(b) if b.0 <= c.0 => Some(b),
+            _ => Some(c),
+        })
+        .expect("four candidates")
+}
+
+/// Intersection point of two segments that properly cross.
+fn crossing(a: (Xy, Xy), b: (Xy, Xy)) -> Option<Xy> {
+    let orient = |p: Xy, q: Xy, r: Xy| (q.0 - p.0) * (r.1 - p.1) - (q.1 - p.1) * (r.0 - p.0);
+    let d1 = orient(b.0, b.1, a.0);
+    let d2 = orient(b.0, b.1, a.1);
+    let d3 = orient(a.0, a.1, b.0);
+    let d4 = orient(a.0, a.1, b.1);
+    if d1 * d2 < 0.0 && d3 * d4 < 0.0 {
+        let t = d1 / (d1 - d2);
+        Some((a.0 .0 + t * (a.1 .0 - a.0 .0), a.0 .1 + t * (a.1 .1 - a.0 .1)))
+    } else {
+        None
+    }
+}
+
+/// Point on segment `s` closest to `p`.
+fn project(s: (Xy, Xy), p: Xy) -> Xy {
+    let (dx, dy) = (s.1 .0 - s.0 .0, s.1 .1 - s.0 .1);
+    let len_sq = dx * dx + dy * dy;
+    if len_sq == 0.0 {
+        return s.0;
+    }
+    let t = (((p.0 - s.0 .0) * dx + (p.1 - s.0 .1) * dy) / len_sq).clamp(0.0, 1.0);
+    (s.0 .0 + t * dx, s.0 .1 + t * dy)
+}
+
+fn dist_sq(a: Xy, b: Xy) -> f64 {
+    (a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::measurement::st_distance;
+    use crate::test_support::{line, square};
+    use surrealgis_core::srid::Srid;
+
+    fn xy(geom: &SurrealGeometry) -> Xy {
+        match geom.geometry_type() {
+            GeometryType::Point(c) => (c.x(), c.y()),
+            _ => panic!("Expected Point"),
+        }
+    }
+
+    #[test]
+    fn distance_matches_returned_points_and_st_distance() {
+        let cases = [
+            (line(&[(0.0, 0.0), (10.0, 0.0)]), square(4.0, 3.0, 2.0)),
+            (line(&[(0.0, 0.0), (5.0, 5.0)]), line(&[(6.0, 0.0), (10.0, 1.0)])),
+            (
+                SurrealGeometry::point(-3.0, 4.0, Srid::WEB_MERCATOR).unwrap(),
+                square(0.0, 0.0, 1.0),
+            ),
+        ];
+        for (a, b) in &cases {
+            let (d, pa, pb) = st_distance_with_points(a, b).unwrap();
+            assert!((d - st_distance(&pa, &pb).unwrap()).abs() < 1e-9);
+            assert!((d - st_distance(a, b).unwrap()).abs() < 1e-9, "{d}");
+        }
+    }
+
+    #[test]
+    fn closest_points_line_to_square() {
+        let (d, on_line, on_square) =
+            st_distance_with_points(&line(&[(0.0, 0.0), (10.0, 0.0)]), &square(4.0, 3.0, 2.0))
+                .unwrap();
+        assert!((d - 3.0).abs() < 1e-12);
+        assert_eq!(on_line.srid().code(), Srid::WEB_MERCATOR.code());
+        assert_eq!(xy(&on_line).1, 0.0);
+        assert_eq!(xy(&on_square).1, 3.0);
+    }
+
+    #[test]
+    fn crossing_lines_share_intersection_point() {
+        let a = line(&[(0.0, 0.0), (2.0, 2.0)]);
+        let b = line(&[(0.0, 2.0), (2.0, 0.0)]);
+        let (d, pa, pb) = st_distance_with_points(&a, &b).unwrap();
+        assert_eq!(d, 0.0);
+        assert_eq!(xy(&pa), (1.0, 1.0));
+        assert_eq!(pa, pb);
+    }
+
+    #[test]
+    fn contained_geometry_has_zero_distance() {
+        let inner = line(&[(4.0, 4.0), (5.0, 5.0)]);
+        let (d, pa, pb) = st_distance_with_points(&inner, &square(0.0, 0.0, 10.0)).unwrap();
+        assert_eq!(d, 0.0);
+        assert_eq!(xy(&pa), (4.0, 4.0));
+        assert_eq!(pa, pb);
+    }
+
+    #[test]
+    fn geographic_points_use_geodesic_distance() {
+        let nyc = SurrealGeometry::point(-73.9857, 40.7484, Srid::WGS84).unwrap();
+        let la = SurrealGeometry::point(-118.2437, 34.0522, Srid::WGS84).unwrap();
+        let (d, _, _) = st_distance_with_points(&nyc, &la).unwrap();
+        assert!((d - st_distance(&nyc, &la).unwrap()).abs() < 1e-6);
+    }
+}
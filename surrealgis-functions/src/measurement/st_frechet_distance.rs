@@ -0,0 +1,99 @@
+use geo::{Distance, Euclidean, Geodesic};
+use geo_types::{Coord, Point};
+use surrealgis_core::geometry::SurrealGeometry;
+use surrealgis_crs::registry;
+
+use crate::FunctionError;
+
+/// Compute the discrete Fréchet distance between two LineStrings: the
+/// smallest "leash length" that lets two walkers traverse the vertex
+/// sequences front to back, each only ever moving forward.
+/// Like `st_hausdorff_distance`, a geographic SRID on `a` selects geodesic
+/// point distance (meters); otherwise the result is in planar units.
+pub fn st_frechet_distance(a: &SurrealGeometry, b: &SurrealGeometry) -> Result<f64, FunctionError> {
+    let va = line_coords(a)?;
+    let vb = line_coords(b)?;
+    if va.is_empty() || vb.is_empty() {
+        return Err(FunctionError::InvalidArgument(
+            "st_frechet_distance requires non-empty LineStrings".to_string(),
+        ));
+    }
+
+    let dist: fn(Coord<f64>, Coord<f64>) -> f64 = if registry::is_geographic(a.srid().code()) {
+        |p, q| Geodesic.distance(Point::from(p), Point::from(q))
+    } else {
+        |p, q| Euclidean.distance(Point::from(p), Point::from(q))
+    };
+
+    // Rolling row of the coupling table: row[j] is the distance for prefixes
+    // va[..=i] and vb[..=j].
+    let mut row = vec![0.0f64; vb.len()];
+    for (i, &p) in va.iter().enumerate() {
+        let mut diagonal = 0.0f64;
+        for (j, &q) in vb.iter().enumerate() {
+            let d = dist(p, q);
+            let reach = match (i, j) {
+                (0, 0) => d,
+                (0, _) => row[j - 1].max(d),
+                (_, 0) => row[0].max(d),
+                _ => diagonal.min(row[j]).min(row[j - 1]).max(d),
+            };
+            diagonal = row[j];
+            row[j] = reach;
+        }
+    }
+    Ok(row[vb.len() - 1])
+}
+
+fn line_coords(geom: &SurrealGeometry) -> Result<Vec<Coord<f64>>, FunctionError> {
+    match geom.to_geo()? {
+        geo_types::Geometry::LineString(line) => Ok(line.0),
+        _ => Err(FunctionError::UnsupportedOperation(format!(
+            "st_frechet_distance requires LineString inputs, got {}",
+            geom.type_name()
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::line;
+    use surrealgis_core::srid::Srid;
+
+    #[test]
+    fn parallel_offset_lines() {
+        let a = line(&[(0.0, 0.0), (5.0, 0.0), (10.0, 0.0)]);
+        let b = line(&[(0.0, 3.0), (5.0, 3.0), (10.0, 3.0)]);
+        assert!((st_frechet_distance(&a, &b).unwrap() - 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn direction_matters() {
+        // Hausdorff would be 0 here, but walking one line backwards forces
+        // the leash to span the whole length at the start
+        let a = line(&[(0.0, 0.0), (10.0, 0.0)]);
+        let b = line(&[(10.0, 0.0), (0.0, 0.0)]);
+        assert!((st_frechet_distance(&a, &b).unwrap() - 10.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn frechet_is_symmetric() {
+        let a = line(&[(0.0, 0.0), (2.0, 1.0), (4.0, 0.0), (6.0, 2.0)]);
+        let b = line(&[(0.0, 1.0), (3.0, 3.0), (6.0, 0.0)]);
+        assert_eq!(
+            st_frechet_distance(&a, &b).unwrap(),
+            st_frechet_distance(&b, &a).unwrap()
+        );
+    }
+
+    #[test]
+    fn non_linestring_rejected() {
+        let a = line(&[(0.0, 0.0), (1.0, 0.0)]);
+        let p = SurrealGeometry::point(0.0, 0.0, Srid::WEB_MERCATOR).unwrap();
+        assert!(matches!(
+            st_frechet_distance(&a, &p),
+            Err(FunctionError::UnsupportedOperation(_))
+        ));
+    }
+}
@@ -0,0 +1,89 @@
+use geo::{CoordsIter, Distance, Euclidean, Geodesic};
+use geo_types::{Coord, Point};
+use surrealgis_core::geometry::SurrealGeometry;
+use surrealgis_crs::registry;
+
+use crate::FunctionError;
+
+/// Compute the discrete Hausdorff distance between the vertex sets of two
+/// geometries: the largest distance from any vertex of one geometry to the
+/// nearest vertex of the other, taken in both directions.
+/// Like `st_distance`, a geographic SRID on `a` selects geodesic point
+/// distance (meters); otherwise the result is in planar units of the SRID.
+pub fn st_hausdorff_distance(a: &SurrealGeometry, b: &SurrealGeometry) -> Result<f64, FunctionError> {
+    let va: Vec<Coord<f64>> = a.to_geo()?.coords_iter().collect();
+    let vb: Vec<Coord<f64>> = b.to_geo()?.coords_iter().collect();
+    if va.is_empty() || vb.is_empty() {
+        return Err(FunctionError::InvalidArgument(
+            "st_hausdorff_distance requires non-empty geometries".to_string(),
+        ));
+    }
+
+    let dist: fn(Coord<f64>, Coord<f64>) -> f64 = if registry::is_geographic(a.srid().code()) {
+        |p, q| Geodesic.distance(Point::from(p), Point::from(q))
+    } else {
+        |p, q| Euclidean.distance(Point::from(p), Point::from(q))
+    };
+
+    Ok(directed(&va, &vb, dist).max(directed(&vb, &va, dist)))
+}
+
+/// Largest distance from a vertex of `from` to its nearest vertex in `to`.
+fn directed(
+    from: &[Coord<f64>],
+    to: &[Coord<f64>],
+    dist: fn(Coord<f64>, Coord<f64>) -> f64,
+) -> f64 {
+    from.iter()
+        .map(|&p| to.iter().map(|&q| dist(p, q)).fold(f64::INFINITY, f64::min))
+        .fold(0.0, f64::max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use surrealgis_core::coordinate::Coordinate;
+    use surrealgis_core::srid::Srid;
+
+    fn line(points: &[(f64, f64)], srid: Srid) -> SurrealGeometry {
+        let coords = points
+            .iter()
+            .map(|&(x, y)| Coordinate::new(x, y).unwrap())
+            .collect();
+        SurrealGeometry::line_string(coords, srid).unwrap()
+    }
+
+    #[test]
+    fn hausdorff_of_simplified_line() {
+        let original = line(&[(0.0, 0.0), (5.0, 2.0), (10.0, 0.0)], Srid::WEB_MERCATOR);
+        let simplified = line(&[(0.0, 0.0), (10.0, 0.0)], Srid::WEB_MERCATOR);
+        let h = st_hausdorff_distance(&original, &simplified).unwrap();
+        // (5,2) is sqrt(29) from both endpoints of the simplified line
+        assert!((h - 29f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn hausdorff_is_symmetric() {
+        let a = line(&[(0.0, 0.0), (3.0, 1.0), (7.0, -2.0)], Srid::WEB_MERCATOR);
+        let b = line(&[(1.0, 0.0), (4.0, 4.0)], Srid::WEB_MERCATOR);
+        let ab = st_hausdorff_distance(&a, &b).unwrap();
+        let ba = st_hausdorff_distance(&b, &a).unwrap();
+        assert_eq!(ab, ba);
+        assert!(ab > 0.0);
+    }
+
+    #[test]
+    fn hausdorff_of_identical_geometries_is_zero() {
+        let a = line(&[(0.0, 0.0), (3.0, 1.0)], Srid::WEB_MERCATOR);
+        assert_eq!(st_hausdorff_distance(&a, &a).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn hausdorff_geographic_uses_meters() {
+        let a = SurrealGeometry::point(0.0, 0.0, Srid::WGS84).unwrap();
+        let b = SurrealGeometry::point(1.0, 0.0, Srid::WGS84).unwrap();
+        let h = st_hausdorff_distance(&a, &b).unwrap();
+        // One degree of longitude on the equator is about 111.3 km
+        assert!((h - 111_319.0).abs() < 100.0, "{h}");
+    }
+}
@@ -1,29 +1,76 @@
 use geo::line_measures::LengthMeasurable;
 use geo::{Euclidean, Geodesic};
 use surrealgis_core::geometry::{GeometryType, SurrealGeometry};
+use surrealgis_crs::{registry, suggest};
 
+use crate::crs::st_transform;
 use crate::FunctionError;
 
 /// Compute the length of a geometry.
-/// For geographic SRID (4326), returns geodesic length in meters.
+/// For geographic SRIDs (per `registry::is_geographic`), returns geodesic
+/// length in meters via `st_length_geodesic`.
 /// For projected SRID, returns Euclidean length in projection units.
 pub fn st_length(geom: &SurrealGeometry) -> Result<f64, FunctionError> {
-    let geo_geom = geom.to_geo()?;
+    if registry::is_geographic(geom.srid().code()) {
+        return st_length_geodesic(geom);
+    }
+    lineal_length(geom, false)
+}
+
+/// Compute the length of a geometry in meters along WGS84 geodesics.
+/// Coordinates must be longitude/latitude, so the SRID must be geographic.
+pub fn st_length_geodesic(geom: &SurrealGeometry) -> Result<f64, FunctionError> {
+    if !registry::is_geographic(geom.srid().code()) {
+        return Err(FunctionError::InvalidArgument(format!(
+            "st_length_geodesic requires a geographic SRID, got {}",
+            geom.srid().code()
+        )));
+    }
+    lineal_length(geom, true)
+}
+
+/// Compute the length of a geographic line as drawn on a projected map.
+///
+/// The geometry is reprojected into `target_srid`, or into the UTM zone
+/// containing its centre when `None`, and measured there with Euclidean
+/// length, in the target's units (meters for UTM). This is neither the true
+/// ground length of [`st_length_geodesic`] nor the planar length in degrees:
+/// it carries the projection's scale distortion, so in Web Mercator it
+/// grows with latitude, while in a best-fit UTM zone it stays within about
+/// 0.1% of the geodesic length.
+pub fn st_rendered_length(
+    geom: &SurrealGeometry,
+    target_srid: Option<i32>,
+) -> Result<f64, FunctionError> {
+    if !registry::is_geographic(geom.srid().code()) {
+        return Err(FunctionError::InvalidArgument(format!(
+            "st_rendered_length requires a geographic SRID, got {}",
+            geom.srid().code()
+        )));
+    }
+    let target = match target_srid {
+        Some(srid) => srid,
+        None => suggest::best_fit_utm(geom).ok_or_else(|| {
+            FunctionError::CrsError("No UTM zone found for the geometry".to_string())
+        })?,
+    };
+    if registry::is_geographic(target) {
+        return Err(FunctionError::InvalidArgument(format!(
+            "st_rendered_length requires a projected target SRID, got {target}"
+        )));
+    }
+    lineal_length(&st_transform(geom, target)?, false)
+}
 
+fn lineal_length(geom: &SurrealGeometry, geodesic: bool) -> Result<f64, FunctionError> {
     match geom.geometry_type() {
         GeometryType::LineString(_) | GeometryType::MultiLineString(_) => {
-            if geom.srid().is_geographic() {
-                match &geo_geom {
-                    geo_types::Geometry::LineString(ls) => Ok(ls.length(&Geodesic)),
-                    geo_types::Geometry::MultiLineString(mls) => Ok(mls.length(&Geodesic)),
-                    _ => unreachable!(),
-                }
-            } else {
-                match &geo_geom {
-                    geo_types::Geometry::LineString(ls) => Ok(ls.length(&Euclidean)),
-                    geo_types::Geometry::MultiLineString(mls) => Ok(mls.length(&Euclidean)),
-                    _ => unreachable!(),
-                }
+            match (&geom.to_geo()?, geodesic) {
+                (geo_types::Geometry::LineString(ls), true) => Ok(ls.length(&Geodesic)),
+                (geo_types::Geometry::LineString(ls), false) => Ok(ls.length(&Euclidean)),
+                (geo_types::Geometry::MultiLineString(mls), true) => Ok(mls.length(&Geodesic)),
+                (geo_types::Geometry::MultiLineString(mls), false) => Ok(mls.length(&Euclidean)),
+                _ => unreachable!(),
             }
         }
         _ => Ok(0.0),
@@ -36,6 +83,37 @@ mod tests {
     use surrealgis_core::coordinate::Coordinate;
     use surrealgis_core::srid::Srid;
 
+    #[test]
+    fn rendered_length_compared_with_geodesic_and_degrees() {
+        // About 7.5 km across Paris, zone 31N
+        let coords = vec![
+            Coordinate::new(2.30, 48.85).unwrap(),
+            Coordinate::new(2.40, 48.86).unwrap(),
+        ];
+        let ls = SurrealGeometry::line_string(coords, Srid::WGS84).unwrap();
+        let geodesic = st_length_geodesic(&ls).unwrap();
+        let degrees = lineal_length(&ls, false).unwrap();
+        let utm = st_rendered_length(&ls, None).unwrap();
+        let mercator = st_rendered_length(&ls, Some(3857)).unwrap();
+
+        assert!((degrees - 0.1005).abs() < 1e-3, "degrees was {degrees}");
+        assert!(geodesic > 7000.0 && geodesic < 8000.0, "geodesic was {geodesic}");
+        // UTM is in meters and close to, but not exactly, the ground length
+        assert!((utm / geodesic - 1.0).abs() < 1e-3, "utm was {utm}");
+        assert_ne!(utm, geodesic);
+        // Web Mercator stretches by about 1 / cos(latitude)
+        let stretch = 1.0 / 48.855_f64.to_radians().cos();
+        assert!((mercator / geodesic - stretch).abs() < 0.01, "mercator was {mercator}");
+
+        let projected = SurrealGeometry::line_string(
+            vec![Coordinate::new(0.0, 0.0).unwrap(), Coordinate::new(1.0, 1.0).unwrap()],
+            Srid::WEB_MERCATOR,
+        )
+        .unwrap();
+        assert!(st_rendered_length(&projected, None).is_err());
+        assert!(st_rendered_length(&ls, Some(4326)).is_err());
+    }
+
     #[test]
     fn euclidean_length_simple() {
         let coords = vec![
@@ -77,4 +155,29 @@ mod tests {
         let poly = SurrealGeometry::polygon(exterior, vec![], Srid::WGS84).unwrap();
         assert_eq!(st_length(&poly).unwrap(), 0.0);
     }
+
+    #[test]
+    fn new_york_to_los_angeles() {
+        let coords = vec![
+            Coordinate::new(-74.0060, 40.7128).unwrap(),
+            Coordinate::new(-118.2437, 34.0522).unwrap(),
+        ];
+        let ls = SurrealGeometry::line_string(coords, Srid::WGS84).unwrap();
+        let km = st_length(&ls).unwrap() / 1000.0;
+        assert!((3_900.0..3_970.0).contains(&km), "Length was {km} km");
+        assert_eq!(st_length(&ls).unwrap(), st_length_geodesic(&ls).unwrap());
+    }
+
+    #[test]
+    fn geodesic_length_rejects_projected_srid() {
+        let coords = vec![
+            Coordinate::new(0.0, 0.0).unwrap(),
+            Coordinate::new(3.0, 4.0).unwrap(),
+        ];
+        let ls = SurrealGeometry::line_string(coords, Srid::WEB_MERCATOR).unwrap();
+        assert!(matches!(
+            st_length_geodesic(&ls),
+            Err(FunctionError::InvalidArgument(_))
+        ));
+    }
 }
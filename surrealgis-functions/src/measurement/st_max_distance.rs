@@ -0,0 +1,52 @@
+use geo::{CoordsIter, Distance, Euclidean, Geodesic};
+use geo_types::{Coord, Point};
+use surrealgis_core::geometry::SurrealGeometry;
+use surrealgis_crs::registry;
+
+use crate::FunctionError;
+
+/// Compute the largest distance between any vertex of `a` and any vertex of
+/// `b` (PostGIS `ST_MaxDistance`).
+/// Like `st_hausdorff_distance`, a geographic SRID on `a` selects geodesic
+/// point distance (meters); otherwise the result is in planar units.
+pub fn st_max_distance(a: &SurrealGeometry, b: &SurrealGeometry) -> Result<f64, FunctionError> {
+    let va: Vec<Coord<f64>> = a.to_geo()?.coords_iter().collect();
+    let vb: Vec<Coord<f64>> = b.to_geo()?.coords_iter().collect();
+    if va.is_empty() || vb.is_empty() {
+        return Err(FunctionError::InvalidArgument(
+            "st_max_distance requires non-empty geometries".to_string(),
+        ));
+    }
+
+    let dist: fn(Coord<f64>, Coord<f64>) -> f64 = if registry::is_geographic(a.srid().code()) {
+        |p, q| Geodesic.distance(Point::from(p), Point::from(q))
+    } else {
+        |p, q| Euclidean.distance(Point::from(p), Point::from(q))
+    };
+
+    Ok(va
+        .iter()
+        .flat_map(|&p| vb.iter().map(move |&q| dist(p, q)))
+        .fold(0.0, f64::max))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::square;
+    use surrealgis_core::srid::Srid;
+
+    #[test]
+    fn two_points_equal_their_distance() {
+        let a = SurrealGeometry::point(0.0, 0.0, Srid::WEB_MERCATOR).unwrap();
+        let b = SurrealGeometry::point(3.0, 4.0, Srid::WEB_MERCATOR).unwrap();
+        assert_eq!(st_max_distance(&a, &b).unwrap(), 5.0);
+    }
+
+    #[test]
+    fn two_squares_use_farthest_corners() {
+        // Farthest corners are (0,0) and (4,3)
+        let d = st_max_distance(&square(0.0, 0.0, 1.0), &square(3.0, 2.0, 1.0)).unwrap();
+        assert!((d - 5.0).abs() < 1e-12);
+    }
+}
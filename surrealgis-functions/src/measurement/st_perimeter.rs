@@ -1,40 +1,52 @@
 use geo::line_measures::LengthMeasurable;
 use geo::{Euclidean, Geodesic};
 use surrealgis_core::geometry::{GeometryType, SurrealGeometry};
+use surrealgis_crs::registry;
 
 use crate::FunctionError;
 
 /// Compute the perimeter of a Polygon (length of exterior ring).
-/// For geographic SRIDs, returns geodesic perimeter in meters.
+/// For geographic SRIDs (per `registry::is_geographic`), returns geodesic
+/// perimeter in meters via `st_perimeter_geodesic`.
 /// For projected SRIDs, returns Euclidean perimeter.
 pub fn st_perimeter(geom: &SurrealGeometry) -> Result<f64, FunctionError> {
+    if registry::is_geographic(geom.srid().code()) {
+        return st_perimeter_geodesic(geom);
+    }
+    exterior_length(geom, false)
+}
+
+/// Compute the perimeter of a Polygon in meters along WGS84 geodesics.
+/// Coordinates must be longitude/latitude, so the SRID must be geographic.
+pub fn st_perimeter_geodesic(geom: &SurrealGeometry) -> Result<f64, FunctionError> {
+    if !registry::is_geographic(geom.srid().code()) {
+        return Err(FunctionError::InvalidArgument(format!(
+            "st_perimeter_geodesic requires a geographic SRID, got {}",
+            geom.srid().code()
+        )));
+    }
+    exterior_length(geom, true)
+}
+
+fn exterior_length(geom: &SurrealGeometry, geodesic: bool) -> Result<f64, FunctionError> {
+    let ring_length = |ring: &geo_types::LineString<f64>| {
+        if geodesic {
+            ring.length(&Geodesic)
+        } else {
+            ring.length(&Euclidean)
+        }
+    };
     let geo_geom = geom.to_geo()?;
 
     match (&geo_geom, geom.geometry_type()) {
-        (geo_types::Geometry::Polygon(poly), _) => {
-            let exterior = poly.exterior();
-            if geom.srid().is_geographic() {
-                Ok(exterior.length(&Geodesic))
-            } else {
-                Ok(exterior.length(&Euclidean))
-            }
-        }
+        (geo_types::Geometry::Polygon(poly), _) => Ok(ring_length(poly.exterior())),
         (geo_types::Geometry::MultiPolygon(mp), _) => {
-            let mut total = 0.0;
-            for poly in &mp.0 {
-                let exterior = poly.exterior();
-                if geom.srid().is_geographic() {
-                    total += exterior.length(&Geodesic);
-                } else {
-                    total += exterior.length(&Euclidean);
-                }
-            }
-            Ok(total)
+            Ok(mp.0.iter().map(|poly| ring_length(poly.exterior())).sum())
         }
         (_, GeometryType::GeometryCollection(geoms)) => {
             let mut total = 0.0;
             for g in geoms {
-                total += st_perimeter(g)?;
+                total += exterior_length(g, geodesic)?;
             }
             Ok(total)
         }
@@ -67,4 +79,20 @@ mod tests {
         let p = SurrealGeometry::point(1.0, 2.0, Srid::WGS84).unwrap();
         assert_eq!(st_perimeter(&p).unwrap(), 0.0);
     }
+
+    #[test]
+    fn geographic_perimeter_in_meters() {
+        let exterior = vec![
+            Coordinate::new(0.0, 0.0).unwrap(),
+            Coordinate::new(1.0, 0.0).unwrap(),
+            Coordinate::new(1.0, 1.0).unwrap(),
+            Coordinate::new(0.0, 1.0).unwrap(),
+            Coordinate::new(0.0, 0.0).unwrap(),
+        ];
+        let poly = SurrealGeometry::polygon(exterior, vec![], Srid::WGS84).unwrap();
+        let km = st_perimeter(&poly).unwrap() / 1000.0;
+        // Two sides of ~111.3 km along parallels, two of ~110.6 km along meridians
+        assert!((440.0..446.0).contains(&km), "Perimeter was {km} km");
+        assert_eq!(st_perimeter(&poly).unwrap(), st_perimeter_geodesic(&poly).unwrap());
+    }
 }
@@ -0,0 +1,139 @@
+use geo::{Destination, Geodesic};
+use surrealgis_core::geometry::{GeometryType, SurrealGeometry};
+
+use crate::FunctionError;
+
+use super::st_azimuth::st_azimuth;
+
+/// Project a Point a given distance along an azimuth (radians from north, clockwise).
+/// For geographic SRIDs the distance is in meters along the geodesic;
+/// for projected SRIDs it is in the projection's units on the plane.
+pub fn st_project(
+    geom: &SurrealGeometry,
+    distance: f64,
+    azimuth: f64,
+) -> Result<SurrealGeometry, FunctionError> {
+    let origin = match geom.geometry_type() {
+        GeometryType::Point(c) => geo_types::Point::new(c.x(), c.y()),
+        _ => return Err(FunctionError::type_mismatch("st_project", &["Point"], geom)),
+    };
+    if !distance.is_finite() || !azimuth.is_finite() {
+        return Err(FunctionError::InvalidArgument(
+            "st_project distance and azimuth must be finite".to_string(),
+        ));
+    }
+
+    let dest = if geom.srid().is_geographic() {
+        Geodesic.destination(origin, azimuth.to_degrees(), distance)
+    } else {
+        geo_types::Point::new(
+            origin.x() + distance * azimuth.sin(),
+            origin.y() + distance * azimuth.cos(),
+        )
+    };
+    SurrealGeometry::point(dest.x(), dest.y(), *geom.srid()).map_err(FunctionError::from)
+}
+
+/// Project `origin` along `azimuth` by `distance`, then measure the azimuth
+/// from `origin` back to the projected point.
+/// Returns the projected point and the re-measured azimuth; with consistent
+/// `st_project`/`st_azimuth` implementations the two azimuths agree.
+pub fn destination_then_azimuth(
+    origin: &SurrealGeometry,
+    distance: f64,
+    azimuth: f64,
+) -> Result<(SurrealGeometry, f64), FunctionError> {
+    let dest = st_project(origin, distance, azimuth)?;
+    let measured = st_azimuth(origin, &dest)?;
+    Ok((dest, measured))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::measurement::st_distance;
+    use surrealgis_core::srid::Srid;
+
+    fn xy(geom: &SurrealGeometry) -> (f64, f64) {
+        match geom.geometry_type() {
+            GeometryType::Point(c) => (c.x(), c.y()),
+            _ => panic!("Expected Point"),
+        }
+    }
+
+    #[test]
+    fn project_planar_east() {
+        let a = SurrealGeometry::point(10.0, 10.0, Srid::WEB_MERCATOR).unwrap();
+        let p = st_project(&a, 5.0, std::f64::consts::FRAC_PI_2).unwrap();
+        let (x, y) = xy(&p);
+        assert!((x - 15.0).abs() < 1e-10);
+        assert!((y - 10.0).abs() < 1e-10);
+        assert_eq!(p.srid().code(), Srid::WEB_MERCATOR.code());
+    }
+
+    #[test]
+    fn project_geographic_north() {
+        let a = SurrealGeometry::point(0.0, 0.0, Srid::WGS84).unwrap();
+        let p = st_project(&a, 111_319.49, 0.0).unwrap();
+        let (x, y) = xy(&p);
+        assert!(x.abs() < 1e-9);
+        assert!((y - 1.0).abs() < 0.01, "Latitude was {y}");
+    }
+
+    #[test]
+    fn project_geographic_east_along_equator() {
+        // One degree of longitude on the WGS84 equator is ~111,319.49 m
+        let a = SurrealGeometry::point(0.0, 0.0, Srid::WGS84).unwrap();
+        let p = st_project(&a, 2.0 * 111_319.49, std::f64::consts::FRAC_PI_2).unwrap();
+        let (x, y) = xy(&p);
+        assert!((x - 2.0).abs() < 1e-6, "Longitude was {x}");
+        assert!(y.abs() < 1e-9, "Latitude was {y}");
+    }
+
+    #[test]
+    fn project_requires_point() {
+        let a = SurrealGeometry::point(0.0, 0.0, Srid::WGS84).unwrap();
+        let mp = crate::editors::st_multi(&a).unwrap();
+        assert!(matches!(
+            st_project(&mp, 1.0, 0.0),
+            Err(FunctionError::TypeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn project_along_azimuth_lands_on_ray_projected() {
+        let a = SurrealGeometry::point(100.0, 200.0, Srid::WEB_MERCATOR).unwrap();
+        let b = SurrealGeometry::point(400.0, -200.0, Srid::WEB_MERCATOR).unwrap();
+        let az = st_azimuth(&a, &b).unwrap();
+        let p = st_project(&a, 250.0, az).unwrap();
+
+        // Distance from a is d, and p lies between a and b on the a->b ray
+        assert!((st_distance(&a, &p).unwrap() - 250.0).abs() < 1e-9);
+        let ab = st_distance(&a, &b).unwrap();
+        let pb = st_distance(&p, &b).unwrap();
+        assert!((ab - pb - 250.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn project_along_azimuth_lands_on_ray_geographic() {
+        let a = SurrealGeometry::point(-0.1278, 51.5074, Srid::WGS84).unwrap();
+        let b = SurrealGeometry::point(2.3522, 48.8566, Srid::WGS84).unwrap();
+        let az = st_azimuth(&a, &b).unwrap();
+        let p = st_project(&a, 100_000.0, az).unwrap();
+
+        assert!((st_distance(&a, &p).unwrap() - 100_000.0).abs() < 1e-3);
+        // On the geodesic a->b: a->p + p->b == a->b
+        let ab = st_distance(&a, &b).unwrap();
+        let pb = st_distance(&p, &b).unwrap();
+        assert!((ab - pb - 100_000.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn round_trip_azimuth_is_consistent() {
+        for srid in [Srid::WGS84, Srid::WEB_MERCATOR] {
+            let a = SurrealGeometry::point(10.0, 20.0, srid).unwrap();
+            let (_, measured) = destination_then_azimuth(&a, 5_000.0, 1.0).unwrap();
+            assert!((measured - 1.0).abs() < 1e-9, "SRID {} gave {measured}", srid.code());
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use std::collections::BTreeSet;
+
+use surrealgis_core::bbox::BoundingBox;
+
+use crate::FunctionError;
+
+/// Geohash base32 alphabet (omits a, i, l and o).
+const BASE32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// Longest geohash supported; 12 characters is under 4cm of resolution.
+pub const MAX_GEOHASH_PRECISION: usize = 12;
+
+/// Upper bound on the number of cells `geohashes_covering` will return.
+pub const MAX_COVERING_CELLS: usize = 100_000;
+
+/// Return the sorted set of geohashes of length `precision` whose cells
+/// together cover `bbox` (in longitude/latitude degrees).
+///
+/// Each geohash is a key prefix, so the result turns a bbox query into a
+/// handful of prefix range scans in an ordered KV store. The box is clamped to
+/// the valid lon/lat range. Fails if the covering would exceed
+/// `MAX_COVERING_CELLS`; use a coarser precision for large boxes.
+pub fn geohashes_covering(
+    bbox: &BoundingBox,
+    precision: usize,
+) -> Result<Vec<String>, FunctionError> {
+    if precision == 0 || precision > MAX_GEOHASH_PRECISION {
+        return Err(FunctionError::InvalidArgument(format!(
+            "Geohash precision must be between 1 and {MAX_GEOHASH_PRECISION}, got {precision}"
+        )));
+    }
+    let (min_x, min_y) = cell_index(bbox.min_x, bbox.min_y, precision);
+    let (max_x, max_y) = cell_index(bbox.max_x, bbox.max_y, precision);
+    let cells = (max_x - min_x + 1).saturating_mul(max_y - min_y + 1);
+    if cells > MAX_COVERING_CELLS as u64 {
+        return Err(FunctionError::InvalidArgument(format!(
+            "Covering needs {cells} geohashes at precision {precision}, more than {MAX_COVERING_CELLS}"
+        )));
+    }
+
+    let mut hashes = BTreeSet::new();
+    for ix in min_x..=max_x {
+        for iy in min_y..=max_y {
+            hashes.insert(cell_hash(ix, iy, precision));
+        }
+    }
+    Ok(hashes.into_iter().collect())
+}
+
+/// Bits of longitude and latitude in a geohash; longitude takes the extra
+/// bit when the total is odd.
+fn bit_counts(precision: usize) -> (u32, u32) {
+    let bits = 5 * precision as u32;
+    (bits.div_ceil(2), bits / 2)
+}
+
+/// Column and row of the cell containing (`lon`, `lat`), clamped to the grid.
+fn cell_index(lon: f64, lat: f64, precision: usize) -> (u64, u64) {
+    let (lon_bits, lat_bits) = bit_counts(precision);
+    let index = |v: f64, min: f64, span: f64, bits: u32| {
+        let cells = 1u64 << bits;
+        let t = ((v - min) / span).clamp(0.0, 1.0);
+        ((t * cells as f64) as u64).min(cells - 1)
+    };
+    (
+        index(lon, -180.0, 360.0, lon_bits),
+        index(lat, -90.0, 180.0, lat_bits),
+    )
+}
+
+/// Interleave the column and row bits (longitude first) and base32 encode.
+fn cell_hash(ix: u64, iy: u64, precision: usize) -> String {
+    let (mut lon_bits, mut lat_bits) = bit_counts(precision);
+    let mut value: u64 = 0;
+    for k in 0..5 * precision {
+        let bit = if k % 2 == 0 {
+            lon_bits -= 1;
+            (ix >> lon_bits) & 1
+        } else {
+            lat_bits -= 1;
+            (iy >> lat_bits) & 1
+        };
+        value = (value << 1) | bit;
+    }
+    (0..precision)
+        .map(|i| BASE32[((value >> (5 * (precision - 1 - i))) & 31) as usize] as char)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(lon: f64, lat: f64, precision: usize) -> String {
+        let (ix, iy) = cell_index(lon, lat, precision);
+        cell_hash(ix, iy, precision)
+    }
+
+    #[test]
+    fn encodes_known_geohash() {
+        assert_eq!(encode(-0.1278, 51.5074, 5), "gcpvj");
+        assert_eq!(encode(10.0, 10.0, 1), "s");
+    }
+
+    #[test]
+    fn small_bbox_covered_by_handful_of_cells() {
+        let bbox = BoundingBox::new(-0.15, 51.49, -0.05, 51.53).unwrap();
+        let hashes = geohashes_covering(&bbox, 5).unwrap();
+        assert_eq!(hashes, vec!["gcpuu", "gcpuv", "gcpuy", "gcpvh", "gcpvj", "gcpvn"]);
+
+        // Every sampled point of the box falls in one of the returned cells
+        for i in 0..=20 {
+            for j in 0..=20 {
+                let lon = -0.15 + 0.1 * i as f64 / 20.0;
+                let lat = 51.49 + 0.04 * j as f64 / 20.0;
+                assert!(hashes.contains(&encode(lon, lat, 5)), "({lon}, {lat}) not covered");
+            }
+        }
+    }
+
+    #[test]
+    fn bbox_around_origin_at_precision_one() {
+        let bbox = BoundingBox::new(-10.0, -10.0, 10.0, 10.0).unwrap();
+        assert_eq!(geohashes_covering(&bbox, 1).unwrap(), vec!["7", "e", "k", "s"]);
+    }
+
+    #[test]
+    fn invalid_precision_and_oversized_covering_rejected() {
+        let bbox = BoundingBox::new(-10.0, -10.0, 10.0, 10.0).unwrap();
+        assert!(geohashes_covering(&bbox, 0).is_err());
+        assert!(geohashes_covering(&bbox, 13).is_err());
+        assert!(matches!(
+            geohashes_covering(&bbox, 8),
+            Err(FunctionError::InvalidArgument(_))
+        ));
+    }
+}
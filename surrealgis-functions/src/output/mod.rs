@@ -1,9 +1,15 @@
+mod geohash;
 mod st_as_text;
 mod st_as_wkb;
 mod st_as_geojson;
+mod st_as_geojson_feature;
 mod st_as_ewkt;
+mod transcode;
 
+pub use geohash::{geohashes_covering, MAX_COVERING_CELLS, MAX_GEOHASH_PRECISION};
 pub use st_as_text::st_as_text;
 pub use st_as_wkb::st_as_wkb;
 pub use st_as_geojson::st_as_geojson;
+pub use st_as_geojson_feature::st_as_geojson_feature;
 pub use st_as_ewkt::st_as_ewkt;
+pub use transcode::transcode_geojson_to_wkb;
@@ -0,0 +1,34 @@
+use serde_json::Value;
+use surrealgis_core::geometry::SurrealGeometry;
+use surrealgis_core::serialization::geojson;
+
+use crate::FunctionError;
+
+/// Convert a geometry to a GeoJSON Feature string carrying `properties` and
+/// an optional `id`.
+pub fn st_as_geojson_feature(
+    geom: &SurrealGeometry,
+    properties: Value,
+    id: Option<Value>,
+) -> Result<String, FunctionError> {
+    let value = geojson::to_geojson_feature(geom, properties, id).map_err(FunctionError::from)?;
+    serde_json::to_string(&value).map_err(|e| FunctionError::InvalidArgument(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use surrealgis_core::srid::Srid;
+
+    #[test]
+    fn point_to_feature() {
+        let p = SurrealGeometry::point(-73.9857, 40.7484, Srid::WGS84).unwrap();
+        let json = st_as_geojson_feature(&p, json!({"name": "ESB"}), Some(json!("esb"))).unwrap();
+        let value: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["type"], "Feature");
+        assert_eq!(value["geometry"]["type"], "Point");
+        assert_eq!(value["properties"]["name"], "ESB");
+        assert_eq!(value["id"], "esb");
+    }
+}
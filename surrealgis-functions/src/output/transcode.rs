@@ -0,0 +1,47 @@
+use serde_json::Value;
+use surrealgis_core::error::GeometryError;
+use surrealgis_core::serialization::{geojson, wkb};
+
+/// Transcode a batch of GeoJSON geometry objects to WKB, for ETL pipelines
+/// loading GeoJSON into a WKB-backed store.
+///
+/// Each item succeeds or fails on its own: the result has one entry per
+/// input, in order, so a malformed record can be logged and skipped without
+/// losing the rest of the batch.
+pub fn transcode_geojson_to_wkb(values: &[Value]) -> Vec<Result<Vec<u8>, GeometryError>> {
+    values
+        .iter()
+        .map(|value| geojson::from_geojson(value).and_then(|geom| wkb::to_wkb(&geom)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn mixed_batch_keeps_per_item_results() {
+        let values = vec![
+            json!({"type": "Point", "coordinates": [1.0, 2.0]}),
+            json!({"type": "Point"}),
+            json!({"type": "LineString", "coordinates": [[0.0, 0.0], [1.0, 1.0]]}),
+            json!({"type": "Hexagon", "coordinates": []}),
+        ];
+        let results = transcode_geojson_to_wkb(&values);
+        assert_eq!(results.len(), 4);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+        assert!(results[2].is_ok());
+        assert!(matches!(results[3], Err(GeometryError::UnsupportedGeometryType(_))));
+
+        let line = wkb::from_wkb(results[2].as_ref().unwrap()).unwrap();
+        assert_eq!(line.type_name(), "LineString");
+        assert_eq!(line.num_points(), 2);
+    }
+
+    #[test]
+    fn empty_batch() {
+        assert!(transcode_geojson_to_wkb(&[]).is_empty());
+    }
+}
@@ -3,14 +3,16 @@ mod st_union;
 mod st_difference;
 mod st_sym_difference;
 
-pub use st_intersection::st_intersection;
-pub use st_union::st_union;
-pub use st_difference::st_difference;
-pub use st_sym_difference::st_sym_difference;
+pub use st_intersection::{st_intersection, st_intersection_with_options};
+pub use st_union::{st_union, st_union_with_options};
+pub use st_difference::{st_difference, st_difference_with_options};
+pub use st_sym_difference::{st_sym_difference, st_sym_difference_with_options};
 
-use geo_types::{Geometry as GeoGeometry, MultiPolygon};
+use geo::Area;
+use geo_types::{Coord, Geometry as GeoGeometry, LineString, MultiPolygon, Polygon};
 use surrealgis_core::geometry::SurrealGeometry;
 
+use crate::support::{supported_for, OpKind};
 use crate::FunctionError;
 
 /// Extract polygon operands from two SurrealGeometry values, converting
@@ -26,6 +28,107 @@ pub(crate) fn extract_polygon_operands(
     Ok((mp_a, mp_b))
 }
 
+/// Options shared by the overlay functions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OverlayOptions {
+    /// Snap-round both inputs and the result to a grid of `grid_size` so that
+    /// near-coincident edges become exactly coincident before the boolean op.
+    pub snap_rounded: bool,
+    /// Grid cell size used when `snap_rounded` is set, in the geometry's units.
+    pub grid_size: f64,
+}
+
+impl Default for OverlayOptions {
+    fn default() -> Self {
+        Self {
+            snap_rounded: false,
+            grid_size: 1e-9,
+        }
+    }
+}
+
+impl OverlayOptions {
+    /// Snap-rounding enabled on a grid of the given cell size.
+    pub fn snap_rounded(grid_size: f64) -> Self {
+        Self {
+            snap_rounded: true,
+            grid_size,
+        }
+    }
+}
+
+/// Run a boolean op on two polygon geometries, pre-noding the operands by
+/// snap-rounding when the options ask for it. Non-polygonal operands are
+/// rejected up front with an error naming `kind`.
+pub(crate) fn overlay_with<F>(
+    kind: OpKind,
+    a: &SurrealGeometry,
+    b: &SurrealGeometry,
+    options: &OverlayOptions,
+    op: F,
+) -> Result<SurrealGeometry, FunctionError>
+where
+    F: FnOnce(&MultiPolygon<f64>, &MultiPolygon<f64>) -> MultiPolygon<f64>,
+{
+    supported_for(kind, a)?;
+    supported_for(kind, b)?;
+    let (mut mp_a, mut mp_b) = extract_polygon_operands(a, b)?;
+    let result = if options.snap_rounded {
+        let size = options.grid_size;
+        if !size.is_finite() || size <= 0.0 {
+            return Err(FunctionError::InvalidArgument(
+                "Overlay grid size must be positive and finite".to_string(),
+            ));
+        }
+        mp_a = snap_multi_polygon(&mp_a, size);
+        mp_b = snap_multi_polygon(&mp_b, size);
+        // The op can introduce intersection vertices off the grid; snap them too
+        snap_multi_polygon(&op(&mp_a, &mp_b), size)
+    } else {
+        op(&mp_a, &mp_b)
+    };
+    let geo_geom = GeoGeometry::MultiPolygon(result);
+    SurrealGeometry::from_geo(&geo_geom, *a.srid()).map_err(FunctionError::from)
+}
+
+/// Snap every vertex to the grid, dropping repeated vertices and any ring or
+/// polygon that collapses to zero area.
+fn snap_multi_polygon(mp: &MultiPolygon<f64>, size: f64) -> MultiPolygon<f64> {
+    let polygons = mp
+        .0
+        .iter()
+        .filter_map(|poly| {
+            let exterior = snap_ring(poly.exterior(), size)?;
+            let holes = poly
+                .interiors()
+                .iter()
+                .filter_map(|ring| snap_ring(ring, size))
+                .collect();
+            Some(Polygon::new(exterior, holes))
+        })
+        .collect();
+    MultiPolygon(polygons)
+}
+
+fn snap_ring(ring: &LineString<f64>, size: f64) -> Option<LineString<f64>> {
+    let mut coords: Vec<Coord<f64>> = Vec::with_capacity(ring.0.len());
+    for c in &ring.0 {
+        let snapped = Coord {
+            x: (c.x / size).round() * size,
+            y: (c.y / size).round() * size,
+        };
+        if coords.last() != Some(&snapped) {
+            coords.push(snapped);
+        }
+    }
+    if coords.len() < 4 {
+        return None;
+    }
+    let ring = LineString::from(coords);
+    let area = Polygon::new(ring.clone(), vec![]).unsigned_area();
+    (area > 0.0).then_some(ring)
+}
+
 fn to_multi_polygon(g: GeoGeometry<f64>) -> Result<MultiPolygon<f64>, FunctionError> {
     match g {
         GeoGeometry::Polygon(p) => Ok(MultiPolygon(vec![p])),
@@ -35,3 +138,107 @@ fn to_multi_polygon(g: GeoGeometry<f64>) -> Result<MultiPolygon<f64>, FunctionEr
         )),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use surrealgis_core::coordinate::Coordinate;
+    use surrealgis_core::srid::Srid;
+
+    fn rect_polygon(x1: f64, y1: f64, x2: f64, y2: f64) -> SurrealGeometry {
+        let exterior = vec![
+            Coordinate::new(x1, y1).unwrap(),
+            Coordinate::new(x2, y1).unwrap(),
+            Coordinate::new(x2, y2).unwrap(),
+            Coordinate::new(x1, y2).unwrap(),
+            Coordinate::new(x1, y1).unwrap(),
+        ];
+        SurrealGeometry::polygon(exterior, vec![], Srid::WEB_MERCATOR).unwrap()
+    }
+
+    fn polygon_count(geom: &SurrealGeometry) -> usize {
+        match geom.to_geo().unwrap() {
+            GeoGeometry::MultiPolygon(mp) => mp.0.len(),
+            GeoGeometry::Polygon(_) => 1,
+            other => panic!("Expected polygonal result, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn near_coincident_edge_leaves_sliver_without_snapping() {
+        // b's left edge sits 1e-6 right of a's right edge
+        let a = rect_polygon(0.0, 0.0, 1.0, 1.0);
+        let b = rect_polygon(1.0 + 1e-6, 0.0, 2.0, 1.0);
+        let union = st_union(&a, &b).unwrap();
+        assert_eq!(polygon_count(&union), 2);
+
+        let c = rect_polygon(1.0 - 1e-6, 0.0, 2.0, 1.0);
+        let overlap = st_intersection(&a, &c).unwrap();
+        let sliver = overlap.to_geo().unwrap().unsigned_area();
+        assert!(sliver > 0.0 && sliver < 1e-5, "sliver area was {sliver}");
+    }
+
+    #[test]
+    fn snap_rounding_removes_slivers() {
+        let options = OverlayOptions::snap_rounded(1e-4);
+        let a = rect_polygon(0.0, 0.0, 1.0, 1.0);
+
+        let b = rect_polygon(1.0 + 1e-6, 0.0, 2.0, 1.0);
+        let union = st_union_with_options(&a, &b, &options).unwrap();
+        assert_eq!(polygon_count(&union), 1);
+        let area = union.to_geo().unwrap().unsigned_area();
+        assert!((area - 2.0).abs() < 1e-9, "area was {area}");
+
+        let c = rect_polygon(1.0 - 1e-6, 0.0, 2.0, 1.0);
+        let overlap = st_intersection_with_options(&a, &c, &options).unwrap();
+        assert_eq!(polygon_count(&overlap), 0);
+
+        let diff = st_difference_with_options(&a, &c, &options).unwrap();
+        let area = diff.to_geo().unwrap().unsigned_area();
+        assert!((area - 1.0).abs() < 1e-9, "area was {area}");
+    }
+
+    #[test]
+    fn default_options_match_plain_functions() {
+        let a = rect_polygon(0.0, 0.0, 2.0, 2.0);
+        let b = rect_polygon(1.0, 1.0, 3.0, 3.0);
+        let plain = st_union(&a, &b).unwrap();
+        let with = st_union_with_options(&a, &b, &OverlayOptions::default()).unwrap();
+        assert_eq!(plain, with);
+    }
+
+    #[test]
+    fn line_operands_fail_pre_check() {
+        let line = SurrealGeometry::line_string(
+            vec![Coordinate::new(0.0, 0.0).unwrap(), Coordinate::new(1.0, 1.0).unwrap()],
+            Srid::WEB_MERCATOR,
+        )
+        .unwrap();
+        let poly = rect_polygon(0.0, 0.0, 1.0, 1.0);
+
+        let err = st_union(&line, &line).unwrap_err();
+        assert_eq!(
+            err,
+            FunctionError::TypeMismatch {
+                function: "st_union",
+                expected: vec!["Polygon", "MultiPolygon"],
+                got: "LineString".to_string(),
+            }
+        );
+        // The second operand is checked too
+        let err = st_difference(&poly, &line).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Type mismatch: st_difference requires Polygon or MultiPolygon input, got LineString"
+        );
+    }
+
+    #[test]
+    fn invalid_grid_size_rejected() {
+        let a = rect_polygon(0.0, 0.0, 1.0, 1.0);
+        for size in [0.0, -1.0, f64::NAN] {
+            let result = st_union_with_options(&a, &a, &OverlayOptions::snap_rounded(size));
+            assert!(matches!(result, Err(FunctionError::InvalidArgument(_))));
+        }
+    }
+}
@@ -1,18 +1,28 @@
 use geo::BooleanOps;
 use surrealgis_core::geometry::SurrealGeometry;
 
+use crate::support::OpKind;
 use crate::FunctionError;
 
+use super::OverlayOptions;
+
 /// Compute the geometric difference of two polygon geometries.
 /// Returns the area of the first geometry that does not overlap with the second.
 pub fn st_difference(
     a: &SurrealGeometry,
     b: &SurrealGeometry,
 ) -> Result<SurrealGeometry, FunctionError> {
-    let (mp_a, mp_b) = super::extract_polygon_operands(a, b)?;
-    let result = mp_a.difference(&mp_b);
-    let geo_geom = geo_types::Geometry::MultiPolygon(result);
-    SurrealGeometry::from_geo(&geo_geom, *a.srid()).map_err(FunctionError::from)
+    st_difference_with_options(a, b, &OverlayOptions::default())
+}
+
+/// Like [`st_difference`], optionally snap-rounding the inputs first to avoid
+/// slivers from near-coincident edges.
+pub fn st_difference_with_options(
+    a: &SurrealGeometry,
+    b: &SurrealGeometry,
+    options: &OverlayOptions,
+) -> Result<SurrealGeometry, FunctionError> {
+    super::overlay_with(OpKind::Difference, a, b, options, |mp_a, mp_b| mp_a.difference(mp_b))
 }
 
 #[cfg(test)]
@@ -72,7 +82,7 @@ mod tests {
         let b = rect_polygon(0.0, 0.0, 2.0, 2.0, Srid::WEB_MERCATOR);
         let result = st_difference(&a, &b);
         assert!(result.is_err());
-        assert!(matches!(result.unwrap_err(), FunctionError::UnsupportedOperation(_)));
+        assert!(matches!(result.unwrap_err(), FunctionError::TypeMismatch { .. }));
     }
 
     #[test]
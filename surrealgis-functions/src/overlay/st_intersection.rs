@@ -1,18 +1,28 @@
 use geo::BooleanOps;
 use surrealgis_core::geometry::SurrealGeometry;
 
+use crate::support::OpKind;
 use crate::FunctionError;
 
+use super::OverlayOptions;
+
 /// Compute the geometric intersection of two polygon geometries.
 /// Returns the area shared by both input geometries.
 pub fn st_intersection(
     a: &SurrealGeometry,
     b: &SurrealGeometry,
 ) -> Result<SurrealGeometry, FunctionError> {
-    let (mp_a, mp_b) = super::extract_polygon_operands(a, b)?;
-    let result = mp_a.intersection(&mp_b);
-    let geo_geom = geo_types::Geometry::MultiPolygon(result);
-    SurrealGeometry::from_geo(&geo_geom, *a.srid()).map_err(FunctionError::from)
+    st_intersection_with_options(a, b, &OverlayOptions::default())
+}
+
+/// Like [`st_intersection`], optionally snap-rounding the inputs first to avoid
+/// slivers from near-coincident edges.
+pub fn st_intersection_with_options(
+    a: &SurrealGeometry,
+    b: &SurrealGeometry,
+    options: &OverlayOptions,
+) -> Result<SurrealGeometry, FunctionError> {
+    super::overlay_with(OpKind::Intersection, a, b, options, |mp_a, mp_b| mp_a.intersection(mp_b))
 }
 
 #[cfg(test)]
@@ -70,7 +80,7 @@ mod tests {
         let b = rect_polygon(0.0, 0.0, 2.0, 2.0, Srid::WEB_MERCATOR);
         let result = st_intersection(&a, &b);
         assert!(result.is_err());
-        assert!(matches!(result.unwrap_err(), FunctionError::UnsupportedOperation(_)));
+        assert!(matches!(result.unwrap_err(), FunctionError::TypeMismatch { .. }));
     }
 
     #[test]
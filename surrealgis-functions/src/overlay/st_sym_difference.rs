@@ -1,8 +1,11 @@
 use geo::BooleanOps;
 use surrealgis_core::geometry::SurrealGeometry;
 
+use crate::support::OpKind;
 use crate::FunctionError;
 
+use super::OverlayOptions;
+
 /// Compute the symmetric difference of two polygon geometries.
 /// Returns the areas that belong to exactly one of the input geometries
 /// (i.e., the union minus the intersection).
@@ -10,10 +13,17 @@ pub fn st_sym_difference(
     a: &SurrealGeometry,
     b: &SurrealGeometry,
 ) -> Result<SurrealGeometry, FunctionError> {
-    let (mp_a, mp_b) = super::extract_polygon_operands(a, b)?;
-    let result = mp_a.xor(&mp_b);
-    let geo_geom = geo_types::Geometry::MultiPolygon(result);
-    SurrealGeometry::from_geo(&geo_geom, *a.srid()).map_err(FunctionError::from)
+    st_sym_difference_with_options(a, b, &OverlayOptions::default())
+}
+
+/// Like [`st_sym_difference`], optionally snap-rounding the inputs first to avoid
+/// slivers from near-coincident edges.
+pub fn st_sym_difference_with_options(
+    a: &SurrealGeometry,
+    b: &SurrealGeometry,
+    options: &OverlayOptions,
+) -> Result<SurrealGeometry, FunctionError> {
+    super::overlay_with(OpKind::SymDifference, a, b, options, |mp_a, mp_b| mp_a.xor(mp_b))
 }
 
 #[cfg(test)]
@@ -73,7 +83,7 @@ mod tests {
         let b = rect_polygon(0.0, 0.0, 2.0, 2.0, Srid::WEB_MERCATOR);
         let result = st_sym_difference(&a, &b);
         assert!(result.is_err());
-        assert!(matches!(result.unwrap_err(), FunctionError::UnsupportedOperation(_)));
+        assert!(matches!(result.unwrap_err(), FunctionError::TypeMismatch { .. }));
     }
 
     #[test]
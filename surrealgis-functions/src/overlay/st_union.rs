@@ -1,18 +1,28 @@
 use geo::BooleanOps;
 use surrealgis_core::geometry::SurrealGeometry;
 
+use crate::support::OpKind;
 use crate::FunctionError;
 
+use super::OverlayOptions;
+
 /// Compute the geometric union of two polygon geometries.
 /// Returns the combined area of both input geometries.
 pub fn st_union(
     a: &SurrealGeometry,
     b: &SurrealGeometry,
 ) -> Result<SurrealGeometry, FunctionError> {
-    let (mp_a, mp_b) = super::extract_polygon_operands(a, b)?;
-    let result = mp_a.union(&mp_b);
-    let geo_geom = geo_types::Geometry::MultiPolygon(result);
-    SurrealGeometry::from_geo(&geo_geom, *a.srid()).map_err(FunctionError::from)
+    st_union_with_options(a, b, &OverlayOptions::default())
+}
+
+/// Like [`st_union`], optionally snap-rounding the inputs first to avoid
+/// slivers from near-coincident edges.
+pub fn st_union_with_options(
+    a: &SurrealGeometry,
+    b: &SurrealGeometry,
+    options: &OverlayOptions,
+) -> Result<SurrealGeometry, FunctionError> {
+    super::overlay_with(OpKind::Union, a, b, options, |mp_a, mp_b| mp_a.union(mp_b))
 }
 
 #[cfg(test)]
@@ -68,9 +78,14 @@ mod tests {
     fn rejects_point_input() {
         let a = SurrealGeometry::point(1.0, 2.0, Srid::WEB_MERCATOR).unwrap();
         let b = rect_polygon(0.0, 0.0, 2.0, 2.0, Srid::WEB_MERCATOR);
-        let result = st_union(&a, &b);
-        assert!(result.is_err());
-        assert!(matches!(result.unwrap_err(), FunctionError::UnsupportedOperation(_)));
+        match st_union(&a, &b) {
+            Err(FunctionError::TypeMismatch { function, expected, got }) => {
+                assert_eq!(function, "st_union");
+                assert_eq!(expected, vec!["Polygon", "MultiPolygon"]);
+                assert_eq!(got, "Point");
+            }
+            other => panic!("Expected TypeMismatch, got {other:?}"),
+        }
     }
 
     #[test]
@@ -1,15 +1,33 @@
 mod st_buffer;
 mod st_convex_hull;
 mod st_concave_hull;
+mod st_alpha_shape;
 mod st_simplify;
 mod st_simplify_preserve_topology;
+mod st_simplify_min_size;
 mod st_delaunay_triangles;
 mod st_voronoi_polygons;
+mod st_polygonize;
+mod st_subdivide;
+mod st_split;
+mod st_offset_curve;
+mod st_oriented_envelope;
+mod st_triangulate_polygon;
+mod st_minimum_bounding_circle;
 
-pub use st_buffer::st_buffer;
+pub use st_buffer::{st_buffer, st_buffer_mitre, DEFAULT_MITRE_LIMIT};
 pub use st_convex_hull::st_convex_hull;
 pub use st_concave_hull::st_concave_hull;
+pub use st_alpha_shape::st_alpha_shape;
 pub use st_simplify::st_simplify;
 pub use st_simplify_preserve_topology::st_simplify_preserve_topology;
+pub use st_simplify_min_size::st_simplify_min_size;
 pub use st_delaunay_triangles::st_delaunay_triangles;
 pub use st_voronoi_polygons::st_voronoi_polygons;
+pub use st_polygonize::{st_polygonize, st_polygonize_full, PolygonizeResult};
+pub use st_subdivide::{st_subdivide, st_subdivide_by_area};
+pub use st_split::st_split;
+pub use st_offset_curve::{st_offset_curve, JoinStyle};
+pub use st_oriented_envelope::st_oriented_envelope;
+pub use st_triangulate_polygon::st_triangulate_polygon;
+pub use st_minimum_bounding_circle::{st_minimum_bounding_circle, st_minimum_bounding_radius};
@@ -0,0 +1,160 @@
+use geo::{BooleanOps, CoordsIter, TriangulateDelaunay};
+use geo_types::{Coord, Geometry, LineString, MultiPolygon, Triangle};
+use surrealgis_core::geometry::SurrealGeometry;
+
+use crate::FunctionError;
+
+/// Compute the alpha shape of the input's vertices.
+///
+/// The vertices are Delaunay-triangulated and every triangle whose
+/// circumradius exceeds `alpha` is discarded; the union of the remaining
+/// triangles is the shape. A large `alpha` keeps every triangle and yields
+/// the convex hull, while smaller values carve into concavities and can
+/// split the shape into several parts. Unlike
+/// [`st_concave_hull`](super::st_concave_hull), the tightness is set directly
+/// by `alpha`, in the geometry's units.
+/// Returns a Polygon, or a MultiPolygon when the shape has several parts.
+pub fn st_alpha_shape(
+    geom: &SurrealGeometry,
+    alpha: f64,
+) -> Result<SurrealGeometry, FunctionError> {
+    if !alpha.is_finite() || alpha <= 0.0 {
+        return Err(FunctionError::InvalidArgument(format!(
+            "st_alpha_shape alpha must be positive, got {alpha}"
+        )));
+    }
+
+    let vertices: Vec<Coord<f64>> = geom.to_geo()?.coords_iter().collect();
+    if vertices.len() < 3 {
+        return Err(FunctionError::InvalidArgument(
+            "st_alpha_shape requires at least 3 points".to_string(),
+        ));
+    }
+
+    // The unconstrained triangulation only reads the vertices, so carrying
+    // them in a LineString (MultiPoint has no lines to offer) is enough
+    let triangles = LineString::new(vertices)
+        .unconstrained_triangulation()
+        .map_err(|e| FunctionError::InvalidArgument(format!("Triangulation failed: {e:?}")))?;
+    let kept: Vec<MultiPolygon<f64>> = triangles
+        .iter()
+        .filter(|tri| circumradius(tri) <= alpha)
+        .map(|tri| MultiPolygon(vec![tri.to_polygon()]))
+        .collect();
+    if kept.is_empty() {
+        return Err(FunctionError::InvalidArgument(format!(
+            "st_alpha_shape alpha {alpha} is smaller than every triangle's circumradius"
+        )));
+    }
+
+    let shape = union_all(kept);
+    let result = if shape.0.len() == 1 {
+        Geometry::Polygon(shape.0.into_iter().next().unwrap())
+    } else {
+        Geometry::MultiPolygon(shape)
+    };
+    SurrealGeometry::from_geo(&result, *geom.srid()).map_err(FunctionError::from)
+}
+
+/// Circumradius `abc / 4K`; infinite for a degenerate triangle.
+fn circumradius(tri: &Triangle<f64>) -> f64 {
+    let [p, q, r] = tri.to_array();
+    let a = (q - r).x.hypot((q - r).y);
+    let b = (p - r).x.hypot((p - r).y);
+    let c = (p - q).x.hypot((p - q).y);
+    let twice_area = ((q.x - p.x) * (r.y - p.y) - (r.x - p.x) * (q.y - p.y)).abs();
+    if twice_area == 0.0 {
+        return f64::INFINITY;
+    }
+    a * b * c / (2.0 * twice_area)
+}
+
+/// Union pairwise in rounds so each boolean op works on similarly sized
+/// operands, rather than growing one ever-larger accumulator.
+fn union_all(mut parts: Vec<MultiPolygon<f64>>) -> MultiPolygon<f64> {
+    while parts.len() > 1 {
+        let mut next = Vec::with_capacity(parts.len().div_ceil(2));
+        let mut iter = parts.into_iter();
+        while let Some(a) = iter.next() {
+            match iter.next() {
+                Some(b) => next.push(a.union(&b)),
+                None => next.push(a),
+            }
+        }
+        parts = next;
+    }
+    parts.pop().unwrap_or_else(|| MultiPolygon(vec![]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geo::Area;
+    use surrealgis_core::coordinate::Coordinate;
+    use surrealgis_core::geometry::GeometryType;
+    use surrealgis_core::srid::Srid;
+
+    /// Unit-spaced grid points covering `[x0, x0 + n] x [y0, y0 + n]`.
+    fn grid(x0: f64, y0: f64, n: usize) -> Vec<Coordinate> {
+        (0..=n)
+            .flat_map(|i| (0..=n).map(move |j| (i, j)))
+            .map(|(i, j)| Coordinate::new(x0 + i as f64, y0 + j as f64).unwrap())
+            .collect()
+    }
+
+    fn two_clusters() -> SurrealGeometry {
+        let mut coords = grid(0.0, 0.0, 3);
+        coords.extend(grid(10.0, 0.0, 3));
+        SurrealGeometry::multi_point(coords, Srid::WEB_MERCATOR).unwrap()
+    }
+
+    #[test]
+    fn large_alpha_gives_convex_hull() {
+        let shape = st_alpha_shape(&two_clusters(), 1000.0).unwrap();
+        assert!(matches!(shape.geometry_type(), GeometryType::Polygon { .. }));
+        // Hull of [0, 13] x [0, 3]
+        let area = shape.to_geo().unwrap().unsigned_area();
+        assert!((area - 39.0).abs() < 1e-9, "area was {area}");
+    }
+
+    #[test]
+    fn small_alpha_splits_clusters() {
+        // Grid triangles have circumradius sqrt(2)/2; the bridging ones are far larger
+        let shape = st_alpha_shape(&two_clusters(), 1.0).unwrap();
+        match shape.geometry_type() {
+            GeometryType::MultiPolygon(parts) => assert_eq!(parts.len(), 2),
+            other => panic!("Expected MultiPolygon, got {other:?}"),
+        }
+        let area = shape.to_geo().unwrap().unsigned_area();
+        assert!((area - 18.0).abs() < 1e-9, "area was {area}");
+    }
+
+    #[test]
+    fn small_alpha_carves_concavity() {
+        // L-shaped block of grid points: [0, 4] x [0, 1] plus [0, 1] x [0, 4]
+        let coords = grid(0.0, 0.0, 4)
+            .into_iter()
+            .filter(|c| c.x() <= 1.0 || c.y() <= 1.0)
+            .collect();
+        let l_shape = SurrealGeometry::multi_point(coords, Srid::WEB_MERCATOR).unwrap();
+        let shape = st_alpha_shape(&l_shape, 1.0).unwrap();
+        assert!(matches!(shape.geometry_type(), GeometryType::Polygon { .. }));
+        // The L covers 7; its convex hull would cover 11.5
+        let area = shape.to_geo().unwrap().unsigned_area();
+        assert!((7.0..8.0).contains(&area), "area was {area}");
+    }
+
+    #[test]
+    fn rejects_bad_alpha_and_too_few_points() {
+        for alpha in [0.0, -1.0, f64::NAN] {
+            assert!(matches!(
+                st_alpha_shape(&two_clusters(), alpha),
+                Err(FunctionError::InvalidArgument(_))
+            ));
+        }
+        let single = SurrealGeometry::multi_point(grid(0.0, 0.0, 0), Srid::WEB_MERCATOR).unwrap();
+        assert!(st_alpha_shape(&single, 1.0).is_err());
+        // Nothing survives an alpha below the smallest circumradius
+        assert!(st_alpha_shape(&two_clusters(), 0.1).is_err());
+    }
+}
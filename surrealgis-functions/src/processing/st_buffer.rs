@@ -1,20 +1,27 @@
 use std::f64::consts::PI;
 
+use geo::BooleanOps;
+use geo_types::{Coord, LineString, MultiPolygon, Polygon};
 use surrealgis_core::geometry::SurrealGeometry;
 
+use crate::support::{supported_for, OpKind};
 use crate::FunctionError;
 
 const BUFFER_SEGMENTS: usize = 64;
 
+/// Default mitre limit used by JTS and PostGIS (`mitre_limit=5.0`).
+pub const DEFAULT_MITRE_LIMIT: f64 = 5.0;
+
 /// Create a buffer around a geometry at a given distance.
 /// Currently only supports Point geometry (creates a circle polygon approximation).
-/// For other geometry types, returns UnsupportedOperation.
+/// For other geometry types, returns TypeMismatch.
 pub fn st_buffer(geom: &SurrealGeometry, distance: f64) -> Result<SurrealGeometry, FunctionError> {
     if distance < 0.0 {
         return Err(FunctionError::InvalidArgument(
             "st_buffer distance must be non-negative".to_string(),
         ));
     }
+    supported_for(OpKind::Buffer, geom)?;
 
     let geo_geom = geom.to_geo()?;
 
@@ -24,14 +31,135 @@ pub fn st_buffer(geom: &SurrealGeometry, distance: f64) -> Result<SurrealGeometr
             let result = geo_types::Geometry::Polygon(circle);
             SurrealGeometry::from_geo(&result, *geom.srid()).map_err(FunctionError::from)
         }
-        _ => Err(FunctionError::UnsupportedOperation(
-            "st_buffer currently only supports Point geometry".to_string(),
-        )),
+        _ => Err(OpKind::Buffer.mismatch(geom)),
     }
 }
 
+/// Buffer a LineString using mitre joins, capping sharp corners with `mitre_limit`.
+///
+/// The limit is the ratio of the mitre length to the buffer distance, as in
+/// JTS and PostGIS. A join whose mitre would reach further than
+/// `mitre_limit * distance` from its vertex is beveled instead, so acute
+/// angles do not grow long spikes. Line ends use round caps. Points fall back
+/// to the circular buffer of [`st_buffer`].
+pub fn st_buffer_mitre(
+    geom: &SurrealGeometry,
+    distance: f64,
+    mitre_limit: f64,
+) -> Result<SurrealGeometry, FunctionError> {
+    if !mitre_limit.is_finite() || mitre_limit < 1.0 {
+        return Err(FunctionError::InvalidArgument(format!(
+            "st_buffer_mitre mitre_limit must be at least 1.0, got {mitre_limit}"
+        )));
+    }
+    supported_for(OpKind::BufferMitre, geom)?;
+
+    let geo_geom = geom.to_geo()?;
+
+    match &geo_geom {
+        geo_types::Geometry::Point(_) => st_buffer(geom, distance),
+        geo_types::Geometry::LineString(line) => {
+            if distance <= 0.0 {
+                return Err(FunctionError::InvalidArgument(
+                    "st_buffer_mitre distance must be positive for a LineString".to_string(),
+                ));
+            }
+            let buffered = line_buffer_mitre(line, distance, mitre_limit)?;
+            let result = if buffered.0.len() == 1 {
+                geo_types::Geometry::Polygon(buffered.0.into_iter().next().unwrap())
+            } else {
+                geo_types::Geometry::MultiPolygon(buffered)
+            };
+            SurrealGeometry::from_geo(&result, *geom.srid()).map_err(FunctionError::from)
+        }
+        _ => Err(OpKind::BufferMitre.mismatch(geom)),
+    }
+}
+
+/// Union of per-segment rectangles, mitre (or bevel) joins and round end caps.
+fn line_buffer_mitre(
+    line: &LineString<f64>,
+    distance: f64,
+    mitre_limit: f64,
+) -> Result<MultiPolygon<f64>, FunctionError> {
+    let mut coords: Vec<Coord<f64>> = Vec::with_capacity(line.0.len());
+    for c in &line.0 {
+        if coords.last() != Some(c) {
+            coords.push(*c);
+        }
+    }
+    if coords.len() < 2 {
+        return Err(FunctionError::InvalidArgument(
+            "st_buffer_mitre requires a LineString with at least two distinct points".to_string(),
+        ));
+    }
+
+    let mut pieces: Vec<Polygon<f64>> = Vec::new();
+    let normals: Vec<Coord<f64>> = coords
+        .windows(2)
+        .map(|w| {
+            let dx = w[1].x - w[0].x;
+            let dy = w[1].y - w[0].y;
+            let len = (dx * dx + dy * dy).sqrt();
+            Coord { x: -dy / len, y: dx / len }
+        })
+        .collect();
+
+    for (w, n) in coords.windows(2).zip(&normals) {
+        let off = Coord { x: n.x * distance, y: n.y * distance };
+        pieces.push(closed_polygon(vec![
+            w[0] + off,
+            w[1] + off,
+            w[1] - off,
+            w[0] - off,
+        ]));
+    }
+
+    for (i, v) in coords.iter().enumerate().skip(1).take(coords.len() - 2) {
+        let (n1, n2) = (normals[i - 1], normals[i]);
+        // Cross product of the segment directions equals that of the normals
+        let cross = n1.x * n2.y - n1.y * n2.x;
+        let dot = n1.x * n2.x + n1.y * n2.y;
+        if cross.abs() < f64::EPSILON && dot > 0.0 {
+            continue;
+        }
+        // The join fills the gap on the outside of the turn
+        let side = if cross > 0.0 { -1.0 } else { 1.0 };
+        let p1 = *v + Coord { x: side * n1.x * distance, y: side * n1.y * distance };
+        let p2 = *v + Coord { x: side * n2.x * distance, y: side * n2.y * distance };
+
+        // Mitre length over distance is 1 / cos(half the angle between normals)
+        let cos_half = ((1.0 + dot) / 2.0).max(0.0).sqrt();
+        if cos_half > 0.0 && 1.0 / cos_half <= mitre_limit {
+            let bx = n1.x + n2.x;
+            let by = n1.y + n2.y;
+            let blen = (bx * bx + by * by).sqrt();
+            let reach = distance / cos_half;
+            let tip = *v + Coord { x: side * bx / blen * reach, y: side * by / blen * reach };
+            pieces.push(closed_polygon(vec![*v, p1, tip, p2]));
+        } else {
+            pieces.push(closed_polygon(vec![*v, p1, p2]));
+        }
+    }
+
+    for end in [coords[0], coords[coords.len() - 1]] {
+        pieces.push(point_buffer_circle(end.x, end.y, distance, BUFFER_SEGMENTS));
+    }
+
+    let mut result = MultiPolygon(vec![pieces[0].clone()]);
+    for piece in &pieces[1..] {
+        result = result.union(&MultiPolygon(vec![piece.clone()]));
+    }
+    Ok(result)
+}
+
+fn closed_polygon(mut coords: Vec<Coord<f64>>) -> Polygon<f64> {
+    coords.push(coords[0]);
+    Polygon::new(LineString(coords), vec![])
+}
+
 /// Generate a circle polygon approximation centered at (cx, cy) with given radius and segments.
-fn point_buffer_circle(
+pub(super) fn point_buffer_circle(
     cx: f64,
     cy: f64,
     radius: f64,
@@ -109,7 +237,75 @@ mod tests {
         ];
         let ls = SurrealGeometry::line_string(coords, Srid::WEB_MERCATOR).unwrap();
         let result = st_buffer(&ls, 1.0);
-        assert!(matches!(result, Err(FunctionError::UnsupportedOperation(_))));
+        assert!(matches!(result, Err(FunctionError::TypeMismatch { .. })));
+    }
+
+    fn acute_polyline() -> SurrealGeometry {
+        let coords = vec![
+            surrealgis_core::coordinate::Coordinate::new(0.0, 0.0).unwrap(),
+            surrealgis_core::coordinate::Coordinate::new(10.0, 0.0).unwrap(),
+            surrealgis_core::coordinate::Coordinate::new(0.0, 1.0).unwrap(),
+        ];
+        SurrealGeometry::line_string(coords, Srid::WEB_MERCATOR).unwrap()
+    }
+
+    fn max_vertex_distance(buffer: &SurrealGeometry, line: &SurrealGeometry) -> f64 {
+        use geo::{Distance, Euclidean};
+        let line = match line.to_geo().unwrap() {
+            geo_types::Geometry::LineString(l) => l,
+            _ => panic!("Expected LineString"),
+        };
+        let polys = match buffer.to_geo().unwrap() {
+            geo_types::Geometry::Polygon(p) => vec![p],
+            geo_types::Geometry::MultiPolygon(mp) => mp.0,
+            _ => panic!("Expected polygonal buffer"),
+        };
+        polys
+            .iter()
+            .flat_map(|p| p.exterior().coords().copied().collect::<Vec<_>>())
+            .map(|c| Euclidean.distance(&geo_types::Point::from(c), &line))
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn mitre_limit_bevels_acute_angle() {
+        let line = acute_polyline();
+        let result = st_buffer_mitre(&line, 1.0, 2.0).unwrap();
+        let reach = max_vertex_distance(&result, &line);
+        assert!(reach <= 2.0 + 1e-9, "spike reaches {reach}, limit is 2.0");
+    }
+
+    #[test]
+    fn mitre_high_limit_keeps_spike() {
+        // Without the limit the mitre at (10,0) reaches roughly 20 units out
+        let line = acute_polyline();
+        let result = st_buffer_mitre(&line, 1.0, 100.0).unwrap();
+        assert!(max_vertex_distance(&result, &line) > 10.0);
+    }
+
+    #[test]
+    fn mitre_right_angle_reaches_corner() {
+        let coords = vec![
+            surrealgis_core::coordinate::Coordinate::new(0.0, 0.0).unwrap(),
+            surrealgis_core::coordinate::Coordinate::new(10.0, 0.0).unwrap(),
+            surrealgis_core::coordinate::Coordinate::new(10.0, 10.0).unwrap(),
+        ];
+        let line = SurrealGeometry::line_string(coords, Srid::WEB_MERCATOR).unwrap();
+        let result = st_buffer_mitre(&line, 1.0, DEFAULT_MITRE_LIMIT).unwrap();
+        let geo = result.to_geo().unwrap();
+        let geo_types::Geometry::Polygon(poly) = geo else {
+            panic!("Expected Polygon");
+        };
+        assert!(poly
+            .exterior()
+            .coords()
+            .any(|c| (c.x - 11.0).abs() < 1e-9 && (c.y + 1.0).abs() < 1e-9));
+    }
+
+    #[test]
+    fn mitre_limit_below_one_rejected() {
+        let result = st_buffer_mitre(&acute_polyline(), 1.0, 0.5);
+        assert!(matches!(result, Err(FunctionError::InvalidArgument(_))));
     }
 
     #[test]
@@ -0,0 +1,252 @@
+use geo::{ConvexHull, CoordsIter};
+use geo_types::{Coord, MultiPoint, Point};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use surrealgis_core::geometry::SurrealGeometry;
+
+use super::st_buffer::point_buffer_circle;
+use crate::FunctionError;
+
+/// Fixed shuffle seed so the same input always yields the same circle.
+const SHUFFLE_SEED: u64 = 0x5eed;
+
+/// Relative slack when testing whether a point lies inside a candidate circle.
+const CONTAINS_EPSILON: f64 = 1e-12;
+
+/// Compute the smallest circle enclosing every vertex of a geometry.
+/// Returns the center as a Point in the input's SRID, and the radius in the
+/// SRID's planar units. A single point has radius 0.
+///
+/// Only convex hull vertices can lie on the enclosing circle, so the input is
+/// reduced to its hull first; the circle is then found with the iterative,
+/// randomized-incremental form of Welzl's algorithm (expected linear time, no
+/// recursion), which keeps millions of input points cheap.
+pub fn st_minimum_bounding_radius(
+    geom: &SurrealGeometry,
+) -> Result<(SurrealGeometry, f64), FunctionError> {
+    let (center, radius) = minimum_circle(geom)?;
+    let point = SurrealGeometry::point(center.x, center.y, *geom.srid())?;
+    Ok((point, radius))
+}
+
+/// Polygon approximating the smallest enclosing circle of a geometry's
+/// vertices, with `num_segments` vertices on the circle. A single point
+/// (radius 0) is returned as that Point.
+pub fn st_minimum_bounding_circle(
+    geom: &SurrealGeometry,
+    num_segments: usize,
+) -> Result<SurrealGeometry, FunctionError> {
+    if num_segments < 3 {
+        return Err(FunctionError::InvalidArgument(format!(
+            "st_minimum_bounding_circle needs at least 3 segments, got {num_segments}"
+        )));
+    }
+    let (center, radius) = minimum_circle(geom)?;
+    let result = if radius == 0.0 {
+        geo_types::Geometry::Point(Point::from(center))
+    } else {
+        geo_types::Geometry::Polygon(point_buffer_circle(center.x, center.y, radius, num_segments))
+    };
+    SurrealGeometry::from_geo(&result, *geom.srid()).map_err(FunctionError::from)
+}
+
+fn minimum_circle(geom: &SurrealGeometry) -> Result<(Coord<f64>, f64), FunctionError> {
+    let coords: Vec<Coord<f64>> = geom.to_geo()?.coords_iter().collect();
+    if coords.is_empty() {
+        return Err(FunctionError::InvalidArgument(
+            "Minimum bounding circle requires a non-empty geometry".to_string(),
+        ));
+    }
+    Ok(enclosing_circle(hull_points(coords)))
+}
+
+/// Reduce a point set to the vertices of its convex hull.
+fn hull_points(coords: Vec<Coord<f64>>) -> Vec<Coord<f64>> {
+    if coords.len() <= 3 {
+        return coords;
+    }
+    let hull = MultiPoint(coords.into_iter().map(Point::from).collect()).convex_hull();
+    let mut ring = hull.exterior().0.clone();
+    if ring.len() > 1 && ring.first() == ring.last() {
+        ring.pop();
+    }
+    ring
+}
+
+/// Welzl's algorithm in its iterative form: shuffle, then grow the
+/// circle whenever a point falls outside, re-fitting with that point (and at
+/// most two more) on the boundary.
+fn enclosing_circle(mut points: Vec<Coord<f64>>) -> (Coord<f64>, f64) {
+    points.shuffle(&mut StdRng::seed_from_u64(SHUFFLE_SEED));
+
+    let mut circle = (points[0], 0.0);
+    for (i, &p) in points.iter().enumerate().skip(1) {
+        if contains(circle, p) {
+            continue;
+        }
+        circle = (p, 0.0);
+        for (j, &q) in points[..i].iter().enumerate() {
+            if contains(circle, q) {
+                continue;
+            }
+            circle = circle_from_two(p, q);
+            for &r in &points[..j] {
+                if !contains(circle, r) {
+                    circle = circle_from_three(p, q, r);
+                }
+            }
+        }
+    }
+    circle
+}
+
+fn contains((center, radius): (Coord<f64>, f64), p: Coord<f64>) -> bool {
+    dist(center, p) <= radius + CONTAINS_EPSILON * radius.max(1.0)
+}
+
+fn dist(a: Coord<f64>, b: Coord<f64>) -> f64 {
+    (a.x - b.x).hypot(a.y - b.y)
+}
+
+fn circle_from_two(a: Coord<f64>, b: Coord<f64>) -> (Coord<f64>, f64) {
+    let center = Coord { x: (a.x + b.x) / 2.0, y: (a.y + b.y) / 2.0 };
+    (center, dist(a, b) / 2.0)
+}
+
+/// Circumcircle of three points; collinear points fall back to the circle on
+/// the farthest pair.
+fn circle_from_three(a: Coord<f64>, b: Coord<f64>, c: Coord<f64>) -> (Coord<f64>, f64) {
+    let (bx, by) = (b.x - a.x, b.y - a.y);
+    let (cx, cy) = (c.x - a.x, c.y - a.y);
+    let d = 2.0 * (bx * cy - by * cx);
+    if d.abs() < f64::EPSILON * (bx.abs() + by.abs() + cx.abs() + cy.abs()).powi(2) {
+        return [circle_from_two(a, b), circle_from_two(a, c), circle_from_two(b, c)]
+            .into_iter()
+            .max_by(|p, q| p.1.total_cmp(&q.1))
+            .unwrap();
+    }
+    let b2 = bx * bx + by * by;
+    let c2 = cx * cx + cy * cy;
+    let ux = (cy * b2 - by * c2) / d;
+    let uy = (bx * c2 - cx * b2) / d;
+    let center = Coord { x: a.x + ux, y: a.y + uy };
+    (center, ux.hypot(uy))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+    use surrealgis_core::coordinate::Coordinate;
+    use surrealgis_core::geometry::GeometryType;
+    use surrealgis_core::srid::Srid;
+
+    fn center_xy(g: &SurrealGeometry) -> (f64, f64) {
+        match g.geometry_type() {
+            GeometryType::Point(c) => (c.x(), c.y()),
+            _ => panic!("Expected Point"),
+        }
+    }
+
+    /// Smallest circle through two or three hull points that contains them all.
+    fn brute_force(hull: &[Coord<f64>]) -> (Coord<f64>, f64) {
+        let n = hull.len();
+        let mut candidates = Vec::new();
+        for i in 0..n {
+            for j in i + 1..n {
+                candidates.push(circle_from_two(hull[i], hull[j]));
+                for k in j + 1..n {
+                    candidates.push(circle_from_three(hull[i], hull[j], hull[k]));
+                }
+            }
+        }
+        candidates
+            .into_iter()
+            .filter(|&c| hull.iter().all(|&p| contains(c, p)))
+            .min_by(|p, q| p.1.total_cmp(&q.1))
+            .unwrap()
+    }
+
+    #[test]
+    fn circle_of_100k_random_points() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let coords: Vec<Coordinate> = (0..100_000)
+            .map(|_| {
+                Coordinate::new(rng.gen_range(-1000.0..1000.0), rng.gen_range(-500.0..500.0))
+                    .unwrap()
+            })
+            .collect();
+        let raw: Vec<Coord<f64>> = coords.iter().map(|c| Coord { x: c.x(), y: c.y() }).collect();
+        let geom = SurrealGeometry::multi_point(coords, Srid::WEB_MERCATOR).unwrap();
+
+        let (center, radius) = st_minimum_bounding_radius(&geom).unwrap();
+        let (cx, cy) = center_xy(&center);
+        let c = Coord { x: cx, y: cy };
+        assert!(raw.iter().all(|&p| dist(c, p) <= radius * (1.0 + 1e-9)));
+
+        let (expected_center, expected_radius) = brute_force(&hull_points(raw));
+        assert!((radius - expected_radius).abs() < 1e-6);
+        assert!(dist(c, expected_center) < 1e-6);
+    }
+
+    #[test]
+    fn single_point_has_zero_radius() {
+        let p = SurrealGeometry::point(3.0, 4.0, Srid::WEB_MERCATOR).unwrap();
+        let (center, radius) = st_minimum_bounding_radius(&p).unwrap();
+        assert_eq!(radius, 0.0);
+        assert_eq!(center_xy(&center), (3.0, 4.0));
+        assert_eq!(st_minimum_bounding_circle(&p, 16).unwrap().type_name(), "Point");
+    }
+
+    #[test]
+    fn three_points_circle_is_tight() {
+        let pts = [(0.0, 0.0), (4.0, 0.0), (2.0, 3.0)];
+        let coords = pts.iter().map(|&(x, y)| Coordinate::new(x, y).unwrap()).collect();
+        let geom = SurrealGeometry::multi_point(coords, Srid::WEB_MERCATOR).unwrap();
+        let (center, radius) = st_minimum_bounding_radius(&geom).unwrap();
+        let (cx, cy) = center_xy(&center);
+        // An acute triangle's smallest circle is its circumcircle: every point on it
+        for (x, y) in pts {
+            assert!(((x - cx).hypot(y - cy) - radius).abs() < 1e-9);
+        }
+        assert!((cx - 2.0).abs() < 1e-9);
+        assert!((cy - 5.0 / 6.0).abs() < 1e-9);
+
+        // An obtuse triangle's circle sits on its longest side, the apex inside
+        let coords = [(0.0, 0.0), (10.0, 0.0), (5.0, 1.0)]
+            .iter()
+            .map(|&(x, y)| Coordinate::new(x, y).unwrap())
+            .collect();
+        let geom = SurrealGeometry::multi_point(coords, Srid::WEB_MERCATOR).unwrap();
+        let (center, radius) = st_minimum_bounding_radius(&geom).unwrap();
+        assert_eq!(center_xy(&center), (5.0, 0.0));
+        assert_eq!(radius, 5.0);
+    }
+
+    #[test]
+    fn collinear_points_use_farthest_pair() {
+        let coords = vec![
+            Coordinate::new(0.0, 0.0).unwrap(),
+            Coordinate::new(4.0, 0.0).unwrap(),
+            Coordinate::new(10.0, 0.0).unwrap(),
+        ];
+        let line = SurrealGeometry::line_string(coords, Srid::WEB_MERCATOR).unwrap();
+        let (center, radius) = st_minimum_bounding_radius(&line).unwrap();
+        assert_eq!(center_xy(&center), (5.0, 0.0));
+        assert_eq!(radius, 5.0);
+    }
+
+    #[test]
+    fn circle_polygon_has_requested_segments() {
+        let coords = vec![
+            Coordinate::new(0.0, 0.0).unwrap(),
+            Coordinate::new(2.0, 0.0).unwrap(),
+        ];
+        let line = SurrealGeometry::line_string(coords, Srid::WEB_MERCATOR).unwrap();
+        let circle = st_minimum_bounding_circle(&line, 32).unwrap();
+        assert_eq!(circle.type_name(), "Polygon");
+        assert_eq!(circle.num_points(), 33);
+        assert_eq!(circle.srid().code(), Srid::WEB_MERCATOR.code());
+    }
+}
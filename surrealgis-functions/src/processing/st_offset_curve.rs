@@ -0,0 +1,214 @@
+use std::f64::consts::PI;
+
+use geo_types::{Coord, Geometry, LineString};
+use surrealgis_core::geometry::SurrealGeometry;
+
+use super::DEFAULT_MITRE_LIMIT;
+use crate::FunctionError;
+
+/// Segments per full circle when approximating round joins, as in `st_buffer`.
+const ROUND_JOIN_SEGMENTS: usize = 64;
+
+/// How [`st_offset_curve`] fills the gap on the outside of a bend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinStyle {
+    /// An arc of radius `distance` around the vertex.
+    Round,
+    /// The offset segments extended until they meet, beveled when the point
+    /// would lie more than [`DEFAULT_MITRE_LIMIT`] times `distance` away.
+    Mitre,
+}
+
+/// Offset a LineString sideways by `distance`, as in PostGIS `ST_OffsetCurve`.
+///
+/// A positive distance offsets to the left of the line's direction, a
+/// negative one to the right. Each segment is shifted along its normal; on
+/// the inside of a bend the shifted segments are cut where they meet, and on
+/// the outside they are joined according to `join_style`. Loops formed where
+/// `distance` exceeds a short segment's length are not removed.
+/// Returns a LineString.
+pub fn st_offset_curve(
+    line: &SurrealGeometry,
+    distance: f64,
+    join_style: JoinStyle,
+) -> Result<SurrealGeometry, FunctionError> {
+    if !distance.is_finite() {
+        return Err(FunctionError::InvalidArgument(format!(
+            "st_offset_curve distance must be finite, got {distance}"
+        )));
+    }
+    let ls = match line.to_geo()? {
+        Geometry::LineString(ls) => ls,
+        _ => {
+            return Err(FunctionError::UnsupportedOperation(format!(
+                "st_offset_curve requires a LineString, got {}",
+                line.type_name()
+            )))
+        }
+    };
+
+    let mut coords: Vec<Coord<f64>> = Vec::with_capacity(ls.0.len());
+    for c in &ls.0 {
+        if coords.last() != Some(c) {
+            coords.push(*c);
+        }
+    }
+    if coords.len() < 2 {
+        return Err(FunctionError::InvalidArgument(
+            "st_offset_curve requires a LineString with at least two distinct points".to_string(),
+        ));
+    }
+
+    let offset = Geometry::LineString(offset_coords(&coords, distance, join_style));
+    SurrealGeometry::from_geo(&offset, *line.srid()).map_err(FunctionError::from)
+}
+
+fn offset_coords(coords: &[Coord<f64>], distance: f64, join_style: JoinStyle) -> LineString<f64> {
+    let normals: Vec<Coord<f64>> = coords
+        .windows(2)
+        .map(|w| {
+            let dx = w[1].x - w[0].x;
+            let dy = w[1].y - w[0].y;
+            let len = (dx * dx + dy * dy).sqrt();
+            Coord { x: -dy / len, y: dx / len }
+        })
+        .collect();
+    let shift = |n: Coord<f64>| Coord {
+        x: n.x * distance,
+        y: n.y * distance,
+    };
+
+    let mut out = vec![coords[0] + shift(normals[0])];
+    for (i, v) in coords.iter().enumerate().skip(1).take(coords.len() - 2) {
+        let (n1, n2) = (normals[i - 1], normals[i]);
+        let cross = n1.x * n2.y - n1.y * n2.x;
+        let dot = n1.x * n2.x + n1.y * n2.y;
+        if cross.abs() < f64::EPSILON && dot > 0.0 {
+            out.push(*v + shift(n1));
+            continue;
+        }
+
+        // A left turn (positive cross) puts the outside of the bend on the right
+        let outside = cross * distance < 0.0;
+        // Distance from the vertex to the mitre point, over |distance|
+        let cos_half = ((1.0 + dot) / 2.0).max(0.0).sqrt();
+        let mitre_fits =
+            cos_half > f64::EPSILON && (!outside || 1.0 / cos_half <= DEFAULT_MITRE_LIMIT);
+
+        if outside && join_style == JoinStyle::Round {
+            push_arc(&mut out, *v, shift(n1), shift(n2), distance);
+        } else if mitre_fits {
+            let bx = n1.x + n2.x;
+            let by = n1.y + n2.y;
+            let blen = (bx * bx + by * by).sqrt();
+            let reach = distance / cos_half;
+            out.push(*v + Coord {
+                x: bx / blen * reach,
+                y: by / blen * reach,
+            });
+        } else {
+            out.push(*v + shift(n1));
+            out.push(*v + shift(n2));
+        }
+    }
+    out.push(coords[coords.len() - 1] + shift(normals[normals.len() - 1]));
+    LineString(out)
+}
+
+/// Arc around `center` from `center + from` to `center + to`, sweeping the
+/// way a line offset by `distance` turns.
+fn push_arc(
+    out: &mut Vec<Coord<f64>>,
+    center: Coord<f64>,
+    from: Coord<f64>,
+    to: Coord<f64>,
+    distance: f64,
+) {
+    let start = from.y.atan2(from.x);
+    let mut sweep = to.y.atan2(to.x) - start;
+    // Offsetting left turns clockwise round an outside bend, right counter-clockwise
+    if distance > 0.0 && sweep > 0.0 {
+        sweep -= 2.0 * PI;
+    } else if distance < 0.0 && sweep < 0.0 {
+        sweep += 2.0 * PI;
+    }
+    let radius = distance.abs();
+    let steps = ((sweep.abs() / (2.0 * PI) * ROUND_JOIN_SEGMENTS as f64).ceil() as usize).max(1);
+    for k in 0..=steps {
+        let angle = start + sweep * k as f64 / steps as f64;
+        out.push(center + Coord {
+            x: radius * angle.cos(),
+            y: radius * angle.sin(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::line;
+    use surrealgis_core::geometry::GeometryType;
+    use surrealgis_core::srid::Srid;
+
+    fn xy(geom: &SurrealGeometry) -> Vec<(f64, f64)> {
+        match geom.geometry_type() {
+            GeometryType::LineString(coords) => coords.iter().map(|c| (c.x(), c.y())).collect(),
+            other => panic!("Expected LineString, got {other:?}"),
+        }
+    }
+
+    fn assert_close(actual: (f64, f64), expected: (f64, f64)) {
+        assert!(
+            (actual.0 - expected.0).abs() < 1e-9 && (actual.1 - expected.1).abs() < 1e-9,
+            "expected {expected:?}, got {actual:?}"
+        );
+    }
+
+    #[test]
+    fn straight_line_shifts_up_by_distance() {
+        let l = line(&[(0.0, 0.0), (5.0, 0.0), (10.0, 0.0)]);
+        let result = st_offset_curve(&l, 2.5, JoinStyle::Round).unwrap();
+        let pts = xy(&result);
+        assert_eq!(pts.len(), 3);
+        for (p, x) in pts.iter().zip([0.0, 5.0, 10.0]) {
+            assert_close(*p, (x, 2.5));
+        }
+        // Negative distances go to the right
+        let right = st_offset_curve(&l, -1.0, JoinStyle::Mitre).unwrap();
+        assert!(xy(&right).iter().all(|&(_, y)| (y + 1.0).abs() < 1e-9));
+    }
+
+    #[test]
+    fn mitre_join_meets_at_corner() {
+        // A left turn: the right-hand offset is on the outside
+        let l = line(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0)]);
+        let outer = xy(&st_offset_curve(&l, -1.0, JoinStyle::Mitre).unwrap());
+        assert_eq!(outer.len(), 3);
+        assert_close(outer[1], (11.0, -1.0));
+        // The inside offsets meet where the shifted segments cross
+        let inner = xy(&st_offset_curve(&l, 1.0, JoinStyle::Round).unwrap());
+        assert_eq!(inner.len(), 3);
+        assert_close(inner[1], (9.0, 1.0));
+    }
+
+    #[test]
+    fn round_join_stays_at_distance_from_vertex() {
+        let l = line(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0)]);
+        let pts = xy(&st_offset_curve(&l, -1.0, JoinStyle::Round).unwrap());
+        assert!(pts.len() > 4);
+        assert_close(pts[1], (10.0, -1.0));
+        assert_close(pts[pts.len() - 2], (11.0, 0.0));
+        for &(x, y) in &pts[1..pts.len() - 1] {
+            assert!(((x - 10.0).hypot(y) - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn non_linestring_is_unsupported() {
+        let pt = SurrealGeometry::point(1.0, 1.0, Srid::WEB_MERCATOR).unwrap();
+        assert!(matches!(
+            st_offset_curve(&pt, 1.0, JoinStyle::Round),
+            Err(FunctionError::UnsupportedOperation(_))
+        ));
+    }
+}
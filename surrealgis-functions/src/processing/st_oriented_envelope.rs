@@ -0,0 +1,164 @@
+use geo::ConvexHull;
+use geo_types::{Coord, Geometry, LineString, Point, Polygon};
+use surrealgis_core::geometry::SurrealGeometry;
+
+use crate::FunctionError;
+
+/// Compute the minimum-area rotated rectangle enclosing a geometry, as in
+/// PostGIS `ST_OrientedEnvelope`.
+///
+/// The smallest enclosing rectangle always has a side on an edge of the
+/// convex hull, so rotating calipers walk the hull's edges, tracking the
+/// extreme vertices along and across each edge, and keep the smallest
+/// rectangle found. Returns a 5-point Polygon; collinear input gives a
+/// zero-width rectangle along the line, and a single point is returned as
+/// that Point.
+pub fn st_oriented_envelope(geom: &SurrealGeometry) -> Result<SurrealGeometry, FunctionError> {
+    let hull = hull_vertices(&geom.to_geo()?);
+    let result = match hull.len() {
+        0 => {
+            return Err(FunctionError::InvalidArgument(
+                "st_oriented_envelope requires a non-empty geometry".to_string(),
+            ))
+        }
+        1 => Geometry::Point(Point::from(hull[0])),
+        _ => Geometry::Polygon(min_area_rectangle(&hull)),
+    };
+    SurrealGeometry::from_geo(&result, *geom.srid()).map_err(FunctionError::from)
+}
+
+/// Distinct convex hull vertices in counter-clockwise order, without the
+/// closing point.
+fn hull_vertices(geom: &Geometry<f64>) -> Vec<Coord<f64>> {
+    let mut hull: Vec<Coord<f64>> = Vec::new();
+    for &c in &geom.convex_hull().exterior().0 {
+        if hull.last() != Some(&c) {
+            hull.push(c);
+        }
+    }
+    while hull.len() > 1 && hull.first() == hull.last() {
+        hull.pop();
+    }
+    hull
+}
+
+fn min_area_rectangle(hull: &[Coord<f64>]) -> Polygon<f64> {
+    let n = hull.len();
+    let dot = |a: Coord<f64>, b: Coord<f64>| a.x * b.x + a.y * b.y;
+    let (mut far_u, mut far_v, mut near_u) = (0, 0, 0);
+    let mut best: Option<(f64, [Coord<f64>; 4])> = None;
+
+    for i in 0..n {
+        let edge = hull[(i + 1) % n] - hull[i];
+        let len = edge.x.hypot(edge.y);
+        let u = edge / len;
+        // Left normal, pointing into a counter-clockwise hull
+        let v = Coord { x: -u.y, y: u.x };
+
+        // Each caliper only moves forward; the hull is convex so the
+        // projections rise then fall. The first edge seeds them in order.
+        let step = |k: usize| (k + 1) % n;
+        while dot(hull[step(far_u)] - hull[far_u], u) > 0.0 {
+            far_u = step(far_u);
+        }
+        if i == 0 {
+            far_v = far_u;
+        }
+        while dot(hull[step(far_v)] - hull[far_v], v) > 0.0 {
+            far_v = step(far_v);
+        }
+        if i == 0 {
+            near_u = far_v;
+        }
+        while dot(hull[step(near_u)] - hull[near_u], u) < 0.0 {
+            near_u = step(near_u);
+        }
+
+        let origin = hull[i];
+        let max_u = dot(hull[far_u] - origin, u);
+        let min_u = dot(hull[near_u] - origin, u);
+        let max_v = dot(hull[far_v] - origin, v);
+        let area = (max_u - min_u) * max_v;
+        if best.as_ref().is_none_or(|(a, _)| area < *a) {
+            let corners = [
+                origin + u * min_u,
+                origin + u * max_u,
+                origin + u * max_u + v * max_v,
+                origin + u * min_u + v * max_v,
+            ];
+            best = Some((area, corners));
+        }
+    }
+
+    let [a, b, c, d] = best.map(|(_, corners)| corners).unwrap_or([hull[0]; 4]);
+    Polygon::new(LineString(vec![a, b, c, d, a]), vec![])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::coords;
+    use geo::Area;
+    use surrealgis_core::coordinate::Coordinate;
+    use surrealgis_core::geometry::GeometryType;
+    use surrealgis_core::srid::Srid;
+
+    fn exterior(geom: &SurrealGeometry) -> &[Coordinate] {
+        match geom.geometry_type() {
+            GeometryType::Polygon { exterior, .. } => exterior,
+            other => panic!("Expected Polygon, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn rotated_rectangle_is_recovered() {
+        // A 4 x 1 rectangle turned 30 degrees, plus an interior point
+        let (s, c) = 30f64.to_radians().sin_cos();
+        let rotate = |x: f64, y: f64| (x * c - y * s, x * s + y * c);
+        let pts: Vec<(f64, f64)> = [(0.0, 0.0), (4.0, 0.0), (4.0, 1.0), (0.0, 1.0), (2.0, 0.5)]
+            .iter()
+            .map(|&(x, y)| rotate(x, y))
+            .collect();
+        let mp = SurrealGeometry::multi_point(coords(&pts), Srid::WEB_MERCATOR).unwrap();
+
+        let env = st_oriented_envelope(&mp).unwrap();
+        assert_eq!(exterior(&env).len(), 5);
+        let area = env.to_geo().unwrap().unsigned_area();
+        assert!((area - 4.0).abs() < 1e-9, "area was {area}");
+        // Axis-aligned, the same points need a much larger box
+        let bbox = mp.bbox().unwrap();
+        assert!((bbox.max_x - bbox.min_x) * (bbox.max_y - bbox.min_y) > 5.0);
+    }
+
+    #[test]
+    fn tilted_thin_line_is_hugged() {
+        let line = SurrealGeometry::line_string(
+            coords(&[(0.0, 0.0), (5.0, 5.001), (10.0, 10.0)]),
+            Srid::new(32632).unwrap(),
+        )
+        .unwrap();
+        let env = st_oriented_envelope(&line).unwrap();
+        assert_eq!(env.srid().code(), 32632);
+        let ring = exterior(&env);
+        assert_eq!(ring.len(), 5);
+        let side = |a: &Coordinate, b: &Coordinate| (a.x() - b.x()).hypot(a.y() - b.y());
+        let (long, short) = {
+            let (s1, s2) = (side(&ring[0], &ring[1]), side(&ring[1], &ring[2]));
+            (s1.max(s2), s1.min(s2))
+        };
+        assert!((long - 200f64.sqrt()).abs() < 1e-3, "long side was {long}");
+        assert!(short < 1e-3, "short side was {short}");
+    }
+
+    #[test]
+    fn collinear_and_single_point_input() {
+        let line = SurrealGeometry::line_string(coords(&[(1.0, 1.0), (4.0, 5.0)]), Srid::WGS84)
+            .unwrap();
+        let env = st_oriented_envelope(&line).unwrap();
+        assert_eq!(exterior(&env).len(), 5);
+        assert!(env.to_geo().unwrap().unsigned_area() < 1e-12);
+
+        let pt = SurrealGeometry::point(2.0, 3.0, Srid::WGS84).unwrap();
+        assert_eq!(st_oriented_envelope(&pt).unwrap().type_name(), "Point");
+    }
+}
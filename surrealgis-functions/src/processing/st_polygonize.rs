@@ -0,0 +1,470 @@
+use std::collections::{HashMap, HashSet};
+
+use geo::Contains;
+use surrealgis_core::coordinate::Coordinate;
+use surrealgis_core::geometry::{GeometryType, SurrealGeometry};
+use surrealgis_core::srid::Srid;
+
+use crate::FunctionError;
+
+/// Output of [`st_polygonize_full`], mirroring PostGIS's ST_Polygonize diagnostics.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolygonizeResult {
+    /// Polygons formed by the linework, with enclosed components as holes.
+    pub polygons: Vec<SurrealGeometry>,
+    /// Edges with a free end, which cannot be part of any ring.
+    pub dangles: Vec<SurrealGeometry>,
+    /// Edges that bound the same face on both sides (bridges between rings).
+    pub cut_edges: Vec<SurrealGeometry>,
+    /// Closed rings that do not form a valid polygon shell (self-intersecting
+    /// or collapsed to zero area).
+    pub invalid_rings: Vec<SurrealGeometry>,
+}
+
+/// Form polygons from a set of lines.
+/// Input must be noded: lines may only meet at their endpoints.
+/// Returns a GeometryCollection of the formed Polygons.
+pub fn st_polygonize(lines: &[SurrealGeometry]) -> Result<SurrealGeometry, FunctionError> {
+    let srid = first_srid(lines)?;
+    let result = st_polygonize_full(lines)?;
+    if result.polygons.is_empty() {
+        return Err(FunctionError::InvalidArgument(
+            "st_polygonize: the lines do not enclose any polygon".to_string(),
+        ));
+    }
+    SurrealGeometry::geometry_collection(result.polygons, srid).map_err(FunctionError::from)
+}
+
+/// Form polygons from a set of lines and report the linework that could not
+/// be used: dangles, cut edges and invalid rings.
+/// Input must be noded: lines may only meet at their endpoints.
+pub fn st_polygonize_full(lines: &[SurrealGeometry]) -> Result<PolygonizeResult, FunctionError> {
+    let srid = first_srid(lines)?;
+    let mut graph = Graph::default();
+    for geom in lines {
+        graph.add_geometry(geom)?;
+    }
+
+    let dangles = graph.remove_dangles();
+
+    let faces = graph.faces();
+    let mut cut_edges = Vec::new();
+    for e in 0..graph.edges.len() {
+        if graph.edges[e].alive && faces.face_of[2 * e] == faces.face_of[2 * e + 1] {
+            graph.edges[e].alive = false;
+            cut_edges.push(e);
+        }
+    }
+    let faces = if cut_edges.is_empty() { faces } else { graph.faces() };
+
+    let mut shells: Vec<Vec<Coordinate>> = Vec::new();
+    let mut holes: Vec<Vec<Coordinate>> = Vec::new();
+    let mut invalid_rings = Vec::new();
+    for cycle in &faces.cycles {
+        let ring = graph.ring(cycle);
+        let area = signed_area(&ring);
+        if area > 0.0 && is_simple_ring(&ring) {
+            shells.push(ring);
+        } else if area < 0.0 {
+            holes.push(ring);
+        } else {
+            invalid_rings.push(SurrealGeometry::line_string(ring, srid)?);
+        }
+    }
+
+    // Each component's outer boundary becomes a hole of the smallest shell
+    // (from another component) that encloses it
+    let shell_polys: Vec<geo_types::Polygon<f64>> =
+        shells.iter().map(|s| geo_types::Polygon::new(to_geo_line(s), vec![])).collect();
+    let shell_areas: Vec<f64> = shells.iter().map(|s| signed_area(s)).collect();
+    let mut shell_holes: Vec<Vec<Vec<Coordinate>>> = vec![Vec::new(); shells.len()];
+    for hole in holes {
+        let probe = geo_types::Point::new(hole[0].x(), hole[0].y());
+        let owner = (0..shells.len())
+            .filter(|&i| shell_polys[i].contains(&probe))
+            .min_by(|&a, &b| shell_areas[a].total_cmp(&shell_areas[b]));
+        if let Some(i) = owner {
+            shell_holes[i].push(hole);
+        }
+    }
+
+    let polygons = shells
+        .into_iter()
+        .zip(shell_holes)
+        .map(|(shell, holes)| SurrealGeometry::polygon(shell, holes, srid))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let to_lines = |edges: Vec<usize>| -> Result<Vec<SurrealGeometry>, FunctionError> {
+        edges
+            .into_iter()
+            .map(|e| {
+                SurrealGeometry::line_string(graph.edges[e].coords.clone(), srid)
+                    .map_err(FunctionError::from)
+            })
+            .collect()
+    };
+
+    Ok(PolygonizeResult {
+        polygons,
+        dangles: to_lines(dangles)?,
+        cut_edges: to_lines(cut_edges)?,
+        invalid_rings,
+    })
+}
+
+fn first_srid(lines: &[SurrealGeometry]) -> Result<Srid, FunctionError> {
+    lines.first().map(|g| *g.srid()).ok_or_else(|| {
+        FunctionError::InvalidArgument("st_polygonize requires at least one line".to_string())
+    })
+}
+
+// ── Planar graph ─────────────────────────────────────────────────────────
+
+struct Edge {
+    coords: Vec<Coordinate>,
+    from: usize,
+    to: usize,
+    alive: bool,
+}
+
+/// Edges between nodes at line endpoints. Half-edge `2e` runs along edge `e`
+/// from `from` to `to`; half-edge `2e + 1` runs back.
+#[derive(Default)]
+struct Graph {
+    nodes: HashMap<(u64, u64), usize>,
+    node_count: usize,
+    edges: Vec<Edge>,
+}
+
+struct Faces {
+    cycles: Vec<Vec<usize>>,
+    face_of: Vec<usize>,
+}
+
+impl Graph {
+    fn add_geometry(&mut self, geom: &SurrealGeometry) -> Result<(), FunctionError> {
+        match geom.geometry_type() {
+            GeometryType::LineString(coords) => self.add_line(coords),
+            GeometryType::MultiLineString(lines) => {
+                lines.iter().for_each(|l| self.add_line(l));
+            }
+            GeometryType::GeometryCollection(geoms) => {
+                for g in geoms {
+                    self.add_geometry(g)?;
+                }
+            }
+            _ => {
+                return Err(FunctionError::InvalidArgument(format!(
+                    "st_polygonize expects LineString geometries, got {}",
+                    geom.type_name()
+                )))
+            }
+        }
+        Ok(())
+    }
+
+    fn add_line(&mut self, coords: &[Coordinate]) {
+        let mut deduped: Vec<Coordinate> = Vec::with_capacity(coords.len());
+        for c in coords {
+            if deduped.last().map(key) != Some(key(c)) {
+                deduped.push(c.clone());
+            }
+        }
+        if deduped.len() < 2 {
+            return;
+        }
+        let from = self.node(&deduped[0]);
+        let to = self.node(&deduped[deduped.len() - 1]);
+        self.edges.push(Edge {
+            coords: deduped,
+            from,
+            to,
+            alive: true,
+        });
+    }
+
+    fn node(&mut self, c: &Coordinate) -> usize {
+        let next = self.node_count;
+        let id = *self.nodes.entry(key(c)).or_insert(next);
+        if id == next {
+            self.node_count += 1;
+        }
+        id
+    }
+
+    /// Repeatedly strip edges that end at a node of degree 1.
+    fn remove_dangles(&mut self) -> Vec<usize> {
+        let mut degree = vec![0usize; self.node_count];
+        let mut incident: Vec<Vec<usize>> = vec![Vec::new(); self.node_count];
+        for (i, e) in self.edges.iter().enumerate() {
+            degree[e.from] += 1;
+            degree[e.to] += 1;
+            incident[e.from].push(i);
+            incident[e.to].push(i);
+        }
+        let mut queue: Vec<usize> = (0..self.node_count).filter(|&n| degree[n] == 1).collect();
+        let mut removed = Vec::new();
+        while let Some(n) = queue.pop() {
+            if degree[n] != 1 {
+                continue;
+            }
+            let Some(&e) = incident[n].iter().find(|&&e| self.edges[e].alive) else {
+                continue;
+            };
+            self.edges[e].alive = false;
+            removed.push(e);
+            let (from, to) = (self.edges[e].from, self.edges[e].to);
+            degree[from] -= 1;
+            degree[to] -= 1;
+            let other = if from == n { to } else { from };
+            if degree[other] == 1 {
+                queue.push(other);
+            }
+        }
+        removed
+    }
+
+    fn dest(&self, h: usize) -> usize {
+        let e = &self.edges[h / 2];
+        if h.is_multiple_of(2) { e.to } else { e.from }
+    }
+
+    /// Direction in which a half-edge leaves its origin.
+    fn angle(&self, h: usize) -> f64 {
+        let coords = &self.edges[h / 2].coords;
+        let (a, b) = if h.is_multiple_of(2) {
+            (&coords[0], &coords[1])
+        } else {
+            (&coords[coords.len() - 1], &coords[coords.len() - 2])
+        };
+        (b.y() - a.y()).atan2(b.x() - a.x())
+    }
+
+    /// Trace the face cycles of the alive edges. Following the outgoing
+    /// half-edge just clockwise of the twin keeps each face on the left, so
+    /// bounded faces come out counter-clockwise.
+    fn faces(&self) -> Faces {
+        let mut outgoing: Vec<Vec<usize>> = vec![Vec::new(); self.node_count];
+        for (e, edge) in self.edges.iter().enumerate() {
+            if edge.alive {
+                outgoing[edge.from].push(2 * e);
+                outgoing[edge.to].push(2 * e + 1);
+            }
+        }
+        for out in &mut outgoing {
+            out.sort_by(|&a, &b| self.angle(a).total_cmp(&self.angle(b)));
+        }
+        let next = |h: usize| -> usize {
+            let v = self.dest(h);
+            let twin = h ^ 1;
+            let out = &outgoing[v];
+            let i = out.iter().position(|&o| o == twin).expect("twin leaves dest");
+            out[(i + out.len() - 1) % out.len()]
+        };
+
+        let mut face_of = vec![usize::MAX; 2 * self.edges.len()];
+        let mut cycles = Vec::new();
+        for start in 0..2 * self.edges.len() {
+            if !self.edges[start / 2].alive || face_of[start] != usize::MAX {
+                continue;
+            }
+            let mut cycle = Vec::new();
+            let mut h = start;
+            while face_of[h] == usize::MAX {
+                face_of[h] = cycles.len();
+                cycle.push(h);
+                h = next(h);
+            }
+            cycles.push(cycle);
+        }
+        Faces { cycles, face_of }
+    }
+
+    /// Closed coordinate ring traced by a face cycle.
+    fn ring(&self, cycle: &[usize]) -> Vec<Coordinate> {
+        let mut ring = Vec::new();
+        for &h in cycle {
+            let coords = &self.edges[h / 2].coords;
+            if h % 2 == 0 {
+                ring.extend(coords[..coords.len() - 1].iter().cloned());
+            } else {
+                ring.extend(coords[1..].iter().rev().cloned());
+            }
+        }
+        ring.push(ring[0].clone());
+        ring
+    }
+}
+
+fn key(c: &Coordinate) -> (u64, u64) {
+    // Adding 0.0 folds -0.0 into 0.0 so both hash to the same node
+    ((c.x() + 0.0).to_bits(), (c.y() + 0.0).to_bits())
+}
+
+fn signed_area(ring: &[Coordinate]) -> f64 {
+    ring.windows(2)
+        .map(|w| w[0].x() * w[1].y() - w[1].x() * w[0].y())
+        .sum::<f64>()
+        / 2.0
+}
+
+/// A closed ring is simple if it revisits no vertex and no two
+/// non-adjacent segments touch.
+fn is_simple_ring(ring: &[Coordinate]) -> bool {
+    let n = ring.len() - 1;
+    if n < 3 {
+        return false;
+    }
+    let mut seen = HashSet::with_capacity(n);
+    if !ring[..n].iter().all(|c| seen.insert(key(c))) {
+        return false;
+    }
+    for i in 0..n {
+        for j in i + 2..n {
+            if i == 0 && j == n - 1 {
+                continue;
+            }
+            if segments_touch(&ring[i], &ring[i + 1], &ring[j], &ring[j + 1]) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+fn orientation(a: &Coordinate, b: &Coordinate, c: &Coordinate) -> f64 {
+    (b.x() - a.x()) * (c.y() - a.y()) - (b.y() - a.y()) * (c.x() - a.x())
+}
+
+fn on_segment(a: &Coordinate, b: &Coordinate, p: &Coordinate) -> bool {
+    p.x() >= a.x().min(b.x())
+        && p.x() <= a.x().max(b.x())
+        && p.y() >= a.y().min(b.y())
+        && p.y() <= a.y().max(b.y())
+}
+
+fn segments_touch(p1: &Coordinate, p2: &Coordinate, q1: &Coordinate, q2: &Coordinate) -> bool {
+    let d1 = orientation(q1, q2, p1);
+    let d2 = orientation(q1, q2, p2);
+    let d3 = orientation(p1, p2, q1);
+    let d4 = orientation(p1, p2, q2);
+    if d1 * d2 < 0.0 && d3 * d4 < 0.0 {
+        return true;
+    }
+    (d1 == 0.0 && on_segment(q1, q2, p1))
+        || (d2 == 0.0 && on_segment(q1, q2, p2))
+        || (d3 == 0.0 && on_segment(p1, p2, q1))
+        || (d4 == 0.0 && on_segment(p1, p2, q2))
+}
+
+fn to_geo_line(ring: &[Coordinate]) -> geo_types::LineString<f64> {
+    ring.iter().map(|c| geo_types::coord! { x: c.x(), y: c.y() }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::measurement::st_area;
+    use crate::test_support::line;
+
+    fn square_lines() -> Vec<SurrealGeometry> {
+        vec![
+            line(&[(0.0, 0.0), (10.0, 0.0)]),
+            line(&[(10.0, 0.0), (10.0, 10.0)]),
+            line(&[(10.0, 10.0), (0.0, 10.0)]),
+            line(&[(0.0, 10.0), (0.0, 0.0)]),
+        ]
+    }
+
+    #[test]
+    fn square_forms_one_polygon() {
+        let result = st_polygonize_full(&square_lines()).unwrap();
+        assert_eq!(result.polygons.len(), 1);
+        assert!((st_area(&result.polygons[0]).unwrap() - 100.0).abs() < 1e-10);
+        assert!(result.dangles.is_empty());
+        assert!(result.cut_edges.is_empty());
+        assert!(result.invalid_rings.is_empty());
+    }
+
+    #[test]
+    fn spur_is_reported_as_dangle() {
+        let mut lines = square_lines();
+        lines.push(line(&[(10.0, 10.0), (12.0, 13.0), (15.0, 15.0)]));
+
+        let result = st_polygonize_full(&lines).unwrap();
+        assert_eq!(result.polygons.len(), 1);
+        assert!((st_area(&result.polygons[0]).unwrap() - 100.0).abs() < 1e-10);
+        assert_eq!(result.dangles.len(), 1);
+        assert_eq!(result.dangles[0], lines[4]);
+        assert!(result.cut_edges.is_empty());
+    }
+
+    #[test]
+    fn chained_spur_is_fully_stripped() {
+        let mut lines = square_lines();
+        lines.push(line(&[(10.0, 10.0), (12.0, 12.0)]));
+        lines.push(line(&[(12.0, 12.0), (14.0, 12.0)]));
+
+        let result = st_polygonize_full(&lines).unwrap();
+        assert_eq!(result.polygons.len(), 1);
+        assert_eq!(result.dangles.len(), 2);
+    }
+
+    #[test]
+    fn bridge_between_rings_is_cut_edge() {
+        let lines = vec![
+            line(&[(1.0, 0.0), (1.0, 1.0), (0.0, 1.0), (0.0, 0.0), (1.0, 0.0)]),
+            line(&[(3.0, 0.0), (4.0, 0.0), (4.0, 1.0), (3.0, 1.0), (3.0, 0.0)]),
+            line(&[(1.0, 0.0), (3.0, 0.0)]),
+        ];
+        let result = st_polygonize_full(&lines).unwrap();
+        assert_eq!(result.polygons.len(), 2);
+        assert_eq!(result.cut_edges, vec![lines[2].clone()]);
+        assert!(result.dangles.is_empty());
+    }
+
+    #[test]
+    fn enclosed_ring_becomes_hole() {
+        let lines = vec![
+            line(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0), (0.0, 0.0)]),
+            line(&[(4.0, 4.0), (6.0, 4.0), (6.0, 6.0), (4.0, 6.0), (4.0, 4.0)]),
+        ];
+        let result = st_polygonize_full(&lines).unwrap();
+        let mut areas: Vec<f64> = result
+            .polygons
+            .iter()
+            .map(|p| st_area(p).unwrap())
+            .collect();
+        areas.sort_by(f64::total_cmp);
+        assert_eq!(areas.len(), 2);
+        assert!((areas[0] - 4.0).abs() < 1e-10);
+        assert!((areas[1] - 96.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn shared_edge_forms_two_polygons() {
+        let mut lines = square_lines();
+        lines.push(line(&[(10.0, 0.0), (20.0, 0.0), (20.0, 10.0), (10.0, 10.0)]));
+        let collection = st_polygonize(&lines).unwrap();
+        match collection.geometry_type() {
+            GeometryType::GeometryCollection(polys) => assert_eq!(polys.len(), 2),
+            _ => panic!("Expected GeometryCollection"),
+        }
+    }
+
+    #[test]
+    fn open_lines_form_nothing() {
+        let lines = vec![line(&[(0.0, 0.0), (1.0, 1.0)])];
+        let result = st_polygonize_full(&lines).unwrap();
+        assert!(result.polygons.is_empty());
+        assert_eq!(result.dangles.len(), 1);
+        assert!(st_polygonize(&lines).is_err());
+    }
+
+    #[test]
+    fn rejects_non_lines() {
+        let p = SurrealGeometry::point(0.0, 0.0, Srid::WEB_MERCATOR).unwrap();
+        assert!(st_polygonize_full(&[p]).is_err());
+        assert!(st_polygonize_full(&[]).is_err());
+    }
+}
@@ -0,0 +1,204 @@
+use geo::Simplify;
+use geo_types::{Geometry, GeometryCollection, LineString, MultiLineString, MultiPolygon, Polygon};
+use surrealgis_core::geometry::SurrealGeometry;
+use surrealgis_core::srid::Srid;
+
+use crate::measurement::{st_area, st_length};
+use crate::FunctionError;
+
+/// Simplify a geometry for display at small scales, then drop every part
+/// too small to show.
+///
+/// The geometry is first simplified with Ramer-Douglas-Peucker, as in
+/// [`st_simplify`](super::st_simplify), using `min_size` as the tolerance for
+/// lines and `sqrt(min_size)` (the side of a square of that area) for
+/// polygons. Afterwards polygon parts and holes with an area below
+/// `min_size`, and line parts shorter than `min_size`, are removed. Sizes are
+/// measured with [`st_area`] and [`st_length`], so geographic input is
+/// compared in square meters and meters. Points are kept. Returns an error
+/// if no part survives.
+pub fn st_simplify_min_size(
+    geom: &SurrealGeometry,
+    min_size: f64,
+) -> Result<SurrealGeometry, FunctionError> {
+    if !min_size.is_finite() || min_size < 0.0 {
+        return Err(FunctionError::InvalidArgument(format!(
+            "st_simplify_min_size min_size must be non-negative, got {min_size}"
+        )));
+    }
+
+    let srid = *geom.srid();
+    let geo_geom = geom.to_geo()?;
+    let filtered = generalize(&geo_geom, min_size, srid)?.ok_or_else(|| {
+        FunctionError::InvalidArgument(format!(
+            "st_simplify_min_size removed every part smaller than {min_size}"
+        ))
+    })?;
+    SurrealGeometry::from_geo(&filtered, srid).map_err(FunctionError::from)
+}
+
+fn generalize(
+    geom: &Geometry<f64>,
+    min_size: f64,
+    srid: Srid,
+) -> Result<Option<Geometry<f64>>, FunctionError> {
+    let polygon_tolerance = min_size.sqrt();
+    let kept = match geom {
+        Geometry::LineString(ls) => {
+            let ls = ls.simplify(min_size);
+            long_enough(&ls, min_size, srid)?.then_some(Geometry::LineString(ls))
+        }
+        Geometry::MultiLineString(mls) => {
+            let mut kept = Vec::new();
+            for ls in &mls.0 {
+                let ls = ls.simplify(min_size);
+                if long_enough(&ls, min_size, srid)? {
+                    kept.push(ls);
+                }
+            }
+            (!kept.is_empty()).then_some(Geometry::MultiLineString(MultiLineString(kept)))
+        }
+        Geometry::Polygon(poly) => {
+            drop_small_rings(&poly.simplify(polygon_tolerance), min_size, srid)?
+                .map(Geometry::Polygon)
+        }
+        Geometry::MultiPolygon(mp) => {
+            let mut kept = Vec::new();
+            for poly in &mp.0 {
+                kept.extend(drop_small_rings(&poly.simplify(polygon_tolerance), min_size, srid)?);
+            }
+            (!kept.is_empty()).then_some(Geometry::MultiPolygon(MultiPolygon(kept)))
+        }
+        Geometry::GeometryCollection(gc) => {
+            let mut kept = Vec::new();
+            for g in &gc.0 {
+                kept.extend(generalize(g, min_size, srid)?);
+            }
+            (!kept.is_empty()).then_some(Geometry::GeometryCollection(GeometryCollection(kept)))
+        }
+        // Points have nothing to simplify or drop
+        other => Some(other.clone()),
+    };
+    Ok(kept)
+}
+
+fn long_enough(ls: &LineString<f64>, min_size: f64, srid: Srid) -> Result<bool, FunctionError> {
+    // Simplification can collapse a line to a single point
+    if ls.0.len() < 2 {
+        return Ok(min_size <= 0.0);
+    }
+    let line = SurrealGeometry::from_geo(&Geometry::LineString(ls.clone()), srid)?;
+    Ok(st_length(&line)? >= min_size)
+}
+
+fn ring_area(ring: &LineString<f64>, srid: Srid) -> Result<f64, FunctionError> {
+    // A ring simplified below four vertices encloses nothing
+    if ring.0.len() < 4 {
+        return Ok(0.0);
+    }
+    let poly = Polygon::new(ring.clone(), vec![]);
+    st_area(&SurrealGeometry::from_geo(&Geometry::Polygon(poly), srid)?)
+}
+
+/// Drop holes below `min_size`, or the whole polygon if its exterior is.
+fn drop_small_rings(
+    poly: &Polygon<f64>,
+    min_size: f64,
+    srid: Srid,
+) -> Result<Option<Polygon<f64>>, FunctionError> {
+    if ring_area(poly.exterior(), srid)? < min_size {
+        return Ok(None);
+    }
+    let mut holes = Vec::new();
+    for ring in poly.interiors() {
+        if ring_area(ring, srid)? >= min_size {
+            holes.push(ring.clone());
+        }
+    }
+    Ok(Some(Polygon::new(poly.exterior().clone(), holes)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geo::Area;
+    use crate::test_support::{coords, square, square_ring};
+    use surrealgis_core::geometry::{GeometryType, PolygonData};
+    use surrealgis_core::srid::Srid;
+
+    #[test]
+    fn sliver_part_is_dropped() {
+        let main = PolygonData {
+            exterior: square_ring(0.0, 0.0, 100.0),
+            holes: vec![],
+        };
+        // 60 x 0.05: long, but only 3 square units
+        let sliver = PolygonData {
+            exterior: coords(&[
+                (200.0, 0.0),
+                (260.0, 0.0),
+                (260.0, 0.05),
+                (200.0, 0.05),
+                (200.0, 0.0),
+            ]),
+            holes: vec![],
+        };
+        let mp = SurrealGeometry::multi_polygon(vec![main, sliver], Srid::WEB_MERCATOR).unwrap();
+
+        let result = st_simplify_min_size(&mp, 10.0).unwrap();
+        match result.geometry_type() {
+            GeometryType::MultiPolygon(polys) => {
+                assert_eq!(polys.len(), 1);
+                assert_eq!(polys[0].exterior[0].x(), 0.0);
+            }
+            other => panic!("Expected MultiPolygon, got {other:?}"),
+        }
+        let area = result.to_geo().unwrap().unsigned_area();
+        assert!((area - 10_000.0).abs() < 1e-6, "area was {area}");
+    }
+
+    #[test]
+    fn small_hole_is_filled() {
+        let poly = SurrealGeometry::polygon(
+            square_ring(0.0, 0.0, 100.0),
+            vec![square_ring(10.0, 10.0, 1.0), square_ring(50.0, 50.0, 20.0)],
+            Srid::WEB_MERCATOR,
+        )
+        .unwrap();
+        let result = st_simplify_min_size(&poly, 10.0).unwrap();
+        match result.geometry_type() {
+            GeometryType::Polygon { holes, .. } => assert_eq!(holes.len(), 1),
+            other => panic!("Expected Polygon, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn short_line_parts_are_dropped() {
+        let mls = SurrealGeometry::multi_line_string(
+            vec![
+                coords(&[(0.0, 0.0), (100.0, 0.0)]),
+                coords(&[(0.0, 10.0), (2.0, 10.0)]),
+            ],
+            Srid::WEB_MERCATOR,
+        )
+        .unwrap();
+        let result = st_simplify_min_size(&mls, 5.0).unwrap();
+        match result.geometry_type() {
+            GeometryType::MultiLineString(lines) => assert_eq!(lines.len(), 1),
+            other => panic!("Expected MultiLineString, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn everything_too_small_is_an_error() {
+        let poly = square(0.0, 0.0, 1.0);
+        assert!(matches!(
+            st_simplify_min_size(&poly, 10.0),
+            Err(FunctionError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            st_simplify_min_size(&poly, -1.0),
+            Err(FunctionError::InvalidArgument(_))
+        ));
+    }
+}
@@ -0,0 +1,438 @@
+use geo::algorithm::line_intersection::{line_intersection, LineIntersection};
+use geo::{BooleanOps, BoundingRect};
+use geo_types::{
+    Coord, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPolygon, Polygon,
+    Rect,
+};
+use surrealgis_core::geometry::SurrealGeometry;
+
+use crate::FunctionError;
+
+/// Half-width of the cut used when a blade does not span the polygon, relative
+/// to the target's extent. Wide enough to survive the overlay's snapping,
+/// narrow enough that the area it removes is negligible.
+const CUT_HALF_WIDTH_FACTOR: f64 = 1e-6;
+
+/// Split a geometry by a blade, as in PostGIS `ST_Split`.
+///
+/// A LineString can be split by a Point lying on it or by a LineString
+/// crossing it; the result holds the sub-lines in order along the target.
+/// A Polygon can be split by a LineString crossing it. A straight blade that
+/// spans every stretch of its line inside the polygon is extended past the
+/// bounding box and the polygon is intersected with the half-planes on either
+/// side, so the pieces share the cut exactly. Any other blade is differenced
+/// out as a thin cut, leaving a gap of about a millionth of the target's
+/// extent between the pieces.
+/// A blade that does not split the target returns it unchanged.
+/// Returns a GeometryCollection of the pieces.
+pub fn st_split(
+    target: &SurrealGeometry,
+    blade: &SurrealGeometry,
+) -> Result<SurrealGeometry, FunctionError> {
+    let pieces = match (target.to_geo()?, blade.to_geo()?) {
+        (Geometry::LineString(line), Geometry::Point(pt)) => {
+            split_line(&line, &point_cuts(&line, pt.0))
+        }
+        (Geometry::LineString(line), Geometry::LineString(b)) => {
+            split_line(&line, &line_cuts(&line, &b))
+        }
+        (Geometry::Polygon(poly), Geometry::LineString(b)) => split_polygon(&poly, &b)?,
+        (Geometry::Polygon(_), _) => {
+            return Err(FunctionError::type_mismatch("st_split", &["LineString"], blade))
+        }
+        (Geometry::LineString(_), _) => {
+            return Err(FunctionError::type_mismatch("st_split", &["Point", "LineString"], blade))
+        }
+        _ => {
+            return Err(FunctionError::type_mismatch(
+                "st_split",
+                &["LineString", "Polygon"],
+                target,
+            ))
+        }
+    };
+
+    let collection = Geometry::GeometryCollection(GeometryCollection(pieces));
+    SurrealGeometry::from_geo(&collection, *target.srid()).map_err(FunctionError::from)
+}
+
+/// A split position: segment index and fraction along that segment.
+type Cut = (usize, f64);
+
+/// Where `pt` lies on `line`, if it does.
+fn point_cuts(line: &LineString<f64>, pt: Coord<f64>) -> Vec<Cut> {
+    line.lines()
+        .enumerate()
+        .find_map(|(i, seg)| {
+            let t = fraction_along(&seg, pt);
+            let on = seg.start + (seg.end - seg.start) * t;
+            let tolerance = 1e-9 * (1.0 + seg.start.x.abs().max(seg.start.y.abs()));
+            ((on.x - pt.x).hypot(on.y - pt.y) <= tolerance).then_some((i, t))
+        })
+        .into_iter()
+        .collect()
+}
+
+/// Every place where `blade` meets `line`; a collinear overlap cuts at both
+/// of its ends.
+fn line_cuts(line: &LineString<f64>, blade: &LineString<f64>) -> Vec<Cut> {
+    let mut cuts = Vec::new();
+    for (i, seg) in line.lines().enumerate() {
+        for b in blade.lines() {
+            match line_intersection(seg, b) {
+                Some(LineIntersection::SinglePoint { intersection, .. }) => {
+                    cuts.push((i, fraction_along(&seg, intersection)));
+                }
+                Some(LineIntersection::Collinear { intersection }) => {
+                    cuts.push((i, fraction_along(&seg, intersection.start)));
+                    cuts.push((i, fraction_along(&seg, intersection.end)));
+                }
+                None => {}
+            }
+        }
+    }
+    cuts
+}
+
+fn fraction_along(seg: &Line<f64>, p: Coord<f64>) -> f64 {
+    let d = seg.end - seg.start;
+    let len_sq = d.x * d.x + d.y * d.y;
+    if len_sq == 0.0 {
+        return 0.0;
+    }
+    (((p.x - seg.start.x) * d.x + (p.y - seg.start.y) * d.y) / len_sq).clamp(0.0, 1.0)
+}
+
+/// Cut `line` at each position, dropping zero-length pieces so cuts at the
+/// line's own endpoints do nothing.
+fn split_line(line: &LineString<f64>, cuts: &[Cut]) -> Vec<Geometry<f64>> {
+    let mut cuts = cuts.to_vec();
+    cuts.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.total_cmp(&b.1)));
+
+    let mut pieces = Vec::new();
+    let mut current = vec![line.0[0]];
+    let mut next_cut = cuts.iter().peekable();
+    for (i, seg) in line.lines().enumerate() {
+        while let Some(&(_, t)) = next_cut.next_if(|c| c.0 == i) {
+            let p = seg.start + (seg.end - seg.start) * t;
+            push_distinct(&mut current, p);
+            if current.len() > 1 {
+                pieces.push(Geometry::LineString(LineString(current)));
+            }
+            current = vec![p];
+        }
+        push_distinct(&mut current, seg.end);
+    }
+    if current.len() > 1 {
+        pieces.push(Geometry::LineString(LineString(current)));
+    }
+    pieces
+}
+
+fn push_distinct(coords: &mut Vec<Coord<f64>>, c: Coord<f64>) {
+    if coords.last() != Some(&c) {
+        coords.push(c);
+    }
+}
+
+fn split_polygon(
+    poly: &Polygon<f64>,
+    blade: &LineString<f64>,
+) -> Result<Vec<Geometry<f64>>, FunctionError> {
+    let bounds = poly
+        .bounding_rect()
+        .ok_or_else(|| FunctionError::InvalidArgument("st_split target is empty".to_string()))?;
+    let target = MultiPolygon(vec![poly.clone()]);
+
+    let spanning = straight_ends(blade).filter(|&(start, end)| spans(poly, start, end, bounds));
+    let parts = match spanning {
+        Some((start, end)) => {
+            let (left, right) = half_planes(start, end, bounds);
+            let mut parts = target.intersection(&MultiPolygon(vec![left])).0;
+            parts.extend(target.intersection(&MultiPolygon(vec![right])).0);
+            parts
+        }
+        // A blade stopping short, inside the polygon or in a hole or notch,
+        // must only cut where it actually runs
+        None => {
+            let half_width = bounds.width().max(bounds.height()) * CUT_HALF_WIDTH_FACTOR;
+            target.difference(&cut_polygon(blade, half_width)).0
+        }
+    };
+    if parts.len() < 2 {
+        return Ok(vec![Geometry::Polygon(poly.clone())]);
+    }
+    Ok(parts.into_iter().map(Geometry::Polygon).collect())
+}
+
+/// The blade's end points, if every vertex lies on the line through them.
+fn straight_ends(blade: &LineString<f64>) -> Option<(Coord<f64>, Coord<f64>)> {
+    let (start, end) = (*blade.0.first()?, *blade.0.last()?);
+    let d = end - start;
+    let len_sq = d.x * d.x + d.y * d.y;
+    if len_sq == 0.0 {
+        return None;
+    }
+    let straight = blade.0.iter().all(|p| {
+        let cross = d.x * (p.y - start.y) - d.y * (p.x - start.x);
+        cross.abs() <= 1e-12 * len_sq
+    });
+    straight.then_some((start, end))
+}
+
+/// How far past its ends the line through `start -> end` must run to leave
+/// `bounds` from anywhere on the blade.
+fn reach(start: Coord<f64>, end: Coord<f64>, bounds: Rect<f64>) -> f64 {
+    let far = |p: Coord<f64>| (p.x - bounds.center().x).hypot(p.y - bounds.center().y);
+    bounds.width().hypot(bounds.height()) + far(start).max(far(end))
+}
+
+/// Whether the blade `start -> end` covers every part of its extended line
+/// that lies inside `poly`, so cutting along the whole line cuts nothing the
+/// blade does not.
+fn spans(poly: &Polygon<f64>, start: Coord<f64>, end: Coord<f64>, bounds: Rect<f64>) -> bool {
+    let d = end - start;
+    let len = d.x.hypot(d.y);
+    let u = d / len;
+    let reach = reach(start, end, bounds);
+    let extended = LineString(vec![start - u * reach, end + u * reach]);
+    let inside = poly.clip(&MultiLineString(vec![extended]), false);
+    let tolerance = 1e-9 * bounds.width().max(bounds.height());
+    !inside.0.is_empty()
+        && inside.0.iter().flat_map(|ls| ls.0.iter()).all(|&p| {
+            let t = (p.x - start.x) * u.x + (p.y - start.y) * u.y;
+            (-tolerance..=len + tolerance).contains(&t)
+        })
+}
+
+/// The two halves, left and right of `start -> end`, of a square around the
+/// line large enough to cover `bounds`.
+fn half_planes(
+    start: Coord<f64>,
+    end: Coord<f64>,
+    bounds: Rect<f64>,
+) -> (Polygon<f64>, Polygon<f64>) {
+    let d = end - start;
+    let u = d / d.x.hypot(d.y);
+    let n = Coord { x: -u.y, y: u.x };
+    let reach = reach(start, end, bounds);
+    let (a, b) = (start - u * reach, end + u * reach);
+    let half = |side: f64| {
+        let offset = n * (side * 2.0 * reach);
+        let ring = if side > 0.0 {
+            vec![a, b, b + offset, a + offset, a]
+        } else {
+            vec![a, a + offset, b + offset, b, a]
+        };
+        Polygon::new(LineString(ring), vec![])
+    };
+    (half(1.0), half(-1.0))
+}
+
+/// The blade widened by `half_width`: a rectangle along each segment plus a
+/// square on each vertex to close the gaps at bends and ends.
+fn cut_polygon(blade: &LineString<f64>, half_width: f64) -> MultiPolygon<f64> {
+    let mut cut = MultiPolygon(vec![]);
+    for seg in blade.lines() {
+        let d = seg.end - seg.start;
+        let len = d.x.hypot(d.y);
+        if len == 0.0 {
+            continue;
+        }
+        let n = Coord {
+            x: -d.y / len * half_width,
+            y: d.x / len * half_width,
+        };
+        let rect = Polygon::new(
+            LineString(vec![
+                seg.start + n,
+                seg.end + n,
+                seg.end - n,
+                seg.start - n,
+                seg.start + n,
+            ]),
+            vec![],
+        );
+        cut = cut.union(&MultiPolygon(vec![rect]));
+    }
+    for &c in &blade.0 {
+        let offset = Coord {
+            x: half_width,
+            y: half_width,
+        };
+        let square = Rect::new(c - offset, c + offset).to_polygon();
+        cut = cut.union(&MultiPolygon(vec![square]));
+    }
+    cut
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{line, square};
+    use geo::Area;
+    use surrealgis_core::coordinate::Coordinate;
+    use surrealgis_core::geometry::GeometryType;
+    use surrealgis_core::srid::Srid;
+
+    fn pieces(result: &SurrealGeometry) -> &[SurrealGeometry] {
+        match result.geometry_type() {
+            GeometryType::GeometryCollection(geoms) => geoms,
+            other => panic!("Expected GeometryCollection, got {other:?}"),
+        }
+    }
+
+    fn line_xs(geom: &SurrealGeometry) -> Vec<f64> {
+        match geom.geometry_type() {
+            GeometryType::LineString(coords) => coords.iter().map(|c| c.x()).collect(),
+            other => panic!("Expected LineString, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn line_split_by_point() {
+        let target = line(&[(0.0, 0.0), (4.0, 0.0), (10.0, 0.0)]);
+        let blade = SurrealGeometry::point(6.0, 0.0, Srid::WEB_MERCATOR).unwrap();
+        let result = st_split(&target, &blade).unwrap();
+        let parts = pieces(&result);
+        assert_eq!(parts.len(), 2);
+        assert_eq!(line_xs(&parts[0]), vec![0.0, 4.0, 6.0]);
+        assert_eq!(line_xs(&parts[1]), vec![6.0, 10.0]);
+    }
+
+    #[test]
+    fn line_split_by_crossing_line() {
+        let target = line(&[(0.0, 0.0), (10.0, 0.0)]);
+        let blade = line(&[(2.0, -1.0), (2.0, 1.0), (7.0, 1.0), (7.0, -1.0)]);
+        let result = st_split(&target, &blade).unwrap();
+        let parts = pieces(&result);
+        assert_eq!(parts.len(), 3);
+        assert_eq!(line_xs(&parts[0]), vec![0.0, 2.0]);
+        assert_eq!(line_xs(&parts[1]), vec![2.0, 7.0]);
+        assert_eq!(line_xs(&parts[2]), vec![7.0, 10.0]);
+    }
+
+    #[test]
+    fn polygon_split_by_line_gives_two_halves() {
+        let blade = line(&[(5.0, -1.0), (5.0, 11.0)]);
+        let result = st_split(&square(0.0, 0.0, 10.0), &blade).unwrap();
+        let parts = pieces(&result);
+        assert_eq!(parts.len(), 2);
+        for part in parts {
+            assert!(matches!(part.geometry_type(), GeometryType::Polygon { .. }));
+            let area = part.to_geo().unwrap().unsigned_area();
+            assert!((area - 50.0).abs() < 1e-3, "area was {area}");
+        }
+    }
+
+    #[test]
+    fn square_split_by_diagonal_gives_two_triangles() {
+        let blade = line(&[(-1.0, -1.0), (11.0, 11.0)]);
+        let result = st_split(&square(0.0, 0.0, 10.0), &blade).unwrap();
+        let parts = pieces(&result);
+        assert_eq!(parts.len(), 2);
+        let mut total = 0.0;
+        for part in parts {
+            match part.geometry_type() {
+                GeometryType::Polygon { exterior, holes } => {
+                    // A triangle: three corners plus the closing point
+                    assert_eq!(exterior.len(), 4);
+                    assert!(holes.is_empty());
+                }
+                other => panic!("Expected Polygon, got {other:?}"),
+            }
+            let area = part.to_geo().unwrap().unsigned_area();
+            // Only the overlay's float snapping separates these from exact
+            assert!((area - 50.0).abs() < 1e-5, "area was {area}");
+            total += area;
+        }
+        assert!((total - 100.0).abs() < 1e-5, "total was {total}");
+    }
+
+    #[test]
+    fn bent_blade_splits_with_thin_cut() {
+        let blade = line(&[(5.0, -1.0), (5.0, 5.0), (11.0, 5.0)]);
+        let result = st_split(&square(0.0, 0.0, 10.0), &blade).unwrap();
+        let parts = pieces(&result);
+        assert_eq!(parts.len(), 2);
+        let mut areas: Vec<f64> =
+            parts.iter().map(|p| p.to_geo().unwrap().unsigned_area()).collect();
+        areas.sort_by(f64::total_cmp);
+        assert!((areas[0] - 25.0).abs() < 1e-3, "areas were {areas:?}");
+        assert!((areas[1] - 75.0).abs() < 1e-3, "areas were {areas:?}");
+    }
+
+    #[test]
+    fn blade_ending_inside_does_not_split() {
+        let poly = square(0.0, 0.0, 10.0);
+        let result = st_split(&poly, &line(&[(5.0, -1.0), (5.0, 5.0)])).unwrap();
+        assert_eq!(pieces(&result), &[poly]);
+    }
+
+    #[test]
+    fn blade_stopping_in_notch_leaves_far_arm_whole() {
+        // A U opening upwards; the blade crosses the left arm and stops in the notch
+        let u = [
+            (0.0, 0.0),
+            (10.0, 0.0),
+            (10.0, 10.0),
+            (7.0, 10.0),
+            (7.0, 3.0),
+            (3.0, 3.0),
+            (3.0, 10.0),
+            (0.0, 10.0),
+            (0.0, 0.0),
+        ];
+        let ring = u.iter().map(|&(x, y)| Coordinate::new(x, y).unwrap()).collect();
+        let poly = SurrealGeometry::polygon(ring, vec![], Srid::WEB_MERCATOR).unwrap();
+        let result = st_split(&poly, &line(&[(-1.0, 5.0), (5.0, 5.0)])).unwrap();
+        let parts = pieces(&result);
+        assert_eq!(parts.len(), 2);
+        let mut areas: Vec<f64> =
+            parts.iter().map(|p| p.to_geo().unwrap().unsigned_area()).collect();
+        areas.sort_by(f64::total_cmp);
+        // Only the top of the left arm comes off
+        assert!((areas[0] - 15.0).abs() < 1e-3, "areas were {areas:?}");
+        assert!((areas[1] - 57.0).abs() < 1e-3, "areas were {areas:?}");
+    }
+
+    #[test]
+    fn blade_into_hole_does_not_split_annulus() {
+        let coords = |pts: &[(f64, f64)]| -> Vec<Coordinate> {
+            pts.iter().map(|&(x, y)| Coordinate::new(x, y).unwrap()).collect()
+        };
+        let annulus = SurrealGeometry::polygon(
+            coords(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0), (0.0, 0.0)]),
+            vec![coords(&[(3.0, 3.0), (7.0, 3.0), (7.0, 7.0), (3.0, 7.0), (3.0, 3.0)])],
+            Srid::WEB_MERCATOR,
+        )
+        .unwrap();
+        let result = st_split(&annulus, &line(&[(-1.0, 5.0), (5.0, 5.0)])).unwrap();
+        assert_eq!(pieces(&result), &[annulus]);
+    }
+
+    #[test]
+    fn non_intersecting_blade_returns_target() {
+        let target = line(&[(0.0, 0.0), (10.0, 0.0)]);
+        let blade = line(&[(0.0, 5.0), (10.0, 5.0)]);
+        let result = st_split(&target, &blade).unwrap();
+        assert_eq!(pieces(&result), &[target]);
+
+        let poly = square(0.0, 0.0, 10.0);
+        let result = st_split(&poly, &line(&[(20.0, 0.0), (20.0, 10.0)])).unwrap();
+        assert_eq!(pieces(&result), &[poly]);
+    }
+
+    #[test]
+    fn rejects_unsupported_types() {
+        let pt = SurrealGeometry::point(5.0, 5.0, Srid::WEB_MERCATOR).unwrap();
+        assert!(matches!(
+            st_split(&square(0.0, 0.0, 10.0), &pt),
+            Err(FunctionError::TypeMismatch { .. })
+        ));
+        assert!(matches!(
+            st_split(&pt, &line(&[(0.0, 0.0), (1.0, 1.0)])),
+            Err(FunctionError::TypeMismatch { .. })
+        ));
+    }
+}
@@ -0,0 +1,301 @@
+use geo::{Area, BoundingRect, CoordsIter};
+use geo_types::{Coord, Geometry as GeoGeometry, MultiPolygon, Polygon, Rect};
+use surrealgis_core::geometry::SurrealGeometry;
+use surrealgis_core::srid::Srid;
+
+use crate::overlay::st_intersection;
+use crate::FunctionError;
+
+/// Recursion limit for subdivision; each level halves a piece along one axis.
+const MAX_SUBDIVIDE_DEPTH: usize = 32;
+
+/// Most pieces a subdivision may produce before it is abandoned.
+const MAX_SUBDIVIDE_PIECES: usize = 1 << 20;
+
+/// Recursively split a Polygon or MultiPolygon until every piece has at most
+/// `max_vertices` vertices (ring closing points included), so that huge
+/// polygons become many small ones that index and test quickly.
+/// Each split halves the piece's bounding box across its longer side and
+/// clips the piece against both halves with [`st_intersection`], so the
+/// pieces tile the input exactly. A piece that still exceeds the budget
+/// after 32 levels of splitting is an error. Returns a MultiPolygon of the
+/// pieces.
+pub fn st_subdivide(
+    geom: &SurrealGeometry,
+    max_vertices: usize,
+) -> Result<SurrealGeometry, FunctionError> {
+    // A closed ring needs at least 4 points; 5 leaves room to split
+    if max_vertices < 5 {
+        return Err(FunctionError::InvalidArgument(format!(
+            "st_subdivide max_vertices must be at least 5, got {max_vertices}"
+        )));
+    }
+    let polygons = polygon_parts(geom, "st_subdivide")?;
+
+    let mut pieces = Vec::new();
+    let fits = |p: &Polygon<f64>| p.coords_count() <= max_vertices;
+    for polygon in polygons {
+        subdivide(polygon, &fits, *geom.srid(), 0, "st_subdivide", &mut pieces)?;
+    }
+    let result = GeoGeometry::MultiPolygon(MultiPolygon(pieces));
+    SurrealGeometry::from_geo(&result, *geom.srid()).map_err(FunctionError::from)
+}
+
+/// Recursively split a Polygon or MultiPolygon until every piece has an area
+/// of at most `max_area`, for balancing work by area rather than by vertex count.
+/// Each split halves the piece's bounding box across its longer side.
+/// A `max_area` that would need more than 2^20 pieces is rejected up front.
+/// Returns a GeometryCollection of the Polygon pieces.
+pub fn st_subdivide_by_area(
+    geom: &SurrealGeometry,
+    max_area: f64,
+) -> Result<SurrealGeometry, FunctionError> {
+    if !max_area.is_finite() || max_area <= 0.0 {
+        return Err(FunctionError::InvalidArgument(
+            "st_subdivide_by_area max_area must be positive and finite".to_string(),
+        ));
+    }
+    let polygons = polygon_parts(geom, "st_subdivide_by_area")?;
+    let area: f64 = polygons.iter().map(|p| p.unsigned_area()).sum();
+    if area / max_area > MAX_SUBDIVIDE_PIECES as f64 {
+        return Err(FunctionError::InvalidArgument(format!(
+            "st_subdivide_by_area max_area {max_area} would split an area of {area} \
+             into more than {MAX_SUBDIVIDE_PIECES} pieces"
+        )));
+    }
+
+    let mut pieces = Vec::new();
+    let fits = |p: &Polygon<f64>| p.unsigned_area() <= max_area;
+    for polygon in polygons {
+        subdivide(polygon, &fits, *geom.srid(), 0, "st_subdivide_by_area", &mut pieces)?;
+    }
+    let collection = GeoGeometry::GeometryCollection(geo_types::GeometryCollection(
+        pieces.into_iter().map(GeoGeometry::Polygon).collect(),
+    ));
+    SurrealGeometry::from_geo(&collection, *geom.srid()).map_err(FunctionError::from)
+}
+
+fn polygon_parts(geom: &SurrealGeometry, name: &str) -> Result<Vec<Polygon<f64>>, FunctionError> {
+    match geom.to_geo()? {
+        GeoGeometry::Polygon(p) => Ok(vec![p]),
+        GeoGeometry::MultiPolygon(mp) => Ok(mp.0),
+        _ => Err(FunctionError::InvalidArgument(format!(
+            "{name} requires a Polygon or MultiPolygon"
+        ))),
+    }
+}
+
+/// Split `polygon` in half until `fits` accepts every piece, appending the
+/// pieces to `out`. Fails rather than returning a piece that does not fit
+/// once `MAX_SUBDIVIDE_DEPTH` splits or `MAX_SUBDIVIDE_PIECES` pieces are
+/// reached.
+fn subdivide<F>(
+    polygon: Polygon<f64>,
+    fits: &F,
+    srid: Srid,
+    depth: usize,
+    name: &str,
+    out: &mut Vec<Polygon<f64>>,
+) -> Result<(), FunctionError>
+where
+    F: Fn(&Polygon<f64>) -> bool,
+{
+    if fits(&polygon) {
+        if out.len() == MAX_SUBDIVIDE_PIECES {
+            return Err(FunctionError::InvalidArgument(format!(
+                "{name} would produce more than {MAX_SUBDIVIDE_PIECES} pieces"
+            )));
+        }
+        out.push(polygon);
+        return Ok(());
+    }
+    let halves = match split_in_half(&polygon, srid)? {
+        Some(halves) if depth < MAX_SUBDIVIDE_DEPTH => halves,
+        _ => {
+            return Err(FunctionError::InvalidArgument(format!(
+                "{name} could not split a piece small enough within {MAX_SUBDIVIDE_DEPTH} levels"
+            )))
+        }
+    };
+    for piece in halves {
+        subdivide(piece, fits, srid, depth + 1, name, out)?;
+    }
+    Ok(())
+}
+
+/// Clip a polygon against the two halves of its bounding box, split across
+/// the longer side. Zero-area slivers are dropped. Returns None for an
+/// empty polygon.
+fn split_in_half(
+    polygon: &Polygon<f64>,
+    srid: Srid,
+) -> Result<Option<Vec<Polygon<f64>>>, FunctionError> {
+    let Some(rect) = polygon.bounding_rect() else {
+        return Ok(None);
+    };
+    let (min, max) = (rect.min(), rect.max());
+    let (first, second) = if rect.width() >= rect.height() {
+        let mid = (min.x + max.x) / 2.0;
+        (
+            Rect::new(min, Coord { x: mid, y: max.y }),
+            Rect::new(Coord { x: mid, y: min.y }, max),
+        )
+    } else {
+        let mid = (min.y + max.y) / 2.0;
+        (
+            Rect::new(min, Coord { x: max.x, y: mid }),
+            Rect::new(Coord { x: min.x, y: mid }, max),
+        )
+    };
+    let subject = SurrealGeometry::from_geo(&GeoGeometry::Polygon(polygon.clone()), srid)?;
+    let mut halves = Vec::new();
+    for half in [first, second] {
+        let half = SurrealGeometry::from_geo(&GeoGeometry::Polygon(half.to_polygon()), srid)?;
+        if let GeoGeometry::MultiPolygon(mp) = st_intersection(&subject, &half)?.to_geo()? {
+            halves.extend(mp.0.into_iter().filter(|piece| piece.unsigned_area() > 0.0));
+        }
+    }
+    Ok(Some(halves))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geo::BooleanOps;
+    use surrealgis_core::coordinate::Coordinate;
+    use surrealgis_core::geometry::GeometryType;
+
+    fn polygon(coords: &[(f64, f64)]) -> SurrealGeometry {
+        let exterior = coords
+            .iter()
+            .map(|&(x, y)| Coordinate::new(x, y).unwrap())
+            .collect();
+        SurrealGeometry::polygon(exterior, vec![], Srid::WEB_MERCATOR).unwrap()
+    }
+
+    fn piece_areas(result: &SurrealGeometry) -> Vec<f64> {
+        match result.geometry_type() {
+            GeometryType::GeometryCollection(items) => items
+                .iter()
+                .map(|g| {
+                    assert_eq!(g.type_name(), "Polygon");
+                    g.to_geo().unwrap().unsigned_area()
+                })
+                .collect(),
+            _ => panic!("Expected GeometryCollection"),
+        }
+    }
+
+    #[test]
+    fn square_splits_into_pieces_under_limit() {
+        let square = polygon(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0), (0.0, 0.0)]);
+        let result = st_subdivide_by_area(&square, 10.0).unwrap();
+        let areas = piece_areas(&result);
+
+        // 100 -> 50 -> 25 -> 12.5 -> 6.25
+        assert_eq!(areas.len(), 16);
+        assert!(areas.iter().all(|&a| a <= 10.0));
+        let total: f64 = areas.iter().sum();
+        assert!((total - 100.0).abs() < 1e-6, "total was {total}");
+        assert_eq!(result.srid().code(), Srid::WEB_MERCATOR.code());
+    }
+
+    #[test]
+    fn concave_polygon_preserves_area() {
+        // U shape, area 100 - 32 = 68
+        let u = polygon(&[
+            (0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (7.0, 10.0), (7.0, 2.0),
+            (3.0, 2.0), (3.0, 10.0), (0.0, 10.0), (0.0, 0.0),
+        ]);
+        let areas = piece_areas(&st_subdivide_by_area(&u, 5.0).unwrap());
+        assert!(areas.iter().all(|&a| a <= 5.0));
+        let total: f64 = areas.iter().sum();
+        assert!((total - 68.0).abs() < 1e-6, "total was {total}");
+    }
+
+    #[test]
+    fn small_polygon_is_returned_whole() {
+        let square = polygon(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0), (0.0, 0.0)]);
+        let areas = piece_areas(&st_subdivide_by_area(&square, 10.0).unwrap());
+        assert_eq!(areas.len(), 1);
+    }
+
+    #[test]
+    fn invalid_arguments_rejected() {
+        let square = polygon(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0), (0.0, 0.0)]);
+        assert!(st_subdivide_by_area(&square, 0.0).is_err());
+        assert!(st_subdivide_by_area(&square, f64::NAN).is_err());
+        // A million-plus pieces is refused before any splitting
+        assert!(matches!(
+            st_subdivide_by_area(&square, 1e-7),
+            Err(FunctionError::InvalidArgument(_))
+        ));
+        let point = SurrealGeometry::point(1.0, 1.0, Srid::WEB_MERCATOR).unwrap();
+        assert!(matches!(
+            st_subdivide_by_area(&point, 1.0),
+            Err(FunctionError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn piece_that_never_fits_is_an_error() {
+        let square = match polygon(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0), (0.0, 0.0)])
+            .to_geo()
+            .unwrap()
+        {
+            GeoGeometry::Polygon(p) => p,
+            other => panic!("Expected Polygon, got {other:?}"),
+        };
+        let mut out = Vec::new();
+        let never = |_: &Polygon<f64>| false;
+        let result = subdivide(square, &never, Srid::WEB_MERCATOR, 0, "st_subdivide", &mut out);
+        assert!(matches!(result, Err(FunctionError::InvalidArgument(_))));
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn dense_polygon_splits_under_vertex_budget() {
+        // 1000-gon approximating a circle of radius 100
+        let n = 1000;
+        let mut ring: Vec<(f64, f64)> = (0..n)
+            .map(|i| {
+                let t = i as f64 / n as f64 * std::f64::consts::TAU;
+                (100.0 * t.cos(), 100.0 * t.sin())
+            })
+            .collect();
+        ring.push(ring[0]);
+        let dense = polygon(&ring);
+
+        let result = st_subdivide(&dense, 64).unwrap();
+        let pieces = match result.to_geo().unwrap() {
+            GeoGeometry::MultiPolygon(mp) => mp,
+            other => panic!("Expected MultiPolygon, got {other:?}"),
+        };
+        assert!(pieces.0.len() > 1);
+        for piece in &pieces.0 {
+            assert!(piece.coords_count() <= 64, "piece had {} vertices", piece.coords_count());
+        }
+
+        // The pieces reassemble into the input, up to boolean-op rounding
+        let original = MultiPolygon(vec![match dense.to_geo().unwrap() {
+            GeoGeometry::Polygon(p) => p,
+            _ => unreachable!(),
+        }]);
+        let union = pieces
+            .0
+            .iter()
+            .fold(MultiPolygon(vec![]), |acc, p| acc.union(&MultiPolygon(vec![p.clone()])));
+        let mismatch = original.xor(&union).unsigned_area();
+        let tolerance = 1e-8 * original.unsigned_area();
+        assert!(mismatch < tolerance, "union differs from input by area {mismatch}");
+    }
+
+    #[test]
+    fn vertex_budget_below_five_rejected() {
+        let square = polygon(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0), (0.0, 0.0)]);
+        assert!(matches!(st_subdivide(&square, 4), Err(FunctionError::InvalidArgument(_))));
+        // Already within budget: one piece, still a MultiPolygon
+        let result = st_subdivide(&square, 5).unwrap();
+        assert!(matches!(result.geometry_type(), GeometryType::MultiPolygon(p) if p.len() == 1));
+    }
+}
@@ -0,0 +1,97 @@
+use geo::TriangulateEarcut;
+use surrealgis_core::geometry::SurrealGeometry;
+
+use crate::support::{supported_for, OpKind};
+use crate::FunctionError;
+
+/// Triangulate a Polygon or MultiPolygon, holes included.
+/// Unlike `st_delaunay_triangles`, which triangulates the convex hull of the
+/// input's vertices, the triangles here follow the polygon's rings: they
+/// cover the polygon exactly and none of them enters a hole.
+/// Returns a GeometryCollection of triangle Polygons.
+pub fn st_triangulate_polygon(geom: &SurrealGeometry) -> Result<SurrealGeometry, FunctionError> {
+    supported_for(OpKind::TriangulatePolygon, geom)?;
+    let polygons = match geom.to_geo()? {
+        geo_types::Geometry::Polygon(p) => vec![p],
+        geo_types::Geometry::MultiPolygon(mp) => mp.0,
+        _ => return Err(OpKind::TriangulatePolygon.mismatch(geom)),
+    };
+
+    let srid = *geom.srid();
+    let triangle_geoms: Result<Vec<SurrealGeometry>, _> = polygons
+        .iter()
+        .flat_map(|poly| poly.earcut_triangles())
+        .map(|tri| {
+            let geo = geo_types::Geometry::Polygon(tri.to_polygon());
+            SurrealGeometry::from_geo(&geo, srid).map_err(FunctionError::from)
+        })
+        .collect();
+
+    SurrealGeometry::geometry_collection(triangle_geoms?, srid).map_err(FunctionError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geo::{Area, BooleanOps};
+    use surrealgis_core::coordinate::Coordinate;
+    use surrealgis_core::geometry::GeometryType;
+    use surrealgis_core::srid::Srid;
+
+    fn ring(points: &[(f64, f64)]) -> Vec<Coordinate> {
+        points.iter().map(|&(x, y)| Coordinate::new(x, y).unwrap()).collect()
+    }
+
+    fn square_with_hole() -> SurrealGeometry {
+        let exterior = ring(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0), (0.0, 0.0)]);
+        let hole = ring(&[(4.0, 4.0), (6.0, 4.0), (6.0, 6.0), (4.0, 6.0), (4.0, 4.0)]);
+        SurrealGeometry::polygon(exterior, vec![hole], Srid::WEB_MERCATOR).unwrap()
+    }
+
+    fn triangles(result: &SurrealGeometry) -> Vec<geo_types::Polygon<f64>> {
+        match result.geometry_type() {
+            GeometryType::GeometryCollection(geoms) => geoms
+                .iter()
+                .map(|g| match g.to_geo().unwrap() {
+                    geo_types::Geometry::Polygon(p) => p,
+                    _ => panic!("Expected triangle Polygon"),
+                })
+                .collect(),
+            _ => panic!("Expected GeometryCollection"),
+        }
+    }
+
+    #[test]
+    fn triangles_avoid_hole_and_cover_polygon() {
+        let result = st_triangulate_polygon(&square_with_hole()).unwrap();
+        let tris = triangles(&result);
+        assert!(!tris.is_empty());
+        assert!(tris.iter().all(|t| t.exterior().0.len() == 4));
+
+        let hole = geo_types::Polygon::new(
+            geo_types::LineString::from(vec![(4.0, 4.0), (6.0, 4.0), (6.0, 6.0), (4.0, 6.0)]),
+            vec![],
+        );
+        for tri in &tris {
+            let overlap = tri.intersection(&hole).unsigned_area();
+            assert!(overlap < 1e-9, "triangle enters the hole by {overlap}");
+        }
+
+        let total: f64 = tris.iter().map(|t| t.unsigned_area()).sum();
+        assert!((total - 96.0).abs() < 1e-9, "total area {total}");
+        assert_eq!(result.srid().code(), Srid::WEB_MERCATOR.code());
+    }
+
+    #[test]
+    fn triangulate_rejects_linestring() {
+        let line = SurrealGeometry::line_string(
+            ring(&[(0.0, 0.0), (1.0, 1.0)]),
+            Srid::WEB_MERCATOR,
+        )
+        .unwrap();
+        assert!(matches!(
+            st_triangulate_polygon(&line),
+            Err(FunctionError::TypeMismatch { .. })
+        ));
+    }
+}
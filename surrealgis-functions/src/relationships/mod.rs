@@ -1,8 +1,16 @@
 mod predicates;
 mod st_relate;
+mod st_equals_crs_aware;
+mod st_equals_ignore_orientation;
+mod st_diff_report;
+mod st_is_simple;
 
 pub use predicates::{
     st_intersects, st_contains, st_within, st_touches, st_crosses,
     st_overlaps, st_disjoint, st_equals, st_covers, st_covered_by,
 };
-pub use st_relate::st_relate;
+pub use st_relate::{st_relate, st_relate_pattern};
+pub use st_equals_crs_aware::st_equals_crs_aware;
+pub use st_equals_ignore_orientation::st_equals_ignore_orientation;
+pub use st_diff_report::{st_diff_report, VertexChange};
+pub use st_is_simple::st_is_simple;
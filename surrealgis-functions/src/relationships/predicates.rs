@@ -28,9 +28,9 @@ pub fn st_intersects(a: &SurrealGeometry, b: &SurrealGeometry) -> Result<bool, F
     if let Some(result) = bbox_pre_filter(a, b) {
         return Ok(result);
     }
-    let ga = a.to_geo()?;
-    let gb = b.to_geo()?;
-    Ok(ga.relate(&gb).is_intersects())
+    let ga = a.to_geo_cached()?;
+    let gb = b.to_geo_cached()?;
+    Ok(ga.relate(gb).is_intersects())
 }
 
 /// Returns true if geometry A contains geometry B.
@@ -38,9 +38,9 @@ pub fn st_contains(a: &SurrealGeometry, b: &SurrealGeometry) -> Result<bool, Fun
     if let Some(false) = bbox_pre_filter(a, b) {
         return Ok(false);
     }
-    let ga = a.to_geo()?;
-    let gb = b.to_geo()?;
-    Ok(ga.relate(&gb).is_contains())
+    let ga = a.to_geo_cached()?;
+    let gb = b.to_geo_cached()?;
+    Ok(ga.relate(gb).is_contains())
 }
 
 /// Returns true if geometry A is within geometry B.
@@ -48,9 +48,9 @@ pub fn st_within(a: &SurrealGeometry, b: &SurrealGeometry) -> Result<bool, Funct
     if let Some(false) = bbox_pre_filter(a, b) {
         return Ok(false);
     }
-    let ga = a.to_geo()?;
-    let gb = b.to_geo()?;
-    Ok(ga.relate(&gb).is_within())
+    let ga = a.to_geo_cached()?;
+    let gb = b.to_geo_cached()?;
+    Ok(ga.relate(gb).is_within())
 }
 
 /// Returns true if the geometries touch (share boundary but not interior).
@@ -58,9 +58,9 @@ pub fn st_touches(a: &SurrealGeometry, b: &SurrealGeometry) -> Result<bool, Func
     if let Some(false) = bbox_pre_filter(a, b) {
         return Ok(false);
     }
-    let ga = a.to_geo()?;
-    let gb = b.to_geo()?;
-    Ok(ga.relate(&gb).is_touches())
+    let ga = a.to_geo_cached()?;
+    let gb = b.to_geo_cached()?;
+    Ok(ga.relate(gb).is_touches())
 }
 
 /// Returns true if the geometries cross each other.
@@ -68,9 +68,9 @@ pub fn st_crosses(a: &SurrealGeometry, b: &SurrealGeometry) -> Result<bool, Func
     if let Some(false) = bbox_pre_filter(a, b) {
         return Ok(false);
     }
-    let ga = a.to_geo()?;
-    let gb = b.to_geo()?;
-    Ok(ga.relate(&gb).is_crosses())
+    let ga = a.to_geo_cached()?;
+    let gb = b.to_geo_cached()?;
+    Ok(ga.relate(gb).is_crosses())
 }
 
 /// Returns true if the geometries overlap.
@@ -78,9 +78,9 @@ pub fn st_overlaps(a: &SurrealGeometry, b: &SurrealGeometry) -> Result<bool, Fun
     if let Some(false) = bbox_pre_filter(a, b) {
         return Ok(false);
     }
-    let ga = a.to_geo()?;
-    let gb = b.to_geo()?;
-    Ok(ga.relate(&gb).is_overlaps())
+    let ga = a.to_geo_cached()?;
+    let gb = b.to_geo_cached()?;
+    Ok(ga.relate(gb).is_overlaps())
 }
 
 /// Returns true if the geometries are spatially disjoint.
@@ -88,17 +88,21 @@ pub fn st_disjoint(a: &SurrealGeometry, b: &SurrealGeometry) -> Result<bool, Fun
     if let Some(result) = bbox_pre_filter_disjoint(a, b) {
         return Ok(result);
     }
-    let ga = a.to_geo()?;
-    let gb = b.to_geo()?;
-    Ok(!ga.relate(&gb).is_intersects())
+    let ga = a.to_geo_cached()?;
+    let gb = b.to_geo_cached()?;
+    Ok(!ga.relate(gb).is_intersects())
 }
 
-/// Returns true if the geometries are topologically equal.
+/// Returns true if the geometries are topologically equal: they cover the
+/// same point set (DE-9IM `T*F**FFF*`), whatever their vertex order or start
+/// point.
 pub fn st_equals(a: &SurrealGeometry, b: &SurrealGeometry) -> Result<bool, FunctionError> {
-    let ga = a.to_geo()?;
-    let gb = b.to_geo()?;
-    let matrix = ga.relate(&gb);
-    Ok(matrix.is_within() && matrix.is_contains())
+    if let Some(false) = bbox_pre_filter(a, b) {
+        return Ok(false);
+    }
+    let ga = a.to_geo_cached()?;
+    let gb = b.to_geo_cached()?;
+    Ok(ga.relate(gb).is_equal_topo())
 }
 
 /// Returns true if geometry A covers geometry B.
@@ -106,9 +110,9 @@ pub fn st_covers(a: &SurrealGeometry, b: &SurrealGeometry) -> Result<bool, Funct
     if let Some(false) = bbox_pre_filter(a, b) {
         return Ok(false);
     }
-    let ga = a.to_geo()?;
-    let gb = b.to_geo()?;
-    Ok(ga.relate(&gb).is_covers())
+    let ga = a.to_geo_cached()?;
+    let gb = b.to_geo_cached()?;
+    Ok(ga.relate(gb).is_covers())
 }
 
 /// Returns true if geometry A is covered by geometry B.
@@ -242,6 +246,52 @@ mod tests {
         assert!(st_equals(&poly_a(), &poly_a()).unwrap());
     }
 
+    #[test]
+    fn vertex_rotated_polygon_equals() {
+        let rotated = SurrealGeometry::polygon(
+            vec![
+                Coordinate::new(1.0, 1.0).unwrap(),
+                Coordinate::new(0.0, 1.0).unwrap(),
+                Coordinate::new(0.0, 0.0).unwrap(),
+                Coordinate::new(1.0, 0.0).unwrap(),
+                Coordinate::new(1.0, 1.0).unwrap(),
+            ],
+            vec![],
+            Srid::WGS84,
+        )
+        .unwrap();
+        let square = SurrealGeometry::polygon(
+            vec![
+                Coordinate::new(0.0, 0.0).unwrap(),
+                Coordinate::new(1.0, 0.0).unwrap(),
+                Coordinate::new(1.0, 1.0).unwrap(),
+                Coordinate::new(0.0, 1.0).unwrap(),
+                Coordinate::new(0.0, 0.0).unwrap(),
+            ],
+            vec![],
+            Srid::WGS84,
+        )
+        .unwrap();
+        assert!(st_equals(&square, &rotated).unwrap());
+        assert!(st_equals(&rotated, &square).unwrap());
+    }
+
+    #[test]
+    fn line_inside_polygon_not_equal() {
+        let line = SurrealGeometry::line_string(
+            vec![
+                Coordinate::new(0.25, 0.25).unwrap(),
+                Coordinate::new(0.75, 0.75).unwrap(),
+            ],
+            Srid::WGS84,
+        )
+        .unwrap();
+        assert!(st_contains(&poly_a(), &line).unwrap());
+        assert!(!st_equals(&poly_a(), &line).unwrap());
+        assert!(!st_equals(&line, &poly_a()).unwrap());
+        assert!(st_equals(&line, &line).unwrap());
+    }
+
     #[test]
     fn polygon_covers_interior_point() {
         assert!(st_covers(&poly_a(), &point_inside_a()).unwrap());
@@ -257,4 +307,26 @@ mod tests {
         // Far polygons should be rejected by bbox pre-filter
         assert!(!st_intersects(&poly_a(), &poly_far()).unwrap());
     }
+
+    #[test]
+    fn predicate_sees_mutation_after_cached_call() {
+        use surrealgis_core::geometry::GeometryType;
+        let mut a = poly_a();
+        let p = point_inside_a();
+        assert!(st_intersects(&a, &p).unwrap());
+
+        // Shrink the polygon to a triangle that misses the point. Its bbox
+        // still covers the point, so the answer has to come from a fresh
+        // conversion rather than the cached one.
+        let shrunk = [(0.2, 0.0), (2.0, 0.0), (2.0, 2.0), (0.2, 0.0)];
+        a.set_geometry_type(GeometryType::Polygon {
+            exterior: shrunk
+                .iter()
+                .map(|&(x, y)| Coordinate::new(x, y).unwrap())
+                .collect(),
+            holes: vec![],
+        });
+        assert!(!st_intersects(&a, &p).unwrap());
+        assert!(st_disjoint(&a, &p).unwrap());
+    }
 }
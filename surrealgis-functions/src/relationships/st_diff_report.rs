@@ -0,0 +1,249 @@
+use surrealgis_core::coordinate::Coordinate;
+use surrealgis_core::geometry::{GeometryType, SurrealGeometry};
+
+use crate::FunctionError;
+
+/// Largest alignment table `diff_part` will build, in cells.
+const MAX_ALIGNMENT_CELLS: usize = 1 << 24;
+
+/// A single vertex-level difference reported by [`st_diff_report`].
+///
+/// `part` numbers the coordinate sequences of the geometry in storage order
+/// (a polygon's exterior ring before its holes, members of multi geometries
+/// and collections in sequence). Indices are positions within that part.
+#[derive(Debug, Clone, PartialEq)]
+pub enum VertexChange {
+    /// A vertex present only in the new geometry, at `index` in the new part.
+    Added { part: usize, index: usize, coord: Coordinate },
+    /// A vertex present only in the old geometry, at `index` in the old part.
+    Removed { part: usize, index: usize, coord: Coordinate },
+    /// A vertex that moved further than the tolerance.
+    Moved {
+        part: usize,
+        old_index: usize,
+        new_index: usize,
+        from: Coordinate,
+        to: Coordinate,
+    },
+}
+
+/// Report the vertices added, removed, or moved further than `tolerance`
+/// between two versions of a geometry, for auditing edits.
+///
+/// Each part of `old` is aligned with the same part of `new` by a minimal
+/// edit script, so an inserted vertex shows up as one addition rather than
+/// shifting every later index. A vertex within `tolerance` of its counterpart
+/// is unchanged. Both geometries must have the same type.
+///
+/// Alignment needs memory proportional to the product of the two lengths
+/// left once matching vertices at both ends are trimmed, so a part where
+/// that product exceeds 2^24 (e.g. two unrelated 4096-vertex rings) is
+/// rejected with `InvalidArgument`.
+pub fn st_diff_report(
+    old: &SurrealGeometry,
+    new: &SurrealGeometry,
+    tolerance: f64,
+) -> Result<Vec<VertexChange>, FunctionError> {
+    if !tolerance.is_finite() || tolerance < 0.0 {
+        return Err(FunctionError::InvalidArgument(
+            "Tolerance must be finite and non-negative".to_string(),
+        ));
+    }
+    if old.type_name() != new.type_name() {
+        return Err(FunctionError::InvalidArgument(format!(
+            "st_diff_report requires geometries of the same type, got {} and {}",
+            old.type_name(),
+            new.type_name()
+        )));
+    }
+
+    let (old_parts, new_parts) = (parts(old), parts(new));
+    let mut changes = Vec::new();
+    for part in 0..old_parts.len().max(new_parts.len()) {
+        let a = old_parts.get(part).copied().unwrap_or_default();
+        let b = new_parts.get(part).copied().unwrap_or_default();
+        diff_part(part, a, b, tolerance, &mut changes)?;
+    }
+    Ok(changes)
+}
+
+fn parts(geom: &SurrealGeometry) -> Vec<&[Coordinate]> {
+    match geom.geometry_type() {
+        GeometryType::Point(c) => vec![std::slice::from_ref(c)],
+        GeometryType::LineString(coords) | GeometryType::MultiPoint(coords) => vec![coords],
+        GeometryType::Polygon { exterior, holes } => std::iter::once(exterior)
+            .chain(holes)
+            .map(Vec::as_slice)
+            .collect(),
+        GeometryType::MultiLineString(lines) => lines.iter().map(Vec::as_slice).collect(),
+        GeometryType::MultiPolygon(polygons) => polygons
+            .iter()
+            .flat_map(|p| std::iter::once(&p.exterior).chain(&p.holes))
+            .map(Vec::as_slice)
+            .collect(),
+        GeometryType::GeometryCollection(geoms) => geoms.iter().flat_map(parts).collect(),
+    }
+}
+
+/// Align two vertex sequences and append their differences.
+///
+/// Vertices matching within tolerance at either end are skipped before
+/// running the quadratic alignment, so typical small edits stay cheap.
+fn diff_part(
+    part: usize,
+    old: &[Coordinate],
+    new: &[Coordinate],
+    tolerance: f64,
+    out: &mut Vec<VertexChange>,
+) -> Result<(), FunctionError> {
+    let same = |a: &Coordinate, b: &Coordinate| distance(a, b) <= tolerance;
+    let prefix = old.iter().zip(new).take_while(|(a, b)| same(a, b)).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| same(a, b))
+        .count();
+    let a = &old[prefix..old.len() - suffix];
+    let b = &new[prefix..new.len() - suffix];
+
+    // cost[i][j]: edits to turn a[i..] into b[j..]; a move counts as one edit
+    let (n, m) = (a.len(), b.len());
+    if (n + 1).saturating_mul(m + 1) > MAX_ALIGNMENT_CELLS {
+        return Err(FunctionError::InvalidArgument(format!(
+            "st_diff_report part {part} differs over {n} old and {m} new vertices, \
+             too many to align"
+        )));
+    }
+    let mut cost = vec![vec![0u32; m + 1]; n + 1];
+    for i in (0..=n).rev() {
+        for j in (0..=m).rev() {
+            cost[i][j] = if i == n {
+                (m - j) as u32
+            } else if j == m {
+                (n - i) as u32
+            } else {
+                let substitute = cost[i + 1][j + 1] + u32::from(!same(&a[i], &b[j]));
+                substitute.min(cost[i + 1][j] + 1).min(cost[i][j + 1] + 1)
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m {
+            let moved = !same(&a[i], &b[j]);
+            if cost[i][j] == cost[i + 1][j + 1] + u32::from(moved) {
+                if moved {
+                    out.push(VertexChange::Moved {
+                        part,
+                        old_index: prefix + i,
+                        new_index: prefix + j,
+                        from: a[i].clone(),
+                        to: b[j].clone(),
+                    });
+                }
+                i += 1;
+                j += 1;
+                continue;
+            }
+        }
+        if i < n && (j == m || cost[i][j] == cost[i + 1][j] + 1) {
+            out.push(VertexChange::Removed { part, index: prefix + i, coord: a[i].clone() });
+            i += 1;
+        } else {
+            out.push(VertexChange::Added { part, index: prefix + j, coord: b[j].clone() });
+            j += 1;
+        }
+    }
+    Ok(())
+}
+
+fn distance(a: &Coordinate, b: &Coordinate) -> f64 {
+    (a.x() - b.x()).hypot(a.y() - b.y())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::line;
+    use surrealgis_core::srid::Srid;
+
+    fn c(x: f64, y: f64) -> Coordinate {
+        Coordinate::new(x, y).unwrap()
+    }
+
+    #[test]
+    fn moved_and_appended_vertices_reported() {
+        let old = line(&[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0)]);
+        let new = line(&[(0.0, 0.0), (1.0, 0.5), (2.0, 0.001), (3.0, 0.0)]);
+        let changes = st_diff_report(&old, &new, 0.01).unwrap();
+        assert_eq!(
+            changes,
+            vec![
+                VertexChange::Moved {
+                    part: 0,
+                    old_index: 1,
+                    new_index: 1,
+                    from: c(1.0, 0.0),
+                    to: c(1.0, 0.5),
+                },
+                VertexChange::Added { part: 0, index: 3, coord: c(3.0, 0.0) },
+            ]
+        );
+    }
+
+    #[test]
+    fn inserted_vertex_does_not_shift_later_ones() {
+        let old = line(&[(0.0, 0.0), (2.0, 0.0), (4.0, 0.0), (6.0, 0.0)]);
+        let new = line(&[(0.0, 0.0), (1.0, 1.0), (2.0, 0.0), (4.0, 0.0), (6.0, 0.0)]);
+        let changes = st_diff_report(&old, &new, 0.0).unwrap();
+        let added = VertexChange::Added { part: 0, index: 1, coord: c(1.0, 1.0) };
+        assert_eq!(changes, vec![added]);
+    }
+
+    #[test]
+    fn removed_vertex_and_hole_reported() {
+        let square = |s: f64| vec![c(0.0, 0.0), c(s, 0.0), c(s, s), c(0.0, s), c(0.0, 0.0)];
+        let hole = vec![c(1.0, 1.0), c(2.0, 1.0), c(2.0, 2.0), c(1.0, 1.0)];
+        let old = SurrealGeometry::polygon(square(10.0), vec![hole], Srid::WGS84).unwrap();
+        let mut shell = square(10.0);
+        shell.remove(2);
+        let new = SurrealGeometry::polygon(shell, vec![], Srid::WGS84).unwrap();
+
+        let changes = st_diff_report(&old, &new, 0.0).unwrap();
+        assert_eq!(changes[0], VertexChange::Removed { part: 0, index: 2, coord: c(10.0, 10.0) });
+        assert_eq!(changes.len(), 5);
+        assert!(changes[1..]
+            .iter()
+            .all(|ch| matches!(ch, VertexChange::Removed { part: 1, .. })));
+    }
+
+    #[test]
+    fn identical_geometries_have_no_changes() {
+        let l = line(&[(0.0, 0.0), (1.0, 1.0)]);
+        assert!(st_diff_report(&l, &l, 0.0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn oversized_alignment_rejected() {
+        let row = |y: f64| -> Vec<(f64, f64)> { (0..5000).map(|i| (i as f64, y)).collect() };
+        let (old, new) = (line(&row(0.0)), line(&row(1.0)));
+        assert!(matches!(
+            st_diff_report(&old, &new, 0.1),
+            Err(FunctionError::InvalidArgument(_))
+        ));
+        // Matching ends are trimmed first, so a single edit still aligns
+        let mut edited = row(0.0);
+        edited[2500].1 = 1.0;
+        assert_eq!(st_diff_report(&old, &line(&edited), 0.1).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn mismatched_types_and_bad_tolerance_rejected() {
+        let l = line(&[(0.0, 0.0), (1.0, 1.0)]);
+        let p = SurrealGeometry::point(0.0, 0.0, Srid::WEB_MERCATOR).unwrap();
+        assert!(st_diff_report(&l, &p, 0.0).is_err());
+        assert!(st_diff_report(&l, &l, -1.0).is_err());
+    }
+}
@@ -0,0 +1,47 @@
+use surrealgis_core::geometry::SurrealGeometry;
+use surrealgis_crs::registry;
+
+use crate::FunctionError;
+
+use super::st_equals;
+
+/// Returns true if the geometries are topologically equal and their SRIDs
+/// describe the same horizontal CRS (e.g. 4326 and 4979).
+/// Equivalent CRSs are compared without reprojection; geometries in
+/// non-equivalent CRSs are never equal.
+pub fn st_equals_crs_aware(
+    a: &SurrealGeometry,
+    b: &SurrealGeometry,
+) -> Result<bool, FunctionError> {
+    if !registry::srid_equivalent(a.srid().code(), b.srid().code()) {
+        return Ok(false);
+    }
+    st_equals(a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use surrealgis_core::srid::Srid;
+
+    #[test]
+    fn equivalent_geographic_crs_are_equal() {
+        let a = SurrealGeometry::point(2.35, 48.85, Srid::WGS84).unwrap();
+        let b = SurrealGeometry::point(2.35, 48.85, Srid::new(4979).unwrap()).unwrap();
+        assert!(st_equals_crs_aware(&a, &b).unwrap());
+    }
+
+    #[test]
+    fn different_crs_are_not_equal() {
+        let a = SurrealGeometry::point(2.35, 48.85, Srid::WGS84).unwrap();
+        let b = SurrealGeometry::point(2.35, 48.85, Srid::WEB_MERCATOR).unwrap();
+        assert!(!st_equals_crs_aware(&a, &b).unwrap());
+    }
+
+    #[test]
+    fn equivalent_crs_different_coordinates() {
+        let a = SurrealGeometry::point(2.35, 48.85, Srid::WGS84).unwrap();
+        let b = SurrealGeometry::point(2.36, 48.85, Srid::new(4979).unwrap()).unwrap();
+        assert!(!st_equals_crs_aware(&a, &b).unwrap());
+    }
+}
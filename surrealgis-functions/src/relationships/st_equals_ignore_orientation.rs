@@ -0,0 +1,62 @@
+use surrealgis_core::geometry::SurrealGeometry;
+
+use crate::editors::st_normalize;
+use crate::FunctionError;
+
+/// Returns true if the geometries have the same SRID and the same vertices
+/// once ring winding, ring start points, and component order are ignored.
+///
+/// Both inputs are compared after [`st_normalize`], so a polygon equals its
+/// ring-reversed self. Unlike [`st_equals`](super::st_equals) this is a
+/// structural comparison: the same shape with extra collinear vertices is
+/// not equal.
+pub fn st_equals_ignore_orientation(
+    a: &SurrealGeometry,
+    b: &SurrealGeometry,
+) -> Result<bool, FunctionError> {
+    Ok(st_normalize(a)? == st_normalize(b)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use surrealgis_core::coordinate::Coordinate;
+    use surrealgis_core::srid::Srid;
+
+    fn polygon(coords: &[(f64, f64)]) -> SurrealGeometry {
+        let exterior = coords
+            .iter()
+            .map(|&(x, y)| Coordinate::new(x, y).unwrap())
+            .collect();
+        SurrealGeometry::polygon(exterior, vec![], Srid::WEB_MERCATOR).unwrap()
+    }
+
+    #[test]
+    fn cw_and_ccw_square_are_equal_ignoring_orientation() {
+        let ccw = polygon(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0), (0.0, 0.0)]);
+        let cw = polygon(&[(0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0), (0.0, 0.0)]);
+        assert_ne!(ccw, cw);
+        assert!(st_equals_ignore_orientation(&ccw, &cw).unwrap());
+    }
+
+    #[test]
+    fn different_start_vertex_is_equal() {
+        let a = polygon(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 0.0)]);
+        let b = polygon(&[(1.0, 1.0), (0.0, 0.0), (1.0, 0.0), (1.0, 1.0)]);
+        assert!(st_equals_ignore_orientation(&a, &b).unwrap());
+    }
+
+    #[test]
+    fn different_shapes_are_not_equal() {
+        let a = polygon(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 0.0)]);
+        let b = polygon(&[(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 0.0)]);
+        assert!(!st_equals_ignore_orientation(&a, &b).unwrap());
+    }
+
+    #[test]
+    fn different_srids_are_not_equal() {
+        let a = SurrealGeometry::point(1.0, 2.0, Srid::WGS84).unwrap();
+        let b = SurrealGeometry::point(1.0, 2.0, Srid::WEB_MERCATOR).unwrap();
+        assert!(!st_equals_ignore_orientation(&a, &b).unwrap());
+    }
+}
@@ -0,0 +1,251 @@
+use std::collections::HashSet;
+
+use surrealgis_core::coordinate::Coordinate;
+use surrealgis_core::geometry::{GeometryType, SurrealGeometry};
+
+use crate::FunctionError;
+
+/// Returns true if the geometry has no anomalous self-intersection or
+/// self-tangency, as in OGC `ST_IsSimple`.
+///
+/// A LineString is simple when its segments meet only where consecutive
+/// segments share a vertex; a closed line may also meet at its endpoints.
+/// Any crossing, any vertex touching another segment and any doubling back
+/// makes it non-simple. MultiPoints must not repeat a point and each ring of a
+/// Polygon must be simple. A MultiLineString is simple when every member is
+/// and two members meet only at points on the boundary of both, that is the
+/// endpoints of open lines. MultiPolygons and collections are simple when
+/// every member is. Repeated consecutive vertices are ignored.
+pub fn st_is_simple(geom: &SurrealGeometry) -> Result<bool, FunctionError> {
+    Ok(is_simple(geom.geometry_type()))
+}
+
+fn is_simple(geometry_type: &GeometryType) -> bool {
+    match geometry_type {
+        GeometryType::Point(_) => true,
+        GeometryType::MultiPoint(coords) => {
+            let mut seen = HashSet::with_capacity(coords.len());
+            coords.iter().all(|c| seen.insert(key(c)))
+        }
+        GeometryType::LineString(coords) => is_simple_line(coords),
+        GeometryType::MultiLineString(lines) => {
+            lines.iter().all(|l| is_simple_line(l))
+                && lines.iter().enumerate().all(|(i, a)| {
+                    lines[i + 1..].iter().all(|b| meet_at_boundary(a, b))
+                })
+        }
+        GeometryType::Polygon { exterior, holes } => {
+            is_simple_line(exterior) && holes.iter().all(|h| is_simple_line(h))
+        }
+        GeometryType::MultiPolygon(polygons) => polygons.iter().all(|p| {
+            is_simple_line(&p.exterior) && p.holes.iter().all(|h| is_simple_line(h))
+        }),
+        GeometryType::GeometryCollection(geoms) => {
+            geoms.iter().all(|g| is_simple(g.geometry_type()))
+        }
+    }
+}
+
+fn is_simple_line(coords: &[Coordinate]) -> bool {
+    let mut pts: Vec<&Coordinate> = Vec::with_capacity(coords.len());
+    for c in coords {
+        if pts.last().is_none_or(|p| key(p) != key(c)) {
+            pts.push(c);
+        }
+    }
+    let n = pts.len().saturating_sub(1);
+    if n < 2 {
+        return true;
+    }
+    let closed = key(pts[0]) == key(pts[n]);
+
+    for i in 0..n {
+        // Adjacent segments share a vertex; they only fail by doubling back
+        if i + 1 < n && folds_back(pts[i], pts[i + 1], pts[i + 2]) {
+            return false;
+        }
+        for j in i + 2..n {
+            let (p1, p2, q1, q2) = (pts[i], pts[i + 1], pts[j], pts[j + 1]);
+            if closed && i == 0 && j == n - 1 {
+                // First and last segments of a ring share the closing vertex
+                if folds_back(q1, q2, p2) {
+                    return false;
+                }
+                continue;
+            }
+            if segments_touch(p1, p2, q1, q2) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// True if two lines meet only at points on the boundary of both.
+fn meet_at_boundary(a: &[Coordinate], b: &[Coordinate]) -> bool {
+    let (boundary_a, boundary_b) = (boundary(a), boundary(b));
+    for p in a.windows(2) {
+        for q in b.windows(2) {
+            let (p1, p2, q1, q2) = (&p[0], &p[1], &q[0], &q[1]);
+            let d1 = orientation(q1, q2, p1);
+            let d2 = orientation(q1, q2, p2);
+            let d3 = orientation(p1, p2, q1);
+            let d4 = orientation(p1, p2, q2);
+            if d1 * d2 < 0.0 && d3 * d4 < 0.0 {
+                return false;
+            }
+            let mut touches: Vec<(u64, u64)> = [
+                (p1, d1 == 0.0 && on_segment(q1, q2, p1)),
+                (p2, d2 == 0.0 && on_segment(q1, q2, p2)),
+                (q1, d3 == 0.0 && on_segment(p1, p2, q1)),
+                (q2, d4 == 0.0 && on_segment(p1, p2, q2)),
+            ]
+            .into_iter()
+            .filter(|&(_, hit)| hit)
+            .map(|(c, _)| key(c))
+            .collect();
+            touches.sort_unstable();
+            touches.dedup();
+            // Two distinct touch points mean the segments overlap along a stretch
+            if touches.len() > 1
+                || touches
+                    .iter()
+                    .any(|k| !boundary_a.contains(k) || !boundary_b.contains(k))
+            {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// The endpoints of an open line; a closed line has no boundary.
+fn boundary(coords: &[Coordinate]) -> Vec<(u64, u64)> {
+    match (coords.first(), coords.last()) {
+        (Some(first), Some(last)) if key(first) != key(last) => vec![key(first), key(last)],
+        _ => Vec::new(),
+    }
+}
+
+/// True if `b -> c` reverses along `a -> b`, overlapping it.
+fn folds_back(a: &Coordinate, b: &Coordinate, c: &Coordinate) -> bool {
+    let dot = (b.x() - a.x()) * (c.x() - b.x()) + (b.y() - a.y()) * (c.y() - b.y());
+    orientation(a, b, c) == 0.0 && dot < 0.0
+}
+
+fn key(c: &Coordinate) -> (u64, u64) {
+    // Adding 0.0 folds -0.0 into 0.0 so both hash to the same key
+    ((c.x() + 0.0).to_bits(), (c.y() + 0.0).to_bits())
+}
+
+fn orientation(a: &Coordinate, b: &Coordinate, c: &Coordinate) -> f64 {
+    (b.x() - a.x()) * (c.y() - a.y()) - (b.y() - a.y()) * (c.x() - a.x())
+}
+
+fn on_segment(a: &Coordinate, b: &Coordinate, p: &Coordinate) -> bool {
+    p.x() >= a.x().min(b.x())
+        && p.x() <= a.x().max(b.x())
+        && p.y() >= a.y().min(b.y())
+        && p.y() <= a.y().max(b.y())
+}
+
+fn segments_touch(p1: &Coordinate, p2: &Coordinate, q1: &Coordinate, q2: &Coordinate) -> bool {
+    let d1 = orientation(q1, q2, p1);
+    let d2 = orientation(q1, q2, p2);
+    let d3 = orientation(p1, p2, q1);
+    let d4 = orientation(p1, p2, q2);
+    if d1 * d2 < 0.0 && d3 * d4 < 0.0 {
+        return true;
+    }
+    (d1 == 0.0 && on_segment(q1, q2, p1))
+        || (d2 == 0.0 && on_segment(q1, q2, p2))
+        || (d3 == 0.0 && on_segment(p1, p2, q1))
+        || (d4 == 0.0 && on_segment(p1, p2, q2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::line;
+    use surrealgis_core::srid::Srid;
+
+    #[test]
+    fn self_crossing_line_is_not_simple() {
+        let bowtie = line(&[(0.0, 0.0), (2.0, 2.0), (0.0, 2.0), (2.0, 0.0)]);
+        assert!(!st_is_simple(&bowtie).unwrap());
+    }
+
+    #[test]
+    fn monotone_line_is_simple() {
+        let l = line(&[(0.0, 0.0), (1.0, 0.5), (2.0, 2.0), (3.0, 2.5)]);
+        assert!(st_is_simple(&l).unwrap());
+    }
+
+    #[test]
+    fn self_tangent_line_is_not_simple() {
+        // The last vertex lands on the first segment
+        let l = line(&[(0.0, 0.0), (2.0, 0.0), (1.0, 1.0), (1.0, 0.0)]);
+        assert!(!st_is_simple(&l).unwrap());
+    }
+
+    #[test]
+    fn doubling_back_is_not_simple() {
+        let l = line(&[(0.0, 0.0), (2.0, 0.0), (1.0, 0.0)]);
+        assert!(!st_is_simple(&l).unwrap());
+    }
+
+    #[test]
+    fn closed_ring_is_simple() {
+        let ring = line(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0), (0.0, 0.0)]);
+        assert!(st_is_simple(&ring).unwrap());
+    }
+
+    #[test]
+    fn repeated_vertices_are_ignored() {
+        let l = line(&[(0.0, 0.0), (1.0, 0.0), (1.0, 0.0), (2.0, 1.0)]);
+        assert!(st_is_simple(&l).unwrap());
+    }
+
+    fn multi_line(lines: &[&[(f64, f64)]]) -> SurrealGeometry {
+        let lines = lines
+            .iter()
+            .map(|points| points.iter().map(|&(x, y)| Coordinate::new(x, y).unwrap()).collect())
+            .collect();
+        SurrealGeometry::multi_line_string(lines, Srid::WEB_MERCATOR).unwrap()
+    }
+
+    #[test]
+    fn multi_line_members_crossing_is_not_simple() {
+        let ml = multi_line(&[&[(0.0, 0.0), (2.0, 2.0)], &[(0.0, 2.0), (2.0, 0.0)]]);
+        assert!(!st_is_simple(&ml).unwrap());
+    }
+
+    #[test]
+    fn multi_line_members_joined_at_endpoints_is_simple() {
+        let ml = multi_line(&[&[(0.0, 0.0), (1.0, 0.0)], &[(1.0, 0.0), (1.0, 1.0)]]);
+        assert!(st_is_simple(&ml).unwrap());
+    }
+
+    #[test]
+    fn multi_line_endpoint_on_member_interior_is_not_simple() {
+        let ml = multi_line(&[&[(0.0, 0.0), (2.0, 0.0)], &[(1.0, 0.0), (1.0, 1.0)]]);
+        assert!(!st_is_simple(&ml).unwrap());
+    }
+
+    #[test]
+    fn multi_line_overlapping_members_is_not_simple() {
+        let ml = multi_line(&[&[(0.0, 0.0), (2.0, 0.0)], &[(1.0, 0.0), (3.0, 0.0)]]);
+        assert!(!st_is_simple(&ml).unwrap());
+    }
+
+    #[test]
+    fn multi_point_with_duplicate_is_not_simple() {
+        let coords = vec![
+            Coordinate::new(0.0, 0.0).unwrap(),
+            Coordinate::new(1.0, 1.0).unwrap(),
+            Coordinate::new(0.0, 0.0).unwrap(),
+        ];
+        let mp = SurrealGeometry::multi_point(coords, Srid::WEB_MERCATOR).unwrap();
+        assert!(!st_is_simple(&mp).unwrap());
+    }
+}
@@ -27,6 +27,37 @@ pub fn st_relate(a: &SurrealGeometry, b: &SurrealGeometry) -> Result<String, Fun
     Ok(matrix_to_string(&matrix))
 }
 
+/// Tests the DE-9IM matrix of `a` and `b` against a 9-character pattern.
+///
+/// Pattern characters follow the usual rules: `T` matches any intersection
+/// (`0`, `1` or `2`), `F` matches no intersection, `0`/`1`/`2` match that
+/// exact dimension and `*` matches anything. `T` and `F` are accepted in
+/// either case. For example `T*****FF*` is the "contains" pattern.
+pub fn st_relate_pattern(
+    a: &SurrealGeometry,
+    b: &SurrealGeometry,
+    pattern: &str,
+) -> Result<bool, FunctionError> {
+    let pattern: Vec<char> = pattern.chars().map(|c| c.to_ascii_uppercase()).collect();
+    if pattern.len() != 9 {
+        return Err(FunctionError::InvalidArgument(format!(
+            "DE-9IM pattern must have 9 characters, got {}",
+            pattern.len()
+        )));
+    }
+    if let Some(bad) = pattern.iter().find(|c| !matches!(c, 'T' | 'F' | '0' | '1' | '2' | '*')) {
+        return Err(FunctionError::InvalidArgument(format!(
+            "Invalid DE-9IM pattern character '{bad}', expected one of T, F, 0, 1, 2, *"
+        )));
+    }
+    let matrix = st_relate(a, b)?;
+    Ok(matrix.chars().zip(&pattern).all(|(m, p)| match p {
+        '*' => true,
+        'T' => matches!(m, '0' | '1' | '2'),
+        _ => m == *p,
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -82,4 +113,53 @@ mod tests {
         // Overlapping polygons should have "2" in the first position (interior-interior)
         assert_eq!(&matrix[0..1], "2");
     }
+
+    fn unit_square() -> SurrealGeometry {
+        SurrealGeometry::polygon(
+            vec![
+                Coordinate::new(0.0, 0.0).unwrap(),
+                Coordinate::new(2.0, 0.0).unwrap(),
+                Coordinate::new(2.0, 2.0).unwrap(),
+                Coordinate::new(0.0, 2.0).unwrap(),
+                Coordinate::new(0.0, 0.0).unwrap(),
+            ],
+            vec![],
+            Srid::WGS84,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn contains_pattern_matches_point_in_polygon() {
+        let poly = unit_square();
+        let inside = SurrealGeometry::point(1.0, 1.0, Srid::WGS84).unwrap();
+        let outside = SurrealGeometry::point(5.0, 5.0, Srid::WGS84).unwrap();
+        assert!(st_relate_pattern(&poly, &inside, "T*****FF*").unwrap());
+        assert!(!st_relate_pattern(&poly, &outside, "T*****FF*").unwrap());
+        // Within, from the point's side, in lowercase
+        assert!(st_relate_pattern(&inside, &poly, "t*f**f***").unwrap());
+    }
+
+    #[test]
+    fn exact_dimension_characters() {
+        let poly = unit_square();
+        let inside = SurrealGeometry::point(1.0, 1.0, Srid::WGS84).unwrap();
+        // Point interior meets polygon interior in dimension 0, not 2
+        assert!(st_relate_pattern(&inside, &poly, "0********").unwrap());
+        assert!(!st_relate_pattern(&inside, &poly, "2********").unwrap());
+    }
+
+    #[test]
+    fn rejects_malformed_patterns() {
+        let a = SurrealGeometry::point(0.0, 0.0, Srid::WGS84).unwrap();
+        for pattern in ["T*F**F**", "T*F**F****", "T*F**X***", ""] {
+            assert!(
+                matches!(
+                    st_relate_pattern(&a, &a, pattern),
+                    Err(FunctionError::InvalidArgument(_))
+                ),
+                "pattern {pattern:?} should be rejected"
+            );
+        }
+    }
 }
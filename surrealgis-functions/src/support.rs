@@ -0,0 +1,101 @@
+use surrealgis_core::geometry::SurrealGeometry;
+
+use crate::FunctionError;
+
+/// Operations whose geo-backed implementation only handles some geometry types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpKind {
+    Union,
+    Intersection,
+    Difference,
+    SymDifference,
+    Buffer,
+    BufferMitre,
+    TriangulatePolygon,
+}
+
+impl OpKind {
+    /// The SQL-facing function name, used in error messages.
+    pub fn name(self) -> &'static str {
+        match self {
+            OpKind::Union => "st_union",
+            OpKind::Intersection => "st_intersection",
+            OpKind::Difference => "st_difference",
+            OpKind::SymDifference => "st_sym_difference",
+            OpKind::Buffer => "st_buffer",
+            OpKind::BufferMitre => "st_buffer_mitre",
+            OpKind::TriangulatePolygon => "st_triangulate_polygon",
+        }
+    }
+
+    /// The geometry type names the operation accepts.
+    pub fn accepted_types(self) -> &'static [&'static str] {
+        match self {
+            OpKind::Union
+            | OpKind::Intersection
+            | OpKind::Difference
+            | OpKind::SymDifference
+            | OpKind::TriangulatePolygon => &["Polygon", "MultiPolygon"],
+            OpKind::Buffer => &["Point"],
+            OpKind::BufferMitre => &["Point", "LineString"],
+        }
+    }
+
+    /// The `TypeMismatch` for handing `geom` to this operation.
+    pub(crate) fn mismatch(self, geom: &SurrealGeometry) -> FunctionError {
+        FunctionError::type_mismatch(self.name(), self.accepted_types(), geom)
+    }
+}
+
+/// Check that `geom` is a type `op` can handle before converting it to geo.
+/// Returns a `TypeMismatch` naming the function, the accepted types and the
+/// type actually received, e.g. "st_union requires Polygon or MultiPolygon
+/// input, got LineString".
+pub fn supported_for(op: OpKind, geom: &SurrealGeometry) -> Result<(), FunctionError> {
+    if op.accepted_types().iter().any(|t| *t == geom.type_name()) {
+        Ok(())
+    } else {
+        Err(op.mismatch(geom))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use surrealgis_core::coordinate::Coordinate;
+    use surrealgis_core::srid::Srid;
+
+    fn line() -> SurrealGeometry {
+        let coords = vec![
+            Coordinate::new(0.0, 0.0).unwrap(),
+            Coordinate::new(1.0, 1.0).unwrap(),
+        ];
+        SurrealGeometry::line_string(coords, Srid::WEB_MERCATOR).unwrap()
+    }
+
+    #[test]
+    fn accepts_matching_types() {
+        let pt = SurrealGeometry::point(1.0, 2.0, Srid::WEB_MERCATOR).unwrap();
+        assert!(supported_for(OpKind::Buffer, &pt).is_ok());
+        assert!(supported_for(OpKind::BufferMitre, &pt).is_ok());
+        assert!(supported_for(OpKind::BufferMitre, &line()).is_ok());
+    }
+
+    #[test]
+    fn reports_accepted_and_actual_type() {
+        let err = supported_for(OpKind::Union, &line()).unwrap_err();
+        assert_eq!(
+            err,
+            FunctionError::TypeMismatch {
+                function: "st_union",
+                expected: vec!["Polygon", "MultiPolygon"],
+                got: "LineString".to_string(),
+            }
+        );
+        let err = supported_for(OpKind::Buffer, &line()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Type mismatch: st_buffer requires Point input, got LineString"
+        );
+    }
+}
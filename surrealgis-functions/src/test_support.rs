@@ -0,0 +1,23 @@
+//! Geometry fixtures shared by the unit tests. Everything is built in Web
+//! Mercator so planar measurements come back in plain units.
+
+use surrealgis_core::coordinate::Coordinate;
+use surrealgis_core::geometry::SurrealGeometry;
+use surrealgis_core::srid::Srid;
+
+pub(crate) fn coords(points: &[(f64, f64)]) -> Vec<Coordinate> {
+    points.iter().map(|&(x, y)| Coordinate::new(x, y).unwrap()).collect()
+}
+
+pub(crate) fn line(points: &[(f64, f64)]) -> SurrealGeometry {
+    SurrealGeometry::line_string(coords(points), Srid::WEB_MERCATOR).unwrap()
+}
+
+/// Closed ring of the axis-aligned square with lower-left corner `(x, y)`.
+pub(crate) fn square_ring(x: f64, y: f64, size: f64) -> Vec<Coordinate> {
+    coords(&[(x, y), (x + size, y), (x + size, y + size), (x, y + size), (x, y)])
+}
+
+pub(crate) fn square(x: f64, y: f64, size: f64) -> SurrealGeometry {
+    SurrealGeometry::polygon(square_ring(x, y, size), vec![], Srid::WEB_MERCATOR).unwrap()
+}
@@ -1,39 +1,120 @@
+use geo::{Distance, Euclidean};
 use rstar::{PointDistance, RTreeObject, AABB};
 use surrealgis_core::bbox::BoundingBox;
+use surrealgis_core::geometry::SurrealGeometry;
+
+use crate::spatial_index::IndexError;
+
+/// Conversion of a geometry's envelope into rstar's [`AABB`], for callers
+/// mixing our geometries into their own rstar trees.
+pub trait ToAabb {
+    /// The 2D envelope as an rstar `AABB`, or `None` when there is no bounding box.
+    fn to_aabb(&self) -> Option<AABB<[f64; 2]>>;
+}
+
+impl ToAabb for SurrealGeometry {
+    fn to_aabb(&self) -> Option<AABB<[f64; 2]>> {
+        self.bbox().map(bbox_to_aabb)
+    }
+}
+
+fn bbox_to_aabb(bbox: &BoundingBox) -> AABB<[f64; 2]> {
+    AABB::from_corners([bbox.min_x, bbox.min_y], [bbox.max_x, bbox.max_y])
+}
 
 /// Wrapper around a geometry ID and its bounding box envelope for use in an R*-tree.
 ///
+/// The ID is any user payload `T` (a record ID, a name, ...); it defaults to `usize`.
+///
+/// Entries built with [`IndexedGeometry::with_geometry`] also retain the geometry
+/// itself so queries can refine bbox candidates with exact distances.
+///
 /// `PartialEq` compares by `id` only, which is required for rstar's `remove` to work
 /// correctly when locating an entry by ID.
 #[derive(Debug, Clone)]
-pub struct IndexedGeometry {
-    id: usize,
+pub struct IndexedGeometry<T = usize> {
+    id: T,
     envelope: AABB<[f64; 2]>,
+    geometry: Option<geo_types::Geometry<f64>>,
 }
 
-impl IndexedGeometry {
+impl<T> IndexedGeometry<T> {
     /// Create a new indexed geometry from an ID and bounding box.
-    pub fn new(id: usize, bbox: &BoundingBox) -> Self {
-        let envelope = AABB::from_corners(
-            [bbox.min_x, bbox.min_y],
-            [bbox.max_x, bbox.max_y],
-        );
-        Self { id, envelope }
+    pub fn new(id: T, bbox: &BoundingBox) -> Self {
+        Self {
+            id,
+            envelope: bbox_to_aabb(bbox),
+            geometry: None,
+        }
+    }
+
+    /// Create a new indexed geometry that retains the geometry for exact refinement.
+    pub fn with_geometry(id: T, geom: &SurrealGeometry) -> Result<Self, IndexError> {
+        let bbox = geom.bbox().ok_or(IndexError::NoBoundingBox)?;
+        let geometry = geom
+            .to_geo()
+            .map_err(|e| IndexError::IndexError(e.to_string()))?;
+        Ok(Self {
+            geometry: Some(geometry),
+            ..Self::new(id, bbox)
+        })
+    }
+
+    /// Reassemble an entry from its stored parts.
+    #[cfg(feature = "serde")]
+    pub(crate) fn from_parts(
+        id: T,
+        envelope: AABB<[f64; 2]>,
+        geometry: Option<geo_types::Geometry<f64>>,
+    ) -> Self {
+        Self {
+            id,
+            envelope,
+            geometry,
+        }
     }
 
     /// Returns the geometry ID.
-    pub fn id(&self) -> usize {
-        self.id
+    pub fn id(&self) -> &T {
+        &self.id
+    }
+
+    /// Returns the entry's envelope as a bounding box.
+    pub fn bbox(&self) -> BoundingBox {
+        let (lower, upper) = (self.envelope.lower(), self.envelope.upper());
+        BoundingBox {
+            min_x: lower[0],
+            min_y: lower[1],
+            max_x: upper[0],
+            max_y: upper[1],
+        }
+    }
+
+    /// Distance from a point to this entry: exact when the geometry is retained,
+    /// otherwise the distance to the envelope.
+    pub fn distance_to_point(&self, point: &[f64; 2]) -> f64 {
+        match &self.geometry {
+            Some(geometry) => {
+                let query = geo_types::Geometry::Point(geo_types::Point::new(point[0], point[1]));
+                Euclidean.distance(&query, geometry)
+            }
+            None => self.envelope.distance_2(point).sqrt(),
+        }
+    }
+
+    /// Returns the retained geometry, if this entry was built with one.
+    pub fn geometry(&self) -> Option<&geo_types::Geometry<f64>> {
+        self.geometry.as_ref()
     }
 }
 
-impl PartialEq for IndexedGeometry {
+impl<T: PartialEq> PartialEq for IndexedGeometry<T> {
     fn eq(&self, other: &Self) -> bool {
         self.id == other.id
     }
 }
 
-impl RTreeObject for IndexedGeometry {
+impl<T> RTreeObject for IndexedGeometry<T> {
     type Envelope = AABB<[f64; 2]>;
 
     fn envelope(&self) -> Self::Envelope {
@@ -41,8 +122,30 @@ impl RTreeObject for IndexedGeometry {
     }
 }
 
-impl PointDistance for IndexedGeometry {
+impl<T> PointDistance for IndexedGeometry<T> {
     fn distance_2(&self, point: &[f64; 2]) -> f64 {
         self.envelope.distance_2(point)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use surrealgis_core::coordinate::Coordinate;
+    use surrealgis_core::srid::Srid;
+
+    #[test]
+    fn polygon_aabb_matches_bbox() {
+        let ring = [(1.0, 2.0), (7.0, 3.0), (5.0, 9.0), (-2.0, 4.0), (1.0, 2.0)]
+            .iter()
+            .map(|&(x, y)| Coordinate::new(x, y).unwrap())
+            .collect();
+        let poly = SurrealGeometry::polygon(ring, vec![], Srid::WEB_MERCATOR).unwrap();
+        let aabb = poly.to_aabb().unwrap();
+        let bbox = poly.bbox().unwrap();
+        assert_eq!(aabb.lower(), [bbox.min_x, bbox.min_y]);
+        assert_eq!(aabb.upper(), [bbox.max_x, bbox.max_y]);
+        assert_eq!(aabb.lower(), [-2.0, 2.0]);
+        assert_eq!(aabb.upper(), [7.0, 9.0]);
+    }
+}
@@ -0,0 +1,88 @@
+use geo::Centroid;
+use rstar::primitives::GeomWithData;
+use rstar::RTree;
+use surrealgis_core::geometry::SurrealGeometry;
+
+use crate::spatial_index::IndexError;
+
+/// For each geometry, find the positions of its `k` nearest others.
+///
+/// Builds one R*-tree over the geometries' centroids and queries it with each
+/// centroid in turn, skipping the geometry itself. `result[i]` holds up to `k`
+/// positions into `geoms`, nearest first by centroid-to-centroid Euclidean
+/// distance; ties come back in tree order. Fewer than `k` neighbors are
+/// returned when there are not enough other geometries.
+pub fn knn_self_join(geoms: &[SurrealGeometry], k: usize) -> Result<Vec<Vec<usize>>, IndexError> {
+    let mut centroids = Vec::with_capacity(geoms.len());
+    for (i, geom) in geoms.iter().enumerate() {
+        let centroid = geom
+            .to_geo()
+            .map_err(|e| IndexError::IndexError(e.to_string()))?
+            .centroid()
+            .ok_or_else(|| IndexError::IndexError(format!("Geometry {i} has no centroid")))?;
+        centroids.push(GeomWithData::new([centroid.x(), centroid.y()], i));
+    }
+    let tree = RTree::bulk_load(centroids.clone());
+
+    Ok(centroids
+        .iter()
+        .map(|query| {
+            tree.nearest_neighbor_iter(query.geom())
+                .filter(|candidate| candidate.data != query.data)
+                .take(k)
+                .map(|candidate| candidate.data)
+                .collect()
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use surrealgis_core::srid::Srid;
+
+    fn points_on_line(n: usize) -> Vec<SurrealGeometry> {
+        (0..n)
+            .map(|i| SurrealGeometry::point(i as f64 * 10.0, 5.0, Srid::WEB_MERCATOR).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn nearest_neighbor_is_adjacent_point() {
+        let n = 20;
+        let neighbors = knn_self_join(&points_on_line(n), 1).unwrap();
+        assert_eq!(neighbors.len(), n);
+        assert_eq!(neighbors[0], vec![1]);
+        assert_eq!(neighbors[n - 1], vec![n - 2]);
+        for (i, nn) in neighbors.iter().enumerate().take(n - 1).skip(1) {
+            assert!(nn[0] == i - 1 || nn[0] == i + 1, "point {i} got {nn:?}");
+        }
+    }
+
+    #[test]
+    fn two_nearest_are_both_sides() {
+        let neighbors = knn_self_join(&points_on_line(10), 2).unwrap();
+        for (i, nn) in neighbors.iter().enumerate().take(9).skip(1) {
+            let mut nn = nn.clone();
+            nn.sort();
+            assert_eq!(nn, vec![i - 1, i + 1]);
+        }
+        // An end point's second neighbor is two steps along
+        assert_eq!(neighbors[0], vec![1, 2]);
+    }
+
+    #[test]
+    fn never_returns_self_and_caps_at_others() {
+        let neighbors = knn_self_join(&points_on_line(3), 5).unwrap();
+        for (i, nn) in neighbors.iter().enumerate() {
+            assert_eq!(nn.len(), 2);
+            assert!(!nn.contains(&i));
+        }
+    }
+
+    #[test]
+    fn k_zero_and_empty_input() {
+        assert!(knn_self_join(&points_on_line(4), 0).unwrap().iter().all(Vec::is_empty));
+        assert!(knn_self_join(&[], 3).unwrap().is_empty());
+    }
+}
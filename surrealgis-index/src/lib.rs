@@ -1,8 +1,15 @@
 pub mod spatial_index;
 pub mod rtree_index;
+pub mod quadtree_index;
 pub mod indexed_geometry;
 pub mod bbox_filter;
+pub mod knn_join;
+
+#[cfg(test)]
+mod test_suite;
 
 pub use spatial_index::{IndexError, SpatialIndex};
-pub use rtree_index::RTreeSpatialIndex;
-pub use indexed_geometry::IndexedGeometry;
+pub use rtree_index::{RTreeSpatialIndex, RTreeSpatialIndexGeneric};
+pub use quadtree_index::QuadTreeSpatialIndex;
+pub use indexed_geometry::{IndexedGeometry, ToAabb};
+pub use knn_join::knn_self_join;
@@ -0,0 +1,476 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use rstar::PointDistance;
+use surrealgis_core::bbox::BoundingBox;
+use surrealgis_core::coordinate::Coordinate;
+use surrealgis_core::geometry::SurrealGeometry;
+
+use crate::bbox_filter::{bbox_contains, bbox_intersects};
+use crate::indexed_geometry::IndexedGeometry;
+use crate::spatial_index::{IndexError, SpatialIndex};
+
+/// Default number of entries a node holds before it splits.
+pub const DEFAULT_NODE_CAPACITY: usize = 16;
+
+/// Nodes at this depth never split, which bounds the tree for many
+/// coincident entries.
+const MAX_DEPTH: usize = 24;
+
+/// Region quadtree backed spatial index.
+///
+/// Entries live in the smallest node whose quadrant fully contains their
+/// bounding box, so inserts and removes never rebalance the tree. This suits
+/// clustered, frequently mutated data where R*-tree reinsertion is costly.
+/// A node splits into four quadrants once it holds more than its capacity.
+/// The root grows (and the tree is rebuilt) when an entry falls outside it.
+/// As with the R*-tree, entries keep only their bounding box unless added
+/// with [`insert_with_geometry`](Self::insert_with_geometry) or
+/// [`bulk_load_with_geometries`](Self::bulk_load_with_geometries).
+pub struct QuadTreeSpatialIndex<T = usize> {
+    root: Option<Node<T>>,
+    capacity: usize,
+    len: usize,
+}
+
+struct Node<T> {
+    bounds: BoundingBox,
+    depth: usize,
+    entries: Vec<IndexedGeometry<T>>,
+    children: Option<Box<[Node<T>; 4]>>,
+}
+
+impl<T> QuadTreeSpatialIndex<T> {
+    /// Create a new empty quadtree with the default node capacity.
+    pub fn new() -> Self {
+        Self::with_node_capacity(DEFAULT_NODE_CAPACITY)
+    }
+
+    /// Create a new empty quadtree whose nodes split above `capacity` entries.
+    pub fn with_node_capacity(capacity: usize) -> Self {
+        Self {
+            root: None,
+            capacity: capacity.max(1),
+            len: 0,
+        }
+    }
+
+    /// Insert a geometry by its ID, retaining the geometry so distance
+    /// queries refine this entry exactly rather than by its bounding box.
+    pub fn insert_with_geometry(
+        &mut self,
+        id: T,
+        geom: &SurrealGeometry,
+    ) -> Result<(), IndexError> {
+        self.insert_entry(IndexedGeometry::with_geometry(id, geom)?);
+        Ok(())
+    }
+
+    /// Bulk load geometries like [`SpatialIndex::bulk_load`], retaining each
+    /// geometry for exact-distance refinement.
+    pub fn bulk_load_with_geometries(
+        entries: Vec<(T, SurrealGeometry)>,
+    ) -> Result<Self, IndexError> {
+        let mut indexed = Vec::with_capacity(entries.len());
+        for (id, geom) in entries {
+            indexed.push(IndexedGeometry::with_geometry(id, &geom)?);
+        }
+        Ok(Self::load(indexed))
+    }
+
+    /// Build an index holding `indexed`, sizing the root once up front so no
+    /// entry forces a rebuild.
+    fn load(indexed: Vec<IndexedGeometry<T>>) -> Self {
+        let mut index = Self::new();
+        if let Some(first) = indexed.first() {
+            let extent = indexed
+                .iter()
+                .fold(first.bbox(), |acc, e| acc.expand(&e.bbox()));
+            index.root = Some(Node::new(square_around(&extent), 0));
+        }
+        for entry in indexed {
+            index.insert_entry(entry);
+        }
+        index
+    }
+
+    fn insert_entry(&mut self, entry: IndexedGeometry<T>) {
+        let bbox = entry.bbox();
+        let root = match self.root.take() {
+            Some(root) if bbox_contains(&root.bounds, &bbox) => root,
+            Some(root) => {
+                let bounds = grown_bounds(&root.bounds, &bbox);
+                let mut entries = Vec::with_capacity(self.len);
+                root.drain_into(&mut entries);
+                let mut grown = Node::new(bounds, 0);
+                for e in entries {
+                    grown.insert(e, self.capacity);
+                }
+                grown
+            }
+            None => Node::new(square_around(&bbox), 0),
+        };
+        let root = self.root.insert(root);
+        root.insert(entry, self.capacity);
+        self.len += 1;
+    }
+}
+
+impl<T> Default for QuadTreeSpatialIndex<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Node<T> {
+    fn new(bounds: BoundingBox, depth: usize) -> Self {
+        Self {
+            bounds,
+            depth,
+            entries: Vec::new(),
+            children: None,
+        }
+    }
+
+    fn insert(&mut self, entry: IndexedGeometry<T>, capacity: usize) {
+        if let Some(children) = &mut self.children {
+            let bbox = entry.bbox();
+            if let Some(child) = children.iter_mut().find(|c| bbox_contains(&c.bounds, &bbox)) {
+                child.insert(entry, capacity);
+                return;
+            }
+        }
+        self.entries.push(entry);
+        if self.children.is_none() && self.entries.len() > capacity && self.depth < MAX_DEPTH {
+            self.split(capacity);
+        }
+    }
+
+    fn split(&mut self, capacity: usize) {
+        let b = &self.bounds;
+        let mid_x = (b.min_x + b.max_x) / 2.0;
+        let mid_y = (b.min_y + b.max_y) / 2.0;
+        let quadrant = |min_x: f64, min_y: f64, max_x: f64, max_y: f64| {
+            Node::new(BoundingBox { min_x, min_y, max_x, max_y }, self.depth + 1)
+        };
+        let children = Box::new([
+            quadrant(b.min_x, b.min_y, mid_x, mid_y),
+            quadrant(mid_x, b.min_y, b.max_x, mid_y),
+            quadrant(b.min_x, mid_y, mid_x, b.max_y),
+            quadrant(mid_x, mid_y, b.max_x, b.max_y),
+        ]);
+        self.children = Some(children);
+        for entry in std::mem::take(&mut self.entries) {
+            self.insert(entry, capacity);
+        }
+    }
+
+    fn drain_into(self, out: &mut Vec<IndexedGeometry<T>>) {
+        out.extend(self.entries);
+        if let Some(children) = self.children {
+            for child in *children {
+                child.drain_into(out);
+            }
+        }
+    }
+
+    /// Visit entries in every node whose bounds pass `visit_node`.
+    fn visit<'a>(
+        &'a self,
+        visit_node: &impl Fn(&BoundingBox) -> bool,
+        f: &mut impl FnMut(&'a IndexedGeometry<T>),
+    ) {
+        if !visit_node(&self.bounds) {
+            return;
+        }
+        self.entries.iter().for_each(&mut *f);
+        if let Some(children) = &self.children {
+            for child in children.iter() {
+                child.visit(visit_node, f);
+            }
+        }
+    }
+
+    /// Remove the first entry matching `pred`, searching depth-first.
+    fn remove_where(&mut self, pred: &impl Fn(&IndexedGeometry<T>) -> bool) -> bool {
+        if let Some(i) = self.entries.iter().position(pred) {
+            self.entries.swap_remove(i);
+            return true;
+        }
+        match &mut self.children {
+            Some(children) => children.iter_mut().any(|c| c.remove_where(pred)),
+            None => false,
+        }
+    }
+}
+
+/// Smallest square containing `bbox`, padded so degenerate boxes still have area.
+fn square_around(bbox: &BoundingBox) -> BoundingBox {
+    let half = ((bbox.max_x - bbox.min_x).max(bbox.max_y - bbox.min_y) / 2.0).max(1.0);
+    let cx = (bbox.min_x + bbox.max_x) / 2.0;
+    let cy = (bbox.min_y + bbox.max_y) / 2.0;
+    BoundingBox {
+        min_x: cx - half,
+        min_y: cy - half,
+        max_x: cx + half,
+        max_y: cy + half,
+    }
+}
+
+/// Root bounds doubled until they also contain `bbox`.
+fn grown_bounds(bounds: &BoundingBox, bbox: &BoundingBox) -> BoundingBox {
+    let mut grown = bounds.clone();
+    while !bbox_contains(&grown, bbox) {
+        let w = grown.max_x - grown.min_x;
+        let h = grown.max_y - grown.min_y;
+        if bbox.min_x < grown.min_x {
+            grown.min_x -= w;
+        } else {
+            grown.max_x += w;
+        }
+        if bbox.min_y < grown.min_y {
+            grown.min_y -= h;
+        } else {
+            grown.max_y += h;
+        }
+    }
+    grown
+}
+
+/// Distance from a point to a bounding box (0 inside).
+fn bbox_distance(bbox: &BoundingBox, pt: &[f64; 2]) -> f64 {
+    let dx = (bbox.min_x - pt[0]).max(pt[0] - bbox.max_x).max(0.0);
+    let dy = (bbox.min_y - pt[1]).max(pt[1] - bbox.max_y).max(0.0);
+    dx.hypot(dy)
+}
+
+/// Best-first search item, ordered so the heap pops the smallest distance.
+enum Candidate<'a, T> {
+    Node(&'a Node<T>),
+    Entry(&'a IndexedGeometry<T>),
+}
+
+struct Queued<'a, T> {
+    dist: f64,
+    candidate: Candidate<'a, T>,
+}
+
+impl<T> PartialEq for Queued<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.dist.total_cmp(&other.dist) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for Queued<'_, T> {}
+
+impl<T> PartialOrd for Queued<'_, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Queued<'_, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.dist.total_cmp(&self.dist)
+    }
+}
+
+impl<T: Clone + PartialEq> SpatialIndex<T> for QuadTreeSpatialIndex<T> {
+    fn insert(&mut self, id: T, geom: &SurrealGeometry) -> Result<(), IndexError> {
+        let bbox = geom.bbox().ok_or(IndexError::NoBoundingBox)?;
+        self.insert_entry(IndexedGeometry::new(id, bbox));
+        Ok(())
+    }
+
+    fn bulk_load(entries: Vec<(T, SurrealGeometry)>) -> Result<Self, IndexError> {
+        let mut indexed = Vec::with_capacity(entries.len());
+        for (id, geom) in entries {
+            let bbox = geom.bbox().ok_or(IndexError::NoBoundingBox)?;
+            indexed.push(IndexedGeometry::new(id, bbox));
+        }
+        Ok(Self::load(indexed))
+    }
+
+    fn query_bbox(&self, bbox: &BoundingBox) -> Vec<T> {
+        let mut results = Vec::new();
+        if let Some(root) = &self.root {
+            let in_range = |bounds: &BoundingBox| bbox_intersects(bounds, bbox);
+            root.visit(&in_range, &mut |entry: &IndexedGeometry<T>| {
+                if bbox_intersects(&entry.bbox(), bbox) {
+                    results.push(entry.id().clone());
+                }
+            });
+        }
+        results
+    }
+
+    fn query_bbox_contained(&self, bbox: &BoundingBox) -> Vec<T> {
+        let mut results = Vec::new();
+        if let Some(root) = &self.root {
+            let in_range = |bounds: &BoundingBox| bbox_intersects(bounds, bbox);
+            root.visit(&in_range, &mut |entry: &IndexedGeometry<T>| {
+                if bbox_contains(bbox, &entry.bbox()) {
+                    results.push(entry.id().clone());
+                }
+            });
+        }
+        results
+    }
+
+    /// Best-first search: nodes are queued by their bounds distance and
+    /// entries by their distance (exact when the geometry is retained), so
+    /// entries pop in distance order.
+    fn query_nearest(&self, point: &Coordinate, k: usize) -> Vec<(T, f64)> {
+        let Some(root) = &self.root else {
+            return Vec::new();
+        };
+        let pt = [point.x(), point.y()];
+        let mut heap = BinaryHeap::new();
+        heap.push(Queued {
+            dist: bbox_distance(&root.bounds, &pt),
+            candidate: Candidate::Node(root),
+        });
+
+        let mut results = Vec::with_capacity(k);
+        while results.len() < k {
+            let Some(Queued { dist, candidate }) = heap.pop() else {
+                break;
+            };
+            match candidate {
+                Candidate::Entry(entry) => results.push((entry.id().clone(), dist)),
+                Candidate::Node(node) => {
+                    for entry in &node.entries {
+                        heap.push(Queued {
+                            dist: entry.distance_to_point(&pt),
+                            candidate: Candidate::Entry(entry),
+                        });
+                    }
+                    if let Some(children) = &node.children {
+                        for child in children.iter() {
+                            heap.push(Queued {
+                                dist: bbox_distance(&child.bounds, &pt),
+                                candidate: Candidate::Node(child),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        results
+    }
+
+    fn query_within_distance(&self, point: &Coordinate, distance: f64) -> Vec<T> {
+        let pt = [point.x(), point.y()];
+        let distance_sq = distance * distance;
+        let mut results = Vec::new();
+        if let Some(root) = &self.root {
+            let in_range = |bounds: &BoundingBox| bbox_distance(bounds, &pt) <= distance;
+            root.visit(&in_range, &mut |entry: &IndexedGeometry<T>| {
+                if entry.distance_2(&pt) <= distance_sq {
+                    results.push(entry.id().clone());
+                }
+            });
+        }
+        results
+    }
+
+    fn remove(&mut self, id: T) -> bool {
+        let removed = self
+            .root
+            .as_mut()
+            .is_some_and(|root| root.remove_where(&|e: &IndexedGeometry<T>| *e.id() == id));
+        if removed {
+            self.len -= 1;
+        }
+        removed
+    }
+
+    /// An entry that retained its geometry keeps retaining it after the update.
+    fn update(&mut self, id: T, geom: &SurrealGeometry) -> Result<bool, IndexError> {
+        let mut retained = false;
+        if let Some(root) = &self.root {
+            root.visit(&|_: &BoundingBox| true, &mut |e: &IndexedGeometry<T>| {
+                retained |= *e.id() == id && e.geometry().is_some();
+            });
+        }
+        // Build the new entry first so a bad geometry leaves the old one in place
+        let entry = if retained {
+            IndexedGeometry::with_geometry(id.clone(), geom)?
+        } else {
+            let bbox = geom.bbox().ok_or(IndexError::NoBoundingBox)?;
+            IndexedGeometry::new(id.clone(), bbox)
+        };
+        let existed = self.remove(id);
+        self.insert_entry(entry);
+        Ok(existed)
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use surrealgis_core::srid::Srid;
+
+    crate::test_suite::spatial_index_test_suite!(crate::quadtree_index::QuadTreeSpatialIndex);
+
+    fn make_point(x: f64, y: f64) -> SurrealGeometry {
+        SurrealGeometry::point(x, y, Srid::WGS84).unwrap()
+    }
+
+    fn depth(node: &Node<usize>) -> usize {
+        match &node.children {
+            Some(children) => 1 + children.iter().map(depth).max().unwrap_or(0),
+            None => 0,
+        }
+    }
+
+    #[test]
+    fn nodes_split_above_capacity() {
+        let mut index = QuadTreeSpatialIndex::with_node_capacity(4);
+        for i in 0..64 {
+            index.insert(i, &make_point((i % 8) as f64, (i / 8) as f64)).unwrap();
+        }
+        let root = index.root.as_ref().unwrap();
+        assert!(root.children.is_some());
+        assert!(depth(root) >= 2);
+        assert_eq!(index.len(), 64);
+    }
+
+    #[test]
+    fn root_grows_for_out_of_bounds_insert() {
+        let mut index = QuadTreeSpatialIndex::new();
+        index.insert(0, &make_point(0.0, 0.0)).unwrap();
+        index.insert(1, &make_point(1000.0, -500.0)).unwrap();
+        index.insert(2, &make_point(-2000.0, 3000.0)).unwrap();
+
+        let all = BoundingBox::new(-5000.0, -5000.0, 5000.0, 5000.0).unwrap();
+        let mut ids = index.query_bbox(&all);
+        ids.sort();
+        assert_eq!(ids, vec![0, 1, 2]);
+        assert_eq!(index.len(), 3);
+    }
+
+    #[test]
+    fn coincident_points_respect_max_depth() {
+        let mut index = QuadTreeSpatialIndex::with_node_capacity(1);
+        for i in 0..100 {
+            index.insert(i, &make_point(5.0, 5.0)).unwrap();
+        }
+        assert!(depth(index.root.as_ref().unwrap()) <= MAX_DEPTH);
+        let nearest = index.query_nearest(&Coordinate::new(5.0, 5.0).unwrap(), 100);
+        assert_eq!(nearest.len(), 100);
+    }
+
+    #[test]
+    fn string_payload() {
+        let mut index = QuadTreeSpatialIndex::new();
+        index.insert("a".to_string(), &make_point(1.0, 1.0)).unwrap();
+        index.insert("b".to_string(), &make_point(9.0, 9.0)).unwrap();
+        let near = index.query_nearest(&Coordinate::new(8.0, 8.0).unwrap(), 1);
+        assert_eq!(near[0].0, "b");
+    }
+}
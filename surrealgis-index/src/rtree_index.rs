@@ -1,47 +1,244 @@
-use rstar::{PointDistance, RTree, AABB};
+use geo::{Distance, Euclidean};
+use geo_types::{coord, Rect};
+use rstar::{Envelope, PointDistance, RTree, RTreeObject, AABB};
 use surrealgis_core::bbox::BoundingBox;
 use surrealgis_core::coordinate::Coordinate;
 use surrealgis_core::geometry::SurrealGeometry;
 
+use crate::bbox_filter::{bbox_contains, expand_bbox};
 use crate::indexed_geometry::IndexedGeometry;
 use crate::spatial_index::{IndexError, SpatialIndex};
 
 /// R*-tree backed spatial index.
 ///
 /// Uses the `rstar` crate's R*-tree implementation for efficient spatial queries.
-/// Geometries are stored as bounding box envelopes keyed by `usize` IDs.
-pub struct RTreeSpatialIndex {
-    tree: RTree<IndexedGeometry>,
+/// Geometries are stored as bounding box envelopes keyed by a payload `T`
+/// (e.g. a record ID). Entries added with [`insert_with_geometry`] or
+/// [`bulk_load_with_geometries`] also keep the geometry itself for
+/// exact-distance refinement, at the cost of holding a copy of it.
+///
+/// [`insert_with_geometry`]: Self::insert_with_geometry
+/// [`bulk_load_with_geometries`]: Self::bulk_load_with_geometries
+pub struct RTreeSpatialIndexGeneric<T> {
+    tree: RTree<IndexedGeometry<T>>,
 }
 
-impl RTreeSpatialIndex {
+/// R*-tree index keyed by `usize` IDs.
+pub type RTreeSpatialIndex = RTreeSpatialIndexGeneric<usize>;
+
+impl<T> RTreeSpatialIndexGeneric<T> {
     /// Create a new empty spatial index.
     pub fn new() -> Self {
         Self {
             tree: RTree::new(),
         }
     }
+
+    /// Insert a geometry by its ID, retaining the geometry so distance
+    /// queries refine this entry exactly rather than by its bounding box.
+    pub fn insert_with_geometry(
+        &mut self,
+        id: T,
+        geom: &SurrealGeometry,
+    ) -> Result<(), IndexError> {
+        self.tree.insert(IndexedGeometry::with_geometry(id, geom)?);
+        Ok(())
+    }
+
+    /// Bulk load geometries like [`SpatialIndex::bulk_load`], retaining each
+    /// geometry for exact-distance refinement.
+    pub fn bulk_load_with_geometries(
+        entries: Vec<(T, SurrealGeometry)>,
+    ) -> Result<Self, IndexError> {
+        let mut indexed = Vec::with_capacity(entries.len());
+        for (id, geom) in entries {
+            indexed.push(IndexedGeometry::with_geometry(id, &geom)?);
+        }
+        Ok(Self {
+            tree: RTree::bulk_load(indexed),
+        })
+    }
+}
+
+impl<T: Clone + PartialEq> RTreeSpatialIndexGeneric<T> {
+    /// Find all geometries within `distance` of the query geometry, returning
+    /// `(id, distance)` pairs sorted by ascending distance.
+    ///
+    /// The query bbox is expanded by `distance` for a coarse R-tree filter, then
+    /// each candidate is refined with the exact Euclidean distance between the
+    /// geometries (in the index's coordinate units). Entries without a retained
+    /// geometry are measured from the exact query geometry to their envelope.
+    pub fn query_geometry_within_distance(
+        &self,
+        geom: &SurrealGeometry,
+        distance: f64,
+    ) -> Result<Vec<(T, f64)>, IndexError> {
+        if !distance.is_finite() || distance < 0.0 {
+            return Err(IndexError::IndexError(format!(
+                "Distance must be a non-negative finite number, got {distance}"
+            )));
+        }
+        let bbox = geom.bbox().ok_or(IndexError::NoBoundingBox)?;
+        let query = geom
+            .to_geo()
+            .map_err(|e| IndexError::IndexError(e.to_string()))?;
+        let expanded = expand_bbox(bbox, distance);
+        let envelope = to_envelope(&expanded);
+
+        let mut results: Vec<(T, f64)> = self
+            .tree
+            .locate_in_envelope_intersecting(&envelope)
+            .filter_map(|entry| {
+                let d = match entry.geometry() {
+                    Some(candidate) => Euclidean.distance(&query, candidate),
+                    None => Euclidean.distance(&query, &envelope_rect(&entry.envelope())),
+                };
+                (d <= distance).then(|| (entry.id().clone(), d))
+            })
+            .collect();
+        results.sort_by(|a, b| a.1.total_cmp(&b.1));
+        Ok(results)
+    }
+
+    /// Compute what changes when a viewport moves from `old_bbox` to `new_bbox`,
+    /// returning `(entered, exited)` IDs: entries intersecting only the new window
+    /// and entries intersecting only the old one. Entries visible in both windows
+    /// are reported in neither.
+    pub fn query_window_delta(
+        &self,
+        old_bbox: &BoundingBox,
+        new_bbox: &BoundingBox,
+    ) -> (Vec<T>, Vec<T>) {
+        let old_env = to_envelope(old_bbox);
+        let new_env = to_envelope(new_bbox);
+        let only_in = |window: &AABB<[f64; 2]>, other: &AABB<[f64; 2]>| -> Vec<T> {
+            self.tree
+                .locate_in_envelope_intersecting(window)
+                .filter(|entry| !entry.envelope().intersects(other))
+                .map(|entry| entry.id().clone())
+                .collect()
+        };
+        (only_in(&new_env, &old_env), only_in(&old_env, &new_env))
+    }
+}
+
+fn to_envelope(bbox: &BoundingBox) -> AABB<[f64; 2]> {
+    AABB::from_corners([bbox.min_x, bbox.min_y], [bbox.max_x, bbox.max_y])
 }
 
-impl Default for RTreeSpatialIndex {
+/// Version tag written ahead of persisted entries, bumped on layout changes.
+#[cfg(feature = "serde")]
+const PERSIST_VERSION: u32 = 1;
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct PersistedIndex<T> {
+    version: u32,
+    entries: Vec<PersistedEntry<T>>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct PersistedEntry<T> {
+    id: T,
+    /// `[min_x, min_y, max_x, max_y]`
+    envelope: [f64; 4],
+    geometry: Option<geo_types::Geometry<f64>>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> RTreeSpatialIndexGeneric<T>
+where
+    T: Clone + PartialEq + serde::Serialize + serde::de::DeserializeOwned,
+{
+    /// Serialize the index entries with bincode.
+    ///
+    /// rstar trees are not serializable themselves, so only the entries are
+    /// written; [`from_bytes`](Self::from_bytes) rebuilds the tree with a bulk load.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, IndexError> {
+        let entries = self
+            .tree
+            .iter()
+            .map(|entry| {
+                let envelope = entry.envelope();
+                let (lower, upper) = (envelope.lower(), envelope.upper());
+                PersistedEntry {
+                    id: entry.id().clone(),
+                    envelope: [lower[0], lower[1], upper[0], upper[1]],
+                    geometry: entry.geometry().cloned(),
+                }
+            })
+            .collect();
+        let persisted = PersistedIndex {
+            version: PERSIST_VERSION,
+            entries,
+        };
+        bincode::serialize(&persisted).map_err(|e| IndexError::IndexError(e.to_string()))
+    }
+
+    /// Rebuild an index from bytes produced by [`to_bytes`](Self::to_bytes).
+    ///
+    /// Every stored envelope must be finite with `min <= max` on both axes;
+    /// anything else is rejected rather than loaded into the tree.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, IndexError> {
+        let persisted: PersistedIndex<T> =
+            bincode::deserialize(bytes).map_err(|e| IndexError::IndexError(e.to_string()))?;
+        if persisted.version != PERSIST_VERSION {
+            return Err(IndexError::IndexError(format!(
+                "Unsupported index format version {}, expected {PERSIST_VERSION}",
+                persisted.version
+            )));
+        }
+        let entries = persisted
+            .entries
+            .into_iter()
+            .map(|e| {
+                let invalid = |reason: String| {
+                    IndexError::IndexError(format!(
+                        "Invalid envelope {:?} in persisted index: {reason}",
+                        e.envelope
+                    ))
+                };
+                if !e.envelope.iter().all(|v| v.is_finite()) {
+                    return Err(invalid("values must be finite".into()));
+                }
+                let [min_x, min_y, max_x, max_y] = e.envelope;
+                let bbox = BoundingBox::new(min_x, min_y, max_x, max_y)
+                    .map_err(|err| invalid(err.to_string()))?;
+                Ok(IndexedGeometry::from_parts(e.id, to_envelope(&bbox), e.geometry))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            tree: RTree::bulk_load(entries),
+        })
+    }
+}
+
+/// The R-tree envelope as a geo rectangle, so it can be measured exactly.
+fn envelope_rect(envelope: &AABB<[f64; 2]>) -> Rect<f64> {
+    let [min_x, min_y] = envelope.lower();
+    let [max_x, max_y] = envelope.upper();
+    Rect::new(coord! { x: min_x, y: min_y }, coord! { x: max_x, y: max_y })
+}
+
+impl<T> Default for RTreeSpatialIndexGeneric<T> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl SpatialIndex for RTreeSpatialIndex {
-    fn insert(&mut self, id: usize, geom: &SurrealGeometry) -> Result<(), IndexError> {
+impl<T: Clone + PartialEq> SpatialIndex<T> for RTreeSpatialIndexGeneric<T> {
+    fn insert(&mut self, id: T, geom: &SurrealGeometry) -> Result<(), IndexError> {
         let bbox = geom.bbox().ok_or(IndexError::NoBoundingBox)?;
-        let indexed = IndexedGeometry::new(id, bbox);
-        self.tree.insert(indexed);
+        self.tree.insert(IndexedGeometry::new(id, bbox));
         Ok(())
     }
 
-    fn bulk_load(entries: Vec<(usize, SurrealGeometry)>) -> Result<Self, IndexError> {
+    fn bulk_load(entries: Vec<(T, SurrealGeometry)>) -> Result<Self, IndexError> {
         let mut indexed = Vec::with_capacity(entries.len());
-        for (id, geom) in &entries {
+        for (id, geom) in entries {
             let bbox = geom.bbox().ok_or(IndexError::NoBoundingBox)?;
-            indexed.push(IndexedGeometry::new(*id, bbox));
+            indexed.push(IndexedGeometry::new(id, bbox));
         }
 
         Ok(Self {
@@ -49,50 +246,95 @@ impl SpatialIndex for RTreeSpatialIndex {
         })
     }
 
-    fn query_bbox(&self, bbox: &BoundingBox) -> Vec<usize> {
-        let envelope = AABB::from_corners(
-            [bbox.min_x, bbox.min_y],
-            [bbox.max_x, bbox.max_y],
-        );
+    fn query_bbox(&self, bbox: &BoundingBox) -> Vec<T> {
+        let envelope = to_envelope(bbox);
         self.tree
             .locate_in_envelope_intersecting(&envelope)
-            .map(|entry| entry.id())
+            .map(|entry| entry.id().clone())
             .collect()
     }
 
-    fn query_nearest(&self, point: &Coordinate, k: usize) -> Vec<(usize, f64)> {
-        let pt = [point.x(), point.y()];
+    fn count_bbox(&self, bbox: &BoundingBox) -> usize {
+        let envelope = to_envelope(bbox);
+        self.tree.locate_in_envelope_intersecting(&envelope).count()
+    }
+
+    fn query_bbox_contained(&self, bbox: &BoundingBox) -> Vec<T> {
+        let envelope = to_envelope(bbox);
         self.tree
-            .nearest_neighbor_iter(&pt)
-            .take(k)
-            .map(|entry| {
-                let dist_sq = entry.distance_2(&pt);
-                (entry.id(), dist_sq.sqrt())
-            })
+            .locate_in_envelope_intersecting(&envelope)
+            .filter(|entry| bbox_contains(bbox, &entry.bbox()))
+            .map(|entry| entry.id().clone())
             .collect()
     }
 
-    fn query_within_distance(&self, point: &Coordinate, distance: f64) -> Vec<usize> {
+    /// Candidates come off the tree in bbox-distance order, which is a lower
+    /// bound on the true distance. Each entry with a retained geometry is
+    /// refined with its exact distance, and the scan stops once the next bbox
+    /// distance exceeds the k-th best distance.
+    fn query_nearest(&self, point: &Coordinate, k: usize) -> Vec<(T, f64)> {
+        if k == 0 {
+            return Vec::new();
+        }
+        let pt = [point.x(), point.y()];
+
+        let mut best: Vec<(T, f64)> = Vec::with_capacity(k);
+        for entry in self.tree.nearest_neighbor_iter(&pt) {
+            let bbox_dist = entry.distance_2(&pt).sqrt();
+            if best.len() == k && bbox_dist > best[k - 1].1 {
+                break;
+            }
+            let dist = entry.distance_to_point(&pt);
+            let pos = best.partition_point(|(_, d)| *d <= dist);
+            if pos < k {
+                best.insert(pos, (entry.id().clone(), dist));
+                best.truncate(k);
+            }
+        }
+        best
+    }
+
+    fn query_within_distance(&self, point: &Coordinate, distance: f64) -> Vec<T> {
         let pt = [point.x(), point.y()];
         // IMPORTANT: rstar's locate_within_distance takes SQUARED distance
         let distance_sq = distance * distance;
         self.tree
             .locate_within_distance(pt, distance_sq)
-            .map(|entry| entry.id())
+            .map(|entry| entry.id().clone())
             .collect()
     }
 
-    fn remove(&mut self, id: usize) -> bool {
+    fn remove(&mut self, id: T) -> bool {
         // Find the entry with the given ID by iterating over the tree,
         // then remove it. We need to clone the entry because rstar::remove
         // requires an owned reference for comparison.
-        let entry = self.tree.iter().find(|e| e.id() == id).cloned();
+        let entry = self.tree.iter().find(|e| *e.id() == id).cloned();
         match entry {
             Some(e) => self.tree.remove(&e).is_some(),
             None => false,
         }
     }
 
+    /// An entry that retained its geometry keeps retaining it after the update.
+    fn update(&mut self, id: T, geom: &SurrealGeometry) -> Result<bool, IndexError> {
+        let retained = self
+            .tree
+            .iter()
+            .any(|e| *e.id() == id && e.geometry().is_some());
+        // Build the new entry first so a bad geometry leaves the old one in place
+        let indexed = if retained {
+            IndexedGeometry::with_geometry(id.clone(), geom)?
+        } else {
+            let bbox = geom.bbox().ok_or(IndexError::NoBoundingBox)?;
+            IndexedGeometry::new(id.clone(), bbox)
+        };
+        // rstar locates entries by envelope, so the old entry must be removed
+        // with its own (old) envelope rather than the new one
+        let existed = self.remove(id);
+        self.tree.insert(indexed);
+        Ok(existed)
+    }
+
     fn len(&self) -> usize {
         self.tree.size()
     }
@@ -128,495 +370,232 @@ mod tests {
         SurrealGeometry::polygon(exterior, vec![], Srid::WGS84).unwrap()
     }
 
-    // ── Basic operations ──────────────────────────────────────────
+    crate::test_suite::spatial_index_test_suite!(crate::rtree_index::RTreeSpatialIndex);
 
-    #[test]
-    fn insert_single_point_and_query() {
-        let mut index = RTreeSpatialIndex::new();
-        index.insert(1, &make_point(5.0, 5.0)).unwrap();
-
-        let results = index.query_bbox(&make_bbox(0.0, 0.0, 10.0, 10.0));
-        assert_eq!(results, vec![1]);
-    }
-
-    #[test]
-    fn insert_and_query_multiple_overlapping_bboxes() {
-        let mut index = RTreeSpatialIndex::new();
-        index.insert(1, &make_polygon_geom(0.0, 0.0, 5.0, 5.0)).unwrap();
-        index.insert(2, &make_polygon_geom(3.0, 3.0, 8.0, 8.0)).unwrap();
-        index.insert(3, &make_polygon_geom(6.0, 6.0, 10.0, 10.0)).unwrap();
-
-        let mut results = index.query_bbox(&make_bbox(4.0, 4.0, 7.0, 7.0));
-        results.sort();
-        assert_eq!(results, vec![1, 2, 3]);
-    }
+    // ── Geometry within distance ──────────────────────────────────
 
-    #[test]
-    fn query_non_intersecting_bbox_returns_empty() {
-        let mut index = RTreeSpatialIndex::new();
-        index.insert(1, &make_polygon_geom(0.0, 0.0, 2.0, 2.0)).unwrap();
-        index.insert(2, &make_polygon_geom(3.0, 3.0, 5.0, 5.0)).unwrap();
-
-        let results = index.query_bbox(&make_bbox(10.0, 10.0, 20.0, 20.0));
-        assert!(results.is_empty());
-    }
-
-    #[test]
-    fn new_index_is_empty() {
-        let index = RTreeSpatialIndex::new();
-        assert!(index.is_empty());
-        assert_eq!(index.len(), 0);
-    }
-
-    #[test]
-    fn len_after_inserts() {
-        let mut index = RTreeSpatialIndex::new();
-        index.insert(1, &make_point(0.0, 0.0)).unwrap();
-        index.insert(2, &make_point(1.0, 1.0)).unwrap();
-        assert_eq!(index.len(), 2);
-        assert!(!index.is_empty());
-    }
-
-    // ── Bulk load ─────────────────────────────────────────────────
-
-    #[test]
-    fn bulk_load_100_points_all_queryable() {
-        let entries: Vec<(usize, SurrealGeometry)> = (0..100)
-            .map(|i| (i, make_point(i as f64, i as f64)))
+    fn make_line(coords: &[(f64, f64)]) -> SurrealGeometry {
+        let coords = coords
+            .iter()
+            .map(|&(x, y)| Coordinate::new(x, y).unwrap())
             .collect();
-
-        let index = RTreeSpatialIndex::bulk_load(entries).unwrap();
-        assert_eq!(index.len(), 100);
-
-        let results = index.query_bbox(&make_bbox(0.0, 0.0, 99.0, 99.0));
-        assert_eq!(results.len(), 100);
+        SurrealGeometry::line_string(coords, Srid::WGS84).unwrap()
     }
 
     #[test]
-    fn bulk_load_vs_sequential_same_results() {
-        let points: Vec<(usize, SurrealGeometry)> = vec![
-            (0, make_point(1.0, 1.0)),
-            (1, make_point(5.0, 5.0)),
-            (2, make_point(9.0, 9.0)),
-        ];
-
-        let bulk_index = RTreeSpatialIndex::bulk_load(points.clone()).unwrap();
-
-        let mut seq_index = RTreeSpatialIndex::new();
-        for (id, geom) in &points {
-            seq_index.insert(*id, geom).unwrap();
-        }
-
-        let query = make_bbox(0.0, 0.0, 10.0, 10.0);
-        let mut bulk_results = bulk_index.query_bbox(&query);
-        let mut seq_results = seq_index.query_bbox(&query);
-        bulk_results.sort();
-        seq_results.sort();
-        assert_eq!(bulk_results, seq_results);
-    }
-
-    #[test]
-    fn bulk_load_empty_creates_empty_index() {
-        let index = RTreeSpatialIndex::bulk_load(vec![]).unwrap();
-        assert!(index.is_empty());
-    }
-
-    // ── k-NN ──────────────────────────────────────────────────────
-
-    #[test]
-    fn knn_three_points_known_distances() {
+    fn geometry_within_distance_returns_sorted_distances() {
         let entries = vec![
             (0, make_point(0.0, 0.0)),
-            (1, make_point(3.0, 0.0)),
-            (2, make_point(5.0, 0.0)),
+            (1, make_point(4.0, 0.0)),
+            (2, make_point(2.0, 0.0)),
+            (3, make_point(10.0, 0.0)),
         ];
         let index = RTreeSpatialIndex::bulk_load(entries).unwrap();
-        let origin = make_coord(0.0, 0.0);
 
-        let nearest_1 = index.query_nearest(&origin, 1);
-        assert_eq!(nearest_1.len(), 1);
-        assert_eq!(nearest_1[0].0, 0);
-        assert!((nearest_1[0].1 - 0.0).abs() < 1e-10);
-
-        let nearest_2 = index.query_nearest(&origin, 2);
-        assert_eq!(nearest_2.len(), 2);
-        assert_eq!(nearest_2[0].0, 0);
-        assert_eq!(nearest_2[1].0, 1);
-        assert!((nearest_2[1].1 - 3.0).abs() < 1e-10);
-
-        let nearest_3 = index.query_nearest(&origin, 3);
-        assert_eq!(nearest_3.len(), 3);
-        assert_eq!(nearest_3[2].0, 2);
-        assert!((nearest_3[2].1 - 5.0).abs() < 1e-10);
+        let results = index
+            .query_geometry_within_distance(&make_point(0.0, 0.0), 5.0)
+            .unwrap();
+        let ids: Vec<usize> = results.iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, vec![0, 2, 1]);
+        assert!((results[1].1 - 2.0).abs() < 1e-10);
+        assert!((results[2].1 - 4.0).abs() < 1e-10);
     }
 
     #[test]
-    fn knn_k_larger_than_index_returns_all() {
+    fn geometry_within_distance_refines_bbox_candidates() {
+        // Both points fall inside the diagonal line's expanded bbox,
+        // but only the one next to the line is within distance.
         let entries = vec![
-            (0, make_point(0.0, 0.0)),
-            (1, make_point(1.0, 1.0)),
+            (0, make_point(5.0, 5.5)),
+            (1, make_point(9.0, 1.0)),
         ];
         let index = RTreeSpatialIndex::bulk_load(entries).unwrap();
+        let line = make_line(&[(0.0, 0.0), (10.0, 10.0)]);
 
-        let results = index.query_nearest(&make_coord(0.0, 0.0), 100);
-        assert_eq!(results.len(), 2);
-    }
-
-    #[test]
-    fn knn_empty_index_returns_empty() {
-        let index = RTreeSpatialIndex::new();
-        let results = index.query_nearest(&make_coord(0.0, 0.0), 5);
-        assert!(results.is_empty());
-    }
-
-    #[test]
-    fn knn_returns_correct_euclidean_distance() {
-        let entries = vec![
-            (0, make_point(3.0, 4.0)), // dist from origin = 5.0
-        ];
-        let index = RTreeSpatialIndex::bulk_load(entries).unwrap();
-
-        let results = index.query_nearest(&make_coord(0.0, 0.0), 1);
+        let results = index.query_geometry_within_distance(&line, 1.0).unwrap();
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].0, 0);
-        assert!((results[0].1 - 5.0).abs() < 1e-10);
+        assert!((results[0].1 - 0.5 / 2f64.sqrt()).abs() < 1e-10);
     }
 
     #[test]
-    fn knn_returns_in_distance_order() {
-        let entries = vec![
-            (0, make_point(10.0, 0.0)),
-            (1, make_point(1.0, 0.0)),
-            (2, make_point(5.0, 0.0)),
-        ];
-        let index = RTreeSpatialIndex::bulk_load(entries).unwrap();
-
-        let results = index.query_nearest(&make_coord(0.0, 0.0), 3);
-        assert_eq!(results[0].0, 1); // closest: dist 1
-        assert_eq!(results[1].0, 2); // middle: dist 5
-        assert_eq!(results[2].0, 0); // farthest: dist 10
-    }
-
-    // ── Within distance ───────────────────────────────────────────
-
-    #[test]
-    fn within_distance_known_points() {
-        let entries = vec![
-            (0, make_point(0.0, 0.0)),  // dist 0
-            (1, make_point(2.0, 0.0)),  // dist 2
-            (2, make_point(4.0, 0.0)),  // dist 4
-            (3, make_point(6.0, 0.0)),  // dist 6
-        ];
-        let index = RTreeSpatialIndex::bulk_load(entries).unwrap();
-
-        let mut results = index.query_within_distance(&make_coord(0.0, 0.0), 3.0);
-        results.sort();
-        assert_eq!(results, vec![0, 1]);
-    }
-
-    #[test]
-    fn within_distance_boundary_inclusion() {
-        let entries = vec![
-            (0, make_point(3.0, 0.0)),  // dist = exactly 3.0
-        ];
-        let index = RTreeSpatialIndex::bulk_load(entries).unwrap();
-
-        let results = index.query_within_distance(&make_coord(0.0, 0.0), 3.0);
-        assert_eq!(results, vec![0]);
-    }
-
-    #[test]
-    fn within_distance_squared_gotcha_handled() {
-        // If we forgot to square the distance, distance=5.0 would be treated
-        // as distance_sq=5.0 (actual distance ~2.236), missing the point at (3,0).
-        let entries = vec![
-            (0, make_point(3.0, 0.0)),  // dist = 3.0
-            (1, make_point(4.0, 0.0)),  // dist = 4.0
-        ];
-        let index = RTreeSpatialIndex::bulk_load(entries).unwrap();
+    fn geometry_within_distance_polygon_query_contains_point() {
+        let mut index = RTreeSpatialIndex::new();
+        index.insert(0, &make_point(6.0, 2.0)).unwrap();
+        index.insert(1, &make_point(20.0, 20.0)).unwrap();
 
-        let mut results = index.query_within_distance(&make_coord(0.0, 0.0), 5.0);
-        results.sort();
-        assert_eq!(results, vec![0, 1]);
+        let poly = make_polygon_geom(0.0, 0.0, 10.0, 10.0);
+        let results = index.query_geometry_within_distance(&poly, 0.0).unwrap();
+        assert_eq!(results, vec![(0, 0.0)]);
     }
 
     #[test]
-    fn within_distance_empty_index() {
+    fn geometry_within_distance_rejects_negative_distance() {
         let index = RTreeSpatialIndex::new();
-        let results = index.query_within_distance(&make_coord(0.0, 0.0), 100.0);
-        assert!(results.is_empty());
+        let result = index.query_geometry_within_distance(&make_point(0.0, 0.0), -1.0);
+        assert!(matches!(result, Err(IndexError::IndexError(_))));
     }
 
-    #[test]
-    fn within_distance_none_in_range() {
-        let entries = vec![
-            (0, make_point(10.0, 10.0)),
-        ];
-        let index = RTreeSpatialIndex::bulk_load(entries).unwrap();
-
-        let results = index.query_within_distance(&make_coord(0.0, 0.0), 1.0);
-        assert!(results.is_empty());
-    }
+    // ── Moving window ─────────────────────────────────────────────
 
     #[test]
-    fn within_distance_diagonal() {
-        // Point at (3, 4) is distance 5 from origin
-        let entries = vec![
-            (0, make_point(3.0, 4.0)),
-        ];
+    fn window_delta_reports_only_changed_ids() {
+        // A row of points at x = 0..20
+        let entries: Vec<(usize, SurrealGeometry)> =
+            (0..20).map(|i| (i, make_point(i as f64, 0.0))).collect();
         let index = RTreeSpatialIndex::bulk_load(entries).unwrap();
 
-        let results = index.query_within_distance(&make_coord(0.0, 0.0), 4.9);
-        assert!(results.is_empty());
-
-        let results = index.query_within_distance(&make_coord(0.0, 0.0), 5.1);
-        assert_eq!(results, vec![0]);
+        // Pan right by 3 units: x in [2.5, 7.5] -> [5.5, 10.5]
+        let old = make_bbox(2.5, -1.0, 7.5, 1.0);
+        let new = make_bbox(5.5, -1.0, 10.5, 1.0);
+        let (mut entered, mut exited) = index.query_window_delta(&old, &new);
+        entered.sort();
+        exited.sort();
+        assert_eq!(entered, vec![8, 9, 10]);
+        assert_eq!(exited, vec![3, 4, 5]);
     }
 
     #[test]
-    fn within_distance_with_bbox_geometry() {
-        // Polygon bbox at (8,8)-(12,12) - nearest corner to origin is (8,8), dist ~11.31
-        let entries = vec![
-            (0, make_polygon_geom(8.0, 8.0, 12.0, 12.0)),
-        ];
+    fn window_delta_matches_bbox_query_difference() {
+        let entries: Vec<(usize, SurrealGeometry)> = (0..100)
+            .map(|i| {
+                let (x, y) = ((i % 10) as f64, (i / 10) as f64);
+                (i, make_polygon_geom(x, y, x + 1.5, y + 1.5))
+            })
+            .collect();
         let index = RTreeSpatialIndex::bulk_load(entries).unwrap();
+        let old = make_bbox(1.2, 1.2, 4.8, 4.8);
+        let new = make_bbox(3.1, 2.4, 6.9, 6.2);
 
-        let results = index.query_within_distance(&make_coord(0.0, 0.0), 11.0);
-        assert!(results.is_empty());
+        let before = index.query_bbox(&old);
+        let after = index.query_bbox(&new);
+        let mut expected_entered: Vec<usize> =
+            after.iter().copied().filter(|id| !before.contains(id)).collect();
+        let mut expected_exited: Vec<usize> =
+            before.iter().copied().filter(|id| !after.contains(id)).collect();
+        expected_entered.sort();
+        expected_exited.sort();
 
-        let results = index.query_within_distance(&make_coord(0.0, 0.0), 12.0);
-        assert_eq!(results, vec![0]);
+        let (mut entered, mut exited) = index.query_window_delta(&old, &new);
+        entered.sort();
+        exited.sort();
+        assert_eq!(entered, expected_entered);
+        assert_eq!(exited, expected_exited);
+        assert!(!entered.is_empty() && !exited.is_empty());
     }
 
-    // ── Remove ────────────────────────────────────────────────────
-
     #[test]
-    fn remove_existing_entry() {
+    fn window_delta_unmoved_window_is_empty() {
         let mut index = RTreeSpatialIndex::new();
-        index.insert(0, &make_point(1.0, 1.0)).unwrap();
-        index.insert(1, &make_point(5.0, 5.0)).unwrap();
-        assert_eq!(index.len(), 2);
-
-        let removed = index.remove(0);
-        assert!(removed);
-        assert_eq!(index.len(), 1);
-
-        // Verify removed entry is gone
-        let results = index.query_bbox(&make_bbox(0.0, 0.0, 2.0, 2.0));
-        assert!(results.is_empty());
-
-        // Verify other entry is still present
-        let results = index.query_bbox(&make_bbox(4.0, 4.0, 6.0, 6.0));
-        assert_eq!(results, vec![1]);
+        index.insert(1, &make_point(1.0, 1.0)).unwrap();
+        let window = make_bbox(0.0, 0.0, 2.0, 2.0);
+        assert_eq!(index.query_window_delta(&window, &window), (vec![], vec![]));
     }
 
-    #[test]
-    fn remove_nonexistent_entry_returns_false() {
-        let mut index = RTreeSpatialIndex::new();
-        index.insert(0, &make_point(1.0, 1.0)).unwrap();
-
-        let removed = index.remove(999);
-        assert!(!removed);
-        assert_eq!(index.len(), 1);
-    }
-
-    #[test]
-    fn remove_from_empty_index_returns_false() {
-        let mut index = RTreeSpatialIndex::new();
-        let removed = index.remove(0);
-        assert!(!removed);
-    }
+    // ── Generic payload ───────────────────────────────────────────
 
     #[test]
-    fn remove_and_requery() {
+    fn string_payload_round_trips_through_queries() {
         let entries = vec![
-            (0, make_point(1.0, 0.0)),
-            (1, make_point(2.0, 0.0)),
-            (2, make_point(3.0, 0.0)),
+            ("city:paris".to_string(), make_point(2.35, 48.85)),
+            ("city:london".to_string(), make_point(-0.13, 51.51)),
+            ("city:tokyo".to_string(), make_point(139.69, 35.69)),
         ];
-        let mut index = RTreeSpatialIndex::bulk_load(entries).unwrap();
+        let mut index = RTreeSpatialIndexGeneric::<String>::bulk_load(entries).unwrap();
 
-        // Remove middle point
-        assert!(index.remove(1));
-        assert_eq!(index.len(), 2);
+        let mut europe = index.query_bbox(&make_bbox(-10.0, 40.0, 10.0, 60.0));
+        europe.sort();
+        assert_eq!(europe, vec!["city:london".to_string(), "city:paris".to_string()]);
 
-        // kNN should skip removed entry
-        let nearest = index.query_nearest(&make_coord(0.0, 0.0), 3);
-        assert_eq!(nearest.len(), 2);
-        let ids: Vec<usize> = nearest.iter().map(|(id, _)| *id).collect();
-        assert!(ids.contains(&0));
-        assert!(ids.contains(&2));
-        assert!(!ids.contains(&1));
+        let nearest = index.query_nearest(&make_coord(140.0, 35.0), 1);
+        assert_eq!(nearest[0].0, "city:tokyo");
 
-        // Within-distance should skip removed entry
-        let mut within = index.query_within_distance(&make_coord(0.0, 0.0), 5.0);
-        within.sort();
-        assert_eq!(within, vec![0, 2]);
+        assert!(index.remove("city:paris".to_string()));
+        assert!(!index.remove("city:paris".to_string()));
+        assert_eq!(index.len(), 2);
     }
 
     #[test]
-    fn remove_all_entries_leaves_empty_index() {
-        let mut index = RTreeSpatialIndex::new();
-        index.insert(0, &make_point(1.0, 1.0)).unwrap();
-        index.insert(1, &make_point(2.0, 2.0)).unwrap();
-
-        assert!(index.remove(0));
-        assert!(index.remove(1));
-        assert!(index.is_empty());
-        assert_eq!(index.len(), 0);
+    fn string_payload_geometry_within_distance() {
+        let mut index = RTreeSpatialIndexGeneric::new();
+        index.insert("a".to_string(), &make_point(0.0, 0.0)).unwrap();
+        index.insert("b".to_string(), &make_point(3.0, 0.0)).unwrap();
 
-        let results = index.query_bbox(&make_bbox(0.0, 0.0, 10.0, 10.0));
-        assert!(results.is_empty());
+        let results = index
+            .query_geometry_within_distance(&make_point(0.0, 0.0), 1.0)
+            .unwrap();
+        assert_eq!(results, vec![("a".to_string(), 0.0)]);
     }
 
-    // ── Scale tests ───────────────────────────────────────────────
+    // ── Persistence ───────────────────────────────────────────────
 
+    #[cfg(feature = "serde")]
     #[test]
-    fn scale_100k_points_bbox_query_vs_brute_force() {
-        // Create 100K points on a grid (100K = 316x316 approximately, use 100x1000)
-        let entries: Vec<(usize, SurrealGeometry)> = (0..100_000)
+    fn bytes_round_trip_preserves_query_results() {
+        let entries: Vec<(usize, SurrealGeometry)> = (0..500)
             .map(|i| {
-                let x = (i % 1000) as f64;
-                let y = (i / 1000) as f64;
-                (i, make_point(x, y))
-            })
-            .collect();
-
-        let index = RTreeSpatialIndex::bulk_load(entries.clone()).unwrap();
-        assert_eq!(index.len(), 100_000);
-
-        // Query a sub-region: x in [10,19], y in [10,19]
-        let query = make_bbox(10.0, 10.0, 19.0, 19.0);
-        let mut rtree_results = index.query_bbox(&query);
-        rtree_results.sort();
-
-        // Brute force comparison
-        let mut brute_results: Vec<usize> = entries
-            .iter()
-            .filter(|(_, geom)| {
-                let bb = geom.bbox().unwrap();
-                bb.min_x <= query.max_x
-                    && bb.max_x >= query.min_x
-                    && bb.min_y <= query.max_y
-                    && bb.max_y >= query.min_y
+                let x = (i % 25) as f64;
+                let y = (i / 25) as f64;
+                if i % 7 == 0 {
+                    (i, make_polygon_geom(x, y, x + 2.5, y + 1.5))
+                } else {
+                    (i, make_point(x, y))
+                }
             })
-            .map(|(id, _)| *id)
             .collect();
-        brute_results.sort();
-
-        assert_eq!(rtree_results, brute_results);
-        // 10 columns (10..19) x 10 rows (10..19) = 100 points
-        assert_eq!(rtree_results.len(), 100);
-    }
-
-    // ── Edge cases ────────────────────────────────────────────────
-
-    #[test]
-    fn degenerate_point_bbox() {
-        let mut index = RTreeSpatialIndex::new();
-        index.insert(0, &make_point(5.0, 5.0)).unwrap();
-
-        let results = index.query_bbox(&make_bbox(5.0, 5.0, 5.0, 5.0));
-        assert_eq!(results, vec![0]);
-    }
-
-    #[test]
-    fn overlapping_identical_bboxes() {
-        let entries = vec![
-            (0, make_polygon_geom(0.0, 0.0, 5.0, 5.0)),
-            (1, make_polygon_geom(0.0, 0.0, 5.0, 5.0)),
-            (2, make_polygon_geom(0.0, 0.0, 5.0, 5.0)),
-        ];
-        let index = RTreeSpatialIndex::bulk_load(entries).unwrap();
-
-        let mut results = index.query_bbox(&make_bbox(2.0, 2.0, 3.0, 3.0));
-        results.sort();
-        assert_eq!(results, vec![0, 1, 2]);
-    }
-
-    #[test]
-    fn very_large_coordinates() {
-        let mut index = RTreeSpatialIndex::new();
-        let big = 1e15;
-        index.insert(0, &make_polygon_geom(-big, -big, big, big)).unwrap();
-
-        let results = index.query_bbox(&make_bbox(-1.0, -1.0, 1.0, 1.0));
-        assert_eq!(results, vec![0]);
-    }
-
-    #[test]
-    fn very_small_coordinates() {
-        let mut index = RTreeSpatialIndex::new();
-        let small = 1e-15;
-        index.insert(0, &make_point(small, small)).unwrap();
-
-        let results = index.query_bbox(&make_bbox(-1.0, -1.0, 1.0, 1.0));
-        assert_eq!(results, vec![0]);
-    }
-
-    #[test]
-    fn touching_bboxes_intersect() {
-        let mut index = RTreeSpatialIndex::new();
-        index.insert(0, &make_polygon_geom(0.0, 0.0, 5.0, 5.0)).unwrap();
-        index.insert(1, &make_polygon_geom(5.0, 0.0, 10.0, 5.0)).unwrap();
-
-        let mut results = index.query_bbox(&make_bbox(5.0, 0.0, 5.0, 5.0));
-        results.sort();
-        assert_eq!(results, vec![0, 1]);
-    }
-
-    #[test]
-    fn query_bbox_partial_overlap() {
-        let mut index = RTreeSpatialIndex::new();
-        index.insert(0, &make_polygon_geom(0.0, 0.0, 5.0, 5.0)).unwrap();
-        index.insert(1, &make_polygon_geom(4.0, 4.0, 9.0, 9.0)).unwrap();
-        index.insert(2, &make_polygon_geom(10.0, 10.0, 15.0, 15.0)).unwrap();
-
-        let mut results = index.query_bbox(&make_bbox(3.0, 3.0, 6.0, 6.0));
-        results.sort();
-        assert_eq!(results, vec![0, 1]);
-    }
-
-    #[test]
-    fn mixed_bbox_sizes_query() {
-        let entries = vec![
-            (0, make_polygon_geom(0.0, 0.0, 100.0, 100.0)),
-            (1, make_polygon_geom(49.0, 49.0, 51.0, 51.0)),
-            (2, make_point(50.0, 50.0)),
-        ];
-        let index = RTreeSpatialIndex::bulk_load(entries).unwrap();
-
-        let mut results = index.query_bbox(&make_bbox(49.5, 49.5, 50.5, 50.5));
-        results.sort();
-        assert_eq!(results, vec![0, 1, 2]);
-    }
-
-    // ── Default trait ─────────────────────────────────────────────
-
-    #[test]
-    fn default_is_empty_index() {
-        let index = RTreeSpatialIndex::default();
-        assert!(index.is_empty());
-    }
-
-    // ── Insert with polygon (bbox extracted) ──────────────────────
-
-    #[test]
-    fn insert_polygon_queries_by_bbox() {
-        let mut index = RTreeSpatialIndex::new();
-        let exterior = vec![
-            Coordinate::new(0.0, 0.0).unwrap(),
-            Coordinate::new(10.0, 0.0).unwrap(),
-            Coordinate::new(10.0, 10.0).unwrap(),
-            Coordinate::new(0.0, 0.0).unwrap(),
-        ];
-        let poly = SurrealGeometry::polygon(exterior, vec![], Srid::WGS84).unwrap();
-        index.insert(0, &poly).unwrap();
-
-        let results = index.query_bbox(&make_bbox(5.0, 5.0, 6.0, 6.0));
-        assert_eq!(results, vec![0]);
+        let index = RTreeSpatialIndex::bulk_load_with_geometries(entries).unwrap();
+
+        let bytes = index.to_bytes().unwrap();
+        let restored = RTreeSpatialIndex::from_bytes(&bytes).unwrap();
+        assert_eq!(restored.len(), index.len());
+
+        for query in [
+            make_bbox(0.0, 0.0, 5.0, 5.0),
+            make_bbox(10.5, 3.2, 14.0, 9.9),
+            make_bbox(-10.0, -10.0, 100.0, 100.0),
+            make_bbox(50.0, 50.0, 60.0, 60.0),
+        ] {
+            let mut expected = index.query_bbox(&query);
+            let mut actual = restored.query_bbox(&query);
+            expected.sort();
+            actual.sort();
+            assert_eq!(actual, expected);
+        }
 
-        let results = index.query_bbox(&make_bbox(20.0, 20.0, 30.0, 30.0));
-        assert!(results.is_empty());
+        // Retained geometries survive, so exact kNN still works
+        let origin = make_coord(3.3, 4.4);
+        let distances = |idx: &RTreeSpatialIndex| -> Vec<f64> {
+            idx.query_nearest(&origin, 5).into_iter().map(|(_, d)| d).collect()
+        };
+        assert_eq!(distances(&restored), distances(&index));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn from_bytes_rejects_garbage() {
+        let result = RTreeSpatialIndex::from_bytes(&[1, 2, 3]);
+        assert!(matches!(result, Err(IndexError::IndexError(_))));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn from_bytes_rejects_invalid_envelopes() {
+        for envelope in [
+            [0.0, 0.0, f64::NAN, 1.0],
+            [0.0, 0.0, 1.0, f64::INFINITY],
+            [2.0, 0.0, 1.0, 1.0],
+        ] {
+            let persisted = PersistedIndex {
+                version: PERSIST_VERSION,
+                entries: vec![PersistedEntry {
+                    id: 0usize,
+                    envelope,
+                    geometry: None,
+                }],
+            };
+            let bytes = bincode::serialize(&persisted).unwrap();
+            let result = RTreeSpatialIndex::from_bytes(&bytes);
+            assert!(matches!(result, Err(IndexError::IndexError(_))), "{envelope:?}");
+        }
     }
 }
@@ -14,27 +14,42 @@ pub enum IndexError {
 /// Repository-pattern trait for spatial indexing.
 ///
 /// Implementations provide efficient spatial queries over a collection
-/// of geometries identified by `usize` IDs.
-pub trait SpatialIndex: Sized {
+/// of geometries identified by IDs of type `T` (`usize` by default).
+pub trait SpatialIndex<T = usize>: Sized {
     /// Insert a geometry by its ID. The bounding box is extracted automatically.
-    fn insert(&mut self, id: usize, geom: &SurrealGeometry) -> Result<(), IndexError>;
+    fn insert(&mut self, id: T, geom: &SurrealGeometry) -> Result<(), IndexError>;
 
     /// Bulk load geometries using the STR packing algorithm for better query performance.
-    fn bulk_load(entries: Vec<(usize, SurrealGeometry)>) -> Result<Self, IndexError>;
+    fn bulk_load(entries: Vec<(T, SurrealGeometry)>) -> Result<Self, IndexError>;
 
     /// Query all geometry IDs whose bounding box intersects the given bounding box.
-    fn query_bbox(&self, bbox: &BoundingBox) -> Vec<usize>;
+    fn query_bbox(&self, bbox: &BoundingBox) -> Vec<T>;
 
-    /// Find the k nearest geometries to a point, returning (id, distance) pairs.
-    fn query_nearest(&self, point: &Coordinate, k: usize) -> Vec<(usize, f64)>;
+    /// Count the geometries whose bounding box intersects the given bounding box.
+    /// Backends should override this to count without collecting the IDs.
+    fn count_bbox(&self, bbox: &BoundingBox) -> usize {
+        self.query_bbox(bbox).len()
+    }
+
+    /// Query all geometry IDs whose bounding box lies entirely inside the given
+    /// bounding box. Shared edges count as inside.
+    fn query_bbox_contained(&self, bbox: &BoundingBox) -> Vec<T>;
+
+    /// Find the k nearest geometries to a point, returning (id, distance) pairs
+    /// sorted by ascending distance to the geometry itself.
+    fn query_nearest(&self, point: &Coordinate, k: usize) -> Vec<(T, f64)>;
 
     /// Find all geometry IDs within a given Euclidean distance of a point.
     ///
     /// Note: `distance` is the actual distance, NOT squared.
-    fn query_within_distance(&self, point: &Coordinate, distance: f64) -> Vec<usize>;
+    fn query_within_distance(&self, point: &Coordinate, distance: f64) -> Vec<T>;
 
     /// Remove a geometry by its ID. Returns true if it was found and removed.
-    fn remove(&mut self, id: usize) -> bool;
+    fn remove(&mut self, id: T) -> bool;
+
+    /// Replace the geometry stored under an ID, inserting it if the ID is absent.
+    /// Returns true if an old entry was replaced. On error the index is unchanged.
+    fn update(&mut self, id: T, geom: &SurrealGeometry) -> Result<bool, IndexError>;
 
     /// Number of entries in the index.
     fn len(&self) -> usize;
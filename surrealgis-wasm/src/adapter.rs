@@ -1,17 +1,86 @@
+use serde::{Deserialize, Serialize};
 use surrealdb_types::Geometry;
+use surrealgis_core::error::GeometryError;
 use surrealgis_core::geometry::SurrealGeometry;
 use surrealgis_core::srid::Srid;
+use surrealgis_functions::FunctionError;
+
+/// Error category reported to the host, mirroring `FunctionError`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ErrorKind {
+    GeometryError,
+    InvalidArgument,
+    UnsupportedOperation,
+    CrsError,
+    TypeMismatch,
+}
+
+/// Structured error payload returned by every export, serialized as
+/// `{"error": "<kind>", "message": "..."}` so the host can branch on `error`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportError {
+    pub error: ErrorKind,
+    pub message: String,
+}
+
+impl ExportError {
+    pub fn new(error: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            error,
+            message: message.into(),
+        }
+    }
+
+    /// Serialize to the JSON string handed back across the WASM boundary.
+    pub fn to_json_string(&self) -> String {
+        serde_json::to_string(self).unwrap_or_else(|_| self.message.clone())
+    }
+}
+
+impl From<FunctionError> for ExportError {
+    fn from(e: FunctionError) -> Self {
+        let kind = match &e {
+            FunctionError::GeometryError(_) => ErrorKind::GeometryError,
+            FunctionError::InvalidArgument(_) => ErrorKind::InvalidArgument,
+            FunctionError::UnsupportedOperation(_) => ErrorKind::UnsupportedOperation,
+            FunctionError::CrsError(_) => ErrorKind::CrsError,
+            FunctionError::TypeMismatch { .. } => ErrorKind::TypeMismatch,
+        };
+        Self::new(kind, e.to_string())
+    }
+}
+
+impl From<GeometryError> for ExportError {
+    fn from(e: GeometryError) -> Self {
+        Self::new(ErrorKind::GeometryError, e.to_string())
+    }
+}
+
+/// Map a `FunctionError` to the structured error string returned by exports.
+pub fn function_error(e: FunctionError) -> String {
+    ExportError::from(e).to_json_string()
+}
+
+/// Map a `GeometryError` to the structured error string returned by exports.
+pub fn geometry_error(e: GeometryError) -> String {
+    ExportError::from(e).to_json_string()
+}
+
+/// Build a structured `InvalidArgument` error string for export-level checks.
+pub fn invalid_argument(message: impl Into<String>) -> String {
+    ExportError::new(ErrorKind::InvalidArgument, message).to_json_string()
+}
 
 /// Convert a `surrealdb_types::Geometry` into a domain `SurrealGeometry`.
 /// SurrealDB geometry has no SRID concept, so we default to WGS84.
 pub fn from_surreal_geometry(g: Geometry) -> Result<SurrealGeometry, String> {
     let geo: geo_types::Geometry<f64> = surreal_geometry_to_geo(g);
-    SurrealGeometry::from_geo(&geo, Srid::WGS84).map_err(|e| e.to_string())
+    SurrealGeometry::from_geo(&geo, Srid::WGS84).map_err(geometry_error)
 }
 
 /// Convert a domain `SurrealGeometry` into a `surrealdb_types::Geometry`.
 pub fn to_surreal_geometry(g: &SurrealGeometry) -> Result<Geometry, String> {
-    let geo = g.to_geo().map_err(|e| e.to_string())?;
+    let geo = g.to_geo().map_err(geometry_error)?;
     Ok(geo_to_surreal_geometry(geo))
 }
 
@@ -122,4 +191,28 @@ mod tests {
         let back = to_surreal_geometry(&domain).unwrap();
         assert!(back.is_collection());
     }
+
+    #[test]
+    fn function_error_is_structured() {
+        let err = function_error(FunctionError::UnsupportedOperation("nope".to_string()));
+        let parsed: ExportError = serde_json::from_str(&err).unwrap();
+        assert_eq!(parsed.error, ErrorKind::UnsupportedOperation);
+        assert_eq!(parsed.message, "Unsupported operation: nope");
+    }
+
+    #[test]
+    fn error_kinds_are_distinguishable() {
+        let geom = function_error(FunctionError::GeometryError(GeometryError::EmptyGeometry));
+        let arg = function_error(FunctionError::InvalidArgument("bad".to_string()));
+        let crs = function_error(FunctionError::CrsError("bad".to_string()));
+        assert!(geom.contains(r#""error":"GeometryError""#));
+        assert!(arg.contains(r#""error":"InvalidArgument""#));
+        assert!(crs.contains(r#""error":"CrsError""#));
+    }
+
+    #[test]
+    fn invalid_argument_payload() {
+        let parsed: ExportError = serde_json::from_str(&invalid_argument("k must be positive")).unwrap();
+        assert_eq!(parsed, ExportError::new(ErrorKind::InvalidArgument, "k must be positive"));
+    }
 }
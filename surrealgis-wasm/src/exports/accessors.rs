@@ -1,24 +1,26 @@
 use surrealism::surrealism;
 use surrealdb_types::Geometry;
+use surrealgis_core::geometry::SurrealGeometry;
+use surrealgis_core::serialization::geojson;
 
 use crate::adapter;
 
 #[surrealism]
 fn st_x(geom: Geometry) -> Result<f64, String> {
     let g = adapter::from_surreal_geometry(geom)?;
-    surrealgis_functions::accessors::st_x(&g).map_err(|e| e.to_string())
+    surrealgis_functions::accessors::st_x(&g).map_err(adapter::function_error)
 }
 
 #[surrealism]
 fn st_y(geom: Geometry) -> Result<f64, String> {
     let g = adapter::from_surreal_geometry(geom)?;
-    surrealgis_functions::accessors::st_y(&g).map_err(|e| e.to_string())
+    surrealgis_functions::accessors::st_y(&g).map_err(adapter::function_error)
 }
 
 #[surrealism]
 fn st_z(geom: Geometry) -> Result<Option<f64>, String> {
     let g = adapter::from_surreal_geometry(geom)?;
-    surrealgis_functions::accessors::st_z(&g).map_err(|e| e.to_string())
+    surrealgis_functions::accessors::st_z(&g).map_err(adapter::function_error)
 }
 
 #[surrealism]
@@ -49,7 +51,7 @@ fn st_dimension(geom: Geometry) -> Result<i64, String> {
 fn st_start_point(geom: Geometry) -> Result<Geometry, String> {
     let g = adapter::from_surreal_geometry(geom)?;
     let result = surrealgis_functions::accessors::st_start_point(&g)
-        .map_err(|e| e.to_string())?;
+        .map_err(adapter::function_error)?;
     adapter::to_surreal_geometry(&result)
 }
 
@@ -57,10 +59,41 @@ fn st_start_point(geom: Geometry) -> Result<Geometry, String> {
 fn st_end_point(geom: Geometry) -> Result<Geometry, String> {
     let g = adapter::from_surreal_geometry(geom)?;
     let result = surrealgis_functions::accessors::st_end_point(&g)
-        .map_err(|e| e.to_string())?;
+        .map_err(adapter::function_error)?;
     adapter::to_surreal_geometry(&result)
 }
 
+#[surrealism]
+fn st_num_geometries(geom: Geometry) -> Result<i64, String> {
+    let g = adapter::from_surreal_geometry(geom)?;
+    Ok(surrealgis_functions::accessors::st_num_geometries(&g) as i64)
+}
+
+#[surrealism]
+fn st_geometry_n(geom: Geometry, n: i64) -> Result<Geometry, String> {
+    let g = adapter::from_surreal_geometry(geom)?;
+    let n = usize::try_from(n)
+        .map_err(|_| adapter::invalid_argument(format!("n must be non-negative, got {n}")))?;
+    let result = surrealgis_functions::accessors::st_geometry_n(&g, n)
+        .map_err(adapter::function_error)?;
+    adapter::to_surreal_geometry(&result)
+}
+
+#[surrealism]
+fn st_dump(geom: Geometry) -> Result<String, String> {
+    let g = adapter::from_surreal_geometry(geom)?;
+    let parts = surrealgis_functions::accessors::st_dump(&g).map_err(adapter::function_error)?;
+    to_geojson_array(&parts)
+}
+
+#[surrealism]
+fn st_dump_points(geom: Geometry) -> Result<String, String> {
+    let g = adapter::from_surreal_geometry(geom)?;
+    let points =
+        surrealgis_functions::accessors::st_dump_points(&g).map_err(adapter::function_error)?;
+    to_geojson_array(&points)
+}
+
 #[surrealism]
 fn st_is_empty(geom: Geometry) -> Result<bool, String> {
     let g = adapter::from_surreal_geometry(geom)?;
@@ -70,26 +103,26 @@ fn st_is_empty(geom: Geometry) -> Result<bool, String> {
 #[surrealism]
 fn st_is_valid(geom: Geometry) -> Result<bool, String> {
     let g = adapter::from_surreal_geometry(geom)?;
-    surrealgis_functions::accessors::st_is_valid(&g).map_err(|e| e.to_string())
+    surrealgis_functions::accessors::st_is_valid(&g).map_err(adapter::function_error)
 }
 
 #[surrealism]
 fn st_is_closed(geom: Geometry) -> Result<bool, String> {
     let g = adapter::from_surreal_geometry(geom)?;
-    surrealgis_functions::accessors::st_is_closed(&g).map_err(|e| e.to_string())
+    surrealgis_functions::accessors::st_is_closed(&g).map_err(adapter::function_error)
 }
 
 #[surrealism]
 fn st_is_ring(geom: Geometry) -> Result<bool, String> {
     let g = adapter::from_surreal_geometry(geom)?;
-    surrealgis_functions::accessors::st_is_ring(&g).map_err(|e| e.to_string())
+    surrealgis_functions::accessors::st_is_ring(&g).map_err(adapter::function_error)
 }
 
 #[surrealism]
 fn st_envelope(geom: Geometry) -> Result<Geometry, String> {
     let g = adapter::from_surreal_geometry(geom)?;
     let result = surrealgis_functions::accessors::st_envelope(&g)
-        .map_err(|e| e.to_string())?;
+        .map_err(adapter::function_error)?;
     adapter::to_surreal_geometry(&result)
 }
 
@@ -97,7 +130,7 @@ fn st_envelope(geom: Geometry) -> Result<Geometry, String> {
 fn st_centroid(geom: Geometry) -> Result<Geometry, String> {
     let g = adapter::from_surreal_geometry(geom)?;
     let result = surrealgis_functions::accessors::st_centroid(&g)
-        .map_err(|e| e.to_string())?;
+        .map_err(adapter::function_error)?;
     adapter::to_surreal_geometry(&result)
 }
 
@@ -105,7 +138,7 @@ fn st_centroid(geom: Geometry) -> Result<Geometry, String> {
 fn st_point_on_surface(geom: Geometry) -> Result<Geometry, String> {
     let g = adapter::from_surreal_geometry(geom)?;
     let result = surrealgis_functions::accessors::st_point_on_surface(&g)
-        .map_err(|e| e.to_string())?;
+        .map_err(adapter::function_error)?;
     adapter::to_surreal_geometry(&result)
 }
 
@@ -113,6 +146,16 @@ fn st_point_on_surface(geom: Geometry) -> Result<Geometry, String> {
 fn st_boundary(geom: Geometry) -> Result<Geometry, String> {
     let g = adapter::from_surreal_geometry(geom)?;
     let result = surrealgis_functions::accessors::st_boundary(&g)
-        .map_err(|e| e.to_string())?;
+        .map_err(adapter::function_error)?;
     adapter::to_surreal_geometry(&result)
 }
+
+/// Serialize geometries as a JSON array of GeoJSON geometry objects.
+fn to_geojson_array(geoms: &[SurrealGeometry]) -> Result<String, String> {
+    let values = geoms
+        .iter()
+        .map(geojson::to_geojson)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(adapter::geometry_error)?;
+    serde_json::to_string(&values).map_err(|e| adapter::invalid_argument(e.to_string()))
+}
@@ -1,38 +1,111 @@
 use surrealism::surrealism;
 use surrealdb_types::Geometry;
+use surrealgis_core::geometry::SurrealGeometry;
 
 use crate::adapter;
 
 #[surrealism]
 fn st_cluster_dbscan(geoms: Vec<Geometry>, eps: f64, min_points: i64) -> Result<Geometry, String> {
-    let gs: Result<Vec<_>, String> = geoms
-        .into_iter()
-        .map(adapter::from_surreal_geometry)
-        .collect();
-    let result =
-        surrealgis_functions::clustering::st_cluster_dbscan(&gs?, eps, min_points as usize)
-            .map_err(|e| e.to_string())?;
-    adapter::to_surreal_geometry(&result)
+    cluster_dbscan(geoms, eps, min_points)
 }
 
 #[surrealism]
 fn st_cluster_kmeans(geoms: Vec<Geometry>, k: i64) -> Result<Geometry, String> {
-    let gs: Result<Vec<_>, String> = geoms
-        .into_iter()
-        .map(adapter::from_surreal_geometry)
-        .collect();
-    let result = surrealgis_functions::clustering::st_cluster_kmeans(&gs?, k as usize)
-        .map_err(|e| e.to_string())?;
-    adapter::to_surreal_geometry(&result)
+    cluster_kmeans(geoms, k)
 }
 
 #[surrealism]
 fn st_cluster_within(geoms: Vec<Geometry>, distance: f64) -> Result<Geometry, String> {
-    let gs: Result<Vec<_>, String> = geoms
-        .into_iter()
-        .map(adapter::from_surreal_geometry)
-        .collect();
-    let result = surrealgis_functions::clustering::st_cluster_within(&gs?, distance)
-        .map_err(|e| e.to_string())?;
+    cluster_within(geoms, distance)
+}
+
+fn cluster_dbscan(geoms: Vec<Geometry>, eps: f64, min_points: i64) -> Result<Geometry, String> {
+    let gs = from_surreal_geometries(geoms)?;
+    let min_points = to_count(min_points, "min_points")?;
+    let result = surrealgis_functions::clustering::st_cluster_dbscan(&gs, eps, min_points)
+        .map_err(adapter::function_error)?;
+    adapter::to_surreal_geometry(&result)
+}
+
+fn cluster_kmeans(geoms: Vec<Geometry>, k: i64) -> Result<Geometry, String> {
+    let gs = from_surreal_geometries(geoms)?;
+    let result = surrealgis_functions::clustering::st_cluster_kmeans(&gs, to_count(k, "k")?)
+        .map_err(adapter::function_error)?;
+    adapter::to_surreal_geometry(&result)
+}
+
+fn cluster_within(geoms: Vec<Geometry>, distance: f64) -> Result<Geometry, String> {
+    let gs = from_surreal_geometries(geoms)?;
+    let result = surrealgis_functions::clustering::st_cluster_within(&gs, distance)
+        .map_err(adapter::function_error)?;
     adapter::to_surreal_geometry(&result)
 }
+
+fn from_surreal_geometries(geoms: Vec<Geometry>) -> Result<Vec<SurrealGeometry>, String> {
+    geoms.into_iter().map(adapter::from_surreal_geometry).collect()
+}
+
+/// Convert a SurrealDB integer argument to a count, rejecting negatives
+/// instead of letting them wrap to huge `usize` values.
+fn to_count(value: i64, name: &str) -> Result<usize, String> {
+    usize::try_from(value)
+        .map_err(|_| adapter::invalid_argument(format!("{name} must be non-negative, got {value}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn six_points() -> Vec<Geometry> {
+        [(0.0, 0.0), (0.1, 0.0), (0.0, 0.1), (10.0, 10.0), (10.1, 10.0), (10.0, 10.1)]
+            .into_iter()
+            .map(|(x, y)| Geometry::from_point(geo_types::Point::new(x, y)))
+            .collect()
+    }
+
+    fn cluster_sizes(result: Geometry) -> Vec<usize> {
+        let domain = adapter::from_surreal_geometry(result).unwrap();
+        match domain.geometry_type() {
+            surrealgis_core::geometry::GeometryType::GeometryCollection(members) => {
+                let mut sizes: Vec<usize> = members.iter().map(|m| m.num_points()).collect();
+                sizes.sort();
+                sizes
+            }
+            _ => panic!("Expected GeometryCollection"),
+        }
+    }
+
+    #[test]
+    fn dbscan_six_points_into_two_groups() {
+        let out = cluster_dbscan(six_points(), 0.5, 2).unwrap();
+        assert_eq!(cluster_sizes(out), vec![3, 3]);
+    }
+
+    #[test]
+    fn kmeans_six_points_into_two_groups() {
+        let out = cluster_kmeans(six_points(), 2).unwrap();
+        assert_eq!(cluster_sizes(out), vec![3, 3]);
+    }
+
+    #[test]
+    fn within_six_points_into_two_groups() {
+        let out = cluster_within(six_points(), 0.5).unwrap();
+        assert_eq!(cluster_sizes(out), vec![3, 3]);
+    }
+
+    #[test]
+    fn dbscan_negative_min_points_is_rejected() {
+        let err = cluster_dbscan(six_points(), 0.5, -2).unwrap_err();
+        let parsed: adapter::ExportError = serde_json::from_str(&err).unwrap();
+        assert_eq!(parsed.error, adapter::ErrorKind::InvalidArgument);
+        assert!(parsed.message.contains("min_points must be non-negative"), "error was {err}");
+    }
+
+    #[test]
+    fn negative_count_is_rejected() {
+        let err: adapter::ExportError = serde_json::from_str(&to_count(-1, "k").unwrap_err()).unwrap();
+        assert_eq!(err.error, adapter::ErrorKind::InvalidArgument);
+        assert!(err.message.contains("k must be non-negative"));
+        assert_eq!(to_count(3, "k").unwrap(), 3);
+    }
+}
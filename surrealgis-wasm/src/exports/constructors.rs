@@ -1,43 +1,109 @@
 use surrealism::surrealism;
 use surrealdb_types::Geometry;
+use surrealgis_core::geometry::SurrealGeometry;
+use surrealgis_core::srid::Srid;
 
 use crate::adapter;
 
+// SurrealDB geometries carry no SRID, so the `_srid` variants only affect how
+// the coordinates are validated and built; the returned value is plain
+// coordinates in the requested CRS.
+
 #[surrealism]
 fn st_point(x: f64, y: f64) -> Result<Geometry, String> {
-    let geom = surrealgis_functions::constructors::st_point(x, y, 4326)
-        .map_err(|e| e.to_string())?;
-    adapter::to_surreal_geometry(&geom)
+    make_point(x, y, Srid::WGS84.code().into())
+}
+
+#[surrealism]
+fn st_point_srid(x: f64, y: f64, srid: i64) -> Result<Geometry, String> {
+    make_point(x, y, srid)
 }
 
 #[surrealism]
 fn st_make_point(x: f64, y: f64) -> Result<Geometry, String> {
     let geom = surrealgis_functions::constructors::st_make_point(x, y, 4326)
-        .map_err(|e| e.to_string())?;
+        .map_err(adapter::function_error)?;
     adapter::to_surreal_geometry(&geom)
 }
 
 #[surrealism]
 fn st_make_line(points: Vec<Geometry>) -> Result<Geometry, String> {
+    make_line(points, Srid::WGS84.code().into())
+}
+
+#[surrealism]
+fn st_make_line_srid(points: Vec<Geometry>, srid: i64) -> Result<Geometry, String> {
+    make_line(points, srid)
+}
+
+#[surrealism]
+fn st_make_polygon(exterior: Geometry, holes: Vec<Geometry>) -> Result<Geometry, String> {
+    make_polygon(exterior, holes, Srid::WGS84.code().into())
+}
+
+#[surrealism]
+fn st_make_polygon_srid(
+    exterior: Geometry,
+    holes: Vec<Geometry>,
+    srid: i64,
+) -> Result<Geometry, String> {
+    make_polygon(exterior, holes, srid)
+}
+
+#[surrealism]
+fn st_make_envelope(xmin: f64, ymin: f64, xmax: f64, ymax: f64) -> Result<Geometry, String> {
+    make_envelope(xmin, ymin, xmax, ymax, Srid::WGS84.code().into())
+}
+
+#[surrealism]
+fn st_make_envelope_srid(
+    xmin: f64,
+    ymin: f64,
+    xmax: f64,
+    ymax: f64,
+    srid: i64,
+) -> Result<Geometry, String> {
+    make_envelope(xmin, ymin, xmax, ymax, srid)
+}
+
+/// Validate a SurrealDB integer as an SRID code.
+fn to_srid(srid: i64) -> Result<i32, String> {
+    let code = i32::try_from(srid)
+        .map_err(|_| adapter::invalid_argument(format!("Invalid SRID: {srid}")))?;
+    Srid::new(code).map_err(adapter::geometry_error)?;
+    Ok(code)
+}
+
+fn build_point(x: f64, y: f64, srid: i64) -> Result<SurrealGeometry, String> {
+    surrealgis_functions::constructors::st_point(x, y, to_srid(srid)?)
+        .map_err(adapter::function_error)
+}
+
+fn make_point(x: f64, y: f64, srid: i64) -> Result<Geometry, String> {
+    adapter::to_surreal_geometry(&build_point(x, y, srid)?)
+}
+
+fn make_line(points: Vec<Geometry>, srid: i64) -> Result<Geometry, String> {
+    let srid = to_srid(srid)?;
     let coords: Result<Vec<(f64, f64)>, String> = points
         .into_iter()
         .map(|g| {
             let pt = g
                 .into_point()
-                .map_err(|e| format!("st_make_line expects Point geometries: {e}"))?;
+                .map_err(|e| adapter::invalid_argument(format!("st_make_line expects Point geometries: {e}")))?;
             Ok((pt.x(), pt.y()))
         })
         .collect();
-    let geom = surrealgis_functions::constructors::st_make_line(&coords?, 4326)
-        .map_err(|e| e.to_string())?;
+    let geom = surrealgis_functions::constructors::st_make_line(&coords?, srid)
+        .map_err(adapter::function_error)?;
     adapter::to_surreal_geometry(&geom)
 }
 
-#[surrealism]
-fn st_make_polygon(exterior: Geometry, holes: Vec<Geometry>) -> Result<Geometry, String> {
+fn make_polygon(exterior: Geometry, holes: Vec<Geometry>, srid: i64) -> Result<Geometry, String> {
+    let srid = to_srid(srid)?;
     let ext_line = exterior
         .into_line()
-        .map_err(|e| format!("st_make_polygon exterior must be a LineString: {e}"))?;
+        .map_err(|e| adapter::invalid_argument(format!("st_make_polygon exterior must be a LineString: {e}")))?;
     let ext_coords: Vec<(f64, f64)> = ext_line.into_points().into_iter().map(|p| (p.x(), p.y())).collect();
 
     let hole_rings: Result<Vec<Vec<(f64, f64)>>, String> = holes
@@ -45,21 +111,54 @@ fn st_make_polygon(exterior: Geometry, holes: Vec<Geometry>) -> Result<Geometry,
         .map(|g| {
             let line = g
                 .into_line()
-                .map_err(|e| format!("st_make_polygon holes must be LineStrings: {e}"))?;
+                .map_err(|e| adapter::invalid_argument(format!("st_make_polygon holes must be LineStrings: {e}")))?;
             Ok(line.into_points().into_iter().map(|p| (p.x(), p.y())).collect())
         })
         .collect();
 
     let geom =
-        surrealgis_functions::constructors::st_make_polygon(&ext_coords, &hole_rings?, 4326)
-            .map_err(|e| e.to_string())?;
+        surrealgis_functions::constructors::st_make_polygon(&ext_coords, &hole_rings?, srid)
+            .map_err(adapter::function_error)?;
     adapter::to_surreal_geometry(&geom)
 }
 
-#[surrealism]
-fn st_make_envelope(xmin: f64, ymin: f64, xmax: f64, ymax: f64) -> Result<Geometry, String> {
+fn make_envelope(xmin: f64, ymin: f64, xmax: f64, ymax: f64, srid: i64) -> Result<Geometry, String> {
     let geom =
-        surrealgis_functions::constructors::st_make_envelope(xmin, ymin, xmax, ymax, 4326)
-            .map_err(|e| e.to_string())?;
+        surrealgis_functions::constructors::st_make_envelope(xmin, ymin, xmax, ymax, to_srid(srid)?)
+            .map_err(adapter::function_error)?;
     adapter::to_surreal_geometry(&geom)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_built_in_web_mercator_reports_3857() {
+        let geom = build_point(-8_235_851.0, 4_975_293.0, 3857).unwrap();
+        let srid = surrealgis_functions::accessors::st_srid(&geom);
+        assert_eq!(srid, 3857);
+    }
+
+    #[test]
+    fn point_srid_keeps_coordinates() {
+        let out = make_point(-8_235_851.0, 4_975_293.0, 3857).unwrap();
+        let pt = out.into_point().unwrap();
+        assert_eq!(pt.x(), -8_235_851.0);
+        assert_eq!(pt.y(), 4_975_293.0);
+    }
+
+    #[test]
+    fn invalid_srid_rejected() {
+        assert!(make_point(0.0, 0.0, 0).is_err());
+        assert!(make_point(0.0, 0.0, -4326).is_err());
+        assert!(make_point(0.0, 0.0, i64::from(i32::MAX) + 1).is_err());
+        assert!(make_envelope(0.0, 0.0, 1.0, 1.0, 0).is_err());
+    }
+
+    #[test]
+    fn envelope_with_srid() {
+        let out = make_envelope(0.0, 0.0, 10.0, 10.0, 3857).unwrap();
+        assert!(out.is_polygon());
+    }
+}
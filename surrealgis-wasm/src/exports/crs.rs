@@ -5,16 +5,42 @@ use crate::adapter;
 
 #[surrealism]
 fn st_transform(geom: Geometry, to_srid: i32) -> Result<Geometry, String> {
-    let g = adapter::from_surreal_geometry(geom)?;
-    let result = surrealgis_functions::crs::st_transform(&g, to_srid)
-        .map_err(|e| e.to_string())?;
-    adapter::to_surreal_geometry(&result)
+    transform(geom, to_srid)
 }
 
 #[surrealism]
 fn st_set_srid(geom: Geometry, new_srid: i32) -> Result<Geometry, String> {
     let g = adapter::from_surreal_geometry(geom)?;
     let result = surrealgis_functions::crs::st_set_srid(&g, new_srid)
-        .map_err(|e| e.to_string())?;
+        .map_err(adapter::function_error)?;
+    adapter::to_surreal_geometry(&result)
+}
+
+fn transform(geom: Geometry, to_srid: i32) -> Result<Geometry, String> {
+    let g = adapter::from_surreal_geometry(geom)?;
+    let result = surrealgis_functions::crs::st_transform(&g, to_srid)
+        .map_err(adapter::function_error)?;
     adapter::to_surreal_geometry(&result)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transform_4326_point_to_web_mercator() {
+        let nyc = Geometry::from_point(geo_types::Point::new(-73.9857, 40.7484));
+        let pt = transform(nyc, 3857).unwrap().into_point().unwrap();
+        approx::assert_abs_diff_eq!(pt.x(), -8_235_851.0, epsilon = 500.0);
+        approx::assert_abs_diff_eq!(pt.y(), 4_975_293.0, epsilon = 500.0);
+    }
+
+    #[test]
+    fn transform_errors_surface_as_structured_crs_errors() {
+        let pt = Geometry::from_point(geo_types::Point::new(1.0, 2.0));
+        let err = transform(pt, 99_999).unwrap_err();
+        let parsed: adapter::ExportError = serde_json::from_str(&err).unwrap();
+        assert_eq!(parsed.error, adapter::ErrorKind::CrsError);
+        assert!(parsed.message.contains("99999"), "error was {err}");
+    }
+}
@@ -7,7 +7,20 @@ use crate::adapter;
 fn st_line_interpolate_point(geom: Geometry, fraction: f64) -> Result<Geometry, String> {
     let g = adapter::from_surreal_geometry(geom)?;
     let result = surrealgis_functions::linear_ref::st_line_interpolate_point(&g, fraction)
-        .map_err(|e| e.to_string())?;
+        .map_err(adapter::function_error)?;
+    adapter::to_surreal_geometry(&result)
+}
+
+#[surrealism]
+fn st_line_interpolate_points(
+    geom: Geometry,
+    fraction: f64,
+    repeat: bool,
+) -> Result<Geometry, String> {
+    let g = adapter::from_surreal_geometry(geom)?;
+    let result =
+        surrealgis_functions::linear_ref::st_line_interpolate_points(&g, fraction, repeat)
+            .map_err(adapter::function_error)?;
     adapter::to_surreal_geometry(&result)
 }
 
@@ -15,7 +28,7 @@ fn st_line_interpolate_point(geom: Geometry, fraction: f64) -> Result<Geometry,
 fn st_line_locate_point(line: Geometry, point: Geometry) -> Result<f64, String> {
     let gl = adapter::from_surreal_geometry(line)?;
     let gp = adapter::from_surreal_geometry(point)?;
-    surrealgis_functions::linear_ref::st_line_locate_point(&gl, &gp).map_err(|e| e.to_string())
+    surrealgis_functions::linear_ref::st_line_locate_point(&gl, &gp).map_err(adapter::function_error)
 }
 
 #[surrealism]
@@ -27,6 +40,6 @@ fn st_line_substring(
     let g = adapter::from_surreal_geometry(geom)?;
     let result =
         surrealgis_functions::linear_ref::st_line_substring(&g, start_fraction, end_fraction)
-            .map_err(|e| e.to_string())?;
+            .map_err(adapter::function_error)?;
     adapter::to_surreal_geometry(&result)
 }
@@ -7,44 +7,95 @@ use crate::adapter;
 fn st_distance(a: Geometry, b: Geometry) -> Result<f64, String> {
     let ga = adapter::from_surreal_geometry(a)?;
     let gb = adapter::from_surreal_geometry(b)?;
-    surrealgis_functions::measurement::st_distance(&ga, &gb).map_err(|e| e.to_string())
+    surrealgis_functions::measurement::st_distance(&ga, &gb).map_err(adapter::function_error)
+}
+
+/// `st_distance` as a JSON object `{"value": ..., "unit": ...}`.
+#[surrealism]
+fn st_distance_ex(a: Geometry, b: Geometry) -> Result<String, String> {
+    let ga = adapter::from_surreal_geometry(a)?;
+    let gb = adapter::from_surreal_geometry(b)?;
+    let (value, unit) = surrealgis_functions::measurement::st_distance_with_unit(&ga, &gb)
+        .map_err(adapter::function_error)?;
+    Ok(serde_json::json!({ "value": value, "unit": unit }).to_string())
 }
 
 #[surrealism]
 fn st_distance_sphere(a: Geometry, b: Geometry) -> Result<f64, String> {
     let ga = adapter::from_surreal_geometry(a)?;
     let gb = adapter::from_surreal_geometry(b)?;
-    surrealgis_functions::measurement::st_distance_sphere(&ga, &gb).map_err(|e| e.to_string())
+    surrealgis_functions::measurement::st_distance_sphere(&ga, &gb).map_err(adapter::function_error)
 }
 
 #[surrealism]
 fn st_area(geom: Geometry) -> Result<f64, String> {
     let g = adapter::from_surreal_geometry(geom)?;
-    surrealgis_functions::measurement::st_area(&g).map_err(|e| e.to_string())
+    surrealgis_functions::measurement::st_area(&g).map_err(adapter::function_error)
 }
 
 #[surrealism]
 fn st_length(geom: Geometry) -> Result<f64, String> {
     let g = adapter::from_surreal_geometry(geom)?;
-    surrealgis_functions::measurement::st_length(&g).map_err(|e| e.to_string())
+    surrealgis_functions::measurement::st_length(&g).map_err(adapter::function_error)
 }
 
 #[surrealism]
 fn st_perimeter(geom: Geometry) -> Result<f64, String> {
     let g = adapter::from_surreal_geometry(geom)?;
-    surrealgis_functions::measurement::st_perimeter(&g).map_err(|e| e.to_string())
+    surrealgis_functions::measurement::st_perimeter(&g).map_err(adapter::function_error)
 }
 
 #[surrealism]
 fn st_azimuth(a: Geometry, b: Geometry) -> Result<f64, String> {
     let ga = adapter::from_surreal_geometry(a)?;
     let gb = adapter::from_surreal_geometry(b)?;
-    surrealgis_functions::measurement::st_azimuth(&ga, &gb).map_err(|e| e.to_string())
+    surrealgis_functions::measurement::st_azimuth(&ga, &gb).map_err(adapter::function_error)
 }
 
 #[surrealism]
 fn st_dwithin(a: Geometry, b: Geometry, distance: f64) -> Result<bool, String> {
     let ga = adapter::from_surreal_geometry(a)?;
     let gb = adapter::from_surreal_geometry(b)?;
-    surrealgis_functions::measurement::st_dwithin(&ga, &gb, distance).map_err(|e| e.to_string())
+    surrealgis_functions::measurement::st_dwithin(&ga, &gb, distance).map_err(adapter::function_error)
+}
+
+#[surrealism]
+fn st_closest_point(a: Geometry, b: Geometry) -> Result<Geometry, String> {
+    let ga = adapter::from_surreal_geometry(a)?;
+    let gb = adapter::from_surreal_geometry(b)?;
+    let result = surrealgis_functions::measurement::st_closest_point(&ga, &gb)
+        .map_err(adapter::function_error)?;
+    adapter::to_surreal_geometry(&result)
+}
+
+#[surrealism]
+fn st_shortest_line(a: Geometry, b: Geometry) -> Result<Geometry, String> {
+    let ga = adapter::from_surreal_geometry(a)?;
+    let gb = adapter::from_surreal_geometry(b)?;
+    let result = surrealgis_functions::measurement::st_shortest_line(&ga, &gb)
+        .map_err(adapter::function_error)?;
+    adapter::to_surreal_geometry(&result)
+}
+
+#[surrealism]
+fn st_hausdorff_distance(a: Geometry, b: Geometry) -> Result<f64, String> {
+    let ga = adapter::from_surreal_geometry(a)?;
+    let gb = adapter::from_surreal_geometry(b)?;
+    surrealgis_functions::measurement::st_hausdorff_distance(&ga, &gb)
+        .map_err(adapter::function_error)
+}
+
+#[surrealism]
+fn st_frechet_distance(a: Geometry, b: Geometry) -> Result<f64, String> {
+    let ga = adapter::from_surreal_geometry(a)?;
+    let gb = adapter::from_surreal_geometry(b)?;
+    surrealgis_functions::measurement::st_frechet_distance(&ga, &gb)
+        .map_err(adapter::function_error)
+}
+
+#[surrealism]
+fn st_max_distance(a: Geometry, b: Geometry) -> Result<f64, String> {
+    let ga = adapter::from_surreal_geometry(a)?;
+    let gb = adapter::from_surreal_geometry(b)?;
+    surrealgis_functions::measurement::st_max_distance(&ga, &gb).map_err(adapter::function_error)
 }
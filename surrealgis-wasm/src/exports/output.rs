@@ -6,23 +6,41 @@ use crate::adapter;
 #[surrealism]
 fn st_as_text(geom: Geometry) -> Result<String, String> {
     let g = adapter::from_surreal_geometry(geom)?;
-    surrealgis_functions::output::st_as_text(&g).map_err(|e| e.to_string())
+    surrealgis_functions::output::st_as_text(&g).map_err(adapter::function_error)
 }
 
 #[surrealism]
 fn st_as_wkb(geom: Geometry) -> Result<String, String> {
     let g = adapter::from_surreal_geometry(geom)?;
-    surrealgis_functions::output::st_as_wkb(&g).map_err(|e| e.to_string())
+    surrealgis_functions::output::st_as_wkb(&g).map_err(adapter::function_error)
 }
 
 #[surrealism]
 fn st_as_geojson(geom: Geometry) -> Result<String, String> {
     let g = adapter::from_surreal_geometry(geom)?;
-    surrealgis_functions::output::st_as_geojson(&g).map_err(|e| e.to_string())
+    surrealgis_functions::output::st_as_geojson(&g).map_err(adapter::function_error)
+}
+
+/// `properties` is a JSON object given as text; `id` is used as a string id.
+#[surrealism]
+fn st_as_geojson_feature(
+    geom: Geometry,
+    properties: String,
+    id: Option<String>,
+) -> Result<String, String> {
+    let g = adapter::from_surreal_geometry(geom)?;
+    let properties: serde_json::Value = serde_json::from_str(&properties)
+        .map_err(|e| adapter::invalid_argument(format!("properties must be JSON: {e}")))?;
+    surrealgis_functions::output::st_as_geojson_feature(
+        &g,
+        properties,
+        id.map(serde_json::Value::String),
+    )
+    .map_err(adapter::function_error)
 }
 
 #[surrealism]
 fn st_as_ewkt(geom: Geometry) -> Result<String, String> {
     let g = adapter::from_surreal_geometry(geom)?;
-    surrealgis_functions::output::st_as_ewkt(&g).map_err(|e| e.to_string())
+    surrealgis_functions::output::st_as_ewkt(&g).map_err(adapter::function_error)
 }
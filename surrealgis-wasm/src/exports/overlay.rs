@@ -5,11 +5,7 @@ use crate::adapter;
 
 #[surrealism]
 fn st_intersection(a: Geometry, b: Geometry) -> Result<Geometry, String> {
-    let ga = adapter::from_surreal_geometry(a)?;
-    let gb = adapter::from_surreal_geometry(b)?;
-    let result =
-        surrealgis_functions::overlay::st_intersection(&ga, &gb).map_err(|e| e.to_string())?;
-    adapter::to_surreal_geometry(&result)
+    intersection(a, b)
 }
 
 #[surrealism]
@@ -17,7 +13,7 @@ fn st_union(a: Geometry, b: Geometry) -> Result<Geometry, String> {
     let ga = adapter::from_surreal_geometry(a)?;
     let gb = adapter::from_surreal_geometry(b)?;
     let result =
-        surrealgis_functions::overlay::st_union(&ga, &gb).map_err(|e| e.to_string())?;
+        surrealgis_functions::overlay::st_union(&ga, &gb).map_err(adapter::function_error)?;
     adapter::to_surreal_geometry(&result)
 }
 
@@ -26,7 +22,7 @@ fn st_difference(a: Geometry, b: Geometry) -> Result<Geometry, String> {
     let ga = adapter::from_surreal_geometry(a)?;
     let gb = adapter::from_surreal_geometry(b)?;
     let result =
-        surrealgis_functions::overlay::st_difference(&ga, &gb).map_err(|e| e.to_string())?;
+        surrealgis_functions::overlay::st_difference(&ga, &gb).map_err(adapter::function_error)?;
     adapter::to_surreal_geometry(&result)
 }
 
@@ -35,6 +31,46 @@ fn st_sym_difference(a: Geometry, b: Geometry) -> Result<Geometry, String> {
     let ga = adapter::from_surreal_geometry(a)?;
     let gb = adapter::from_surreal_geometry(b)?;
     let result =
-        surrealgis_functions::overlay::st_sym_difference(&ga, &gb).map_err(|e| e.to_string())?;
+        surrealgis_functions::overlay::st_sym_difference(&ga, &gb).map_err(adapter::function_error)?;
+    adapter::to_surreal_geometry(&result)
+}
+
+fn intersection(a: Geometry, b: Geometry) -> Result<Geometry, String> {
+    let ga = adapter::from_surreal_geometry(a)?;
+    let gb = adapter::from_surreal_geometry(b)?;
+    let result =
+        surrealgis_functions::overlay::st_intersection(&ga, &gb).map_err(adapter::function_error)?;
     adapter::to_surreal_geometry(&result)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geo_types::{LineString, Polygon};
+
+    fn square(min: f64, max: f64) -> Geometry {
+        let ring = LineString::from(vec![(min, min), (max, min), (max, max), (min, max), (min, min)]);
+        Geometry::from_polygon(Polygon::new(ring, vec![]))
+    }
+
+    #[test]
+    fn intersection_of_overlapping_squares() {
+        let out = intersection(square(0.0, 10.0), square(5.0, 15.0)).unwrap();
+
+        let domain = adapter::from_surreal_geometry(out).unwrap();
+        let bbox = domain.bbox().unwrap();
+        approx::assert_abs_diff_eq!(bbox.min_x, 5.0, epsilon = 1e-9);
+        approx::assert_abs_diff_eq!(bbox.min_y, 5.0, epsilon = 1e-9);
+        approx::assert_abs_diff_eq!(bbox.max_x, 10.0, epsilon = 1e-9);
+        approx::assert_abs_diff_eq!(bbox.max_y, 10.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn non_polygon_operand_reports_clear_error() {
+        let pt = Geometry::from_point(geo_types::Point::new(1.0, 1.0));
+        let err = intersection(pt, square(0.0, 10.0)).unwrap_err();
+        let parsed: adapter::ExportError = serde_json::from_str(&err).unwrap();
+        assert_eq!(parsed.error, adapter::ErrorKind::TypeMismatch);
+        assert!(parsed.message.contains("Polygon or MultiPolygon"), "error was {err}");
+    }
+}
@@ -5,25 +5,19 @@ use crate::adapter;
 
 #[surrealism]
 fn st_buffer(geom: Geometry, distance: f64) -> Result<Geometry, String> {
-    let g = adapter::from_surreal_geometry(geom)?;
-    let result = surrealgis_functions::processing::st_buffer(&g, distance)
-        .map_err(|e| e.to_string())?;
-    adapter::to_surreal_geometry(&result)
+    buffer(geom, distance)
 }
 
 #[surrealism]
 fn st_convex_hull(geom: Geometry) -> Result<Geometry, String> {
-    let g = adapter::from_surreal_geometry(geom)?;
-    let result = surrealgis_functions::processing::st_convex_hull(&g)
-        .map_err(|e| e.to_string())?;
-    adapter::to_surreal_geometry(&result)
+    convex_hull(geom)
 }
 
 #[surrealism]
 fn st_concave_hull(geom: Geometry, concavity: f64) -> Result<Geometry, String> {
     let g = adapter::from_surreal_geometry(geom)?;
     let result = surrealgis_functions::processing::st_concave_hull(&g, concavity)
-        .map_err(|e| e.to_string())?;
+        .map_err(adapter::function_error)?;
     adapter::to_surreal_geometry(&result)
 }
 
@@ -31,7 +25,7 @@ fn st_concave_hull(geom: Geometry, concavity: f64) -> Result<Geometry, String> {
 fn st_simplify(geom: Geometry, tolerance: f64) -> Result<Geometry, String> {
     let g = adapter::from_surreal_geometry(geom)?;
     let result = surrealgis_functions::processing::st_simplify(&g, tolerance)
-        .map_err(|e| e.to_string())?;
+        .map_err(adapter::function_error)?;
     adapter::to_surreal_geometry(&result)
 }
 
@@ -40,7 +34,7 @@ fn st_simplify_preserve_topology(geom: Geometry, tolerance: f64) -> Result<Geome
     let g = adapter::from_surreal_geometry(geom)?;
     let result =
         surrealgis_functions::processing::st_simplify_preserve_topology(&g, tolerance)
-            .map_err(|e| e.to_string())?;
+            .map_err(adapter::function_error)?;
     adapter::to_surreal_geometry(&result)
 }
 
@@ -48,7 +42,7 @@ fn st_simplify_preserve_topology(geom: Geometry, tolerance: f64) -> Result<Geome
 fn st_delaunay_triangles(geom: Geometry) -> Result<Geometry, String> {
     let g = adapter::from_surreal_geometry(geom)?;
     let result = surrealgis_functions::processing::st_delaunay_triangles(&g)
-        .map_err(|e| e.to_string())?;
+        .map_err(adapter::function_error)?;
     adapter::to_surreal_geometry(&result)
 }
 
@@ -56,6 +50,52 @@ fn st_delaunay_triangles(geom: Geometry) -> Result<Geometry, String> {
 fn st_voronoi_polygons(geom: Geometry) -> Result<Geometry, String> {
     let g = adapter::from_surreal_geometry(geom)?;
     let result = surrealgis_functions::processing::st_voronoi_polygons(&g)
-        .map_err(|e| e.to_string())?;
+        .map_err(adapter::function_error)?;
+    adapter::to_surreal_geometry(&result)
+}
+
+fn buffer(geom: Geometry, distance: f64) -> Result<Geometry, String> {
+    let g = adapter::from_surreal_geometry(geom)?;
+    let result = surrealgis_functions::processing::st_buffer(&g, distance)
+        .map_err(adapter::function_error)?;
+    adapter::to_surreal_geometry(&result)
+}
+
+fn convex_hull(geom: Geometry) -> Result<Geometry, String> {
+    let g = adapter::from_surreal_geometry(geom)?;
+    let result = surrealgis_functions::processing::st_convex_hull(&g)
+        .map_err(adapter::function_error)?;
     adapter::to_surreal_geometry(&result)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buffer_point_through_boundary() {
+        let pt = Geometry::from_point(geo_types::Point::new(0.0, 0.0));
+        let Geometry::Polygon(poly) = buffer(pt, 1.0).unwrap() else {
+            panic!("Expected Polygon");
+        };
+        for c in poly.exterior().coords() {
+            approx::assert_abs_diff_eq!((c.x * c.x + c.y * c.y).sqrt(), 1.0, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn convex_hull_multipoint_through_boundary() {
+        let mp = geo_types::MultiPoint::new(vec![
+            geo_types::Point::new(0.0, 0.0),
+            geo_types::Point::new(4.0, 0.0),
+            geo_types::Point::new(4.0, 4.0),
+            geo_types::Point::new(0.0, 4.0),
+            geo_types::Point::new(2.0, 2.0),
+        ]);
+        let Geometry::Polygon(poly) = convex_hull(Geometry::from_multipoint(mp)).unwrap() else {
+            panic!("Expected Polygon");
+        };
+        // Interior point (2, 2) is dropped: 4 corners + closing vertex
+        assert_eq!(poly.exterior().0.len(), 5);
+    }
+}